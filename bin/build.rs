@@ -12,4 +12,7 @@ cfg_select! {
                 .compile()
         }
     }
+    _ => {
+        fn main() {}
+    }
 }
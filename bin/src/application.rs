@@ -1,29 +1,48 @@
 use convertible_couch_lib::{
+    application_error::{ApplicationError, DeviceKind},
     application_result::ApplicationResult,
+    backends::{
+        BackendsInfos, displays_backend::DisplaysBackend, speakers_backend::SpeakersBackend,
+    },
+    configuration::{Configuration, cycle::Cycle, profile::Profile},
     displays_settings::{
-        CurrentDisplaysSettings, CurrentDisplaysSettingsApiTrait, DisplayInfo, DisplaysSettings,
-        DisplaysSettingsResult,
+        DisplayInfo, DisplayModeRequest, DisplayRotation, DisplayTransformRequest, DisplaysPlan,
+        DisplaysSettings, DisplaysSettingsResult, DisplaysSnapshot, DisplaysTopology,
+        DisplaysTopologyResult,
     },
     log::{LogLevel, configure_logger},
+    snapshot::{Snapshot, SnapshotStore},
     speakers_settings::{
-        CurrentSpeakersSettings, CurrentSpeakersSettingsApiTrait, SpeakerInfo, SpeakersSettings,
-        SpeakersSettingsResult,
+        SpeakerInfo, SpeakersPlan, SpeakersSettings, SpeakersSettingsResult, SpeakersSnapshot,
     },
     trace_fn,
 };
+use serde::Serialize;
 
 use crate::commands::{
-    Arguments, Commands, change::ChangeCommands, info::Device,
-    shared::log_level_option::LogLevelOption,
+    Arguments, Commands,
+    change::{
+        ChangeCommands, DisplaysOptions, HdrOption, RotationOption, SpeakersOptions, TopologyOption,
+    },
+    info::{Device, InfoCommands},
+    shared::{
+        displays_backend_option::DisplaysBackendOption, log_level_option::LogLevelOption,
+        speakers_backend_option::SpeakersBackendOption,
+    },
+    snapshot::SnapshotCommands,
 };
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Serialize)]
+#[serde(tag = "command", content = "result", rename_all = "snake_case")]
 pub enum CommandResult {
     Change(ApplicationChangeResult),
     Info(ApplicationInfoResult),
+    Snapshot(ApplicationSnapshotResult),
+    Plan(ApplicationPlanResult),
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
 pub enum ApplicationChangeResult {
     DisplaysAndSpeakers {
         displays_result: DisplaysSettingsResult,
@@ -35,9 +54,13 @@ pub enum ApplicationChangeResult {
     SpeakersOnly {
         speakers_result: SpeakersSettingsResult,
     },
+    Topology {
+        topology_result: DisplaysTopologyResult,
+    },
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
 pub enum ApplicationInfoResult {
     DisplaysAndSpeakers {
         displays_result: Vec<DisplayInfo>,
@@ -49,66 +72,99 @@ pub enum ApplicationInfoResult {
     SpeakersOnly {
         speakers_result: Vec<SpeakerInfo>,
     },
+    Backends {
+        backends_result: BackendsInfos,
+    },
+}
+
+/// What a change run with `--dry-run` would do.
+#[derive(Debug, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ApplicationPlanResult {
+    DisplaysAndSpeakers {
+        displays_plan: DisplaysPlan,
+        speakers_plan: SpeakersPlan,
+    },
+    DisplaysOnly {
+        displays_plan: DisplaysPlan,
+    },
+    SpeakersOnly {
+        speakers_plan: SpeakersPlan,
+    },
+    Topology {
+        topology_plan: DisplaysTopologyResult,
+    },
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ApplicationSnapshotResult {
+    Saved { snapshot_name: String },
+    Restored { snapshot_name: String },
+}
+
+/// The devices captured before a change touches them, so that a change failing half-way can be
+/// rolled back.
+struct ChangeSnapshot {
+    displays: Option<DisplaysSnapshot>,
+    speakers: Option<SpeakersSnapshot>,
 }
 
 pub struct Application {
-    displays_settings: CurrentDisplaysSettings,
-    speakers_settings: CurrentSpeakersSettings,
+    displays_settings: Box<dyn DisplaysSettings>,
+    speakers_settings: Box<dyn SpeakersSettings>,
+    backends_infos: BackendsInfos,
+    configuration: Configuration,
+    snapshot_store: SnapshotStore,
 }
 
 impl Application {
     pub fn bootstrap(
-        displays_settings_api: Box<dyn CurrentDisplaysSettingsApiTrait>,
-        speakers_settings_api: Box<dyn CurrentSpeakersSettingsApiTrait>,
+        displays_settings: Box<dyn DisplaysSettings>,
+        speakers_settings: Box<dyn SpeakersSettings>,
+        backends_infos: BackendsInfos,
+        configuration: Configuration,
+        snapshot_store: SnapshotStore,
     ) -> Self {
         trace_fn!();
 
         Self {
-            displays_settings: CurrentDisplaysSettings::new(displays_settings_api),
-            speakers_settings: CurrentSpeakersSettings::new(speakers_settings_api),
+            displays_settings,
+            speakers_settings,
+            backends_infos,
+            configuration,
+            snapshot_store,
         }
     }
 
     pub fn execute(&mut self, args: &Arguments) -> ApplicationResult<CommandResult> {
-        let log_level = match &args.command {
-            Commands::Change(change_commands) => match change_commands {
-                ChangeCommands::DisplaysAndSpeakers {
-                    displays: _,
-                    speakers: _,
-                    shared,
-                } => map_to_log_level(&shared.log_level),
-                ChangeCommands::Displays {
-                    displays: _,
-                    shared,
-                } => map_to_log_level(&shared.log_level),
-                ChangeCommands::Speakers {
-                    speakers: _,
-                    shared,
-                } => map_to_log_level(&shared.log_level),
-            },
-            Commands::Info { device: _, shared } => map_to_log_level(&shared.log_level),
-        };
+        let log_level = map_to_log_level(&args.command.shared().log_level);
 
         configure_logger(&log_level)?;
 
         trace_fn!();
 
         match &args.command {
+            Commands::Change(change_commands) if change_commands.dry_run() => {
+                self.plan_change(change_commands)
+            }
             Commands::Change(change_commands) => match change_commands {
                 ChangeCommands::DisplaysAndSpeakers {
                     displays,
                     speakers,
+                    dry_run: _,
                     shared: _,
                 } => {
-                    let displays_result = self.displays_settings.change_primary_display(
-                        &displays.desktop_display_name,
-                        &displays.couch_display_name,
-                    )?;
+                    let change_snapshot =
+                        self.save_change_snapshot(&[DeviceKind::Display, DeviceKind::Speaker])?;
 
-                    let speakers_result = self.speakers_settings.change_default_speaker(
-                        &speakers.desktop_speaker_name,
-                        &speakers.couch_speaker_name,
-                    )?;
+                    let displays_result = self.change_displays(displays).map_err(|error| {
+                        self.roll_back(&change_snapshot, DeviceKind::Display, error)
+                    })?;
+
+                    let speakers_result = self.change_speakers(speakers).map_err(|error| {
+                        self.roll_back(&change_snapshot, DeviceKind::Speaker, error)
+                    })?;
 
                     Ok(CommandResult::Change(
                         ApplicationChangeResult::DisplaysAndSpeakers {
@@ -119,12 +175,14 @@ impl Application {
                 }
                 ChangeCommands::Displays {
                     displays,
+                    dry_run: _,
                     shared: _,
                 } => {
-                    let displays_result = self.displays_settings.change_primary_display(
-                        &displays.desktop_display_name,
-                        &displays.couch_display_name,
-                    )?;
+                    let change_snapshot = self.save_change_snapshot(&[DeviceKind::Display])?;
+
+                    let displays_result = self.change_displays(displays).map_err(|error| {
+                        self.roll_back(&change_snapshot, DeviceKind::Display, error)
+                    })?;
 
                     Ok(CommandResult::Change(
                         ApplicationChangeResult::DisplaysOnly { displays_result },
@@ -132,19 +190,167 @@ impl Application {
                 }
                 ChangeCommands::Speakers {
                     speakers,
+                    dry_run: _,
                     shared: _,
                 } => {
-                    let speakers_result = self.speakers_settings.change_default_speaker(
-                        &speakers.desktop_speaker_name,
-                        &speakers.couch_speaker_name,
-                    )?;
+                    let change_snapshot = self.save_change_snapshot(&[DeviceKind::Speaker])?;
+
+                    let speakers_result = self.change_speakers(speakers).map_err(|error| {
+                        self.roll_back(&change_snapshot, DeviceKind::Speaker, error)
+                    })?;
 
                     Ok(CommandResult::Change(
                         ApplicationChangeResult::SpeakersOnly { speakers_result },
                     ))
                 }
+                ChangeCommands::Profile {
+                    profile_name,
+                    dry_run: _,
+                    shared: _,
+                } => {
+                    let profile = self.configuration.get_profile(profile_name)?.clone();
+                    let displays_infos = self.displays_settings.get_displays_infos()?;
+                    let speakers_infos = self.speakers_settings.get_speakers_infos()?;
+
+                    let profile = profile.resolve(&displays_infos, &speakers_infos)?;
+                    let mode_request = profile.mode_request();
+                    let transform_request = profile.transform_request();
+                    let (displays_plan, speakers_plan) = self.plan_profile(&profile)?;
+
+                    let change_snapshot =
+                        self.save_change_snapshot(&[DeviceKind::Display, DeviceKind::Speaker])?;
+
+                    let displays_result = self
+                        .displays_settings
+                        .set_primary_display(&profile.display)
+                        .and_then(|displays_result| {
+                            self.change_display_mode(displays_result, &mode_request)
+                        })
+                        .and_then(|displays_result| {
+                            self.change_display_transform(displays_result, &transform_request)
+                        })
+                        .and_then(|displays_result| {
+                            self.change_display_hdr(displays_result, profile.hdr)
+                        })
+                        .and_then(|displays_result| {
+                            self.verify_displays(&displays_plan, displays_result)
+                        })
+                        .map_err(|error| {
+                            self.roll_back(&change_snapshot, DeviceKind::Display, error)
+                        })?;
+                    let speakers_result = self
+                        .speakers_settings
+                        .set_default_speaker(&profile.speaker)
+                        .and_then(|speakers_result| {
+                            self.verify_speakers(&speakers_plan, speakers_result)
+                        })
+                        .map_err(|error| {
+                            self.roll_back(&change_snapshot, DeviceKind::Speaker, error)
+                        })?;
+
+                    Ok(CommandResult::Change(
+                        ApplicationChangeResult::DisplaysAndSpeakers {
+                            displays_result,
+                            speakers_result,
+                        },
+                    ))
+                }
+                ChangeCommands::Cycle {
+                    displays,
+                    speakers,
+                    reverse,
+                    dry_run: _,
+                    shared: _,
+                } => {
+                    let cycle = self.get_cycle(displays, speakers)?;
+
+                    let change_result = match (cycle.displays.is_empty(), cycle.speakers.is_empty())
+                    {
+                        (false, true) => {
+                            let change_snapshot =
+                                self.save_change_snapshot(&[DeviceKind::Display])?;
+
+                            let displays_result = self
+                                .cycle_displays(&cycle.displays, *reverse)
+                                .map_err(|error| {
+                                    self.roll_back(&change_snapshot, DeviceKind::Display, error)
+                                })?;
+
+                            ApplicationChangeResult::DisplaysOnly { displays_result }
+                        }
+                        (true, _) => {
+                            let change_snapshot =
+                                self.save_change_snapshot(&[DeviceKind::Speaker])?;
+
+                            let speakers_result = self
+                                .cycle_speakers(&cycle.speakers, *reverse)
+                                .map_err(|error| {
+                                    self.roll_back(&change_snapshot, DeviceKind::Speaker, error)
+                                })?;
+
+                            ApplicationChangeResult::SpeakersOnly { speakers_result }
+                        }
+                        (false, false) => {
+                            let change_snapshot = self.save_change_snapshot(&[
+                                DeviceKind::Display,
+                                DeviceKind::Speaker,
+                            ])?;
+
+                            let displays_result = self
+                                .cycle_displays(&cycle.displays, *reverse)
+                                .map_err(|error| {
+                                    self.roll_back(&change_snapshot, DeviceKind::Display, error)
+                                })?;
+
+                            let speakers_result = self
+                                .cycle_speakers(&cycle.speakers, *reverse)
+                                .map_err(|error| {
+                                    self.roll_back(&change_snapshot, DeviceKind::Speaker, error)
+                                })?;
+
+                            ApplicationChangeResult::DisplaysAndSpeakers {
+                                displays_result,
+                                speakers_result,
+                            }
+                        }
+                    };
+
+                    Ok(CommandResult::Change(change_result))
+                }
+                ChangeCommands::Topology {
+                    topology,
+                    display,
+                    dry_run: _,
+                    shared: _,
+                } => {
+                    let topology = map_to_displays_topology(topology, display.as_deref())?;
+                    let change_snapshot = self.save_change_snapshot(&[DeviceKind::Display])?;
+
+                    let topology_result = self
+                        .displays_settings
+                        .set_topology(&topology)
+                        .and_then(|topology_result| self.verify_topology(topology_result))
+                        .map_err(|error| {
+                            self.roll_back(&change_snapshot, DeviceKind::Display, error)
+                        })?;
+
+                    Ok(CommandResult::Change(ApplicationChangeResult::Topology {
+                        topology_result,
+                    }))
+                }
             },
-            Commands::Info { device, shared: _ } => match device {
+            Commands::Info {
+                subject: Some(InfoCommands::Backends),
+                device: _,
+                shared: _,
+            } => Ok(CommandResult::Info(ApplicationInfoResult::Backends {
+                backends_result: self.backends_infos.clone(),
+            })),
+            Commands::Info {
+                subject: None,
+                device,
+                shared: _,
+            } => match device {
                 Device::DisplaysAndSpeakers => {
                     let displays_result = self.displays_settings.get_displays_infos()?;
                     let speakers_result = self.speakers_settings.get_speakers_infos()?;
@@ -171,7 +377,603 @@ impl Application {
                     }))
                 }
             },
+            Commands::Snapshot(SnapshotCommands::Save {
+                snapshot_name,
+                shared: _,
+            }) => {
+                let displays_snapshot = self.displays_settings.save_snapshot()?;
+                let speakers_snapshot = self.speakers_settings.save_snapshot()?;
+
+                self.snapshot_store.save(
+                    snapshot_name,
+                    &Snapshot::new(displays_snapshot, speakers_snapshot),
+                )?;
+
+                Ok(CommandResult::Snapshot(ApplicationSnapshotResult::Saved {
+                    snapshot_name: snapshot_name.clone(),
+                }))
+            }
+            Commands::Snapshot(SnapshotCommands::Restore {
+                snapshot_name,
+                shared: _,
+            }) => {
+                let snapshot = self.snapshot_store.load(snapshot_name)?;
+
+                self.displays_settings
+                    .restore_snapshot(&snapshot.displays)?;
+                self.speakers_settings
+                    .restore_snapshot(&snapshot.speakers)
+                    .map_err(|error| {
+                        ApplicationError::partial_failure(
+                            DeviceKind::Display,
+                            DeviceKind::Speaker,
+                            error,
+                        )
+                    })?;
+
+                Ok(CommandResult::Snapshot(
+                    ApplicationSnapshotResult::Restored {
+                        snapshot_name: snapshot_name.clone(),
+                    },
+                ))
+            }
+        }
+    }
+
+    /// Resolves the devices and computes what the change would do, without changing anything.
+    fn plan_change(
+        &mut self,
+        change_commands: &ChangeCommands,
+    ) -> ApplicationResult<CommandResult> {
+        trace_fn!();
+
+        let plan_result = match change_commands {
+            ChangeCommands::DisplaysAndSpeakers {
+                displays, speakers, ..
+            } => ApplicationPlanResult::DisplaysAndSpeakers {
+                displays_plan: self.plan_displays(displays)?,
+                speakers_plan: self.plan_speakers(speakers)?,
+            },
+            ChangeCommands::Displays { displays, .. } => ApplicationPlanResult::DisplaysOnly {
+                displays_plan: self.plan_displays(displays)?,
+            },
+            ChangeCommands::Speakers { speakers, .. } => ApplicationPlanResult::SpeakersOnly {
+                speakers_plan: self.plan_speakers(speakers)?,
+            },
+            ChangeCommands::Profile { profile_name, .. } => {
+                let profile = self.configuration.get_profile(profile_name)?.clone();
+                let displays_infos = self.displays_settings.get_displays_infos()?;
+                let speakers_infos = self.speakers_settings.get_speakers_infos()?;
+
+                let profile = profile.resolve(&displays_infos, &speakers_infos)?;
+                let (displays_plan, speakers_plan) = self.plan_profile(&profile)?;
+
+                ApplicationPlanResult::DisplaysAndSpeakers {
+                    displays_plan,
+                    speakers_plan,
+                }
+            }
+            ChangeCommands::Cycle {
+                displays,
+                speakers,
+                reverse,
+                ..
+            } => {
+                let cycle = self.get_cycle(displays, speakers)?;
+
+                match (cycle.displays.is_empty(), cycle.speakers.is_empty()) {
+                    (false, true) => ApplicationPlanResult::DisplaysOnly {
+                        displays_plan: self
+                            .displays_settings
+                            .plan_cycle_primary_display(&cycle.displays, *reverse)?,
+                    },
+                    (true, _) => ApplicationPlanResult::SpeakersOnly {
+                        speakers_plan: self
+                            .speakers_settings
+                            .plan_cycle_default_speaker(&cycle.speakers, *reverse)?,
+                    },
+                    (false, false) => ApplicationPlanResult::DisplaysAndSpeakers {
+                        displays_plan: self
+                            .displays_settings
+                            .plan_cycle_primary_display(&cycle.displays, *reverse)?,
+                        speakers_plan: self
+                            .speakers_settings
+                            .plan_cycle_default_speaker(&cycle.speakers, *reverse)?,
+                    },
+                }
+            }
+            ChangeCommands::Topology {
+                topology, display, ..
+            } => ApplicationPlanResult::Topology {
+                topology_plan: self
+                    .displays_settings
+                    .plan_topology(&map_to_displays_topology(topology, display.as_deref())?)?,
+            },
+        };
+
+        Ok(CommandResult::Plan(plan_result))
+    }
+
+    /// The cycle given on the command line, or the one of the configuration file when no device
+    /// is given.
+    fn get_cycle(&self, displays: &[String], speakers: &[String]) -> ApplicationResult<Cycle> {
+        trace_fn!();
+
+        if displays.is_empty() && speakers.is_empty() {
+            return self.configuration.get_cycle().cloned();
+        }
+
+        Ok(Cycle {
+            displays: displays.to_vec(),
+            speakers: speakers.to_vec(),
+        })
+    }
+
+    /// Plans the display and the speaker of a resolved profile, the settings it gives included.
+    fn plan_profile(
+        &mut self,
+        profile: &Profile,
+    ) -> ApplicationResult<(DisplaysPlan, SpeakersPlan)> {
+        trace_fn!();
+
+        let displays_plan = self
+            .displays_settings
+            .plan_set_primary_display(&profile.display)?;
+        let displays_plan = self.plan_display_mode(displays_plan, &profile.mode_request())?;
+        let displays_plan =
+            self.plan_display_transform(displays_plan, &profile.transform_request())?;
+        let displays_plan = self.plan_display_hdr(displays_plan, profile.hdr)?;
+        let speakers_plan = self
+            .speakers_settings
+            .plan_set_default_speaker(&profile.speaker)?;
+
+        Ok((displays_plan, speakers_plan))
+    }
+
+    fn plan_displays(&mut self, displays: &DisplaysOptions) -> ApplicationResult<DisplaysPlan> {
+        trace_fn!();
+
+        let displays_plan = match displays {
+            DisplaysOptions {
+                to: Some(display_name),
+                ..
+            } => self
+                .displays_settings
+                .plan_set_primary_display(display_name),
+            DisplaysOptions {
+                desktop_display_name: Some(desktop_display_name),
+                couch_display_name: Some(couch_display_name),
+                to: None,
+                ..
+            } => self
+                .displays_settings
+                .plan_swap_primary_display(desktop_display_name, couch_display_name),
+            _ => Err(ApplicationError::MissingArgument {
+                message: String::from(
+                    "Either the desktop and couch displays or the display to set are required",
+                ),
+            }),
+        }?;
+
+        let displays_plan =
+            self.plan_display_mode(displays_plan, &map_to_display_mode_request(displays))?;
+
+        let displays_plan = self
+            .plan_display_transform(displays_plan, &map_to_display_transform_request(displays))?;
+
+        self.plan_display_hdr(displays_plan, map_to_hdr(displays))
+    }
+
+    /// Selects the mode the new primary display would be driven at, which fails when it does
+    /// not support the one asked.
+    fn plan_display_mode(
+        &mut self,
+        displays_plan: DisplaysPlan,
+        mode_request: &DisplayModeRequest,
+    ) -> ApplicationResult<DisplaysPlan> {
+        trace_fn!();
+
+        if mode_request.is_empty() {
+            return Ok(displays_plan);
         }
+
+        let mode = self
+            .displays_settings
+            .plan_display_mode(&displays_plan.new_primary_display, mode_request)?;
+
+        Ok(displays_plan.with_mode(mode))
+    }
+
+    /// Checks that the new primary display could be rotated and scaled as asked, which fails
+    /// when the backend cannot set the scale.
+    fn plan_display_transform(
+        &mut self,
+        displays_plan: DisplaysPlan,
+        transform_request: &DisplayTransformRequest,
+    ) -> ApplicationResult<DisplaysPlan> {
+        trace_fn!();
+
+        if transform_request.is_empty() {
+            return Ok(displays_plan);
+        }
+
+        self.displays_settings
+            .plan_display_transform(&displays_plan.new_primary_display, transform_request)?;
+
+        Ok(displays_plan.with_transform(transform_request))
+    }
+
+    /// Checks that HDR could be turned on or off on the new primary display, which fails when
+    /// the backend or the display cannot show it.
+    fn plan_display_hdr(
+        &mut self,
+        displays_plan: DisplaysPlan,
+        is_hdr_enabled: Option<bool>,
+    ) -> ApplicationResult<DisplaysPlan> {
+        trace_fn!();
+
+        let Some(is_hdr_enabled) = is_hdr_enabled else {
+            return Ok(displays_plan);
+        };
+
+        self.displays_settings
+            .plan_display_hdr(&displays_plan.new_primary_display)?;
+
+        Ok(displays_plan.with_hdr(is_hdr_enabled))
+    }
+
+    fn plan_speakers(&mut self, speakers: &SpeakersOptions) -> ApplicationResult<SpeakersPlan> {
+        trace_fn!();
+
+        match speakers {
+            SpeakersOptions {
+                to_speaker: Some(speaker_name),
+                ..
+            } => self
+                .speakers_settings
+                .plan_set_default_speaker(speaker_name),
+            SpeakersOptions {
+                desktop_speaker_name: Some(desktop_speaker_name),
+                couch_speaker_name: Some(couch_speaker_name),
+                to_speaker: None,
+            } => self
+                .speakers_settings
+                .plan_swap_default_speaker(desktop_speaker_name, couch_speaker_name),
+            _ => Err(ApplicationError::MissingArgument {
+                message: String::from(
+                    "Either the desktop and couch speakers or the speaker to set are required",
+                ),
+            }),
+        }
+    }
+
+    fn change_displays(
+        &mut self,
+        displays: &DisplaysOptions,
+    ) -> ApplicationResult<DisplaysSettingsResult> {
+        trace_fn!();
+
+        let mode_request = map_to_display_mode_request(displays);
+        let transform_request = map_to_display_transform_request(displays);
+        let is_hdr_enabled = map_to_hdr(displays);
+
+        // The mode, the rotation, the scale and HDR are checked first so that an unsupported one
+        // changes nothing, the plan being what the change is verified against.
+        let displays_plan = self.plan_displays(displays)?;
+
+        let displays_result = match displays {
+            DisplaysOptions {
+                to: Some(display_name),
+                ..
+            } => self.displays_settings.set_primary_display(display_name),
+            DisplaysOptions {
+                desktop_display_name: Some(desktop_display_name),
+                couch_display_name: Some(couch_display_name),
+                to: None,
+                ..
+            } => self
+                .displays_settings
+                .swap_primary_display(desktop_display_name, couch_display_name),
+            _ => Err(ApplicationError::MissingArgument {
+                message: String::from(
+                    "Either the desktop and couch displays or the display to set are required",
+                ),
+            }),
+        }?;
+
+        let displays_result = self.change_display_mode(displays_result, &mode_request)?;
+        let displays_result = self.change_display_transform(displays_result, &transform_request)?;
+        let displays_result = self.change_display_hdr(displays_result, is_hdr_enabled)?;
+
+        self.verify_displays(&displays_plan, displays_result)
+    }
+
+    /// Drives the new primary display at the mode asked, if any.
+    fn change_display_mode(
+        &mut self,
+        mut displays_result: DisplaysSettingsResult,
+        mode_request: &DisplayModeRequest,
+    ) -> ApplicationResult<DisplaysSettingsResult> {
+        trace_fn!();
+
+        if mode_request.is_empty() {
+            return Ok(displays_result);
+        }
+
+        let mode = self
+            .displays_settings
+            .set_display_mode(&displays_result.new_primary_display, mode_request)?;
+
+        displays_result.new_mode = Some(mode);
+
+        Ok(displays_result)
+    }
+
+    /// Rotates and scales the new primary display as asked, if at all.
+    fn change_display_transform(
+        &mut self,
+        mut displays_result: DisplaysSettingsResult,
+        transform_request: &DisplayTransformRequest,
+    ) -> ApplicationResult<DisplaysSettingsResult> {
+        trace_fn!();
+
+        if transform_request.is_empty() {
+            return Ok(displays_result);
+        }
+
+        self.displays_settings
+            .set_display_transform(&displays_result.new_primary_display, transform_request)?;
+
+        displays_result.new_rotation = transform_request.rotation;
+        displays_result.new_scale_percent = transform_request.scale_percent;
+
+        Ok(displays_result)
+    }
+
+    /// Turns HDR on or off on the new primary display as asked, if at all.
+    fn change_display_hdr(
+        &mut self,
+        mut displays_result: DisplaysSettingsResult,
+        is_hdr_enabled: Option<bool>,
+    ) -> ApplicationResult<DisplaysSettingsResult> {
+        trace_fn!();
+
+        let Some(is_hdr_enabled) = is_hdr_enabled else {
+            return Ok(displays_result);
+        };
+
+        self.displays_settings
+            .set_display_hdr(&displays_result.new_primary_display, is_hdr_enabled)?;
+
+        displays_result.new_hdr_enabled = Some(is_hdr_enabled);
+
+        Ok(displays_result)
+    }
+
+    fn cycle_displays(
+        &mut self,
+        display_selectors: &[String],
+        reverse: bool,
+    ) -> ApplicationResult<DisplaysSettingsResult> {
+        trace_fn!();
+
+        let displays_plan = self
+            .displays_settings
+            .plan_cycle_primary_display(display_selectors, reverse)?;
+        let displays_result = self
+            .displays_settings
+            .cycle_primary_display(display_selectors, reverse)?;
+
+        self.verify_displays(&displays_plan, displays_result)
+    }
+
+    /// Checks that the displays accepted by the platform are reported the way they were planned.
+    fn verify_displays(
+        &mut self,
+        displays_plan: &DisplaysPlan,
+        displays_result: DisplaysSettingsResult,
+    ) -> ApplicationResult<DisplaysSettingsResult> {
+        trace_fn!();
+
+        self.displays_settings
+            .verify_displays(displays_plan, &self.configuration.verification)?;
+
+        Ok(displays_result)
+    }
+
+    /// Checks that the displays turned on by the platform are the ones of the topology.
+    fn verify_topology(
+        &mut self,
+        topology_result: DisplaysTopologyResult,
+    ) -> ApplicationResult<DisplaysTopologyResult> {
+        trace_fn!();
+
+        self.displays_settings
+            .verify_topology(&topology_result, &self.configuration.verification)?;
+
+        Ok(topology_result)
+    }
+
+    /// Captures the devices of the given kinds before a change touches them.
+    fn save_change_snapshot(&mut self, kinds: &[DeviceKind]) -> ApplicationResult<ChangeSnapshot> {
+        trace_fn!();
+
+        let displays = if kinds.contains(&DeviceKind::Display) {
+            Some(self.displays_settings.save_snapshot()?)
+        } else {
+            None
+        };
+        let speakers = if kinds.contains(&DeviceKind::Speaker) {
+            Some(self.speakers_settings.save_snapshot()?)
+        } else {
+            None
+        };
+
+        Ok(ChangeSnapshot { displays, speakers })
+    }
+
+    /// Changes back the devices which a failed change left changed, such as the primary display
+    /// once the mode or the default speaker failed to be set, so that the machine is not left
+    /// half-switched. A change which failed before changing anything, such as an unknown display,
+    /// is reported as it is, and both errors are reported when the devices cannot be changed back.
+    fn roll_back(
+        &mut self,
+        change_snapshot: &ChangeSnapshot,
+        failed: DeviceKind,
+        error: ApplicationError,
+    ) -> ApplicationError {
+        trace_fn!();
+
+        let changed_displays_snapshot = change_snapshot.displays.as_ref().filter(|snapshot| {
+            !self
+                .displays_settings
+                .save_snapshot()
+                .is_ok_and(|current_snapshot| current_snapshot == **snapshot)
+        });
+        let changed_speakers_snapshot = change_snapshot.speakers.as_ref().filter(|snapshot| {
+            !self
+                .speakers_settings
+                .save_snapshot()
+                .is_ok_and(|current_snapshot| current_snapshot == **snapshot)
+        });
+
+        let rolled_back = match (changed_displays_snapshot, changed_speakers_snapshot) {
+            (None, None) => return error,
+            (Some(_), _) => DeviceKind::Display,
+            (None, Some(_)) => DeviceKind::Speaker,
+        };
+
+        let rollback_result = changed_displays_snapshot
+            .map_or(Ok(()), |snapshot| {
+                self.displays_settings.restore_snapshot(snapshot)
+            })
+            .and_then(|()| {
+                changed_speakers_snapshot.map_or(Ok(()), |snapshot| {
+                    self.speakers_settings.restore_snapshot(snapshot)
+                })
+            });
+
+        match rollback_result {
+            Ok(()) => ApplicationError::rollback_performed(rolled_back, failed, error),
+            Err(rollback_error) => {
+                ApplicationError::rollback_failed(rolled_back, failed, error, rollback_error)
+            }
+        }
+    }
+
+    fn change_speakers(
+        &mut self,
+        speakers: &SpeakersOptions,
+    ) -> ApplicationResult<SpeakersSettingsResult> {
+        trace_fn!();
+
+        let speakers_plan = self.plan_speakers(speakers)?;
+        let speakers_result = match speakers {
+            SpeakersOptions {
+                to_speaker: Some(speaker_name),
+                ..
+            } => self.speakers_settings.set_default_speaker(speaker_name),
+            SpeakersOptions {
+                desktop_speaker_name: Some(desktop_speaker_name),
+                couch_speaker_name: Some(couch_speaker_name),
+                to_speaker: None,
+            } => self
+                .speakers_settings
+                .swap_default_speaker(desktop_speaker_name, couch_speaker_name),
+            _ => Err(ApplicationError::MissingArgument {
+                message: String::from(
+                    "Either the desktop and couch speakers or the speaker to set are required",
+                ),
+            }),
+        }?;
+
+        self.verify_speakers(&speakers_plan, speakers_result)
+    }
+
+    fn cycle_speakers(
+        &mut self,
+        speaker_selectors: &[String],
+        reverse: bool,
+    ) -> ApplicationResult<SpeakersSettingsResult> {
+        trace_fn!();
+
+        let speakers_plan = self
+            .speakers_settings
+            .plan_cycle_default_speaker(speaker_selectors, reverse)?;
+        let speakers_result = self
+            .speakers_settings
+            .cycle_default_speaker(speaker_selectors, reverse)?;
+
+        self.verify_speakers(&speakers_plan, speakers_result)
+    }
+
+    /// Checks that the default speaker accepted by the platform is reported the way it was
+    /// planned.
+    fn verify_speakers(
+        &mut self,
+        speakers_plan: &SpeakersPlan,
+        speakers_result: SpeakersSettingsResult,
+    ) -> ApplicationResult<SpeakersSettingsResult> {
+        trace_fn!();
+
+        self.speakers_settings
+            .verify_speakers(speakers_plan, &self.configuration.verification)?;
+
+        Ok(speakers_result)
+    }
+}
+
+fn map_to_display_mode_request(displays_options: &DisplaysOptions) -> DisplayModeRequest {
+    DisplayModeRequest {
+        resolution: displays_options.resolution,
+        refresh_rate_hertz: displays_options.refresh,
+    }
+}
+
+fn map_to_display_transform_request(displays_options: &DisplaysOptions) -> DisplayTransformRequest {
+    DisplayTransformRequest {
+        rotation: displays_options
+            .rotation
+            .as_ref()
+            .map(map_to_display_rotation),
+        scale_percent: displays_options.scale,
+    }
+}
+
+fn map_to_display_rotation(rotation_option: &RotationOption) -> DisplayRotation {
+    match rotation_option {
+        RotationOption::Degrees0 => DisplayRotation::Degrees0,
+        RotationOption::Degrees90 => DisplayRotation::Degrees90,
+        RotationOption::Degrees180 => DisplayRotation::Degrees180,
+        RotationOption::Degrees270 => DisplayRotation::Degrees270,
+    }
+}
+
+fn map_to_hdr(displays_options: &DisplaysOptions) -> Option<bool> {
+    displays_options
+        .hdr
+        .as_ref()
+        .map(|hdr_option| match hdr_option {
+            HdrOption::On => true,
+            HdrOption::Off => false,
+        })
+}
+
+fn map_to_displays_topology(
+    topology_option: &TopologyOption,
+    display_name: Option<&str>,
+) -> ApplicationResult<DisplaysTopology> {
+    match (topology_option, display_name) {
+        (TopologyOption::Extend, _) => Ok(DisplaysTopology::Extend),
+        (TopologyOption::Clone, _) => Ok(DisplaysTopology::Clone),
+        (TopologyOption::Internal, _) => Ok(DisplaysTopology::Internal),
+        (TopologyOption::External, _) => Ok(DisplaysTopology::External),
+        (TopologyOption::Only, Some(display_name)) => {
+            Ok(DisplaysTopology::Only(String::from(display_name)))
+        }
+        (TopologyOption::Only, None) => Err(ApplicationError::MissingArgument {
+            message: String::from("The display to keep turned on is required"),
+        }),
     }
 }
 
@@ -186,13 +988,49 @@ fn map_to_log_level(log_level_option: &LogLevelOption) -> LogLevel {
     }
 }
 
+pub fn map_to_displays_backend(displays_backend_option: &DisplaysBackendOption) -> DisplaysBackend {
+    match displays_backend_option {
+        DisplaysBackendOption::InMemory => DisplaysBackend::InMemory,
+        #[cfg(target_os = "windows")]
+        DisplaysBackendOption::Windows => DisplaysBackend::Windows,
+        #[cfg(target_os = "linux")]
+        DisplaysBackendOption::X11 => DisplaysBackend::X11,
+        #[cfg(target_os = "linux")]
+        DisplaysBackendOption::Wlroots => DisplaysBackend::Wlroots,
+        #[cfg(target_os = "linux")]
+        DisplaysBackendOption::Mutter => DisplaysBackend::Mutter,
+        #[cfg(target_os = "linux")]
+        DisplaysBackendOption::KScreen => DisplaysBackend::KScreen,
+    }
+}
+
+pub fn map_to_speakers_backend(speakers_backend_option: &SpeakersBackendOption) -> SpeakersBackend {
+    match speakers_backend_option {
+        SpeakersBackendOption::InMemory => SpeakersBackend::InMemory,
+        #[cfg(target_os = "windows")]
+        SpeakersBackendOption::Windows => SpeakersBackend::Windows,
+        #[cfg(target_os = "linux")]
+        SpeakersBackendOption::PulseAudio => SpeakersBackend::PulseAudio,
+        #[cfg(target_os = "linux")]
+        SpeakersBackendOption::PipeWire => SpeakersBackend::PipeWire,
+    }
+}
+
 #[cfg(test)]
 mod should {
-    use convertible_couch_lib::log::LogLevel;
+    use clap::ValueEnum;
+    use convertible_couch_lib::{
+        backends::{Backend, displays_backend::DisplaysBackend, speakers_backend::SpeakersBackend},
+        log::LogLevel,
+    };
     use test_case::test_case;
 
     use crate::{
-        application::map_to_log_level, commands::shared::log_level_option::LogLevelOption,
+        application::{map_to_displays_backend, map_to_log_level, map_to_speakers_backend},
+        commands::shared::{
+            displays_backend_option::DisplaysBackendOption, log_level_option::LogLevelOption,
+            speakers_backend_option::SpeakersBackendOption,
+        },
     };
 
     #[test_case(LogLevelOption::Off => LogLevel::Off)]
@@ -206,4 +1044,28 @@ mod should {
     ) -> LogLevel {
         map_to_log_level(&log_level_option)
     }
+
+    #[test]
+    fn map_each_displays_backend_option_to_a_displays_backend() {
+        // Act
+        let displays_backends = DisplaysBackendOption::value_variants()
+            .iter()
+            .map(map_to_displays_backend)
+            .collect::<Vec<DisplaysBackend>>();
+
+        // Assert
+        assert_eq!(displays_backends, DisplaysBackend::all());
+    }
+
+    #[test]
+    fn map_each_speakers_backend_option_to_a_speakers_backend() {
+        // Act
+        let speakers_backends = SpeakersBackendOption::value_variants()
+            .iter()
+            .map(map_to_speakers_backend)
+            .collect::<Vec<SpeakersBackend>>();
+
+        // Assert
+        assert_eq!(speakers_backends, SpeakersBackend::all());
+    }
 }
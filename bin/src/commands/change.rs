@@ -1,25 +1,158 @@
-use clap::{Args, Subcommand};
+use clap::{Args, Subcommand, ValueEnum, builder::PossibleValue};
+use convertible_couch_lib::displays_settings::DisplayResolution;
 
 use crate::commands::shared::SharedOptions;
 
 #[derive(Args, Debug)]
 pub struct DisplaysOptions {
     /// The name of the display to use on your dekstop
-    #[arg(long)]
-    pub desktop_display_name: String,
+    #[arg(long, required_unless_present = "to", requires = "couch_display_name")]
+    pub desktop_display_name: Option<String>,
     /// The name of the display to use on your couch
+    #[arg(
+        long,
+        required_unless_present = "to",
+        requires = "desktop_display_name"
+    )]
+    pub couch_display_name: Option<String>,
+    /// The name of the display to set as primary, even if it already is
+    #[arg(long, conflicts_with_all = ["desktop_display_name", "couch_display_name"])]
+    pub to: Option<String>,
+    /// The resolution to drive the new primary display at, such as 3840x2160
+    #[arg(long)]
+    pub resolution: Option<DisplayResolution>,
+    /// The refresh rate in hertz to drive the new primary display at, such as 120
     #[arg(long)]
-    pub couch_display_name: String,
+    pub refresh: Option<u32>,
+    /// The clockwise rotation in degrees to turn the new primary display to
+    #[arg(long, value_enum)]
+    pub rotation: Option<RotationOption>,
+    /// The scale in percent of the desktop shown on the new primary display, such as 200
+    #[arg(long, value_parser = clap::value_parser!(u32).range(100..=500))]
+    pub scale: Option<u32>,
+    /// Whether to turn HDR on or off on the new primary display
+    #[arg(long, value_enum)]
+    pub hdr: Option<HdrOption>,
 }
 
 #[derive(Args, Debug)]
 pub struct SpeakersOptions {
     /// The name of the speaker to use on your desktop
-    #[arg(long)]
-    pub desktop_speaker_name: String,
+    #[arg(
+        long,
+        required_unless_present = "to_speaker",
+        requires = "couch_speaker_name"
+    )]
+    pub desktop_speaker_name: Option<String>,
     /// The name of the speaker to use on your couch
-    #[arg(long)]
-    pub couch_speaker_name: String,
+    #[arg(
+        long,
+        required_unless_present = "to_speaker",
+        requires = "desktop_speaker_name"
+    )]
+    pub couch_speaker_name: Option<String>,
+    /// The name of the speaker to set as default, even if it already is
+    #[arg(long, conflicts_with_all = ["desktop_speaker_name", "couch_speaker_name"])]
+    pub to_speaker: Option<String>,
+}
+
+/// Models the displays one wishes the desktop to spread over, a set of displays of one's own
+/// being turned back on by restoring the snapshot it was saved in
+#[derive(Clone, Debug, PartialEq)]
+pub enum TopologyOption {
+    /// Extend the desktop over every connected display
+    Extend,
+    /// Show the same desktop on every connected display
+    Clone,
+    /// Keep only the internal displays turned on
+    Internal,
+    /// Keep only the external displays turned on
+    External,
+    /// Keep only the display given with `--display` turned on
+    Only,
+}
+
+impl ValueEnum for TopologyOption {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[
+            Self::Extend,
+            Self::Clone,
+            Self::Internal,
+            Self::External,
+            Self::Only,
+        ]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        let canonical_argument_value = match self {
+            Self::Extend => PossibleValue::new("extend"),
+            Self::Clone => PossibleValue::new("clone"),
+            Self::Internal => PossibleValue::new("internal"),
+            Self::External => PossibleValue::new("external"),
+            Self::Only => PossibleValue::new("only"),
+        };
+
+        Some(canonical_argument_value)
+    }
+}
+
+/// Models the clockwise rotation one wishes a display to be turned to
+#[derive(Clone, Debug, PartialEq)]
+pub enum RotationOption {
+    /// Keep the display upright
+    Degrees0,
+    /// Turn the display a quarter turn clockwise
+    Degrees90,
+    /// Turn the display upside down
+    Degrees180,
+    /// Turn the display a quarter turn counter-clockwise
+    Degrees270,
+}
+
+impl ValueEnum for RotationOption {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[
+            Self::Degrees0,
+            Self::Degrees90,
+            Self::Degrees180,
+            Self::Degrees270,
+        ]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        let canonical_argument_value = match self {
+            Self::Degrees0 => PossibleValue::new("0"),
+            Self::Degrees90 => PossibleValue::new("90"),
+            Self::Degrees180 => PossibleValue::new("180"),
+            Self::Degrees270 => PossibleValue::new("270"),
+        };
+
+        Some(canonical_argument_value)
+    }
+}
+
+/// Models whether one wishes HDR to be shown on a display
+#[derive(Clone, Debug, PartialEq)]
+pub enum HdrOption {
+    /// Turn HDR on
+    On,
+    /// Turn HDR off
+    Off,
+}
+
+impl ValueEnum for HdrOption {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[Self::On, Self::Off]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        let canonical_argument_value = match self {
+            Self::On => PossibleValue::new("on"),
+            Self::Off => PossibleValue::new("off"),
+        };
+
+        Some(canonical_argument_value)
+    }
 }
 
 #[derive(Debug, Subcommand)]
@@ -30,6 +163,9 @@ pub enum ChangeCommands {
         displays: DisplaysOptions,
         #[command(flatten)]
         speakers: SpeakersOptions,
+        /// Print what would change without changing anything
+        #[arg(long)]
+        dry_run: bool,
         #[command(flatten)]
         shared: SharedOptions,
     },
@@ -37,6 +173,9 @@ pub enum ChangeCommands {
     Displays {
         #[command(flatten)]
         displays: DisplaysOptions,
+        /// Print what would change without changing anything
+        #[arg(long)]
+        dry_run: bool,
         #[command(flatten)]
         shared: SharedOptions,
     },
@@ -44,7 +183,118 @@ pub enum ChangeCommands {
     Speakers {
         #[command(flatten)]
         speakers: SpeakersOptions,
+        /// Print what would change without changing anything
+        #[arg(long)]
+        dry_run: bool,
+        #[command(flatten)]
+        shared: SharedOptions,
+    },
+    /// Change primary display and default speaker to the ones of a profile
+    Profile {
+        /// The name of the profile declared in the configuration file
+        profile_name: String,
+        /// Print what would change without changing anything
+        #[arg(long)]
+        dry_run: bool,
+        #[command(flatten)]
+        shared: SharedOptions,
+    },
+    /// Change primary display and/or default speaker to the next ones of a cycle
+    Cycle {
+        /// A display to cycle through, repeated in order, the cycle of the configuration file being used by default
+        #[arg(long = "display")]
+        displays: Vec<String>,
+        /// A speaker to cycle through, repeated in order, the cycle of the configuration file being used by default
+        #[arg(long = "speaker")]
+        speakers: Vec<String>,
+        /// Change to the previous display and speaker of the cycle instead of the next ones
+        #[arg(long)]
+        reverse: bool,
+        /// Print what would change without changing anything
+        #[arg(long)]
+        dry_run: bool,
+        #[command(flatten)]
+        shared: SharedOptions,
+    },
+    /// Change which displays are turned on and whether they extend or clone the desktop
+    Topology {
+        /// The displays to turn on, the other ones being turned off
+        #[arg(value_enum)]
+        topology: TopologyOption,
+        /// The name of the only display to keep turned on
+        #[arg(long, required_if_eq("topology", "only"))]
+        display: Option<String>,
+        /// Print what would change without changing anything
+        #[arg(long)]
+        dry_run: bool,
         #[command(flatten)]
         shared: SharedOptions,
     },
 }
+
+impl ChangeCommands {
+    pub fn dry_run(&self) -> bool {
+        match self {
+            ChangeCommands::DisplaysAndSpeakers { dry_run, .. } => *dry_run,
+            ChangeCommands::Displays { dry_run, .. } => *dry_run,
+            ChangeCommands::Speakers { dry_run, .. } => *dry_run,
+            ChangeCommands::Profile { dry_run, .. } => *dry_run,
+            ChangeCommands::Cycle { dry_run, .. } => *dry_run,
+            ChangeCommands::Topology { dry_run, .. } => *dry_run,
+        }
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use clap::{ValueEnum, builder::PossibleValue};
+    use test_case::test_case;
+
+    use crate::commands::change::{HdrOption, RotationOption, TopologyOption};
+
+    #[test]
+    fn provide_all_possible_argument_values() {
+        // Act
+        let value_variants = TopologyOption::value_variants();
+
+        // Assert
+        assert_eq!(
+            value_variants,
+            [
+                TopologyOption::Extend,
+                TopologyOption::Clone,
+                TopologyOption::Internal,
+                TopologyOption::External,
+                TopologyOption::Only
+            ]
+        );
+    }
+
+    #[test_case(TopologyOption::Extend => Some(PossibleValue::new("extend")); "when topology is extend")]
+    #[test_case(TopologyOption::Clone => Some(PossibleValue::new("clone")); "when topology is clone")]
+    #[test_case(TopologyOption::Internal => Some(PossibleValue::new("internal")); "when topology is internal")]
+    #[test_case(TopologyOption::External => Some(PossibleValue::new("external")); "when topology is external")]
+    #[test_case(TopologyOption::Only => Some(PossibleValue::new("only")); "when topology is only")]
+    fn provide_the_canonical_argument_value(topology: TopologyOption) -> Option<PossibleValue> {
+        // Act
+        topology.to_possible_value()
+    }
+
+    #[test_case(RotationOption::Degrees0 => Some(PossibleValue::new("0")); "when rotation is 0 degrees")]
+    #[test_case(RotationOption::Degrees90 => Some(PossibleValue::new("90")); "when rotation is 90 degrees")]
+    #[test_case(RotationOption::Degrees180 => Some(PossibleValue::new("180")); "when rotation is 180 degrees")]
+    #[test_case(RotationOption::Degrees270 => Some(PossibleValue::new("270")); "when rotation is 270 degrees")]
+    fn provide_the_canonical_rotation_argument_value(
+        rotation: RotationOption,
+    ) -> Option<PossibleValue> {
+        // Act
+        rotation.to_possible_value()
+    }
+
+    #[test_case(HdrOption::On => Some(PossibleValue::new("on")); "when hdr is on")]
+    #[test_case(HdrOption::Off => Some(PossibleValue::new("off")); "when hdr is off")]
+    fn provide_the_canonical_hdr_argument_value(hdr: HdrOption) -> Option<PossibleValue> {
+        // Act
+        hdr.to_possible_value()
+    }
+}
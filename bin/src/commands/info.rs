@@ -1,4 +1,10 @@
-use clap::{ValueEnum, builder::PossibleValue};
+use clap::{Subcommand, ValueEnum, builder::PossibleValue};
+
+#[derive(Debug, Subcommand)]
+pub enum InfoCommands {
+    /// List the displays and speakers backends available on this platform
+    Backends,
+}
 
 /// Models the choice of which device(s) one wishes to obtain information from
 #[derive(Clone, Debug, PartialEq)]
@@ -29,10 +35,10 @@ impl ValueEnum for Device {
 
 #[cfg(test)]
 mod should {
-    use clap::{ValueEnum, builder::PossibleValue};
+    use clap::{Subcommand, ValueEnum, builder::PossibleValue};
     use test_case::test_case;
 
-    use crate::commands::info::Device;
+    use crate::commands::info::{Device, InfoCommands};
 
     #[test]
     fn provide_all_possible_argument_values() {
@@ -57,4 +63,11 @@ mod should {
         // Act
         log_level.to_possible_value()
     }
+
+    #[test_case("backends" => true; "when subcommand is backends")]
+    #[test_case("displays" => false; "when subcommand is unknown")]
+    fn provide_the_info_subcommands(subcommand_name: &str) -> bool {
+        // Act
+        InfoCommands::has_subcommand(subcommand_name)
+    }
 }
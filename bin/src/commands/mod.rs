@@ -1,10 +1,16 @@
 use clap::{Parser, Subcommand};
 
-use crate::commands::{change::ChangeCommands, info::Device, shared::SharedOptions};
+use crate::commands::{
+    change::ChangeCommands,
+    info::{Device, InfoCommands},
+    shared::SharedOptions,
+    snapshot::SnapshotCommands,
+};
 
 pub mod change;
 pub mod info;
 pub mod shared;
+pub mod snapshot;
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
@@ -20,10 +26,35 @@ pub enum Commands {
     Change(ChangeCommands),
     /// Get informations about displays and/or speakers
     Info {
+        #[command(subcommand)]
+        subject: Option<InfoCommands>,
         /// Which device(s) to get informations about
         #[arg(short, long, value_enum, default_value_t = Device::DisplaysAndSpeakers)]
         device: Device,
         #[command(flatten)]
         shared: SharedOptions,
     },
+    /// Save or restore the displays configuration and default speakers
+    #[command(subcommand)]
+    Snapshot(SnapshotCommands),
+}
+
+impl Commands {
+    pub fn shared(&self) -> &SharedOptions {
+        match self {
+            Commands::Change(change_commands) => match change_commands {
+                ChangeCommands::DisplaysAndSpeakers { shared, .. } => shared,
+                ChangeCommands::Displays { shared, .. } => shared,
+                ChangeCommands::Speakers { shared, .. } => shared,
+                ChangeCommands::Profile { shared, .. } => shared,
+                ChangeCommands::Cycle { shared, .. } => shared,
+                ChangeCommands::Topology { shared, .. } => shared,
+            },
+            Commands::Info { shared, .. } => shared,
+            Commands::Snapshot(snapshot_commands) => match snapshot_commands {
+                SnapshotCommands::Save { shared, .. } => shared,
+                SnapshotCommands::Restore { shared, .. } => shared,
+            },
+        }
+    }
 }
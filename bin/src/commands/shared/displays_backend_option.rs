@@ -0,0 +1,115 @@
+use clap::{ValueEnum, builder::PossibleValue};
+
+/// An enum representing the displays backends which can be selected on the current platform.
+#[derive(Clone, Debug, PartialEq)]
+pub enum DisplaysBackendOption {
+    /// Corresponds to the simulated machine.
+    InMemory,
+    /// Corresponds to the Win32 display configuration API.
+    #[cfg(target_os = "windows")]
+    Windows,
+    /// Corresponds to X11 RandR.
+    #[cfg(target_os = "linux")]
+    X11,
+    /// Corresponds to the wlr-output-management Wayland protocol.
+    #[cfg(target_os = "linux")]
+    Wlroots,
+    /// Corresponds to GNOME Mutter's DisplayConfig D-Bus API.
+    #[cfg(target_os = "linux")]
+    Mutter,
+    /// Corresponds to KDE Plasma's KScreen.
+    #[cfg(target_os = "linux")]
+    KScreen,
+}
+
+impl ValueEnum for DisplaysBackendOption {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[
+            Self::InMemory,
+            #[cfg(target_os = "windows")]
+            Self::Windows,
+            #[cfg(target_os = "linux")]
+            Self::X11,
+            #[cfg(target_os = "linux")]
+            Self::Wlroots,
+            #[cfg(target_os = "linux")]
+            Self::Mutter,
+            #[cfg(target_os = "linux")]
+            Self::KScreen,
+        ]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        let canonical_argument_value = match self {
+            Self::InMemory => PossibleValue::new("in-memory"),
+            #[cfg(target_os = "windows")]
+            Self::Windows => PossibleValue::new("windows"),
+            #[cfg(target_os = "linux")]
+            Self::X11 => PossibleValue::new("x11"),
+            #[cfg(target_os = "linux")]
+            Self::Wlroots => PossibleValue::new("wlroots"),
+            #[cfg(target_os = "linux")]
+            Self::Mutter => PossibleValue::new("mutter"),
+            #[cfg(target_os = "linux")]
+            Self::KScreen => PossibleValue::new("kscreen"),
+        };
+
+        Some(canonical_argument_value)
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use clap::{ValueEnum, builder::PossibleValue};
+    use test_case::test_case;
+
+    use crate::commands::shared::displays_backend_option::DisplaysBackendOption;
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn provide_all_possible_argument_values() {
+        // Act
+        let value_variants = DisplaysBackendOption::value_variants();
+
+        // Assert
+        assert_eq!(
+            value_variants,
+            [
+                DisplaysBackendOption::InMemory,
+                DisplaysBackendOption::X11,
+                DisplaysBackendOption::Wlroots,
+                DisplaysBackendOption::Mutter,
+                DisplaysBackendOption::KScreen
+            ]
+        );
+    }
+
+    #[cfg(target_os = "windows")]
+    #[test]
+    fn provide_all_possible_argument_values() {
+        // Act
+        let value_variants = DisplaysBackendOption::value_variants();
+
+        // Assert
+        assert_eq!(
+            value_variants,
+            [
+                DisplaysBackendOption::InMemory,
+                DisplaysBackendOption::Windows
+            ]
+        );
+    }
+
+    #[test_case(DisplaysBackendOption::InMemory => Some(PossibleValue::new("in-memory")); "when backend is in-memory")]
+    #[cfg_attr(target_os = "windows", test_case(DisplaysBackendOption::Windows => Some(PossibleValue::new("windows")); "when backend is windows"))]
+    #[cfg_attr(target_os = "linux", test_case(DisplaysBackendOption::X11 => Some(PossibleValue::new("x11")); "when backend is x11"))]
+    #[cfg_attr(target_os = "linux", test_case(DisplaysBackendOption::Wlroots => Some(PossibleValue::new("wlroots")); "when backend is wlroots"))]
+    #[cfg_attr(target_os = "linux", test_case(DisplaysBackendOption::Mutter => Some(PossibleValue::new("mutter")); "when backend is mutter"))]
+    #[cfg_attr(target_os = "linux", test_case(DisplaysBackendOption::KScreen => Some(PossibleValue::new("kscreen")); "when backend is kscreen"))]
+    fn provide_the_canonical_argument_value(
+        displays_backend: DisplaysBackendOption,
+    ) -> Option<PossibleValue> {
+        // Act
+        displays_backend.to_possible_value()
+    }
+}
@@ -1,12 +1,32 @@
+use std::path::PathBuf;
+
 use clap::Args;
 
-use crate::commands::shared::log_level_option::LogLevelOption;
+use crate::commands::shared::{
+    displays_backend_option::DisplaysBackendOption, log_level_option::LogLevelOption,
+    output_option::OutputOption, speakers_backend_option::SpeakersBackendOption,
+};
 
+pub mod displays_backend_option;
 pub mod log_level_option;
+pub mod output_option;
+pub mod speakers_backend_option;
 
 #[derive(Args, Debug)]
 pub struct SharedOptions {
     /// Set the program's log level
-    #[arg(short, long, value_enum, default_value_t = LogLevelOption::Error)]
+    #[arg(short, long, value_enum, default_value_t = LogLevelOption::Error, global = true)]
     pub log_level: LogLevelOption,
+    /// Override the detected displays backend
+    #[arg(long, value_enum, global = true)]
+    pub displays_backend: Option<DisplaysBackendOption>,
+    /// Override the detected speakers backend
+    #[arg(long, value_enum, global = true)]
+    pub speakers_backend: Option<SpeakersBackendOption>,
+    /// Read the profiles from this configuration file instead of the default one
+    #[arg(long, global = true)]
+    pub config: Option<PathBuf>,
+    /// Set the format the results and the errors are printed in
+    #[arg(short, long, value_enum, default_value_t = OutputOption::Table, global = true)]
+    pub output: OutputOption,
 }
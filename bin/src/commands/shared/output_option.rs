@@ -0,0 +1,98 @@
+use clap::{ValueEnum, builder::PossibleValue};
+
+/// An enum representing the formats the results can be printed in.
+#[derive(Clone, Debug, PartialEq)]
+pub enum OutputOption {
+    /// Corresponds to a JSON document.
+    Json,
+    /// Corresponds to a YAML document.
+    Yaml,
+    /// Corresponds to human readable tables.
+    Table,
+}
+
+impl ValueEnum for OutputOption {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[Self::Json, Self::Yaml, Self::Table]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        let canonical_argument_value = match self {
+            Self::Json => PossibleValue::new("json"),
+            Self::Yaml => PossibleValue::new("yaml"),
+            Self::Table => PossibleValue::new("table"),
+        };
+
+        Some(canonical_argument_value)
+    }
+}
+
+impl OutputOption {
+    /// Finds the format asked on a command line which clap rejected, so that the usage error can
+    /// still be printed in that format, the last one given winning as with clap.
+    pub fn find_in(args: &[String]) -> Self {
+        let mut output = Self::Table;
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            let value = match arg.as_str() {
+                "--output" | "-o" => args.next().map(String::as_str),
+                _ => arg
+                    .strip_prefix("--output=")
+                    .or_else(|| arg.strip_prefix("-o"))
+                    .map(|value| value.strip_prefix('=').unwrap_or(value)),
+            };
+
+            if let Some(found_output) = value.and_then(|value| Self::from_str(value, false).ok()) {
+                output = found_output;
+            }
+        }
+
+        output
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use clap::{ValueEnum, builder::PossibleValue};
+    use test_case::test_case;
+
+    use crate::commands::shared::output_option::OutputOption;
+
+    #[test]
+    fn provide_all_possible_argument_values() {
+        // Act
+        let value_variants = OutputOption::value_variants();
+
+        // Assert
+        assert_eq!(
+            value_variants,
+            [OutputOption::Json, OutputOption::Yaml, OutputOption::Table]
+        );
+    }
+
+    #[test_case(OutputOption::Json => Some(PossibleValue::new("json")); "when output is json")]
+    #[test_case(OutputOption::Yaml => Some(PossibleValue::new("yaml")); "when output is yaml")]
+    #[test_case(OutputOption::Table => Some(PossibleValue::new("table")); "when output is table")]
+    fn provide_the_canonical_argument_value(output: OutputOption) -> Option<PossibleValue> {
+        // Act
+        output.to_possible_value()
+    }
+
+    #[test_case(&["info", "--output", "json", "--unknown"] => OutputOption::Json; "when given as a separate value")]
+    #[test_case(&["info", "--output=yaml", "--unknown"] => OutputOption::Yaml; "when given with an equal sign")]
+    #[test_case(&["info", "-ojson", "--unknown"] => OutputOption::Json; "when given as a short option")]
+    #[test_case(&["info", "-o", "yaml", "--output", "json"] => OutputOption::Json; "when given several times")]
+    #[test_case(&["info", "--output", "xml"] => OutputOption::Table; "when the value is invalid")]
+    #[test_case(&["info", "--unknown"] => OutputOption::Table; "when not given")]
+    fn find_the_output_in_the_arguments(args: &[&str]) -> OutputOption {
+        // Arrange
+        let args = args
+            .iter()
+            .map(|arg| String::from(*arg))
+            .collect::<Vec<_>>();
+
+        // Act
+        OutputOption::find_in(&args)
+    }
+}
@@ -0,0 +1,97 @@
+use clap::{ValueEnum, builder::PossibleValue};
+
+/// An enum representing the speakers backends which can be selected on the current platform.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SpeakersBackendOption {
+    /// Corresponds to the simulated machine.
+    InMemory,
+    /// Corresponds to the Core Audio API.
+    #[cfg(target_os = "windows")]
+    Windows,
+    /// Corresponds to PulseAudio.
+    #[cfg(target_os = "linux")]
+    PulseAudio,
+    /// Corresponds to PipeWire, through its PulseAudio compatibility layer.
+    #[cfg(target_os = "linux")]
+    PipeWire,
+}
+
+impl ValueEnum for SpeakersBackendOption {
+    fn value_variants<'a>() -> &'a [Self] {
+        &[
+            Self::InMemory,
+            #[cfg(target_os = "windows")]
+            Self::Windows,
+            #[cfg(target_os = "linux")]
+            Self::PulseAudio,
+            #[cfg(target_os = "linux")]
+            Self::PipeWire,
+        ]
+    }
+
+    fn to_possible_value(&self) -> Option<PossibleValue> {
+        let canonical_argument_value = match self {
+            Self::InMemory => PossibleValue::new("in-memory"),
+            #[cfg(target_os = "windows")]
+            Self::Windows => PossibleValue::new("windows"),
+            #[cfg(target_os = "linux")]
+            Self::PulseAudio => PossibleValue::new("pulseaudio"),
+            #[cfg(target_os = "linux")]
+            Self::PipeWire => PossibleValue::new("pipewire"),
+        };
+
+        Some(canonical_argument_value)
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use clap::{ValueEnum, builder::PossibleValue};
+    use test_case::test_case;
+
+    use crate::commands::shared::speakers_backend_option::SpeakersBackendOption;
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn provide_all_possible_argument_values() {
+        // Act
+        let value_variants = SpeakersBackendOption::value_variants();
+
+        // Assert
+        assert_eq!(
+            value_variants,
+            [
+                SpeakersBackendOption::InMemory,
+                SpeakersBackendOption::PulseAudio,
+                SpeakersBackendOption::PipeWire
+            ]
+        );
+    }
+
+    #[cfg(target_os = "windows")]
+    #[test]
+    fn provide_all_possible_argument_values() {
+        // Act
+        let value_variants = SpeakersBackendOption::value_variants();
+
+        // Assert
+        assert_eq!(
+            value_variants,
+            [
+                SpeakersBackendOption::InMemory,
+                SpeakersBackendOption::Windows
+            ]
+        );
+    }
+
+    #[test_case(SpeakersBackendOption::InMemory => Some(PossibleValue::new("in-memory")); "when backend is in-memory")]
+    #[cfg_attr(target_os = "windows", test_case(SpeakersBackendOption::Windows => Some(PossibleValue::new("windows")); "when backend is windows"))]
+    #[cfg_attr(target_os = "linux", test_case(SpeakersBackendOption::PulseAudio => Some(PossibleValue::new("pulseaudio")); "when backend is pulseaudio"))]
+    #[cfg_attr(target_os = "linux", test_case(SpeakersBackendOption::PipeWire => Some(PossibleValue::new("pipewire")); "when backend is pipewire"))]
+    fn provide_the_canonical_argument_value(
+        speakers_backend: SpeakersBackendOption,
+    ) -> Option<PossibleValue> {
+        // Act
+        speakers_backend.to_possible_value()
+    }
+}
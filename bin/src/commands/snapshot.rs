@@ -0,0 +1,21 @@
+use clap::Subcommand;
+
+use crate::commands::shared::SharedOptions;
+
+#[derive(Debug, Subcommand)]
+pub enum SnapshotCommands {
+    /// Save the current displays configuration and default speakers under a name
+    Save {
+        /// The name to save the snapshot under, overwriting any snapshot of the same name
+        snapshot_name: String,
+        #[command(flatten)]
+        shared: SharedOptions,
+    },
+    /// Restore the displays configuration and default speakers saved under a name
+    Restore {
+        /// The name of a snapshot previously saved
+        snapshot_name: String,
+        #[command(flatten)]
+        shared: SharedOptions,
+    },
+}
@@ -0,0 +1,121 @@
+use convertible_couch_lib::{application_error::ApplicationError, trace_fn};
+
+/// Returned for the errors which fit none of the other codes.
+pub const FAILURE_EXIT_CODE: u8 = 1;
+/// Returned by clap for the invalid arguments.
+pub const USAGE_EXIT_CODE: u8 = 2;
+pub const DEVICE_NOT_FOUND_EXIT_CODE: u8 = 3;
+pub const PLATFORM_CALL_EXIT_CODE: u8 = 4;
+pub const CONVERSION_EXIT_CODE: u8 = 5;
+pub const CONFIG_EXIT_CODE: u8 = 6;
+pub const LOGGER_EXIT_CODE: u8 = 7;
+/// Some of the devices were changed before the failure and stay changed.
+pub const PARTIAL_FAILURE_EXIT_CODE: u8 = 8;
+/// Some of the devices were changed before the failure and were changed back.
+pub const ROLLBACK_PERFORMED_EXIT_CODE: u8 = 9;
+pub const SNAPSHOT_EXIT_CODE: u8 = 10;
+/// A change was accepted by the platform but the devices did not end up changed.
+pub const VERIFICATION_FAILED_EXIT_CODE: u8 = 11;
+pub const UNSUPPORTED_MODE_EXIT_CODE: u8 = 12;
+pub const UNSUPPORTED_SETTING_EXIT_CODE: u8 = 13;
+pub const AMBIGUOUS_DEVICE_EXIT_CODE: u8 = 14;
+pub const MISSING_ARGUMENT_EXIT_CODE: u8 = 15;
+pub const INVALID_TOPOLOGY_EXIT_CODE: u8 = 16;
+pub const BACKEND_NOT_DETECTED_EXIT_CODE: u8 = 17;
+
+/// Gives every kind of error its own exit code, so that scripts can react to a device being
+/// unplugged differently than to the platform failing. The code 2 is left to the usage errors
+/// reported by clap. The table is documented in the README.
+pub fn map_to_exit_code(error: &ApplicationError) -> u8 {
+    trace_fn!();
+
+    match error {
+        ApplicationError::Custom(_) => FAILURE_EXIT_CODE,
+        ApplicationError::DeviceNotFound { .. } => DEVICE_NOT_FOUND_EXIT_CODE,
+        ApplicationError::AmbiguousDevice { .. } => AMBIGUOUS_DEVICE_EXIT_CODE,
+        ApplicationError::PlatformCall { .. } => PLATFORM_CALL_EXIT_CODE,
+        ApplicationError::Conversion { .. } => CONVERSION_EXIT_CODE,
+        ApplicationError::Config { .. } => CONFIG_EXIT_CODE,
+        ApplicationError::Logger { .. } => LOGGER_EXIT_CODE,
+        ApplicationError::Snapshot { .. } => SNAPSHOT_EXIT_CODE,
+        ApplicationError::PartialFailure { .. } | ApplicationError::RollbackFailed { .. } => {
+            PARTIAL_FAILURE_EXIT_CODE
+        }
+        ApplicationError::RollbackPerformed { .. } => ROLLBACK_PERFORMED_EXIT_CODE,
+        ApplicationError::VerificationFailed { .. } => VERIFICATION_FAILED_EXIT_CODE,
+        ApplicationError::UnsupportedMode { .. } => UNSUPPORTED_MODE_EXIT_CODE,
+        ApplicationError::UnsupportedSetting { .. } => UNSUPPORTED_SETTING_EXIT_CODE,
+        ApplicationError::MissingArgument { .. } => MISSING_ARGUMENT_EXIT_CODE,
+        ApplicationError::InvalidTopology { .. } => INVALID_TOPOLOGY_EXIT_CODE,
+        ApplicationError::BackendNotDetected { .. } => BACKEND_NOT_DETECTED_EXIT_CODE,
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use convertible_couch_lib::application_error::{
+        ApplicationError, DeviceKind, DeviceRole, VerificationMismatch,
+    };
+    use test_case::test_case;
+
+    use crate::exit_code::{
+        AMBIGUOUS_DEVICE_EXIT_CODE, BACKEND_NOT_DETECTED_EXIT_CODE, CONFIG_EXIT_CODE,
+        CONVERSION_EXIT_CODE, DEVICE_NOT_FOUND_EXIT_CODE, FAILURE_EXIT_CODE,
+        INVALID_TOPOLOGY_EXIT_CODE, LOGGER_EXIT_CODE, MISSING_ARGUMENT_EXIT_CODE,
+        PARTIAL_FAILURE_EXIT_CODE, PLATFORM_CALL_EXIT_CODE, ROLLBACK_PERFORMED_EXIT_CODE,
+        SNAPSHOT_EXIT_CODE, UNSUPPORTED_MODE_EXIT_CODE, UNSUPPORTED_SETTING_EXIT_CODE,
+        USAGE_EXIT_CODE, VERIFICATION_FAILED_EXIT_CODE, map_to_exit_code,
+    };
+
+    const README: &str = include_str!("../../README.md");
+
+    #[test_case(ApplicationError::Custom(String::from("Something wrong happened")) => 1; "when the error is custom")]
+    #[test_case(ApplicationError::device_not_found(DeviceKind::Display, DeviceRole::Couch, &["Epson EF-12"], vec![String::from("LG TV SSCR2")]) => 3; "when a device is not found")]
+    #[test_case(ApplicationError::PlatformCall { api: String::from("xrandr"), code: Some(1), message: String::from("Can't open display"), source: None } => 4; "when a platform call fails")]
+    #[test_case(ApplicationError::Conversion { message: String::from("Failed to convert an int"), source: None } => 5; "when a conversion fails")]
+    #[test_case(ApplicationError::Config { message: String::from("Failed to parse the configuration"), source: None } => 6; "when the configuration is invalid")]
+    #[test_case(ApplicationError::Logger { message: String::from("Failed to configure the logger"), source: None } => 7; "when the logger cannot be configured")]
+    #[test_case(ApplicationError::partial_failure(DeviceKind::Display, DeviceKind::Speaker, ApplicationError::Custom(String::from("Something wrong happened"))) => 8; "when a change is partially applied")]
+    #[test_case(ApplicationError::rollback_performed(DeviceKind::Display, DeviceKind::Speaker, ApplicationError::Custom(String::from("Something wrong happened"))) => 9; "when a change is rolled back")]
+    #[test_case(ApplicationError::rollback_failed(DeviceKind::Display, DeviceKind::Speaker, ApplicationError::Custom(String::from("Something wrong happened")), ApplicationError::Custom(String::from("Something else wrong happened"))) => 8; "when a change cannot be rolled back")]
+    #[test_case(ApplicationError::Snapshot { message: String::from("Snapshot couch does not exist"), source: None } => 10; "when a snapshot cannot be saved or restored")]
+    #[test_case(ApplicationError::VerificationFailed { kind: DeviceKind::Display, mismatches: vec![VerificationMismatch::device(DeviceKind::Display, "LG TV SSCR2", Some("DELL U2723QE"))], attempts: 3 } => 11; "when a change is not applied")]
+    #[test_case(ApplicationError::UnsupportedMode { display: String::from("LG TV SSCR2"), requested: String::from("3840x2160@144"), supported: vec![String::from("3840x2160@60")] } => 12; "when a mode is not supported")]
+    #[test_case(ApplicationError::UnsupportedSetting { display: String::from("LG TV SSCR2"), setting: String::from("scale") } => 13; "when a setting is not supported")]
+    #[test_case(ApplicationError::AmbiguousDevice { kind: DeviceKind::Display, role: DeviceRole::Couch, selector: String::from("glob:LG*"), candidates: vec![String::from("LG TV SSCR2"), String::from("LG TV SSCR2 (2)")] } => 14; "when a selector is ambiguous")]
+    #[test_case(ApplicationError::MissingArgument { message: String::from("The display to keep turned on is required") } => 15; "when an argument is missing")]
+    #[test_case(ApplicationError::InvalidTopology { message: String::from("There is no internal display to turn on") } => 16; "when a topology is invalid")]
+    #[test_case(ApplicationError::BackendNotDetected { kind: DeviceKind::Display } => 17; "when no backend is detected")]
+    fn map_every_kind_of_error_to_its_own_exit_code(error: ApplicationError) -> u8 {
+        // Act
+        map_to_exit_code(&error)
+    }
+
+    #[test_case(FAILURE_EXIT_CODE; "when the error is custom")]
+    #[test_case(USAGE_EXIT_CODE; "when the arguments are invalid")]
+    #[test_case(DEVICE_NOT_FOUND_EXIT_CODE; "when a device is not found")]
+    #[test_case(PLATFORM_CALL_EXIT_CODE; "when a platform call fails")]
+    #[test_case(CONVERSION_EXIT_CODE; "when a conversion fails")]
+    #[test_case(CONFIG_EXIT_CODE; "when the configuration is invalid")]
+    #[test_case(LOGGER_EXIT_CODE; "when the logger cannot be configured")]
+    #[test_case(PARTIAL_FAILURE_EXIT_CODE; "when a change is partially applied")]
+    #[test_case(ROLLBACK_PERFORMED_EXIT_CODE; "when a change is rolled back")]
+    #[test_case(SNAPSHOT_EXIT_CODE; "when a snapshot cannot be saved or restored")]
+    #[test_case(VERIFICATION_FAILED_EXIT_CODE; "when a change is not applied")]
+    #[test_case(UNSUPPORTED_MODE_EXIT_CODE; "when a mode is not supported")]
+    #[test_case(UNSUPPORTED_SETTING_EXIT_CODE; "when a setting is not supported")]
+    #[test_case(AMBIGUOUS_DEVICE_EXIT_CODE; "when a selector is ambiguous")]
+    #[test_case(MISSING_ARGUMENT_EXIT_CODE; "when an argument is missing")]
+    #[test_case(INVALID_TOPOLOGY_EXIT_CODE; "when a topology is invalid")]
+    #[test_case(BACKEND_NOT_DETECTED_EXIT_CODE; "when no backend is detected")]
+    fn document_every_exit_code_in_the_readme(exit_code: u8) {
+        // Arrange
+        let row_prefix = format!("| {exit_code} | ");
+
+        // Act
+        let is_documented = README.lines().any(|line| line.starts_with(&row_prefix));
+
+        // Assert
+        assert!(is_documented, "Exit code {exit_code} is not in the README");
+    }
+}
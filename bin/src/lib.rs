@@ -1,2 +1,4 @@
 pub mod application;
 pub mod commands;
+pub mod exit_code;
+pub mod output;
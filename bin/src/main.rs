@@ -1,135 +1,88 @@
 use clap::Parser;
 use convertible_couch::{
-    application::{Application, ApplicationChangeResult, ApplicationInfoResult, CommandResult},
-    commands::Arguments,
+    application::{Application, map_to_displays_backend, map_to_speakers_backend},
+    commands::{Arguments, shared::output_option::OutputOption},
+    exit_code::{USAGE_EXIT_CODE, map_to_exit_code},
+    output::{format_command_result, format_error, format_usage_error},
 };
 use convertible_couch_lib::{
-    displays_settings::{CurrentDisplaysSettingsApi, DisplayInfo, DisplaysSettingsResult},
-    speakers_settings::{CurrentSpeakersSettingsApi, SpeakerInfo, SpeakersSettingsResult},
+    application_error::ApplicationError,
+    backends::{
+        Backend, BackendsInfos, displays_backend::DisplaysBackend, get_backends_infos,
+        speakers_backend::SpeakersBackend, std_based_environment_api::StdBasedEnvironmentApi,
+    },
+    configuration::Configuration,
+    snapshot::{SnapshotStore, get_default_snapshots_directory},
 };
-use std::process::ExitCode;
+use std::{env, process::ExitCode};
 
 fn main() -> ExitCode {
-    let args = Arguments::parse();
+    let args = match Arguments::try_parse() {
+        Ok(args) => args,
+        Err(error) => return report_usage_error(error),
+    };
+    let shared = args.command.shared();
+    let environment_api = StdBasedEnvironmentApi;
 
-    let displays_settings_api = Box::new(CurrentDisplaysSettingsApi);
-    let speakers_settings_api = Box::new(CurrentSpeakersSettingsApi);
-    let mut application = Application::bootstrap(displays_settings_api, speakers_settings_api);
+    let detected_displays_backend = DisplaysBackend::detect(&environment_api);
+    let displays_backend = match (&shared.displays_backend, &detected_displays_backend) {
+        (Some(displays_backend), _) => map_to_displays_backend(displays_backend),
+        (None, Ok(displays_backend)) => *displays_backend,
+        (None, Err(error)) => return report_error(error, &shared.output),
+    };
+    let detected_speakers_backend = SpeakersBackend::detect(&environment_api);
+    let speakers_backend = match (&shared.speakers_backend, &detected_speakers_backend) {
+        (Some(speakers_backend), _) => map_to_speakers_backend(speakers_backend),
+        (None, Ok(speakers_backend)) => *speakers_backend,
+        (None, Err(error)) => return report_error(error, &shared.output),
+    };
 
-    let application_result = application.execute(&args);
+    let backends_infos = BackendsInfos {
+        displays_backends: get_backends_infos(detected_displays_backend.ok(), displays_backend),
+        speakers_backends: get_backends_infos(detected_speakers_backend.ok(), speakers_backend),
+    };
+    let configuration = match Configuration::load(shared.config.as_deref(), &environment_api) {
+        Ok(configuration) => configuration,
+        Err(error) => return report_error(&error, &shared.output),
+    };
+
+    let mut application = Application::bootstrap(
+        displays_backend.create_settings(),
+        speakers_backend.create_settings(),
+        backends_infos,
+        configuration,
+        SnapshotStore::new(get_default_snapshots_directory(&environment_api)),
+    );
+
+    let application_result = application
+        .execute(&args)
+        .and_then(|result| format_command_result(&result, &shared.output));
 
     match application_result {
-        Ok(result) => {
-            match result {
-                CommandResult::Change(application_change_result) => match application_change_result
-                {
-                    ApplicationChangeResult::DisplaysAndSpeakers {
-                        displays_result,
-                        speakers_result,
-                    } => {
-                        log_change_displays_settings_result(displays_result);
-                        log_change_speakers_settings_result(speakers_result);
-                    }
-                    ApplicationChangeResult::DisplaysOnly { displays_result } => {
-                        log_change_displays_settings_result(displays_result)
-                    }
-                    ApplicationChangeResult::SpeakersOnly { speakers_result } => {
-                        log_change_speakers_settings_result(speakers_result)
-                    }
-                },
-                CommandResult::Info(application_info_result) => match application_info_result {
-                    ApplicationInfoResult::DisplaysAndSpeakers {
-                        displays_result,
-                        speakers_result,
-                    } => {
-                        log_info_displays_settings_result(displays_result);
-                        log_info_speakers_settings_result(speakers_result);
-                    }
-                    ApplicationInfoResult::DisplaysOnly { displays_result } => {
-                        log_info_displays_settings_result(displays_result)
-                    }
-                    ApplicationInfoResult::SpeakersOnly { speakers_result } => {
-                        log_info_speakers_settings_result(speakers_result)
-                    }
-                },
-            }
+        Ok(formatted_result) => {
+            print!("{formatted_result}");
 
             ExitCode::SUCCESS
         }
-        Err(error) => {
-            eprintln!("{error}");
-
-            ExitCode::FAILURE
-        }
+        Err(error) => report_error(&error, &shared.output),
     }
 }
 
-fn log_change_speakers_settings_result(speakers_result: SpeakersSettingsResult) {
-    println!(
-        "Default speaker set to {0}",
-        speakers_result.new_default_speaker
-    );
-}
-
-fn log_change_displays_settings_result(displays_result: DisplaysSettingsResult) {
-    println!(
-        "Primary display set to {}",
-        displays_result.new_primary_display
-    );
-}
+/// Help and version requests, as well as usage errors printed as tables, are left to clap.
+fn report_usage_error(error: clap::Error) -> ExitCode {
+    let output = OutputOption::find_in(&env::args().collect::<Vec<String>>());
 
-fn log_info_displays_settings_result(displays_result: Vec<DisplayInfo>) {
-    let primary_column_name = "PRIMARY";
-    let name_column_name = "NAME";
-    let column_separator = "   ";
-    let primary_column_width = primary_column_name.len();
-    let max_name_width = displays_result
-        .iter()
-        .map(|r| r.name.len())
-        .max()
-        .unwrap_or(name_column_name.len());
-    let table_width = primary_column_width + column_separator.len() + max_name_width;
+    if !error.use_stderr() || output == OutputOption::Table {
+        error.exit();
+    }
 
-    println!("Displays");
-    println!("{primary_column_name}{column_separator}{name_column_name}");
-    println!("{}", "-".repeat(table_width));
+    eprint!("{}", format_usage_error(&error, &output));
 
-    for display_result in displays_result {
-        println!(
-            "{:<primary_column_width$}{column_separator}{:<name_column_width$}",
-            display_result.is_primary,
-            display_result.name,
-            primary_column_width = primary_column_width,
-            name_column_width = max_name_width
-        )
-    }
-    println!();
+    ExitCode::from(USAGE_EXIT_CODE)
 }
 
-fn log_info_speakers_settings_result(speakers_result: Vec<SpeakerInfo>) {
-    let default_column_name = "DEFAULT";
-    let name_column_name = "NAME";
-    let column_separator = "   ";
-    let default_column_width = default_column_name.len();
-    let max_name_width = speakers_result
-        .iter()
-        .map(|r| r.name.len())
-        .max()
-        .unwrap_or(name_column_name.len());
-    let table_width = default_column_width + column_separator.len() + max_name_width;
-
-    println!("Speakers");
-    println!("{default_column_name}{column_separator}{name_column_name}");
-    println!("{}", "-".repeat(table_width));
+fn report_error(error: &ApplicationError, output: &OutputOption) -> ExitCode {
+    eprint!("{}", format_error(error, output));
 
-    for speaker_result in speakers_result {
-        println!(
-            "{:<default_column_width$}{column_separator}{:<name_column_width$}",
-            speaker_result.is_default,
-            speaker_result.name,
-            default_column_width = default_column_width,
-            name_column_width = max_name_width
-        )
-    }
-    println!();
+    ExitCode::from(map_to_exit_code(error))
 }
@@ -0,0 +1,1109 @@
+use std::fmt::Write;
+
+use convertible_couch_lib::{
+    application_error::{ApplicationError, VerificationMismatch},
+    application_result::ApplicationResult,
+    backends::BackendInfo,
+    displays_settings::{
+        DisplayAdvancedColor, DisplayConnector, DisplayInfo, DisplayMode, DisplayRotation,
+        DisplaysPlan, DisplaysTopologyResult,
+    },
+    speakers_settings::{SpeakerFormFactor, SpeakerInfo, SpeakerRole, SpeakerState, SpeakersPlan},
+    trace_fn,
+};
+use serde::Serialize;
+
+use crate::{
+    application::{
+        ApplicationChangeResult, ApplicationInfoResult, ApplicationPlanResult,
+        ApplicationSnapshotResult, CommandResult,
+    },
+    commands::shared::output_option::OutputOption,
+    exit_code::{USAGE_EXIT_CODE, map_to_exit_code},
+};
+
+/// Bumped whenever a field of the JSON and YAML documents is renamed or removed, so that scripts
+/// can detect a breaking change instead of silently misreading the output.
+pub const OUTPUT_SCHEMA_VERSION: u32 = 1;
+
+#[derive(Serialize)]
+struct ResultDocument<'a> {
+    schema_version: u32,
+    #[serde(flatten)]
+    result: &'a CommandResult,
+}
+
+#[derive(Serialize)]
+struct ErrorDocument {
+    schema_version: u32,
+    error: ErrorObject,
+}
+
+/// The fields of the error which scripts can react to without parsing the message, the ones the
+/// error does not carry being left out.
+#[derive(Serialize)]
+struct ErrorObject {
+    kind: &'static str,
+    exit_code: u8,
+    message: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    requested: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    candidates: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    suggestions: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    mismatches: Vec<VerificationMismatch>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    api: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<i32>,
+}
+
+pub fn format_command_result(
+    result: &CommandResult,
+    output: &OutputOption,
+) -> ApplicationResult<String> {
+    trace_fn!();
+
+    let document = ResultDocument {
+        schema_version: OUTPUT_SCHEMA_VERSION,
+        result,
+    };
+
+    match output {
+        OutputOption::Json => to_json(&document),
+        OutputOption::Yaml => to_yaml(&document),
+        OutputOption::Table => Ok(format_command_result_as_table(result)),
+    }
+}
+
+/// Falls back on the bare message when the error itself cannot be serialized, since there is
+/// nothing more to report at that point.
+pub fn format_error(error: &ApplicationError, output: &OutputOption) -> String {
+    trace_fn!();
+
+    let mut error_object = ErrorObject {
+        kind: map_to_error_kind(error),
+        exit_code: map_to_exit_code(error),
+        message: error.to_string(),
+        requested: Vec::new(),
+        candidates: Vec::new(),
+        suggestions: Vec::new(),
+        mismatches: Vec::new(),
+        api: None,
+        code: None,
+    };
+
+    match error {
+        ApplicationError::DeviceNotFound {
+            requested,
+            candidates,
+            suggestions,
+            ..
+        } => {
+            error_object.requested = requested.clone();
+            error_object.candidates = candidates.clone();
+            error_object.suggestions = suggestions.clone();
+        }
+        ApplicationError::AmbiguousDevice {
+            selector,
+            candidates,
+            ..
+        } => {
+            error_object.requested = vec![selector.clone()];
+            error_object.candidates = candidates.clone();
+        }
+        ApplicationError::UnsupportedMode {
+            requested,
+            supported,
+            ..
+        } => {
+            error_object.requested = vec![requested.clone()];
+            error_object.candidates = supported.clone();
+        }
+        ApplicationError::VerificationFailed { mismatches, .. } => {
+            error_object.mismatches = mismatches.clone();
+        }
+        ApplicationError::PlatformCall { api, code, .. } => {
+            error_object.api = Some(api.clone());
+            error_object.code = *code;
+        }
+        _ => {}
+    }
+
+    format_error_object(error_object, output)
+}
+
+/// Prints the usage errors of clap as documents too when one is asked for, the table output
+/// keeping the error as clap renders it.
+pub fn format_usage_error(error: &clap::Error, output: &OutputOption) -> String {
+    trace_fn!();
+
+    let error_object = ErrorObject {
+        kind: "usage",
+        exit_code: USAGE_EXIT_CODE,
+        message: error.to_string().trim_end().to_string(),
+        requested: Vec::new(),
+        candidates: Vec::new(),
+        suggestions: Vec::new(),
+        mismatches: Vec::new(),
+        api: None,
+        code: None,
+    };
+
+    format_error_object(error_object, output)
+}
+
+fn format_error_object(error_object: ErrorObject, output: &OutputOption) -> String {
+    trace_fn!();
+
+    let message = format!("{}\n", error_object.message);
+    let document = ErrorDocument {
+        schema_version: OUTPUT_SCHEMA_VERSION,
+        error: error_object,
+    };
+
+    let formatted_error = match output {
+        OutputOption::Json => to_json(&document),
+        OutputOption::Yaml => to_yaml(&document),
+        OutputOption::Table => Ok(message.clone()),
+    };
+
+    formatted_error.unwrap_or(message)
+}
+
+fn map_to_error_kind(error: &ApplicationError) -> &'static str {
+    match error {
+        ApplicationError::Custom(_) => "custom",
+        ApplicationError::DeviceNotFound { .. } => "device_not_found",
+        ApplicationError::AmbiguousDevice { .. } => "ambiguous_device",
+        ApplicationError::PlatformCall { .. } => "platform_call",
+        ApplicationError::Conversion { .. } => "conversion",
+        ApplicationError::Config { .. } => "config",
+        ApplicationError::Logger { .. } => "logger",
+        ApplicationError::Snapshot { .. } => "snapshot",
+        ApplicationError::PartialFailure { .. } => "partial_failure",
+        ApplicationError::RollbackPerformed { .. } => "rollback_performed",
+        ApplicationError::RollbackFailed { .. } => "rollback_failed",
+        ApplicationError::UnsupportedMode { .. } => "unsupported_mode",
+        ApplicationError::UnsupportedSetting { .. } => "unsupported_setting",
+        ApplicationError::MissingArgument { .. } => "missing_argument",
+        ApplicationError::InvalidTopology { .. } => "invalid_topology",
+        ApplicationError::VerificationFailed { .. } => "verification_failed",
+        ApplicationError::BackendNotDetected { .. } => "backend_not_detected",
+    }
+}
+
+fn to_json<T: Serialize>(document: &T) -> ApplicationResult<String> {
+    trace_fn!();
+
+    serde_json::to_string_pretty(document)
+        .map(|json| format!("{json}\n"))
+        .map_err(|error| {
+            ApplicationError::Custom(format!("Failed to serialize the output to JSON: {error}"))
+        })
+}
+
+fn to_yaml<T: Serialize>(document: &T) -> ApplicationResult<String> {
+    trace_fn!();
+
+    serde_yaml_ng::to_string(document).map_err(|error| {
+        ApplicationError::Custom(format!("Failed to serialize the output to YAML: {error}"))
+    })
+}
+
+fn format_command_result_as_table(result: &CommandResult) -> String {
+    trace_fn!();
+
+    match result {
+        CommandResult::Change(application_change_result) => match application_change_result {
+            ApplicationChangeResult::DisplaysAndSpeakers {
+                displays_result,
+                speakers_result,
+            } => format!(
+                "Primary display set to {}\nDefault speaker set to {}\n",
+                format_new_primary_display(
+                    &displays_result.new_primary_display,
+                    displays_result.new_mode,
+                    displays_result.new_rotation,
+                    displays_result.new_scale_percent,
+                    displays_result.new_hdr_enabled
+                ),
+                speakers_result.new_default_speaker
+            ),
+            ApplicationChangeResult::DisplaysOnly { displays_result } => format!(
+                "Primary display set to {}\n",
+                format_new_primary_display(
+                    &displays_result.new_primary_display,
+                    displays_result.new_mode,
+                    displays_result.new_rotation,
+                    displays_result.new_scale_percent,
+                    displays_result.new_hdr_enabled
+                )
+            ),
+            ApplicationChangeResult::SpeakersOnly { speakers_result } => format!(
+                "Default speaker set to {}\n",
+                speakers_result.new_default_speaker
+            ),
+            ApplicationChangeResult::Topology { topology_result } => format!(
+                "Active displays set to {}\n",
+                format_active_displays(topology_result)
+            ),
+        },
+        CommandResult::Info(application_info_result) => match application_info_result {
+            ApplicationInfoResult::DisplaysAndSpeakers {
+                displays_result,
+                speakers_result,
+            } => format!(
+                "{}{}",
+                format_displays_table(displays_result),
+                format_speakers_table(speakers_result)
+            ),
+            ApplicationInfoResult::DisplaysOnly { displays_result } => {
+                format_displays_table(displays_result)
+            }
+            ApplicationInfoResult::SpeakersOnly { speakers_result } => {
+                format_speakers_table(speakers_result)
+            }
+            ApplicationInfoResult::Backends { backends_result } => format!(
+                "{}{}",
+                format_backends_table("Displays backends", &backends_result.displays_backends),
+                format_backends_table("Speakers backends", &backends_result.speakers_backends)
+            ),
+        },
+        CommandResult::Snapshot(application_snapshot_result) => match application_snapshot_result {
+            ApplicationSnapshotResult::Saved { snapshot_name } => {
+                format!("Snapshot {snapshot_name} saved\n")
+            }
+            ApplicationSnapshotResult::Restored { snapshot_name } => {
+                format!("Snapshot {snapshot_name} restored\n")
+            }
+        },
+        CommandResult::Plan(application_plan_result) => match application_plan_result {
+            ApplicationPlanResult::DisplaysAndSpeakers {
+                displays_plan,
+                speakers_plan,
+            } => format!(
+                "{}{}",
+                format_displays_plan(displays_plan),
+                format_speakers_plan(speakers_plan)
+            ),
+            ApplicationPlanResult::DisplaysOnly { displays_plan } => {
+                format_displays_plan(displays_plan)
+            }
+            ApplicationPlanResult::SpeakersOnly { speakers_plan } => {
+                format_speakers_plan(speakers_plan)
+            }
+            ApplicationPlanResult::Topology { topology_plan } => format!(
+                "Active displays would be set to {}\n",
+                format_active_displays(topology_plan)
+            ),
+        },
+    }
+}
+
+fn format_displays_plan(displays_plan: &DisplaysPlan) -> String {
+    trace_fn!();
+
+    format!(
+        "Primary display would be set to {}\n\n{}",
+        format_new_primary_display(
+            &displays_plan.new_primary_display,
+            displays_plan.new_mode,
+            displays_plan.new_rotation,
+            displays_plan.new_scale_percent,
+            displays_plan.new_hdr_enabled
+        ),
+        format_displays_table(&displays_plan.displays)
+    )
+}
+
+fn format_new_primary_display(
+    new_primary_display: &str,
+    new_mode: Option<DisplayMode>,
+    new_rotation: Option<DisplayRotation>,
+    new_scale_percent: Option<u32>,
+    new_hdr_enabled: Option<bool>,
+) -> String {
+    trace_fn!();
+
+    let new_settings = [
+        new_mode.map(|new_mode| format!("at {new_mode}")),
+        new_rotation.map(|new_rotation| format!("rotated {}°", u16::from(new_rotation))),
+        new_scale_percent.map(|new_scale_percent| format!("scaled {new_scale_percent}%")),
+        new_hdr_enabled.map(|new_hdr_enabled| format!("HDR {}", format_on_off(new_hdr_enabled))),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<String>>();
+
+    if new_settings.is_empty() {
+        return String::from(new_primary_display);
+    }
+
+    format!("{new_primary_display} {}", new_settings.join(", "))
+}
+
+fn format_speakers_plan(speakers_plan: &SpeakersPlan) -> String {
+    trace_fn!();
+
+    format!(
+        "Default speaker would be set to {} for {}\n\n{}",
+        speakers_plan.new_default_speaker,
+        format_speaker_roles(&speakers_plan.roles),
+        format_speakers_table(&speakers_plan.speakers)
+    )
+}
+
+fn format_active_displays(topology_result: &DisplaysTopologyResult) -> String {
+    trace_fn!();
+
+    let active_displays = topology_result.active_displays.join(", ");
+
+    if topology_result.is_cloned {
+        format!("{active_displays} (cloned)")
+    } else {
+        active_displays
+    }
+}
+
+fn format_speaker_roles(roles: &[SpeakerRole]) -> String {
+    trace_fn!();
+
+    if roles.contains(&SpeakerRole::All) {
+        return String::from("every role");
+    }
+
+    let role_names = roles
+        .iter()
+        .map(|role| format_speaker_role(*role))
+        .collect::<Vec<&str>>();
+
+    match role_names.as_slice() {
+        [role_name] => format!("the {role_name} role"),
+        _ => format!("the {} roles", role_names.join(", ")),
+    }
+}
+
+fn format_displays_table(displays_result: &[DisplayInfo]) -> String {
+    trace_fn!();
+
+    let rows = displays_result
+        .iter()
+        .map(|display_result| {
+            vec![
+                display_result.is_primary.to_string(),
+                display_result.name.clone(),
+                format!(
+                    "{}x{}",
+                    display_result.resolution.width, display_result.resolution.height
+                ),
+                display_result
+                    .refresh_rate_millihertz
+                    .map_or_else(not_available, |refresh_rate| {
+                        format!("{:.2} Hz", f64::from(refresh_rate) / 1000.0)
+                    }),
+                format!(
+                    "({}, {})",
+                    display_result.position.x, display_result.position.y
+                ),
+                format!("{}°", u16::from(display_result.rotation)),
+                display_result
+                    .scale_percent
+                    .map_or_else(not_available, |scale| format!("{scale}%")),
+                display_result
+                    .advanced_color
+                    .map_or_else(not_available, format_hdr),
+                String::from(format_connector(display_result.connector)),
+                display_result.id.clone(),
+            ]
+        })
+        .collect::<Vec<Vec<String>>>();
+
+    format_table(
+        "Displays",
+        &[
+            "PRIMARY",
+            "NAME",
+            "RESOLUTION",
+            "REFRESH",
+            "POSITION",
+            "ROTATION",
+            "SCALE",
+            "HDR",
+            "CONNECTOR",
+            "ID",
+        ],
+        &rows,
+    )
+}
+
+fn format_speakers_table(speakers_result: &[SpeakerInfo]) -> String {
+    trace_fn!();
+
+    let rows = speakers_result
+        .iter()
+        .map(|speaker_result| {
+            vec![
+                speaker_result.is_default.to_string(),
+                speaker_result.name.clone(),
+                String::from(format_speaker_state(speaker_result.state)),
+                String::from(format_speaker_form_factor(speaker_result.form_factor)),
+                speaker_result
+                    .channels
+                    .map_or_else(not_available, |channels| channels.to_string()),
+                speaker_result
+                    .sample_format
+                    .clone()
+                    .unwrap_or_else(not_available),
+                speaker_result
+                    .sample_rate_hertz
+                    .map_or_else(not_available, |sample_rate| format!("{sample_rate} Hz")),
+                speaker_result
+                    .volume_percent
+                    .map_or_else(not_available, |volume| format!("{volume}%")),
+                speaker_result
+                    .is_muted
+                    .map_or_else(not_available, |is_muted| is_muted.to_string()),
+                speaker_result.id.clone(),
+            ]
+        })
+        .collect::<Vec<Vec<String>>>();
+
+    format_table(
+        "Speakers",
+        &[
+            "DEFAULT",
+            "NAME",
+            "STATE",
+            "FORM FACTOR",
+            "CHANNELS",
+            "FORMAT",
+            "RATE",
+            "VOLUME",
+            "MUTED",
+            "ID",
+        ],
+        &rows,
+    )
+}
+
+fn format_backends_table(title: &str, backends_result: &[BackendInfo]) -> String {
+    trace_fn!();
+
+    let rows = backends_result
+        .iter()
+        .map(|backend_result| {
+            vec![
+                backend_result.is_detected.to_string(),
+                backend_result.is_selected.to_string(),
+                backend_result.name.clone(),
+            ]
+        })
+        .collect::<Vec<Vec<String>>>();
+
+    format_table(title, &["DETECTED", "SELECTED", "NAME"], &rows)
+}
+
+fn format_connector(connector: DisplayConnector) -> &'static str {
+    trace_fn!();
+
+    match connector {
+        DisplayConnector::Internal => "Internal",
+        DisplayConnector::Hdmi => "HDMI",
+        DisplayConnector::DisplayPort => "DisplayPort",
+        DisplayConnector::Dvi => "DVI",
+        DisplayConnector::Vga => "VGA",
+        DisplayConnector::Other => "Other",
+        DisplayConnector::Unknown => "Unknown",
+    }
+}
+
+fn format_hdr(advanced_color: DisplayAdvancedColor) -> String {
+    trace_fn!();
+
+    if !advanced_color.is_hdr_supported {
+        return String::from("unsupported");
+    }
+
+    String::from(format_on_off(advanced_color.is_hdr_enabled))
+}
+
+fn format_on_off(is_on: bool) -> &'static str {
+    trace_fn!();
+
+    if is_on { "on" } else { "off" }
+}
+
+fn format_speaker_state(state: SpeakerState) -> &'static str {
+    trace_fn!();
+
+    match state {
+        SpeakerState::Active => "Active",
+        SpeakerState::Disabled => "Disabled",
+        SpeakerState::Unplugged => "Unplugged",
+        SpeakerState::NotPresent => "Not present",
+        SpeakerState::Unknown => "Unknown",
+    }
+}
+
+fn format_speaker_role(role: SpeakerRole) -> &'static str {
+    trace_fn!();
+
+    match role {
+        SpeakerRole::All => "all",
+        SpeakerRole::Console => "console",
+        SpeakerRole::Multimedia => "multimedia",
+        SpeakerRole::Communications => "communications",
+    }
+}
+
+fn format_speaker_form_factor(form_factor: SpeakerFormFactor) -> &'static str {
+    trace_fn!();
+
+    match form_factor {
+        SpeakerFormFactor::Speakers => "Speakers",
+        SpeakerFormFactor::Headphones => "Headphones",
+        SpeakerFormFactor::Headset => "Headset",
+        SpeakerFormFactor::Hdmi => "HDMI",
+        SpeakerFormFactor::Spdif => "S/PDIF",
+        SpeakerFormFactor::LineOut => "Line out",
+        SpeakerFormFactor::Other => "Other",
+        SpeakerFormFactor::Unknown => "Unknown",
+    }
+}
+
+fn not_available() -> String {
+    trace_fn!();
+
+    String::from("-")
+}
+
+/// Every column is as wide as its header or its widest cell, the cells being left aligned and
+/// the lines trimmed.
+fn format_table(title: &str, column_names: &[&str], rows: &[Vec<String>]) -> String {
+    trace_fn!();
+
+    let column_separator = "   ";
+    let column_widths = column_names
+        .iter()
+        .enumerate()
+        .map(|(column_index, column_name)| {
+            rows.iter()
+                .map(|row| row[column_index].chars().count())
+                .chain([column_name.chars().count()])
+                .max()
+                .unwrap_or_default()
+        })
+        .collect::<Vec<usize>>();
+    let table_width = column_widths.iter().sum::<usize>()
+        + column_separator.len() * column_widths.len().saturating_sub(1);
+
+    let format_row = |cells: Vec<&str>| {
+        cells
+            .iter()
+            .zip(&column_widths)
+            .map(|(cell, column_width)| format!("{cell:<column_width$}"))
+            .collect::<Vec<String>>()
+            .join(column_separator)
+    };
+
+    let mut table = String::new();
+
+    writeln!(table, "{title}").unwrap();
+    writeln!(table, "{}", format_row(column_names.to_vec()).trim_end()).unwrap();
+    writeln!(table, "{}", "-".repeat(table_width)).unwrap();
+
+    for row in rows {
+        writeln!(
+            table,
+            "{}",
+            format_row(row.iter().map(String::as_str).collect()).trim_end()
+        )
+        .unwrap();
+    }
+    writeln!(table).unwrap();
+
+    table
+}
+
+#[cfg(test)]
+mod should {
+    use clap::error::ErrorKind;
+    use convertible_couch_lib::{
+        application_error::{ApplicationError, DeviceKind, DeviceRole},
+        displays_settings::{
+            DisplayAdvancedColor, DisplayColorEncoding, DisplayConnector, DisplayInfo, DisplayMode,
+            DisplayPosition, DisplayResolution, DisplayRotation, DisplaysPlan,
+            DisplaysSettingsResult, DisplaysTopologyResult,
+        },
+        speakers_settings::{
+            SpeakerFormFactor, SpeakerInfo, SpeakerRole, SpeakerState, SpeakersPlan,
+            SpeakersSettingsResult,
+        },
+    };
+    use test_case::test_case;
+
+    use crate::{
+        application::{
+            ApplicationChangeResult, ApplicationInfoResult, ApplicationPlanResult, CommandResult,
+        },
+        commands::shared::output_option::OutputOption,
+        output::{format_command_result, format_error, format_usage_error},
+    };
+
+    fn change_result() -> CommandResult {
+        CommandResult::Change(ApplicationChangeResult::DisplaysAndSpeakers {
+            displays_result: DisplaysSettingsResult {
+                new_primary_display: String::from("LG TV SSCR2"),
+                new_mode: None,
+                new_rotation: None,
+                new_scale_percent: None,
+                new_hdr_enabled: None,
+            },
+            speakers_result: SpeakersSettingsResult {
+                new_default_speaker: String::from("LG TV SSCR2"),
+            },
+        })
+    }
+
+    fn info_result() -> CommandResult {
+        CommandResult::Info(ApplicationInfoResult::DisplaysOnly {
+            displays_result: vec![
+                DisplayInfo {
+                    is_primary: true,
+                    name: String::from("DELL U2723QE"),
+                    id: String::from("DP-1"),
+                    connector: DisplayConnector::DisplayPort,
+                    resolution: DisplayResolution {
+                        width: 3840,
+                        height: 2160,
+                    },
+                    position: DisplayPosition { x: 0, y: 0 },
+                    refresh_rate_millihertz: Some(59940),
+                    rotation: DisplayRotation::Degrees0,
+                    scale_percent: Some(150),
+                    advanced_color: Some(DisplayAdvancedColor {
+                        is_hdr_supported: true,
+                        is_hdr_enabled: true,
+                        bits_per_color_channel: Some(10),
+                        color_encoding: DisplayColorEncoding::Rgb,
+                    }),
+                },
+                DisplayInfo {
+                    is_primary: false,
+                    name: String::from("LG TV SSCR2"),
+                    id: String::from("HDMI-A-1"),
+                    connector: DisplayConnector::Hdmi,
+                    resolution: DisplayResolution {
+                        width: 1080,
+                        height: 1920,
+                    },
+                    position: DisplayPosition { x: 2560, y: 0 },
+                    refresh_rate_millihertz: None,
+                    rotation: DisplayRotation::Degrees90,
+                    scale_percent: None,
+                    advanced_color: None,
+                },
+            ],
+        })
+    }
+
+    #[test_case(OutputOption::Json => Ok(String::from(r#"{
+  "schema_version": 1,
+  "command": "change",
+  "result": {
+    "kind": "displays_and_speakers",
+    "displays_result": {
+      "new_primary_display": "LG TV SSCR2"
+    },
+    "speakers_result": {
+      "new_default_speaker": "LG TV SSCR2"
+    }
+  }
+}
+"#)); "when output is json")]
+    #[test_case(OutputOption::Yaml => Ok(String::from(r#"schema_version: 1
+command: change
+result:
+  kind: displays_and_speakers
+  displays_result:
+    new_primary_display: LG TV SSCR2
+  speakers_result:
+    new_default_speaker: LG TV SSCR2
+"#)); "when output is yaml")]
+    #[test_case(OutputOption::Table => Ok(String::from("Primary display set to LG TV SSCR2\nDefault speaker set to LG TV SSCR2\n")); "when output is table")]
+    fn format_the_result_of_a_change(output: OutputOption) -> Result<String, ApplicationError> {
+        // Act
+        format_command_result(&change_result(), &output)
+    }
+
+    #[test_case(OutputOption::Json => Ok(String::from(r#"{
+  "schema_version": 1,
+  "command": "info",
+  "result": {
+    "kind": "displays_only",
+    "displays_result": [
+      {
+        "is_primary": true,
+        "name": "DELL U2723QE",
+        "id": "DP-1",
+        "connector": "display_port",
+        "resolution": {
+          "width": 3840,
+          "height": 2160
+        },
+        "position": {
+          "x": 0,
+          "y": 0
+        },
+        "refresh_rate_millihertz": 59940,
+        "rotation": 0,
+        "scale_percent": 150,
+        "advanced_color": {
+          "is_hdr_supported": true,
+          "is_hdr_enabled": true,
+          "bits_per_color_channel": 10,
+          "color_encoding": "rgb"
+        }
+      },
+      {
+        "is_primary": false,
+        "name": "LG TV SSCR2",
+        "id": "HDMI-A-1",
+        "connector": "hdmi",
+        "resolution": {
+          "width": 1080,
+          "height": 1920
+        },
+        "position": {
+          "x": 2560,
+          "y": 0
+        },
+        "refresh_rate_millihertz": null,
+        "rotation": 90,
+        "scale_percent": null,
+        "advanced_color": null
+      }
+    ]
+  }
+}
+"#)); "when output is json")]
+    #[test_case(OutputOption::Table => Ok(String::from("\
+Displays
+PRIMARY   NAME           RESOLUTION   REFRESH    POSITION    ROTATION   SCALE   HDR   CONNECTOR     ID
+------------------------------------------------------------------------------------------------------------
+true      DELL U2723QE   3840x2160    59.94 Hz   (0, 0)      0°         150%    on    DisplayPort   DP-1
+false     LG TV SSCR2    1080x1920    -          (2560, 0)   90°        -       -     HDMI          HDMI-A-1
+
+")); "when output is table")]
+    fn format_the_result_of_an_info(output: OutputOption) -> Result<String, ApplicationError> {
+        // Act
+        format_command_result(&info_result(), &output)
+    }
+
+    #[test]
+    fn format_the_speakers_of_an_info_as_a_table() {
+        // Arrange
+        let result = CommandResult::Info(ApplicationInfoResult::SpeakersOnly {
+            speakers_result: vec![
+                SpeakerInfo {
+                    is_default: true,
+                    name: String::from("Speakers (Realtek(R) Audio)"),
+                    id: String::from("{0.0.0.00000000}.{5e2f0c1a}"),
+                    state: SpeakerState::Active,
+                    form_factor: SpeakerFormFactor::Speakers,
+                    channels: Some(2),
+                    sample_format: Some(String::from("float32le")),
+                    sample_rate_hertz: Some(48000),
+                    volume_percent: Some(40),
+                    is_muted: Some(false),
+                },
+                SpeakerInfo {
+                    is_default: false,
+                    name: String::from("LG TV SSCR2"),
+                    id: String::from("{0.0.0.00000000}.{9b61d3e7}"),
+                    state: SpeakerState::Unplugged,
+                    form_factor: SpeakerFormFactor::Hdmi,
+                    ..Default::default()
+                },
+            ],
+        });
+
+        // Act
+        let table = format_command_result(&result, &OutputOption::Table);
+
+        // Assert
+        assert_eq!(
+            table,
+            Ok(String::from(
+                "\
+Speakers
+DEFAULT   NAME                          STATE       FORM FACTOR   CHANNELS   FORMAT      RATE       VOLUME   MUTED   ID
+------------------------------------------------------------------------------------------------------------------------------------------------
+true      Speakers (Realtek(R) Audio)   Active      Speakers      2          float32le   48000 Hz   40%      false   {0.0.0.00000000}.{5e2f0c1a}
+false     LG TV SSCR2                   Unplugged   HDMI          -          -           -          -        -       {0.0.0.00000000}.{9b61d3e7}
+
+"
+            ))
+        );
+    }
+
+    #[test_case(vec![SpeakerRole::All] => Ok(String::from("\
+Default speaker would be set to LG TV SSCR2 for every role
+
+Speakers
+DEFAULT   NAME          STATE    FORM FACTOR   CHANNELS   FORMAT   RATE   VOLUME   MUTED   ID
+----------------------------------------------------------------------------------------------------------------------
+true      LG TV SSCR2   Active   HDMI          -          -        -      -        -       {0.0.0.00000000}.{9b61d3e7}
+
+")); "when the speaker would be the default one for every role")]
+    #[test_case(vec![SpeakerRole::Console] => Ok(String::from("\
+Default speaker would be set to LG TV SSCR2 for the console role
+
+Speakers
+DEFAULT   NAME          STATE    FORM FACTOR   CHANNELS   FORMAT   RATE   VOLUME   MUTED   ID
+----------------------------------------------------------------------------------------------------------------------
+true      LG TV SSCR2   Active   HDMI          -          -        -      -        -       {0.0.0.00000000}.{9b61d3e7}
+
+")); "when the speaker would be the default one for the console role")]
+    fn format_the_plan_of_a_change_of_speaker_as_a_table(
+        roles: Vec<SpeakerRole>,
+    ) -> Result<String, ApplicationError> {
+        // Arrange
+        let result = CommandResult::Plan(ApplicationPlanResult::SpeakersOnly {
+            speakers_plan: SpeakersPlan {
+                new_default_speaker: String::from("LG TV SSCR2"),
+                roles,
+                speakers: vec![SpeakerInfo {
+                    is_default: true,
+                    name: String::from("LG TV SSCR2"),
+                    id: String::from("{0.0.0.00000000}.{9b61d3e7}"),
+                    state: SpeakerState::Active,
+                    form_factor: SpeakerFormFactor::Hdmi,
+                    ..Default::default()
+                }],
+            },
+        });
+
+        // Act
+        format_command_result(&result, &OutputOption::Table)
+    }
+
+    #[test_case(None, None, None, None => Ok(String::from("Primary display set to LG TV SSCR2\n")); "when the mode is left untouched")]
+    #[test_case(Some(59940), None, None, None => Ok(String::from("Primary display set to LG TV SSCR2 at 3840x2160@59.94\n")); "when the mode is set")]
+    #[test_case(None, Some(DisplayRotation::Degrees90), Some(200), None => Ok(String::from("Primary display set to LG TV SSCR2 rotated 90°, scaled 200%\n")); "when the rotation and the scale are set")]
+    #[test_case(None, None, None, Some(true) => Ok(String::from("Primary display set to LG TV SSCR2 HDR on\n")); "when hdr is turned on")]
+    #[test_case(Some(59940), None, None, Some(false) => Ok(String::from("Primary display set to LG TV SSCR2 at 3840x2160@59.94, HDR off\n")); "when the mode is set and hdr is turned off")]
+    fn format_the_result_of_a_change_of_display_as_a_table(
+        refresh_rate_millihertz: Option<u32>,
+        new_rotation: Option<DisplayRotation>,
+        new_scale_percent: Option<u32>,
+        new_hdr_enabled: Option<bool>,
+    ) -> Result<String, ApplicationError> {
+        // Arrange
+        let result = CommandResult::Change(ApplicationChangeResult::DisplaysOnly {
+            displays_result: DisplaysSettingsResult {
+                new_primary_display: String::from("LG TV SSCR2"),
+                new_mode: refresh_rate_millihertz.map(|refresh_rate_millihertz| DisplayMode {
+                    resolution: DisplayResolution {
+                        width: 3840,
+                        height: 2160,
+                    },
+                    refresh_rate_millihertz,
+                }),
+                new_rotation,
+                new_scale_percent,
+                new_hdr_enabled,
+            },
+        });
+
+        // Act
+        format_command_result(&result, &OutputOption::Table)
+    }
+
+    #[test_case(false => Ok(String::from("Active displays set to Internal Display, LG TV SSCR2\n")); "when the desktop is extended")]
+    #[test_case(true => Ok(String::from("Active displays set to Internal Display, LG TV SSCR2 (cloned)\n")); "when the desktop is cloned")]
+    fn format_the_result_of_a_change_of_topology_as_a_table(
+        is_cloned: bool,
+    ) -> Result<String, ApplicationError> {
+        // Arrange
+        let result = CommandResult::Change(ApplicationChangeResult::Topology {
+            topology_result: DisplaysTopologyResult {
+                active_displays: vec![
+                    String::from("Internal Display"),
+                    String::from("LG TV SSCR2"),
+                ],
+                is_cloned,
+            },
+        });
+
+        // Act
+        format_command_result(&result, &OutputOption::Table)
+    }
+
+    #[test]
+    fn format_the_plan_of_a_change_of_topology_as_a_table() {
+        // Arrange
+        let result = CommandResult::Plan(ApplicationPlanResult::Topology {
+            topology_plan: DisplaysTopologyResult {
+                active_displays: vec![String::from("LG TV SSCR2")],
+                is_cloned: false,
+            },
+        });
+
+        // Act
+        let table = format_command_result(&result, &OutputOption::Table);
+
+        // Assert
+        assert_eq!(
+            table,
+            Ok(String::from(
+                "Active displays would be set to LG TV SSCR2\n"
+            ))
+        );
+    }
+
+    #[test]
+    fn format_the_plan_of_a_change_of_display_as_a_table() {
+        // Arrange
+        let result = CommandResult::Plan(ApplicationPlanResult::DisplaysOnly {
+            displays_plan: DisplaysPlan {
+                new_primary_display: String::from("LG TV SSCR2"),
+                new_mode: None,
+                new_rotation: None,
+                new_scale_percent: None,
+                new_hdr_enabled: None,
+                displays: vec![DisplayInfo {
+                    is_primary: true,
+                    name: String::from("LG TV SSCR2"),
+                    id: String::from("HDMI-A-1"),
+                    connector: DisplayConnector::Hdmi,
+                    resolution: DisplayResolution {
+                        width: 3840,
+                        height: 2160,
+                    },
+                    position: DisplayPosition { x: 0, y: 0 },
+                    refresh_rate_millihertz: Some(60000),
+                    rotation: DisplayRotation::Degrees0,
+                    scale_percent: Some(200),
+                    advanced_color: Some(DisplayAdvancedColor {
+                        is_hdr_supported: true,
+                        is_hdr_enabled: false,
+                        bits_per_color_channel: Some(8),
+                        color_encoding: DisplayColorEncoding::Rgb,
+                    }),
+                }],
+            },
+        });
+
+        // Act
+        let table = format_command_result(&result, &OutputOption::Table);
+
+        // Assert
+        assert_eq!(
+            table,
+            Ok(String::from(
+                "\
+Primary display would be set to LG TV SSCR2
+
+Displays
+PRIMARY   NAME          RESOLUTION   REFRESH    POSITION   ROTATION   SCALE   HDR   CONNECTOR   ID
+--------------------------------------------------------------------------------------------------------
+true      LG TV SSCR2   3840x2160    60.00 Hz   (0, 0)     0°         200%    off   HDMI        HDMI-A-1
+
+"
+            ))
+        );
+    }
+
+    #[test_case(OutputOption::Json => String::from("{\n  \"schema_version\": 1,\n  \"error\": {\n    \"kind\": \"custom\",\n    \"exit_code\": 1,\n    \"message\": \"Display is invalid, possible values are [DELL U2723QE]\"\n  }\n}\n"); "when output is json")]
+    #[test_case(OutputOption::Yaml => String::from("schema_version: 1\nerror:\n  kind: custom\n  exit_code: 1\n  message: Display is invalid, possible values are [DELL U2723QE]\n"); "when output is yaml")]
+    #[test_case(OutputOption::Table => String::from("Display is invalid, possible values are [DELL U2723QE]\n"); "when output is table")]
+    fn format_an_error(output: OutputOption) -> String {
+        // Arrange
+        let error = ApplicationError::Custom(String::from(
+            "Display is invalid, possible values are [DELL U2723QE]",
+        ));
+
+        // Act
+        format_error(&error, &output)
+    }
+
+    #[test_case(OutputOption::Json => String::from("{\n  \"schema_version\": 1,\n  \"error\": {\n    \"kind\": \"device_not_found\",\n    \"exit_code\": 3,\n    \"message\": \"Display is invalid, did you mean DELL U2723QE? Possible values are [DELL U2723QE]\",\n    \"requested\": [\n      \"DELL U2723\"\n    ],\n    \"candidates\": [\n      \"DELL U2723QE\"\n    ],\n    \"suggestions\": [\n      \"DELL U2723QE\"\n    ]\n  }\n}\n"); "when output is json")]
+    #[test_case(OutputOption::Yaml => String::from("schema_version: 1\nerror:\n  kind: device_not_found\n  exit_code: 3\n  message: Display is invalid, did you mean DELL U2723QE? Possible values are [DELL U2723QE]\n  requested:\n  - DELL U2723\n  candidates:\n  - DELL U2723QE\n  suggestions:\n  - DELL U2723QE\n"); "when output is yaml")]
+    #[test_case(OutputOption::Table => String::from("Display is invalid, did you mean DELL U2723QE? Possible values are [DELL U2723QE]\n"); "when output is table")]
+    fn format_the_devices_of_an_invalid_device_error(output: OutputOption) -> String {
+        // Arrange
+        let error = ApplicationError::device_not_found(
+            DeviceKind::Display,
+            DeviceRole::Target,
+            &["DELL U2723"],
+            vec![String::from("DELL U2723QE")],
+        );
+
+        // Act
+        format_error(&error, &output)
+    }
+
+    #[test]
+    fn format_the_api_and_the_code_of_a_platform_call_error() {
+        // Arrange
+        let error = ApplicationError::PlatformCall {
+            api: String::from("ChangeDisplaySettingsExW"),
+            code: Some(-1),
+            message: String::from("DISP_CHANGE_FAILED"),
+            source: None,
+        };
+
+        // Act
+        let formatted_error = format_error(&error, &OutputOption::Json);
+
+        // Assert
+        assert_eq!(
+            formatted_error,
+            "{\n  \"schema_version\": 1,\n  \"error\": {\n    \"kind\": \"platform_call\",\n    \"exit_code\": 4,\n    \"message\": \"ChangeDisplaySettingsExW failed: DISP_CHANGE_FAILED\",\n    \"api\": \"ChangeDisplaySettingsExW\",\n    \"code\": -1\n  }\n}\n"
+        );
+    }
+
+    #[test]
+    fn format_the_selector_and_the_candidates_of_an_ambiguous_device_error() {
+        // Arrange
+        let error = ApplicationError::AmbiguousDevice {
+            kind: DeviceKind::Display,
+            role: DeviceRole::Couch,
+            selector: String::from("glob:LG*"),
+            candidates: vec![
+                String::from("LG TV SSCR2 (1)"),
+                String::from("LG TV SSCR2 (2)"),
+            ],
+        };
+
+        // Act
+        let formatted_error = format_error(&error, &OutputOption::Yaml);
+
+        // Assert
+        assert!(
+            formatted_error.contains("  kind: ambiguous_device\n  exit_code: 14\n"),
+            "{formatted_error}"
+        );
+        assert!(
+            formatted_error.ends_with("  requested:\n  - glob:LG*\n  candidates:\n  - LG TV SSCR2 (1)\n  - LG TV SSCR2 (2)\n"),
+            "{formatted_error}"
+        );
+    }
+
+    #[test_case(OutputOption::Json => String::from("{\n  \"schema_version\": 1,\n  \"error\": {\n    \"kind\": \"usage\",\n    \"exit_code\": 2,\n    \"message\": \"error: unexpected argument '--unknown' found\"\n  }\n}\n"); "when output is json")]
+    #[test_case(OutputOption::Yaml => String::from("schema_version: 1\nerror:\n  kind: usage\n  exit_code: 2\n  message: 'error: unexpected argument ''--unknown'' found'\n"); "when output is yaml")]
+    fn format_a_usage_error(output: OutputOption) -> String {
+        // Arrange
+        let error = clap::Error::raw(
+            ErrorKind::UnknownArgument,
+            "unexpected argument '--unknown' found\n",
+        );
+
+        // Act
+        format_usage_error(&error, &output)
+    }
+}
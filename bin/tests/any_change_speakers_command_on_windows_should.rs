@@ -1,15 +1,17 @@
 #![cfg(target_os = "windows")]
 
-use convertible_couch_lib::func;
+use convertible_couch::exit_code::{ROLLBACK_PERFORMED_EXIT_CODE, VERIFICATION_FAILED_EXIT_CODE};
+use convertible_couch_lib::{application_error::DeviceKind, func};
 use convertible_couch_testing::{
     arrangements::{
         builders::{
             application::ApplicationBuilder,
             arguments::{ArgumentsBuilder, speakers::ChangeSpeakersCommand},
+            command_result::CommandResultBuilder,
         },
         fuzzing::{ComputerBuilder, Fuzzer, speakers::Function},
     },
-    assertions::assert_that_result_is_a_win32_error,
+    assertions::{assert_that_exit_code_is, assert_that_result_is_a_win32_error},
 };
 use test_case::test_matrix;
 
@@ -69,3 +71,67 @@ fn report_any_speakers_error(change_speakers_command: ChangeSpeakersCommand, fun
     // Assert
     assert_that_result_is_a_win32_error(actual_result, win_32_error);
 }
+
+#[test_matrix(
+    [
+        ChangeSpeakersCommand::ChangeDisplaysAndSpeakers,
+        ChangeSpeakersCommand::ChangeSpeakers
+    ]; "when"
+)]
+fn report_a_change_of_the_default_speaker_which_is_not_applied(
+    change_speakers_command: ChangeSpeakersCommand,
+) {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+    let (default_speaker_name, alternative_speaker_name) = fuzzer.generate_two_speakers_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_displays()
+        .with_speakers()
+        .of_which_there_are_at_least(2)
+        .whose_default_one_is_named(&default_speaker_name)
+        .with_an_alternative_one_named(&alternative_speaker_name)
+        .for_which_ipolicy_config_vista_set_default_endpoint_is_not_applied()
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::speakers().change(
+        &change_speakers_command,
+        &primary_display_name,
+        &secondary_display_name,
+        &default_speaker_name,
+        &alternative_speaker_name,
+    );
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_error = CommandResultBuilder::verification_failed_error(
+        DeviceKind::Speaker,
+        &alternative_speaker_name,
+        &default_speaker_name,
+    );
+    let (expected_result, expected_exit_code) = match change_speakers_command {
+        ChangeSpeakersCommand::ChangeDisplaysAndSpeakers => (
+            CommandResultBuilder::rollback_performed_error(
+                DeviceKind::Display,
+                DeviceKind::Speaker,
+                expected_error,
+            ),
+            ROLLBACK_PERFORMED_EXIT_CODE,
+        ),
+        _ => (expected_error, VERIFICATION_FAILED_EXIT_CODE),
+    };
+
+    assert_eq!(actual_result, expected_result);
+    assert_that_exit_code_is(&actual_result, expected_exit_code);
+}
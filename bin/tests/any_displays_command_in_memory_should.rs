@@ -0,0 +1,360 @@
+#![cfg(not(target_os = "windows"))]
+
+use convertible_couch::{
+    commands::change::HdrOption,
+    exit_code::{
+        FAILURE_EXIT_CODE, PARTIAL_FAILURE_EXIT_CODE, ROLLBACK_PERFORMED_EXIT_CODE,
+        VERIFICATION_FAILED_EXIT_CODE,
+    },
+};
+use convertible_couch_lib::{
+    application_error::{DeviceKind, VerificationMismatch},
+    func,
+};
+use convertible_couch_testing::{
+    arrangements::{
+        builders::{
+            application::ApplicationBuilder,
+            arguments::{
+                ArgumentsBuilder,
+                displays::{ChangeDisplaysCommand, DisplaysCommand},
+            },
+            command_result::CommandResultBuilder,
+        },
+        fuzzing::{ComputerBuilder, Fuzzer, displays::Function},
+    },
+    assertions::assert_that_exit_code_is,
+};
+use test_case::test_matrix;
+
+#[test_matrix(
+    [
+        DisplaysCommand::ChangeDisplaysAndSpeakers,
+        DisplaysCommand::ChangeDisplays,
+        DisplaysCommand::InfoDisplaysAndSpeakers,
+        DisplaysCommand::InfoDisplays
+    ]; "when"
+)]
+fn report_any_error_while_getting_the_displays(displays_command: DisplaysCommand) {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+    let (default_speaker_name, alternative_speaker_name) = fuzzer.generate_two_speakers_names();
+    let error = fuzzer.generate_in_memory_error();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .for_which_function_fails_with(Function::GetDisplays, error.clone())
+        .build_displays()
+        .with_speakers()
+        .of_which_there_are_at_least(2)
+        .whose_default_one_is_named(&default_speaker_name)
+        .with_an_alternative_one_named(&alternative_speaker_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::displays().any(
+        &displays_command,
+        &primary_display_name,
+        &secondary_display_name,
+        &default_speaker_name,
+        &alternative_speaker_name,
+    );
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::custom_error(error);
+
+    assert_eq!(actual_result, expected_result);
+    assert_that_exit_code_is(&actual_result, FAILURE_EXIT_CODE);
+}
+
+#[test_matrix(
+    [
+        ChangeDisplaysCommand::ChangeDisplaysAndSpeakers,
+        ChangeDisplaysCommand::ChangeDisplays
+    ]; "when"
+)]
+fn report_any_error_while_setting_the_displays(change_displays_command: ChangeDisplaysCommand) {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+    let (default_speaker_name, alternative_speaker_name) = fuzzer.generate_two_speakers_names();
+    let error = fuzzer.generate_in_memory_error();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .for_which_function_fails_with(Function::SetDisplays, error.clone())
+        .build_displays()
+        .with_speakers()
+        .of_which_there_are_at_least(2)
+        .whose_default_one_is_named(&default_speaker_name)
+        .with_an_alternative_one_named(&alternative_speaker_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::displays().change(
+        &change_displays_command,
+        &primary_display_name,
+        &secondary_display_name,
+        &default_speaker_name,
+        &alternative_speaker_name,
+    );
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::custom_error(error);
+
+    assert_eq!(actual_result, expected_result);
+    assert_that_exit_code_is(&actual_result, FAILURE_EXIT_CODE);
+}
+
+#[test_matrix(
+    [
+        ChangeDisplaysCommand::ChangeDisplaysAndSpeakers,
+        ChangeDisplaysCommand::ChangeDisplays
+    ]; "when"
+)]
+fn report_a_change_of_the_displays_which_is_not_applied(
+    change_displays_command: ChangeDisplaysCommand,
+) {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+    let (default_speaker_name, alternative_speaker_name) = fuzzer.generate_two_speakers_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .for_which_set_displays_is_not_applied()
+        .build_displays()
+        .with_speakers()
+        .of_which_there_are_at_least(2)
+        .whose_default_one_is_named(&default_speaker_name)
+        .with_an_alternative_one_named(&alternative_speaker_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::displays().change(
+        &change_displays_command,
+        &primary_display_name,
+        &secondary_display_name,
+        &default_speaker_name,
+        &alternative_speaker_name,
+    );
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::verification_failed_error(
+        DeviceKind::Display,
+        &secondary_display_name,
+        &primary_display_name,
+    );
+
+    assert_eq!(actual_result, expected_result);
+    assert_that_exit_code_is(&actual_result, VERIFICATION_FAILED_EXIT_CODE);
+}
+
+#[test]
+fn change_the_primary_display_back_when_hdr_is_not_turned_on() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_supporting_hdr_named(&secondary_display_name)
+        .for_which_set_displays_is_not_applied_once_after_applying(1)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let info_args = ArgumentsBuilder::info().displays_only().build();
+    let change_args = ArgumentsBuilder::change()
+        .displays_only_to(&secondary_display_name)
+        .at_hdr(HdrOption::On)
+        .build();
+
+    let initial_info_result = application.execute(&info_args);
+
+    // Act
+    let actual_result = application.execute(&change_args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::rollback_performed_error(
+        DeviceKind::Display,
+        DeviceKind::Display,
+        CommandResultBuilder::settings_verification_failed_error(
+            DeviceKind::Display,
+            vec![VerificationMismatch::new(
+                &format!("HDR of {secondary_display_name}"),
+                "on",
+                "off",
+            )],
+        ),
+    );
+
+    assert_eq!(actual_result, expected_result);
+    assert_that_exit_code_is(&actual_result, ROLLBACK_PERFORMED_EXIT_CODE);
+    assert_eq!(application.execute(&info_args), initial_info_result);
+}
+
+#[test]
+fn change_the_primary_display_back_when_hdr_cannot_be_turned_on() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+    let error = fuzzer.generate_in_memory_error();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_supporting_hdr_named(&secondary_display_name)
+        .for_which_set_displays_fails_once_after_succeeding_with(1, error.clone())
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let info_args = ArgumentsBuilder::info().displays_only().build();
+    let change_args = ArgumentsBuilder::change()
+        .displays_only_to(&secondary_display_name)
+        .at_hdr(HdrOption::On)
+        .build();
+
+    let initial_info_result = application.execute(&info_args);
+
+    // Act
+    let actual_result = application.execute(&change_args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::rollback_performed_error(
+        DeviceKind::Display,
+        DeviceKind::Display,
+        CommandResultBuilder::custom_error(error),
+    );
+
+    assert_eq!(actual_result, expected_result);
+    assert_that_exit_code_is(&actual_result, ROLLBACK_PERFORMED_EXIT_CODE);
+    assert_eq!(application.execute(&info_args), initial_info_result);
+}
+
+#[test]
+fn change_the_primary_display_of_a_profile_back_when_hdr_cannot_be_turned_on() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+    let (default_speaker_name, alternative_speaker_name) = fuzzer.generate_two_speakers_names();
+    let error = fuzzer.generate_in_memory_error();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_supporting_hdr_named(&secondary_display_name)
+        .for_which_set_displays_fails_once_after_succeeding_with(1, error.clone())
+        .build_displays()
+        .with_speakers()
+        .of_which_there_are_at_least(2)
+        .whose_default_one_is_named(&default_speaker_name)
+        .with_an_alternative_one_named(&alternative_speaker_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer)
+        .with_profile_at_hdr(
+            "couch",
+            &secondary_display_name,
+            &alternative_speaker_name,
+            true,
+        )
+        .build();
+
+    let info_args = ArgumentsBuilder::info().displays_only().build();
+    let change_args = ArgumentsBuilder::change().profile("couch").build();
+
+    let initial_info_result = application.execute(&info_args);
+
+    // Act
+    let actual_result = application.execute(&change_args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::rollback_performed_error(
+        DeviceKind::Display,
+        DeviceKind::Display,
+        CommandResultBuilder::custom_error(error),
+    );
+
+    assert_eq!(actual_result, expected_result);
+    assert_that_exit_code_is(&actual_result, ROLLBACK_PERFORMED_EXIT_CODE);
+    assert_eq!(application.execute(&info_args), initial_info_result);
+}
+
+#[test]
+fn report_both_errors_when_the_primary_display_cannot_be_changed_back_after_hdr_fails() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+    let error = fuzzer.generate_in_memory_error();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_supporting_hdr_named(&secondary_display_name)
+        .for_which_set_displays_fails_after_succeeding_with(1, error.clone())
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::change()
+        .displays_only_to(&secondary_display_name)
+        .at_hdr(HdrOption::On)
+        .build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::rollback_failed_error(
+        DeviceKind::Display,
+        DeviceKind::Display,
+        CommandResultBuilder::custom_error(error.clone()),
+        CommandResultBuilder::custom_error(error),
+    );
+
+    assert_eq!(actual_result, expected_result);
+    assert_that_exit_code_is(&actual_result, PARTIAL_FAILURE_EXIT_CODE);
+}
@@ -1,16 +1,20 @@
 #![cfg(target_os = "windows")]
 
-use convertible_couch_lib::func;
+use convertible_couch::exit_code::{PLATFORM_CALL_EXIT_CODE, VERIFICATION_FAILED_EXIT_CODE};
+use convertible_couch_lib::{application_error::DeviceKind, func};
 use convertible_couch_testing::{
     arrangements::{
         builders::{
             application::ApplicationBuilder,
-            arguments::{ArgumentsBuilder, displays::DisplaysCommand},
+            arguments::{
+                ArgumentsBuilder,
+                displays::{ChangeDisplaysCommand, DisplaysCommand},
+            },
             command_result::CommandResultBuilder,
         },
         fuzzing::{ComputerBuilder, Fuzzer, displays::Function},
     },
-    assertions::assert_that_result_is_a_win32_error,
+    assertions::{assert_that_exit_code_is, assert_that_result_is_a_win32_error},
 };
 use test_case::test_matrix;
 use windows::Win32::Foundation::ERROR_INSUFFICIENT_BUFFER;
@@ -67,6 +71,7 @@ fn report_any_display_error(displays_command: DisplaysCommand, function: Functio
     let actual_result = application.execute(&args);
 
     // Assert
+    assert_that_exit_code_is(&actual_result, PLATFORM_CALL_EXIT_CODE);
     assert_that_result_is_a_win32_error(actual_result, win_32_error);
 }
 
@@ -101,6 +106,11 @@ fn overcome_query_display_config_returning_an_insufficient_buffer_error(
         .with_an_alternative_one_named(&alternative_speaker_name)
         .build_computer();
 
+    let primary_display = computer.display_named(&primary_display_name);
+    let secondary_display = computer.display_named(&secondary_display_name);
+    let default_speaker = computer.speaker_named(&default_speaker_name);
+    let alternative_speaker = computer.speaker_named(&alternative_speaker_name);
+
     let mut application = ApplicationBuilder::new(computer).build();
 
     let args = ArgumentsBuilder::displays().any(
@@ -117,11 +127,64 @@ fn overcome_query_display_config_returning_an_insufficient_buffer_error(
     // Assert
     let expected_command_result = CommandResultBuilder::displays().any(
         &displays_command,
+        &primary_display,
+        &secondary_display,
+        &default_speaker,
+        &alternative_speaker,
+    );
+
+    assert_eq!(actual_result, expected_command_result);
+}
+
+#[test_matrix(
+    [
+        ChangeDisplaysCommand::ChangeDisplaysAndSpeakers,
+        ChangeDisplaysCommand::ChangeDisplays
+    ]; "when"
+)]
+fn report_a_change_of_the_displays_which_is_not_applied(
+    change_displays_command: ChangeDisplaysCommand,
+) {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+    let (default_speaker_name, alternative_speaker_name) = fuzzer.generate_two_speakers_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .for_which_set_display_config_is_not_applied()
+        .build_displays()
+        .with_speakers()
+        .of_which_there_are_at_least(2)
+        .whose_default_one_is_named(&default_speaker_name)
+        .with_an_alternative_one_named(&alternative_speaker_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::displays().change(
+        &change_displays_command,
         &primary_display_name,
         &secondary_display_name,
         &default_speaker_name,
         &alternative_speaker_name,
     );
 
-    assert_eq!(actual_result, expected_command_result);
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::verification_failed_error(
+        DeviceKind::Display,
+        &secondary_display_name,
+        &primary_display_name,
+    );
+
+    assert_eq!(actual_result, expected_result);
+    assert_that_exit_code_is(&actual_result, VERIFICATION_FAILED_EXIT_CODE);
 }
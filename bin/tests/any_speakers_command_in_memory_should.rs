@@ -0,0 +1,350 @@
+#![cfg(not(target_os = "windows"))]
+
+use convertible_couch::exit_code::{
+    FAILURE_EXIT_CODE, PARTIAL_FAILURE_EXIT_CODE, ROLLBACK_PERFORMED_EXIT_CODE,
+    VERIFICATION_FAILED_EXIT_CODE,
+};
+use convertible_couch_lib::{application_error::DeviceKind, func};
+use convertible_couch_testing::{
+    arrangements::{
+        builders::{
+            application::ApplicationBuilder,
+            arguments::{
+                ArgumentsBuilder,
+                speakers::{ChangeSpeakersCommand, SpeakersCommand},
+            },
+            command_result::CommandResultBuilder,
+        },
+        fuzzing::{ComputerBuilder, Fuzzer, speakers::Function},
+    },
+    assertions::assert_that_exit_code_is,
+};
+use test_case::test_matrix;
+
+#[test_matrix(
+    [
+        SpeakersCommand::ChangeDisplaysAndSpeakers,
+        SpeakersCommand::ChangeSpeakers,
+        SpeakersCommand::InfoDisplaysAndSpeakers,
+        SpeakersCommand::InfoSpeakers
+    ]; "when"
+)]
+fn report_any_error_while_getting_the_speakers(speakers_command: SpeakersCommand) {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+    let (default_speaker_name, alternative_speaker_name) = fuzzer.generate_two_speakers_names();
+    let error = fuzzer.generate_in_memory_error();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_displays()
+        .with_speakers()
+        .of_which_there_are_at_least(2)
+        .whose_default_one_is_named(&default_speaker_name)
+        .with_an_alternative_one_named(&alternative_speaker_name)
+        .for_which_function_fails_with(Function::GetSpeakers, error.clone())
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::speakers().any(
+        &speakers_command,
+        &primary_display_name,
+        &secondary_display_name,
+        &default_speaker_name,
+        &alternative_speaker_name,
+    );
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::custom_error(error);
+
+    assert_eq!(actual_result, expected_result);
+    assert_that_exit_code_is(&actual_result, FAILURE_EXIT_CODE);
+}
+
+#[test_matrix(
+    [
+        ChangeSpeakersCommand::ChangeDisplaysAndSpeakers,
+        ChangeSpeakersCommand::ChangeSpeakers
+    ]; "when"
+)]
+fn report_any_error_while_setting_the_default_speaker(
+    change_speakers_command: ChangeSpeakersCommand,
+) {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+    let (default_speaker_name, alternative_speaker_name) = fuzzer.generate_two_speakers_names();
+    let error = fuzzer.generate_in_memory_error();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_displays()
+        .with_speakers()
+        .of_which_there_are_at_least(2)
+        .whose_default_one_is_named(&default_speaker_name)
+        .with_an_alternative_one_named(&alternative_speaker_name)
+        .for_which_function_fails_with(Function::SetDefaultSpeaker, error.clone())
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::speakers().change(
+        &change_speakers_command,
+        &primary_display_name,
+        &secondary_display_name,
+        &default_speaker_name,
+        &alternative_speaker_name,
+    );
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_error = CommandResultBuilder::custom_error(error);
+    let (expected_result, expected_exit_code) = match change_speakers_command {
+        ChangeSpeakersCommand::ChangeDisplaysAndSpeakers => (
+            CommandResultBuilder::rollback_performed_error(
+                DeviceKind::Display,
+                DeviceKind::Speaker,
+                expected_error,
+            ),
+            ROLLBACK_PERFORMED_EXIT_CODE,
+        ),
+        _ => (expected_error, FAILURE_EXIT_CODE),
+    };
+
+    assert_eq!(actual_result, expected_result);
+    assert_that_exit_code_is(&actual_result, expected_exit_code);
+}
+
+#[test]
+fn change_the_primary_display_back_when_the_default_speaker_cannot_be_set() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+    let (default_speaker_name, alternative_speaker_name) = fuzzer.generate_two_speakers_names();
+    let error = fuzzer.generate_in_memory_error();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_displays()
+        .with_speakers()
+        .of_which_there_are_at_least(2)
+        .whose_default_one_is_named(&default_speaker_name)
+        .with_an_alternative_one_named(&alternative_speaker_name)
+        .for_which_function_fails_with(Function::SetDefaultSpeaker, error.clone())
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let info_args = ArgumentsBuilder::info().displays_only().build();
+    let change_args = ArgumentsBuilder::change()
+        .displays_and_speakers(
+            &primary_display_name,
+            &secondary_display_name,
+            &default_speaker_name,
+            &alternative_speaker_name,
+        )
+        .build();
+
+    let initial_info_result = application.execute(&info_args);
+
+    // Act
+    let actual_result = application.execute(&change_args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::rollback_performed_error(
+        DeviceKind::Display,
+        DeviceKind::Speaker,
+        CommandResultBuilder::custom_error(error),
+    );
+
+    assert_eq!(actual_result, expected_result);
+    assert_that_exit_code_is(&actual_result, ROLLBACK_PERFORMED_EXIT_CODE);
+    assert_eq!(application.execute(&info_args), initial_info_result);
+}
+
+#[test]
+fn change_the_primary_display_of_a_cycle_back_when_the_default_speaker_cannot_be_set() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+    let (default_speaker_name, alternative_speaker_name) = fuzzer.generate_two_speakers_names();
+    let error = fuzzer.generate_in_memory_error();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_displays()
+        .with_speakers()
+        .of_which_there_are_at_least(2)
+        .whose_default_one_is_named(&default_speaker_name)
+        .with_an_alternative_one_named(&alternative_speaker_name)
+        .for_which_function_fails_with(Function::SetDefaultSpeaker, error.clone())
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let info_args = ArgumentsBuilder::info().displays_only().build();
+    let cycle_args = ArgumentsBuilder::change()
+        .cycle(
+            &[&primary_display_name, &secondary_display_name],
+            &[&default_speaker_name, &alternative_speaker_name],
+        )
+        .build();
+
+    let initial_info_result = application.execute(&info_args);
+
+    // Act
+    let actual_result = application.execute(&cycle_args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::rollback_performed_error(
+        DeviceKind::Display,
+        DeviceKind::Speaker,
+        CommandResultBuilder::custom_error(error),
+    );
+
+    assert_eq!(actual_result, expected_result);
+    assert_that_exit_code_is(&actual_result, ROLLBACK_PERFORMED_EXIT_CODE);
+    assert_eq!(application.execute(&info_args), initial_info_result);
+}
+
+#[test]
+fn report_both_errors_when_the_primary_display_cannot_be_changed_back() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+    let (default_speaker_name, alternative_speaker_name) = fuzzer.generate_two_speakers_names();
+    let speakers_error = fuzzer.generate_in_memory_error();
+    let displays_error = fuzzer.generate_in_memory_error();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .for_which_set_displays_fails_after_succeeding_with(1, displays_error.clone())
+        .build_displays()
+        .with_speakers()
+        .of_which_there_are_at_least(2)
+        .whose_default_one_is_named(&default_speaker_name)
+        .with_an_alternative_one_named(&alternative_speaker_name)
+        .for_which_function_fails_with(Function::SetDefaultSpeaker, speakers_error.clone())
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::change()
+        .displays_and_speakers(
+            &primary_display_name,
+            &secondary_display_name,
+            &default_speaker_name,
+            &alternative_speaker_name,
+        )
+        .build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::rollback_failed_error(
+        DeviceKind::Display,
+        DeviceKind::Speaker,
+        CommandResultBuilder::custom_error(speakers_error),
+        CommandResultBuilder::custom_error(displays_error),
+    );
+
+    assert_eq!(actual_result, expected_result);
+    assert_that_exit_code_is(&actual_result, PARTIAL_FAILURE_EXIT_CODE);
+}
+
+#[test_matrix(
+    [
+        ChangeSpeakersCommand::ChangeDisplaysAndSpeakers,
+        ChangeSpeakersCommand::ChangeSpeakers
+    ]; "when"
+)]
+fn report_a_change_of_the_default_speaker_which_is_not_applied(
+    change_speakers_command: ChangeSpeakersCommand,
+) {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+    let (default_speaker_name, alternative_speaker_name) = fuzzer.generate_two_speakers_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_displays()
+        .with_speakers()
+        .of_which_there_are_at_least(2)
+        .whose_default_one_is_named(&default_speaker_name)
+        .with_an_alternative_one_named(&alternative_speaker_name)
+        .for_which_set_default_speaker_is_not_applied()
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::speakers().change(
+        &change_speakers_command,
+        &primary_display_name,
+        &secondary_display_name,
+        &default_speaker_name,
+        &alternative_speaker_name,
+    );
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_error = CommandResultBuilder::verification_failed_error(
+        DeviceKind::Speaker,
+        &alternative_speaker_name,
+        &default_speaker_name,
+    );
+    let (expected_result, expected_exit_code) = match change_speakers_command {
+        ChangeSpeakersCommand::ChangeDisplaysAndSpeakers => (
+            CommandResultBuilder::rollback_performed_error(
+                DeviceKind::Display,
+                DeviceKind::Speaker,
+                expected_error,
+            ),
+            ROLLBACK_PERFORMED_EXIT_CODE,
+        ),
+        _ => (expected_error, VERIFICATION_FAILED_EXIT_CODE),
+    };
+
+    assert_eq!(actual_result, expected_result);
+    assert_that_exit_code_is(&actual_result, expected_exit_code);
+}
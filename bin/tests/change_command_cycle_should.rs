@@ -0,0 +1,306 @@
+use convertible_couch::exit_code::CONFIG_EXIT_CODE;
+use convertible_couch_lib::func;
+use convertible_couch_testing::{
+    arrangements::{
+        builders::{
+            application::ApplicationBuilder, arguments::ArgumentsBuilder,
+            command_result::CommandResultBuilder,
+        },
+        fuzzing::{ComputerBuilder, Fuzzer},
+    },
+    assertions::assert_that_exit_code_is,
+};
+
+#[test]
+fn change_primary_display_and_default_speaker_to_the_next_ones_of_a_cycle() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+    let (default_speaker_name, alternative_speaker_name) = fuzzer.generate_two_speakers_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_displays()
+        .with_speakers()
+        .of_which_there_are_at_least(2)
+        .whose_default_one_is_named(&default_speaker_name)
+        .with_an_alternative_one_named(&alternative_speaker_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::change()
+        .cycle(
+            &[&primary_display_name, &secondary_display_name],
+            &[&default_speaker_name, &alternative_speaker_name],
+        )
+        .build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::change()
+        .displays_and_speakers(&secondary_display_name, &alternative_speaker_name);
+
+    assert_eq!(actual_result, expected_result);
+}
+
+#[test]
+fn change_primary_display_and_default_speaker_to_the_first_ones_of_a_cycle_once_wrapped_around() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+    let (default_speaker_name, alternative_speaker_name) = fuzzer.generate_two_speakers_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_displays()
+        .with_speakers()
+        .of_which_there_are_at_least(2)
+        .whose_default_one_is_named(&default_speaker_name)
+        .with_an_alternative_one_named(&alternative_speaker_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::change()
+        .cycle(
+            &[&primary_display_name, &secondary_display_name],
+            &[&default_speaker_name, &alternative_speaker_name],
+        )
+        .build();
+
+    // Act
+    let actual_result = application
+        .execute(&args)
+        .and_then(|_| application.execute(&args));
+
+    // Assert
+    let expected_result = CommandResultBuilder::change()
+        .displays_and_speakers(&primary_display_name, &default_speaker_name);
+
+    assert_eq!(actual_result, expected_result);
+}
+
+#[test]
+fn change_primary_display_and_default_speaker_to_the_previous_ones_of_a_cycle() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+    let (default_speaker_name, alternative_speaker_name) = fuzzer.generate_two_speakers_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_displays()
+        .with_speakers()
+        .of_which_there_are_at_least(2)
+        .whose_default_one_is_named(&default_speaker_name)
+        .with_an_alternative_one_named(&alternative_speaker_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::change()
+        .cycle(
+            &[&secondary_display_name, &primary_display_name],
+            &[&alternative_speaker_name, &default_speaker_name],
+        )
+        .reverse()
+        .build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::change()
+        .displays_and_speakers(&secondary_display_name, &alternative_speaker_name);
+
+    assert_eq!(actual_result, expected_result);
+}
+
+#[test]
+fn change_only_primary_display_to_the_next_one_of_a_cycle_of_displays() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::change()
+        .cycle(&[&primary_display_name, &secondary_display_name], &[])
+        .build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::change().displays_only(&secondary_display_name);
+
+    assert_eq!(actual_result, expected_result);
+}
+
+#[test]
+fn change_only_default_speaker_to_the_next_one_of_a_cycle_of_speakers() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (default_speaker_name, alternative_speaker_name) = fuzzer.generate_two_speakers_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_speakers()
+        .of_which_there_are_at_least(2)
+        .whose_default_one_is_named(&default_speaker_name)
+        .with_an_alternative_one_named(&alternative_speaker_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::change()
+        .cycle(&[], &[&default_speaker_name, &alternative_speaker_name])
+        .build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::change().speakers_only(&alternative_speaker_name);
+
+    assert_eq!(actual_result, expected_result);
+}
+
+#[test]
+fn change_primary_display_and_default_speaker_to_the_next_ones_of_the_cycle_of_the_configuration() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+    let (default_speaker_name, alternative_speaker_name) = fuzzer.generate_two_speakers_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_displays()
+        .with_speakers()
+        .of_which_there_are_at_least(2)
+        .whose_default_one_is_named(&default_speaker_name)
+        .with_an_alternative_one_named(&alternative_speaker_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer)
+        .with_cycle(
+            &[&primary_display_name, &secondary_display_name],
+            &[&default_speaker_name, &alternative_speaker_name],
+        )
+        .build();
+
+    let args = ArgumentsBuilder::change().cycle(&[], &[]).build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::change()
+        .displays_and_speakers(&secondary_display_name, &alternative_speaker_name);
+
+    assert_eq!(actual_result, expected_result);
+}
+
+#[test]
+fn prefer_the_cycle_given_to_the_one_of_the_configuration() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+    let (default_speaker_name, alternative_speaker_name) = fuzzer.generate_two_speakers_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_displays()
+        .with_speakers()
+        .of_which_there_are_at_least(2)
+        .whose_default_one_is_named(&default_speaker_name)
+        .with_an_alternative_one_named(&alternative_speaker_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer)
+        .with_cycle(
+            &[&primary_display_name, &secondary_display_name],
+            &[&default_speaker_name, &alternative_speaker_name],
+        )
+        .build();
+
+    let args = ArgumentsBuilder::change()
+        .cycle(&[&primary_display_name, &secondary_display_name], &[])
+        .build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::change().displays_only(&secondary_display_name);
+
+    assert_eq!(actual_result, expected_result);
+}
+
+#[test]
+fn fail_to_cycle_when_no_cycle_is_given_nor_declared() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .build_displays()
+        .with_speakers()
+        .of_which_there_are_at_least(2)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::change().cycle(&[], &[]).build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::config_error(
+        "Cycle is empty, give the displays and/or speakers to cycle through or declare them in the configuration file",
+    );
+
+    assert_eq!(actual_result, expected_result);
+    assert_that_exit_code_is(&actual_result, CONFIG_EXIT_CODE);
+}
@@ -92,3 +92,42 @@ fn change_primary_display_and_default_speaker_back_and_forth() {
 
     assert_eq!(actual_result, expected_result);
 }
+
+#[test]
+fn set_primary_display_and_default_speaker() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+    let (default_speaker_name, alternative_speaker_name) = fuzzer.generate_two_speakers_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_displays()
+        .with_speakers()
+        .of_which_there_are_at_least(2)
+        .whose_default_one_is_named(&default_speaker_name)
+        .with_an_alternative_one_named(&alternative_speaker_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::change()
+        .displays_and_speakers_to(&secondary_display_name, &alternative_speaker_name)
+        .build();
+
+    // Act
+    let actual_result = application
+        .execute(&args)
+        .and_then(|_| application.execute(&args));
+
+    // Assert
+    let expected_result = CommandResultBuilder::change()
+        .displays_and_speakers(&secondary_display_name, &alternative_speaker_name);
+
+    assert_eq!(actual_result, expected_result);
+}
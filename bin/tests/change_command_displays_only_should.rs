@@ -1,10 +1,18 @@
-use convertible_couch_lib::{displays_settings::INTERNAL_DISPLAY_NAME, func};
-use convertible_couch_testing::arrangements::{
-    builders::{
-        application::ApplicationBuilder, arguments::ArgumentsBuilder,
-        command_result::CommandResultBuilder,
+use convertible_couch::exit_code::{AMBIGUOUS_DEVICE_EXIT_CODE, DEVICE_NOT_FOUND_EXIT_CODE};
+use convertible_couch_lib::{
+    application_error::{DeviceKind, DeviceRole},
+    displays_settings::INTERNAL_DISPLAY_NAME,
+    func,
+};
+use convertible_couch_testing::{
+    arrangements::{
+        builders::{
+            application::ApplicationBuilder, arguments::ArgumentsBuilder,
+            command_result::CommandResultBuilder,
+        },
+        fuzzing::{ComputerBuilder, Fuzzer},
     },
-    fuzzing::{ComputerBuilder, Fuzzer},
+    assertions::assert_that_exit_code_is,
 };
 
 #[test]
@@ -158,11 +166,16 @@ fn validate_the_desktop_display() {
     let actual_result = application.execute(&args);
 
     // Assert
-    let expected_result = CommandResultBuilder::custom_error(format!(
-        "Desktop display is invalid, possible values are [{primary_display_name}, {secondary_display_name}]"
-    ));
+    let expected_result = CommandResultBuilder::device_not_found_error(
+        DeviceKind::Display,
+        DeviceRole::Desktop,
+        &[&invalid_display_name],
+        &[],
+        &[&primary_display_name, &secondary_display_name],
+    );
 
     assert_eq!(actual_result, expected_result);
+    assert_that_exit_code_is(&actual_result, DEVICE_NOT_FOUND_EXIT_CODE);
 }
 
 #[test]
@@ -191,11 +204,16 @@ fn validate_the_couch_display() {
     let actual_result = application.execute(&args);
 
     // Assert
-    let expected_result = CommandResultBuilder::custom_error(format!(
-        "Couch display is invalid, possible values are [{primary_display_name}, {secondary_display_name}]"
-    ));
+    let expected_result = CommandResultBuilder::device_not_found_error(
+        DeviceKind::Display,
+        DeviceRole::Couch,
+        &[&invalid_display_name],
+        &[],
+        &[&primary_display_name, &secondary_display_name],
+    );
 
     assert_eq!(actual_result, expected_result);
+    assert_that_exit_code_is(&actual_result, DEVICE_NOT_FOUND_EXIT_CODE);
 }
 
 #[test]
@@ -228,9 +246,258 @@ fn validate_both_desktop_and_couch_displays() {
     let actual_result = application.execute(&args);
 
     // Assert
-    let expected_result = CommandResultBuilder::custom_error(format!(
-        "Desktop and couch displays are invalid, possible values are [{primary_display_name}, {secondary_display_name}]"
-    ));
+    let expected_result = CommandResultBuilder::device_not_found_error(
+        DeviceKind::Display,
+        DeviceRole::DesktopAndCouch,
+        &[&invalid_desktop_display_name, &invalid_couch_display_name],
+        &[],
+        &[&primary_display_name, &secondary_display_name],
+    );
+
+    assert_eq!(actual_result, expected_result);
+    assert_that_exit_code_is(&actual_result, DEVICE_NOT_FOUND_EXIT_CODE);
+}
+
+#[test]
+fn set_the_couch_display_as_primary() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::change()
+        .displays_only_to(&secondary_display_name)
+        .build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::change().displays_only(&secondary_display_name);
+
+    assert_eq!(actual_result, expected_result);
+}
+
+#[test]
+fn set_the_couch_display_as_primary_when_it_already_is() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::change()
+        .displays_only_to(&secondary_display_name)
+        .build();
+
+    // Act
+    let actual_result = application
+        .execute(&args)
+        .and_then(|_| application.execute(&args));
+
+    // Assert
+    let expected_result = CommandResultBuilder::change().displays_only(&secondary_display_name);
+
+    assert_eq!(actual_result, expected_result);
+}
+
+#[test]
+fn validate_the_display_to_set_as_primary() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (invalid_display_name, primary_display_name, secondary_display_name) =
+        fuzzer.generate_three_display_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::change()
+        .displays_only_to(&invalid_display_name)
+        .build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::device_not_found_error(
+        DeviceKind::Display,
+        DeviceRole::Target,
+        &[&invalid_display_name],
+        &[],
+        &[&primary_display_name, &secondary_display_name],
+    );
+
+    assert_eq!(actual_result, expected_result);
+    assert_that_exit_code_is(&actual_result, DEVICE_NOT_FOUND_EXIT_CODE);
+}
+
+#[test]
+fn set_the_display_selected_by_a_glob_as_primary() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let (secondary_display_name_start, _) =
+        secondary_display_name.split_at(secondary_display_name.len() - 1);
+
+    let args = ArgumentsBuilder::change()
+        .displays_only_to(&format!("glob:{secondary_display_name_start}?"))
+        .build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::change().displays_only(&secondary_display_name);
+
+    assert_eq!(actual_result, expected_result);
+}
+
+#[test]
+fn swap_the_displays_selected_by_their_names_whatever_their_case() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::change()
+        .displays_only(
+            &primary_display_name.to_lowercase(),
+            &secondary_display_name.to_uppercase(),
+        )
+        .build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::change().displays_only(&secondary_display_name);
+
+    assert_eq!(actual_result, expected_result);
+}
+
+#[test]
+fn list_the_candidates_when_the_selector_matches_several_displays() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_computer();
+
+    let primary_display = computer.display_named(&primary_display_name);
+    let secondary_display = computer.display_named(&secondary_display_name);
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::change()
+        .displays_only(&primary_display_name, "re:.")
+        .build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::ambiguous_display_error(
+        DeviceRole::Couch,
+        "re:.",
+        &[&primary_display, &secondary_display],
+    );
+
+    assert_eq!(actual_result, expected_result);
+    assert_that_exit_code_is(&actual_result, AMBIGUOUS_DEVICE_EXIT_CODE);
+}
+
+#[test]
+fn suggest_the_closest_display_name_when_the_couch_display_is_misspelled() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let (misspelled_display_name, _) =
+        secondary_display_name.split_at(secondary_display_name.len() - 1);
+
+    let args = ArgumentsBuilder::change()
+        .displays_only(&primary_display_name, misspelled_display_name)
+        .build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::device_not_found_error(
+        DeviceKind::Display,
+        DeviceRole::Couch,
+        &[misspelled_display_name],
+        &[&secondary_display_name],
+        &[&primary_display_name, &secondary_display_name],
+    );
 
     assert_eq!(actual_result, expected_result);
+    assert_that_exit_code_is(&actual_result, DEVICE_NOT_FOUND_EXIT_CODE);
 }
@@ -0,0 +1,232 @@
+use convertible_couch_lib::func;
+use convertible_couch_testing::arrangements::{
+    builders::{
+        application::ApplicationBuilder, arguments::ArgumentsBuilder,
+        command_result::CommandResultBuilder,
+    },
+    fuzzing::{ComputerBuilder, Fuzzer},
+};
+
+#[test]
+fn plan_the_change_of_primary_display_and_default_speaker() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+    let (default_speaker_name, alternative_speaker_name) = fuzzer.generate_two_speakers_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_displays()
+        .with_speakers()
+        .of_which_there_are_at_least(2)
+        .whose_default_one_is_named(&default_speaker_name)
+        .with_an_alternative_one_named(&alternative_speaker_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let dry_run_args = ArgumentsBuilder::change()
+        .displays_and_speakers(
+            &primary_display_name,
+            &secondary_display_name,
+            &default_speaker_name,
+            &alternative_speaker_name,
+        )
+        .dry_run()
+        .build();
+    let change_args = ArgumentsBuilder::change()
+        .displays_and_speakers(
+            &primary_display_name,
+            &secondary_display_name,
+            &default_speaker_name,
+            &alternative_speaker_name,
+        )
+        .build();
+    let info_args = ArgumentsBuilder::info().displays_and_speakers().build();
+
+    // Act
+    let actual_result = application.execute(&dry_run_args);
+
+    // Assert
+    let info_result_after_change = application
+        .execute(&change_args)
+        .and_then(|_| application.execute(&info_args));
+
+    let expected_result = CommandResultBuilder::plan().displays_and_speakers(
+        &secondary_display_name,
+        &alternative_speaker_name,
+        info_result_after_change,
+    );
+
+    assert_eq!(actual_result, expected_result);
+}
+
+#[test]
+fn plan_the_change_of_primary_display_to_a_display() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let dry_run_args = ArgumentsBuilder::change()
+        .displays_only_to(&secondary_display_name)
+        .dry_run()
+        .build();
+    let change_args = ArgumentsBuilder::change()
+        .displays_only_to(&secondary_display_name)
+        .build();
+    let info_args = ArgumentsBuilder::info().displays_only().build();
+
+    // Act
+    let actual_result = application.execute(&dry_run_args);
+
+    // Assert
+    let info_result_after_change = application
+        .execute(&change_args)
+        .and_then(|_| application.execute(&info_args));
+
+    let expected_result = CommandResultBuilder::plan()
+        .displays_only(&secondary_display_name, info_result_after_change);
+
+    assert_eq!(actual_result, expected_result);
+}
+
+#[test]
+fn plan_the_change_of_default_speaker_to_a_speaker() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (default_speaker_name, alternative_speaker_name) = fuzzer.generate_two_speakers_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_speakers()
+        .of_which_there_are_at_least(2)
+        .whose_default_one_is_named(&default_speaker_name)
+        .with_an_alternative_one_named(&alternative_speaker_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let dry_run_args = ArgumentsBuilder::change()
+        .speakers_only_to(&alternative_speaker_name)
+        .dry_run()
+        .build();
+    let change_args = ArgumentsBuilder::change()
+        .speakers_only_to(&alternative_speaker_name)
+        .build();
+    let info_args = ArgumentsBuilder::info().speakers_only().build();
+
+    // Act
+    let actual_result = application.execute(&dry_run_args);
+
+    // Assert
+    let info_result_after_change = application
+        .execute(&change_args)
+        .and_then(|_| application.execute(&info_args));
+
+    let expected_result = CommandResultBuilder::plan()
+        .speakers_only(&alternative_speaker_name, info_result_after_change);
+
+    assert_eq!(actual_result, expected_result);
+}
+
+#[test]
+fn change_nothing_when_planning_a_change() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+    let (default_speaker_name, alternative_speaker_name) = fuzzer.generate_two_speakers_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_displays()
+        .with_speakers()
+        .of_which_there_are_at_least(2)
+        .whose_default_one_is_named(&default_speaker_name)
+        .with_an_alternative_one_named(&alternative_speaker_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let dry_run_args = ArgumentsBuilder::change()
+        .displays_and_speakers(
+            &primary_display_name,
+            &secondary_display_name,
+            &default_speaker_name,
+            &alternative_speaker_name,
+        )
+        .dry_run()
+        .build();
+    let info_args = ArgumentsBuilder::info().displays_and_speakers().build();
+
+    let expected_result = application.execute(&info_args);
+
+    // Act
+    let actual_result = application
+        .execute(&dry_run_args)
+        .and_then(|_| application.execute(&info_args));
+
+    // Assert
+    assert_eq!(actual_result, expected_result);
+}
+
+#[test]
+fn plan_the_change_of_primary_display_to_the_next_one_of_a_cycle() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let dry_run_args = ArgumentsBuilder::change()
+        .cycle(&[&primary_display_name, &secondary_display_name], &[])
+        .dry_run()
+        .build();
+    let change_args = ArgumentsBuilder::change()
+        .cycle(&[&primary_display_name, &secondary_display_name], &[])
+        .build();
+    let info_args = ArgumentsBuilder::info().displays_only().build();
+
+    // Act
+    let actual_result = application.execute(&dry_run_args);
+
+    // Assert
+    let info_result_after_change = application
+        .execute(&change_args)
+        .and_then(|_| application.execute(&info_args));
+
+    let expected_result = CommandResultBuilder::plan()
+        .displays_only(&secondary_display_name, info_result_after_change);
+
+    assert_eq!(actual_result, expected_result);
+}
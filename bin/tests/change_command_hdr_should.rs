@@ -0,0 +1,222 @@
+use convertible_couch::{commands::change::HdrOption, exit_code::UNSUPPORTED_SETTING_EXIT_CODE};
+use convertible_couch_lib::func;
+use convertible_couch_testing::{
+    arrangements::{
+        builders::{
+            application::ApplicationBuilder, arguments::ArgumentsBuilder,
+            command_result::CommandResultBuilder,
+        },
+        fuzzing::{ComputerBuilder, Fuzzer},
+    },
+    assertions::assert_that_exit_code_is,
+};
+
+#[test]
+fn turn_hdr_on_on_the_new_primary_display() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_supporting_hdr_named(&secondary_display_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::change()
+        .displays_only_to(&secondary_display_name)
+        .at_hdr(HdrOption::On)
+        .build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result =
+        CommandResultBuilder::change().displays_only_at_hdr(&secondary_display_name, true);
+
+    assert_eq!(actual_result, expected_result);
+}
+
+#[test]
+fn keep_hdr_on_on_a_display_when_the_primary_display_changes_back() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_supporting_hdr_named(&secondary_display_name)
+        .build_computer();
+
+    let primary_display = computer.display_named(&primary_display_name);
+    let secondary_display = computer.display_named(&secondary_display_name);
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let hdr_args = ArgumentsBuilder::change()
+        .displays_only_to(&secondary_display_name)
+        .at_hdr(HdrOption::On)
+        .build();
+    let change_back_args = ArgumentsBuilder::change()
+        .displays_only_to(&primary_display_name)
+        .build();
+    let info_args = ArgumentsBuilder::info().displays_only().build();
+
+    // Act
+    let actual_result = application
+        .execute(&hdr_args)
+        .and_then(|_| application.execute(&change_back_args))
+        .and_then(|_| application.execute(&info_args));
+
+    // Assert
+    let expected_result = CommandResultBuilder::info()
+        .displays_only()
+        .with_primary_display(&primary_display)
+        .with_hdr_secondary_display(&secondary_display, true)
+        .build();
+
+    assert_eq!(actual_result, expected_result);
+}
+
+#[test]
+fn turn_hdr_on_on_the_display_of_a_profile() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+    let (default_speaker_name, alternative_speaker_name) = fuzzer.generate_two_speakers_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_supporting_hdr_named(&secondary_display_name)
+        .build_displays()
+        .with_speakers()
+        .of_which_there_are_at_least(2)
+        .whose_default_one_is_named(&default_speaker_name)
+        .with_an_alternative_one_named(&alternative_speaker_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer)
+        .with_profile_at_hdr(
+            "couch",
+            &secondary_display_name,
+            &alternative_speaker_name,
+            true,
+        )
+        .build();
+
+    let args = ArgumentsBuilder::change().profile("couch").build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::change().displays_and_speakers_at_hdr(
+        &secondary_display_name,
+        true,
+        &alternative_speaker_name,
+    );
+
+    assert_eq!(actual_result, expected_result);
+}
+
+#[test]
+fn plan_hdr_being_turned_on_on_the_new_primary_display() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_supporting_hdr_named(&secondary_display_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let dry_run_args = ArgumentsBuilder::change()
+        .displays_only_to(&secondary_display_name)
+        .at_hdr(HdrOption::On)
+        .dry_run()
+        .build();
+    let change_args = ArgumentsBuilder::change()
+        .displays_only_to(&secondary_display_name)
+        .at_hdr(HdrOption::On)
+        .build();
+    let info_args = ArgumentsBuilder::info().displays_only().build();
+
+    // Act
+    let actual_result = application.execute(&dry_run_args);
+
+    // Assert
+    let info_result_after_change = application
+        .execute(&change_args)
+        .and_then(|_| application.execute(&info_args));
+
+    let expected_result = CommandResultBuilder::plan().displays_only_at_hdr(
+        &secondary_display_name,
+        true,
+        info_result_after_change,
+    );
+
+    assert_eq!(actual_result, expected_result);
+}
+
+#[test]
+fn fail_to_turn_hdr_on_on_a_display_which_does_not_support_it_without_changing_anything() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_computer();
+
+    let primary_display = computer.display_named(&primary_display_name);
+    let secondary_display = computer.display_named(&secondary_display_name);
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::change()
+        .displays_only_to(&secondary_display_name)
+        .at_hdr(HdrOption::On)
+        .build();
+    let info_args = ArgumentsBuilder::info().displays_only().build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result =
+        CommandResultBuilder::unsupported_setting_error(&secondary_display, "HDR");
+    let expected_info_result = CommandResultBuilder::info()
+        .displays_only()
+        .with_primary_display(&primary_display)
+        .with_secondary_display(&secondary_display)
+        .build();
+
+    assert_eq!(actual_result, expected_result);
+    assert_eq!(application.execute(&info_args), expected_info_result);
+    assert_that_exit_code_is(&actual_result, UNSUPPORTED_SETTING_EXIT_CODE);
+}
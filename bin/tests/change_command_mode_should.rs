@@ -0,0 +1,268 @@
+use convertible_couch::exit_code::UNSUPPORTED_MODE_EXIT_CODE;
+use convertible_couch_lib::{
+    displays_settings::{DisplayResolution, INTERNAL_DISPLAY_NAME},
+    func,
+};
+use convertible_couch_testing::{
+    arrangements::{
+        builders::{
+            application::ApplicationBuilder, arguments::ArgumentsBuilder,
+            command_result::CommandResultBuilder,
+        },
+        fuzzing::{ComputerBuilder, Fuzzer},
+    },
+    assertions::assert_that_exit_code_is,
+};
+
+#[test]
+fn drive_the_new_primary_display_at_the_mode_asked() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_computer();
+
+    let mode = computer.display_named(&secondary_display_name).modes[0].to_display_mode();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::change()
+        .displays_only_to(&secondary_display_name)
+        .at_mode(
+            Some(mode.resolution),
+            Some(mode.refresh_rate_millihertz / 1000),
+        )
+        .build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result =
+        CommandResultBuilder::change().displays_only_at_mode(&secondary_display_name, mode);
+
+    assert_eq!(actual_result, expected_result);
+}
+
+#[test]
+fn drive_the_new_primary_display_at_the_highest_refresh_rate_of_the_resolution_asked() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_computer();
+
+    let mode = computer.display_named(&secondary_display_name).modes[0].to_display_mode();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::change()
+        .displays_only(&primary_display_name, &secondary_display_name)
+        .at_mode(Some(mode.resolution), None)
+        .build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result =
+        CommandResultBuilder::change().displays_only_at_mode(&secondary_display_name, mode);
+
+    assert_eq!(actual_result, expected_result);
+}
+
+#[test]
+fn drive_the_display_of_a_profile_at_its_mode() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+    let (default_speaker_name, alternative_speaker_name) = fuzzer.generate_two_speakers_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_displays()
+        .with_speakers()
+        .of_which_there_are_at_least(2)
+        .whose_default_one_is_named(&default_speaker_name)
+        .with_an_alternative_one_named(&alternative_speaker_name)
+        .build_computer();
+
+    let mode = computer.display_named(&secondary_display_name).modes[0].to_display_mode();
+
+    let mut application = ApplicationBuilder::new(computer)
+        .with_profile_at_mode(
+            "couch",
+            &secondary_display_name,
+            &alternative_speaker_name,
+            &mode,
+        )
+        .build();
+
+    let args = ArgumentsBuilder::change().profile("couch").build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::change().displays_and_speakers_at_mode(
+        &secondary_display_name,
+        mode,
+        &alternative_speaker_name,
+    );
+
+    assert_eq!(actual_result, expected_result);
+}
+
+#[test]
+fn plan_the_mode_the_new_primary_display_would_be_driven_at() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_computer();
+
+    let mode = computer.display_named(&secondary_display_name).modes[0].to_display_mode();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let dry_run_args = ArgumentsBuilder::change()
+        .displays_only_to(&secondary_display_name)
+        .at_mode(
+            Some(mode.resolution),
+            Some(mode.refresh_rate_millihertz / 1000),
+        )
+        .dry_run()
+        .build();
+    let change_args = ArgumentsBuilder::change()
+        .displays_only_to(&secondary_display_name)
+        .at_mode(
+            Some(mode.resolution),
+            Some(mode.refresh_rate_millihertz / 1000),
+        )
+        .build();
+    let info_args = ArgumentsBuilder::info().displays_only().build();
+
+    // Act
+    let actual_result = application.execute(&dry_run_args);
+
+    // Assert
+    let info_result_after_change = application
+        .execute(&change_args)
+        .and_then(|_| application.execute(&info_args));
+
+    let expected_result = CommandResultBuilder::plan().displays_only_at_mode(
+        &secondary_display_name,
+        mode,
+        info_result_after_change,
+    );
+
+    assert_eq!(actual_result, expected_result);
+}
+
+#[test]
+fn fail_to_drive_the_new_primary_display_at_a_mode_it_does_not_support() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_computer();
+
+    let primary_display = computer.display_named(&primary_display_name);
+    let secondary_display = computer.display_named(&secondary_display_name);
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::change()
+        .displays_only_to(&secondary_display_name)
+        .at_mode(
+            Some(DisplayResolution {
+                width: 7681,
+                height: 4321,
+            }),
+            None,
+        )
+        .build();
+    let info_args = ArgumentsBuilder::info().displays_only().build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result =
+        CommandResultBuilder::unsupported_mode_error(&secondary_display, "7681x4321");
+    let expected_info_result = CommandResultBuilder::info()
+        .displays_only()
+        .with_primary_display(&primary_display)
+        .with_secondary_display(&secondary_display)
+        .build();
+
+    assert_eq!(actual_result, expected_result);
+    assert_eq!(application.execute(&info_args), expected_info_result);
+    assert_that_exit_code_is(&actual_result, UNSUPPORTED_MODE_EXIT_CODE);
+}
+
+#[test]
+fn fail_to_drive_the_internal_display_at_a_refresh_rate_it_does_not_support() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .including_an_internal_display()
+        .build_computer();
+
+    let internal_display = computer.display_named("");
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::change()
+        .displays_only_to(INTERNAL_DISPLAY_NAME)
+        .at_mode(None, Some(1000))
+        .build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::unsupported_mode_error(
+        &internal_display,
+        &format!("{}@1000", internal_display.resolution),
+    );
+
+    assert_eq!(actual_result, expected_result);
+}
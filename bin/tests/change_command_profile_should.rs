@@ -0,0 +1,223 @@
+use convertible_couch::exit_code::{CONFIG_EXIT_CODE, DEVICE_NOT_FOUND_EXIT_CODE};
+use convertible_couch_lib::{
+    application_error::{DeviceKind, DeviceRole},
+    func,
+};
+use convertible_couch_testing::{
+    arrangements::{
+        builders::{
+            application::ApplicationBuilder, arguments::ArgumentsBuilder,
+            command_result::CommandResultBuilder,
+        },
+        fuzzing::{ComputerBuilder, Fuzzer},
+    },
+    assertions::assert_that_exit_code_is,
+};
+
+#[test]
+fn change_primary_display_and_default_speaker_to_the_ones_of_a_profile() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+    let (default_speaker_name, alternative_speaker_name) = fuzzer.generate_two_speakers_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_displays()
+        .with_speakers()
+        .of_which_there_are_at_least(2)
+        .whose_default_one_is_named(&default_speaker_name)
+        .with_an_alternative_one_named(&alternative_speaker_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer)
+        .with_profile("desk", &primary_display_name, &default_speaker_name)
+        .with_profile("couch", &secondary_display_name, &alternative_speaker_name)
+        .build();
+
+    let args = ArgumentsBuilder::change().profile("couch").build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::change()
+        .displays_and_speakers(&secondary_display_name, &alternative_speaker_name);
+
+    assert_eq!(actual_result, expected_result);
+}
+
+#[test]
+fn change_primary_display_and_default_speaker_to_the_ones_of_a_profile_back_and_forth() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+    let (default_speaker_name, alternative_speaker_name) = fuzzer.generate_two_speakers_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_displays()
+        .with_speakers()
+        .of_which_there_are_at_least(2)
+        .whose_default_one_is_named(&default_speaker_name)
+        .with_an_alternative_one_named(&alternative_speaker_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer)
+        .with_profile("desk", &primary_display_name, &default_speaker_name)
+        .with_profile("couch", &secondary_display_name, &alternative_speaker_name)
+        .build();
+
+    let couch_args = ArgumentsBuilder::change().profile("couch").build();
+    let desk_args = ArgumentsBuilder::change().profile("desk").build();
+
+    // Act
+    let actual_result = application
+        .execute(&couch_args)
+        .and_then(|_| application.execute(&desk_args));
+
+    // Assert
+    let expected_result = CommandResultBuilder::change()
+        .displays_and_speakers(&primary_display_name, &default_speaker_name);
+
+    assert_eq!(actual_result, expected_result);
+}
+
+#[test]
+fn keep_primary_display_and_default_speaker_when_they_are_the_ones_of_the_profile() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+    let (default_speaker_name, alternative_speaker_name) = fuzzer.generate_two_speakers_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_displays()
+        .with_speakers()
+        .of_which_there_are_at_least(2)
+        .whose_default_one_is_named(&default_speaker_name)
+        .with_an_alternative_one_named(&alternative_speaker_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer)
+        .with_profile("desk", &primary_display_name, &default_speaker_name)
+        .build();
+
+    let args = ArgumentsBuilder::change().profile("desk").build();
+
+    // Act
+    let actual_result = application
+        .execute(&args)
+        .and_then(|_| application.execute(&args));
+
+    // Assert
+    let expected_result = CommandResultBuilder::change()
+        .displays_and_speakers(&primary_display_name, &default_speaker_name);
+
+    assert_eq!(actual_result, expected_result);
+}
+
+#[test]
+fn fail_when_the_profile_is_not_declared() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+    let (default_speaker_name, alternative_speaker_name) = fuzzer.generate_two_speakers_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_displays()
+        .with_speakers()
+        .of_which_there_are_at_least(2)
+        .whose_default_one_is_named(&default_speaker_name)
+        .with_an_alternative_one_named(&alternative_speaker_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer)
+        .with_profile("desk", &primary_display_name, &default_speaker_name)
+        .with_profile("couch", &secondary_display_name, &alternative_speaker_name)
+        .build();
+
+    let args = ArgumentsBuilder::change().profile("projector").build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result =
+        CommandResultBuilder::config_error("Profile is invalid, possible values are [couch, desk]");
+
+    assert_eq!(actual_result, expected_result);
+    assert_that_exit_code_is(&actual_result, CONFIG_EXIT_CODE);
+}
+
+#[test]
+fn fail_when_the_display_of_the_profile_is_not_connected() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name, projector_display_name) =
+        fuzzer.generate_three_display_names();
+    let (default_speaker_name, alternative_speaker_name) = fuzzer.generate_two_speakers_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_displays()
+        .with_speakers()
+        .of_which_there_are(2)
+        .whose_default_one_is_named(&default_speaker_name)
+        .with_an_alternative_one_named(&alternative_speaker_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer)
+        .with_profile(
+            "projector",
+            &projector_display_name,
+            &alternative_speaker_name,
+        )
+        .build();
+
+    let args = ArgumentsBuilder::change().profile("projector").build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let mut possible_display_names = [primary_display_name.as_str(), &secondary_display_name];
+    possible_display_names.sort();
+
+    let expected_result = CommandResultBuilder::device_not_found_error(
+        DeviceKind::Display,
+        DeviceRole::Profile,
+        &[&projector_display_name],
+        &[],
+        &possible_display_names,
+    );
+
+    assert_eq!(actual_result, expected_result);
+    assert_that_exit_code_is(&actual_result, DEVICE_NOT_FOUND_EXIT_CODE);
+}
@@ -0,0 +1,198 @@
+use convertible_couch::commands::change::RotationOption;
+use convertible_couch_lib::{
+    displays_settings::{DisplayRotation, DisplayTransformRequest},
+    func,
+};
+use convertible_couch_testing::arrangements::{
+    builders::{
+        application::ApplicationBuilder, arguments::ArgumentsBuilder,
+        command_result::CommandResultBuilder,
+    },
+    fuzzing::{ComputerBuilder, Fuzzer},
+};
+
+#[test]
+fn turn_the_new_primary_display_to_the_rotation_asked() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::change()
+        .displays_only_to(&secondary_display_name)
+        .at_transform(Some(RotationOption::Degrees90), None)
+        .build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::change().displays_only_at_transform(
+        &secondary_display_name,
+        &DisplayTransformRequest {
+            rotation: Some(DisplayRotation::Degrees90),
+            scale_percent: None,
+        },
+    );
+
+    assert_eq!(actual_result, expected_result);
+}
+
+#[test]
+fn keep_the_rotation_of_a_display_when_the_primary_display_changes_back() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_computer();
+
+    let primary_display = computer.display_named(&primary_display_name);
+    let secondary_display = computer.display_named(&secondary_display_name);
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let rotate_args = ArgumentsBuilder::change()
+        .displays_only_to(&secondary_display_name)
+        .at_transform(Some(RotationOption::Degrees270), None)
+        .build();
+    let change_back_args = ArgumentsBuilder::change()
+        .displays_only_to(&primary_display_name)
+        .build();
+    let info_args = ArgumentsBuilder::info().displays_only().build();
+
+    // Act
+    let actual_result = application
+        .execute(&rotate_args)
+        .and_then(|_| application.execute(&change_back_args))
+        .and_then(|_| application.execute(&info_args));
+
+    // Assert
+    let expected_result = CommandResultBuilder::info()
+        .displays_only()
+        .with_primary_display(&primary_display)
+        .with_transformed_secondary_display(
+            &secondary_display,
+            &DisplayTransformRequest {
+                rotation: Some(DisplayRotation::Degrees270),
+                scale_percent: None,
+            },
+        )
+        .build();
+
+    assert_eq!(actual_result, expected_result);
+}
+
+#[test]
+fn turn_the_display_of_a_profile_to_its_rotation() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+    let (default_speaker_name, alternative_speaker_name) = fuzzer.generate_two_speakers_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_displays()
+        .with_speakers()
+        .of_which_there_are_at_least(2)
+        .whose_default_one_is_named(&default_speaker_name)
+        .with_an_alternative_one_named(&alternative_speaker_name)
+        .build_computer();
+
+    let transform_request = DisplayTransformRequest {
+        rotation: Some(DisplayRotation::Degrees180),
+        scale_percent: None,
+    };
+
+    let mut application = ApplicationBuilder::new(computer)
+        .with_profile_at_transform(
+            "couch",
+            &secondary_display_name,
+            &alternative_speaker_name,
+            &transform_request,
+        )
+        .build();
+
+    let args = ArgumentsBuilder::change().profile("couch").build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::change().displays_and_speakers_at_transform(
+        &secondary_display_name,
+        &transform_request,
+        &alternative_speaker_name,
+    );
+
+    assert_eq!(actual_result, expected_result);
+}
+
+#[test]
+fn plan_the_rotation_the_new_primary_display_would_be_turned_to() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let dry_run_args = ArgumentsBuilder::change()
+        .displays_only_to(&secondary_display_name)
+        .at_transform(Some(RotationOption::Degrees90), None)
+        .dry_run()
+        .build();
+    let change_args = ArgumentsBuilder::change()
+        .displays_only_to(&secondary_display_name)
+        .at_transform(Some(RotationOption::Degrees90), None)
+        .build();
+    let info_args = ArgumentsBuilder::info().displays_only().build();
+
+    // Act
+    let actual_result = application.execute(&dry_run_args);
+
+    // Assert
+    let info_result_after_change = application
+        .execute(&change_args)
+        .and_then(|_| application.execute(&info_args));
+
+    let expected_result = CommandResultBuilder::plan().displays_only_at_transform(
+        &secondary_display_name,
+        &DisplayTransformRequest {
+            rotation: Some(DisplayRotation::Degrees90),
+            scale_percent: None,
+        },
+        info_result_after_change,
+    );
+
+    assert_eq!(actual_result, expected_result);
+}
@@ -0,0 +1,62 @@
+#![cfg(target_os = "windows")]
+
+use convertible_couch::exit_code::UNSUPPORTED_SETTING_EXIT_CODE;
+use convertible_couch_lib::func;
+use convertible_couch_testing::{
+    arrangements::{
+        builders::{
+            application::ApplicationBuilder, arguments::ArgumentsBuilder,
+            command_result::CommandResultBuilder,
+        },
+        fuzzing::{ComputerBuilder, Fuzzer},
+    },
+    assertions::assert_that_exit_code_is,
+};
+use test_case::test_case;
+
+/// Windows offers the fuzzed displays the scales up to 225%.
+#[test_case(130; "when the scale is not one of windows")]
+#[test_case(300; "when the scale is beyond the ones offered")]
+fn fail_to_scale_the_new_primary_display_to_a_scale_not_offered_without_changing_anything(
+    scale_percent: u32,
+) {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_computer();
+
+    let primary_display = computer.display_named(&primary_display_name);
+    let secondary_display = computer.display_named(&secondary_display_name);
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::change()
+        .displays_only_to(&secondary_display_name)
+        .at_transform(None, Some(scale_percent))
+        .build();
+    let info_args = ArgumentsBuilder::info().displays_only().build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result =
+        CommandResultBuilder::unsupported_setting_error(&secondary_display, "scale");
+    let expected_info_result = CommandResultBuilder::info()
+        .displays_only()
+        .with_primary_display(&primary_display)
+        .with_secondary_display(&secondary_display)
+        .build();
+
+    assert_eq!(actual_result, expected_result);
+    assert_eq!(application.execute(&info_args), expected_info_result);
+    assert_that_exit_code_is(&actual_result, UNSUPPORTED_SETTING_EXIT_CODE);
+}
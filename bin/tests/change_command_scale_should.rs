@@ -0,0 +1,62 @@
+use convertible_couch_lib::{displays_settings::DisplayTransformRequest, func};
+use convertible_couch_testing::arrangements::{
+    builders::{
+        application::ApplicationBuilder, arguments::ArgumentsBuilder,
+        command_result::CommandResultBuilder,
+    },
+    fuzzing::{ComputerBuilder, Fuzzer},
+};
+
+#[test]
+fn scale_the_new_primary_display_and_keep_its_scale_when_the_primary_display_changes_back() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_computer();
+
+    let primary_display = computer.display_named(&primary_display_name);
+    let secondary_display = computer.display_named(&secondary_display_name);
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let scale_args = ArgumentsBuilder::change()
+        .displays_only_to(&secondary_display_name)
+        .at_transform(None, Some(200))
+        .build();
+    let change_back_args = ArgumentsBuilder::change()
+        .displays_only_to(&primary_display_name)
+        .build();
+    let info_args = ArgumentsBuilder::info().displays_only().build();
+
+    // Act
+    let actual_result = application.execute(&scale_args);
+
+    // Assert
+    let transform_request = DisplayTransformRequest {
+        rotation: None,
+        scale_percent: Some(200),
+    };
+    let expected_result = CommandResultBuilder::change()
+        .displays_only_at_transform(&secondary_display_name, &transform_request);
+    let expected_info_result = CommandResultBuilder::info()
+        .displays_only()
+        .with_primary_display(&primary_display)
+        .with_transformed_secondary_display(&secondary_display, &transform_request)
+        .build();
+
+    assert_eq!(actual_result, expected_result);
+    assert_eq!(
+        application
+            .execute(&change_back_args)
+            .and_then(|_| application.execute(&info_args)),
+        expected_info_result
+    );
+}
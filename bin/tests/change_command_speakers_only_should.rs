@@ -1,10 +1,17 @@
-use convertible_couch_lib::func;
-use convertible_couch_testing::arrangements::{
-    builders::{
-        application::ApplicationBuilder, arguments::ArgumentsBuilder,
-        command_result::CommandResultBuilder,
+use convertible_couch::exit_code::{AMBIGUOUS_DEVICE_EXIT_CODE, DEVICE_NOT_FOUND_EXIT_CODE};
+use convertible_couch_lib::{
+    application_error::{DeviceKind, DeviceRole},
+    func,
+};
+use convertible_couch_testing::{
+    arrangements::{
+        builders::{
+            application::ApplicationBuilder, arguments::ArgumentsBuilder,
+            command_result::CommandResultBuilder,
+        },
+        fuzzing::{ComputerBuilder, Fuzzer},
     },
-    fuzzing::{ComputerBuilder, Fuzzer},
+    assertions::assert_that_exit_code_is,
 };
 
 #[test]
@@ -99,11 +106,16 @@ fn validate_the_desktop_and_couch_speaker_name() {
     let actual_result = application.execute(&args);
 
     // Assert
-    let expected_result = CommandResultBuilder::custom_error(format!(
-        "Desktop and couch speakers are invalid, possible values are [{default_speaker_name}, {alternative_speaker_name}]"
-    ));
+    let expected_result = CommandResultBuilder::device_not_found_error(
+        DeviceKind::Speaker,
+        DeviceRole::DesktopAndCouch,
+        &[&invalid_speaker_name_1, &invalid_speaker_name_2],
+        &[],
+        &[&default_speaker_name, &alternative_speaker_name],
+    );
 
     assert_eq!(actual_result, expected_result);
+    assert_that_exit_code_is(&actual_result, DEVICE_NOT_FOUND_EXIT_CODE);
 }
 
 #[test]
@@ -132,11 +144,16 @@ fn validate_the_desktop_speaker_name() {
     let actual_result = application.execute(&args);
 
     // Assert
-    let expected_result = CommandResultBuilder::custom_error(format!(
-        "Desktop speaker is invalid, possible values are [{default_speaker_name}, {alternative_speaker_name}]"
-    ));
+    let expected_result = CommandResultBuilder::device_not_found_error(
+        DeviceKind::Speaker,
+        DeviceRole::Desktop,
+        &[&invalid_speaker_name],
+        &[],
+        &[&default_speaker_name, &alternative_speaker_name],
+    );
 
     assert_eq!(actual_result, expected_result);
+    assert_that_exit_code_is(&actual_result, DEVICE_NOT_FOUND_EXIT_CODE);
 }
 
 #[test]
@@ -165,9 +182,287 @@ fn validate_the_couch_speaker_name() {
     let actual_result = application.execute(&args);
 
     // Assert
-    let expected_result = CommandResultBuilder::custom_error(format!(
-        "Couch speaker is invalid, possible values are [{default_speaker_name}, {alternative_speaker_name}]"
-    ));
+    let expected_result = CommandResultBuilder::device_not_found_error(
+        DeviceKind::Speaker,
+        DeviceRole::Couch,
+        &[&invalid_speaker_name],
+        &[],
+        &[&default_speaker_name, &alternative_speaker_name],
+    );
+
+    assert_eq!(actual_result, expected_result);
+    assert_that_exit_code_is(&actual_result, DEVICE_NOT_FOUND_EXIT_CODE);
+}
+
+#[test]
+fn set_the_couch_speaker_as_default() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (default_speaker_name, alternative_speaker_name) = fuzzer.generate_two_speakers_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_speakers()
+        .of_which_there_are_at_least(2)
+        .whose_default_one_is_named(&default_speaker_name)
+        .with_an_alternative_one_named(&alternative_speaker_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::change()
+        .speakers_only_to(&alternative_speaker_name)
+        .build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::change().speakers_only(&alternative_speaker_name);
+
+    assert_eq!(actual_result, expected_result);
+}
+
+#[test]
+fn set_the_couch_speaker_as_default_when_it_already_is() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (default_speaker_name, alternative_speaker_name) = fuzzer.generate_two_speakers_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_speakers()
+        .of_which_there_are_at_least(2)
+        .whose_default_one_is_named(&default_speaker_name)
+        .with_an_alternative_one_named(&alternative_speaker_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::change()
+        .speakers_only_to(&alternative_speaker_name)
+        .build();
+
+    // Act
+    let actual_result = application
+        .execute(&args)
+        .and_then(|_| application.execute(&args));
+
+    // Assert
+    let expected_result = CommandResultBuilder::change().speakers_only(&alternative_speaker_name);
+
+    assert_eq!(actual_result, expected_result);
+}
+
+#[test]
+fn validate_the_speaker_to_set_as_default() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (invalid_speaker_name, default_speaker_name, alternative_speaker_name) =
+        fuzzer.generate_three_speakers_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_speakers()
+        .of_which_there_are(2)
+        .whose_default_one_is_named(&default_speaker_name)
+        .with_an_alternative_one_named(&alternative_speaker_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::change()
+        .speakers_only_to(&invalid_speaker_name)
+        .build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::device_not_found_error(
+        DeviceKind::Speaker,
+        DeviceRole::Target,
+        &[&invalid_speaker_name],
+        &[],
+        &[&default_speaker_name, &alternative_speaker_name],
+    );
+
+    assert_eq!(actual_result, expected_result);
+    assert_that_exit_code_is(&actual_result, DEVICE_NOT_FOUND_EXIT_CODE);
+}
+
+#[test]
+fn set_the_speaker_selected_by_its_id_as_default() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (default_speaker_name, alternative_speaker_name) = fuzzer.generate_two_speakers_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_speakers()
+        .of_which_there_are_at_least(2)
+        .whose_default_one_is_named(&default_speaker_name)
+        .with_an_alternative_one_named(&alternative_speaker_name)
+        .build_computer();
+
+    let alternative_speaker = computer.speaker_named(&alternative_speaker_name);
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::change()
+        .speakers_only_to(&format!("id:{}", alternative_speaker.id))
+        .build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::change().speakers_only(&alternative_speaker_name);
+
+    assert_eq!(actual_result, expected_result);
+}
+
+#[test]
+fn set_the_speaker_selected_by_its_id_as_default_when_another_one_shares_its_name() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (default_speaker_name, alternative_speaker_name) = fuzzer.generate_two_speakers_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_speakers()
+        .of_which_there_are_at_least(3)
+        .whose_default_one_is_named(&default_speaker_name)
+        .with_two_alternative_ones_named(&alternative_speaker_name)
+        .build_computer();
+
+    let alternative_speakers = computer.speakers_named(&alternative_speaker_name);
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::change()
+        .speakers_only_to(&format!("id:{}", alternative_speakers[1].id))
+        .build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::change().speakers_only(&alternative_speaker_name);
+
+    assert_eq!(actual_result, expected_result);
+}
+
+#[test]
+fn swap_the_speakers_selected_by_their_names_whatever_their_case() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (default_speaker_name, alternative_speaker_name) = fuzzer.generate_two_speakers_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_speakers()
+        .of_which_there_are_at_least(2)
+        .whose_default_one_is_named(&default_speaker_name)
+        .with_an_alternative_one_named(&alternative_speaker_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::change()
+        .speakers_only(
+            &default_speaker_name.to_lowercase(),
+            &alternative_speaker_name.to_uppercase(),
+        )
+        .build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::change().speakers_only(&alternative_speaker_name);
+
+    assert_eq!(actual_result, expected_result);
+}
+
+#[test]
+fn list_the_candidates_when_the_selector_matches_several_speakers() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (default_speaker_name, alternative_speaker_name) = fuzzer.generate_two_speakers_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_speakers()
+        .of_which_there_are(2)
+        .whose_default_one_is_named(&default_speaker_name)
+        .with_an_alternative_one_named(&alternative_speaker_name)
+        .build_computer();
+
+    let default_speaker = computer.speaker_named(&default_speaker_name);
+    let alternative_speaker = computer.speaker_named(&alternative_speaker_name);
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::change().speakers_only_to("re:.").build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::ambiguous_speaker_error(
+        DeviceRole::Target,
+        "re:.",
+        &[&default_speaker, &alternative_speaker],
+    );
+
+    assert_eq!(actual_result, expected_result);
+    assert_that_exit_code_is(&actual_result, AMBIGUOUS_DEVICE_EXIT_CODE);
+}
+
+#[test]
+fn suggest_the_closest_speaker_name_when_the_desktop_speaker_is_misspelled() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (default_speaker_name, alternative_speaker_name) = fuzzer.generate_two_speakers_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_speakers()
+        .of_which_there_are(2)
+        .whose_default_one_is_named(&default_speaker_name)
+        .with_an_alternative_one_named(&alternative_speaker_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let (misspelled_speaker_name, _) =
+        default_speaker_name.split_at(default_speaker_name.len() - 1);
+
+    let args = ArgumentsBuilder::change()
+        .speakers_only(misspelled_speaker_name, &alternative_speaker_name)
+        .build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::device_not_found_error(
+        DeviceKind::Speaker,
+        DeviceRole::Desktop,
+        &[misspelled_speaker_name],
+        &[&default_speaker_name],
+        &[&default_speaker_name, &alternative_speaker_name],
+    );
 
     assert_eq!(actual_result, expected_result);
+    assert_that_exit_code_is(&actual_result, DEVICE_NOT_FOUND_EXIT_CODE);
 }
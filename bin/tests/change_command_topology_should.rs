@@ -0,0 +1,291 @@
+use convertible_couch::{
+    commands::change::TopologyOption,
+    exit_code::{INVALID_TOPOLOGY_EXIT_CODE, MISSING_ARGUMENT_EXIT_CODE},
+};
+use convertible_couch_lib::{
+    application_error::{DeviceKind, DeviceRole},
+    displays_settings::INTERNAL_DISPLAY_NAME,
+    func,
+};
+use convertible_couch_testing::{
+    arrangements::{
+        builders::{
+            application::ApplicationBuilder, arguments::ArgumentsBuilder,
+            command_result::CommandResultBuilder,
+        },
+        fuzzing::{ComputerBuilder, Fuzzer},
+    },
+    assertions::assert_that_exit_code_is,
+};
+
+#[test]
+fn turn_on_only_the_given_display() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_computer();
+
+    let secondary_display = computer.display_named(&secondary_display_name);
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::change()
+        .only(&secondary_display_name)
+        .build();
+    let info_args = ArgumentsBuilder::info().displays_only().build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result =
+        CommandResultBuilder::change().topology(&[&secondary_display_name], false);
+    let expected_info_result = CommandResultBuilder::info()
+        .displays_only()
+        .with_only_display(&secondary_display)
+        .build();
+
+    assert_eq!(actual_result, expected_result);
+    assert_eq!(application.execute(&info_args), expected_info_result);
+}
+
+#[test]
+fn extend_the_desktop_over_every_display_again() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_computer();
+
+    let mut other_displays_names = computer.displays_names_primary_first();
+
+    other_displays_names.retain(|display_name| *display_name != secondary_display_name);
+    other_displays_names.sort();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let only_args = ArgumentsBuilder::change()
+        .only(&secondary_display_name)
+        .build();
+    let extend_args = ArgumentsBuilder::change()
+        .topology(TopologyOption::Extend)
+        .build();
+
+    // Act
+    let actual_result = application
+        .execute(&only_args)
+        .and_then(|_| application.execute(&extend_args));
+
+    // Assert
+    let expected_active_displays_names = [secondary_display_name.as_str()]
+        .into_iter()
+        .chain(other_displays_names.iter().map(String::as_str))
+        .collect::<Vec<&str>>();
+    let expected_result =
+        CommandResultBuilder::change().topology(&expected_active_displays_names, false);
+
+    assert_eq!(actual_result, expected_result);
+}
+
+#[test]
+fn clone_the_desktop_on_every_display() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .build_computer();
+
+    let displays_names = computer.displays_names_primary_first();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::change()
+        .topology(TopologyOption::Clone)
+        .build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_active_displays_names = displays_names
+        .iter()
+        .map(String::as_str)
+        .collect::<Vec<&str>>();
+    let expected_result =
+        CommandResultBuilder::change().topology(&expected_active_displays_names, true);
+
+    assert_eq!(actual_result, expected_result);
+}
+
+#[test]
+fn turn_on_only_the_internal_display() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .including_an_internal_display()
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::change()
+        .topology(TopologyOption::Internal)
+        .build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::change().topology(&[INTERNAL_DISPLAY_NAME], false);
+
+    assert_eq!(actual_result, expected_result);
+}
+
+#[test]
+fn turn_on_only_the_external_displays() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .including_an_internal_display()
+        .build_computer();
+
+    let displays_names = computer.displays_names_primary_first();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::change()
+        .topology(TopologyOption::External)
+        .build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_active_displays_names = displays_names
+        .iter()
+        .skip(1)
+        .map(String::as_str)
+        .collect::<Vec<&str>>();
+    let expected_result =
+        CommandResultBuilder::change().topology(&expected_active_displays_names, false);
+
+    assert_eq!(actual_result, expected_result);
+}
+
+#[test]
+fn validate_the_display_to_keep() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (invalid_display_name, primary_display_name, secondary_display_name) =
+        fuzzer.generate_three_display_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::change()
+        .only(&invalid_display_name)
+        .build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::device_not_found_error(
+        DeviceKind::Display,
+        DeviceRole::Target,
+        &[&invalid_display_name],
+        &[],
+        &[&primary_display_name, &secondary_display_name],
+    );
+
+    assert_eq!(actual_result, expected_result);
+}
+
+#[test]
+fn require_the_display_to_keep() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::change()
+        .topology(TopologyOption::Only)
+        .build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result =
+        CommandResultBuilder::missing_argument_error("The display to keep turned on is required");
+
+    assert_eq!(actual_result, expected_result);
+    assert_that_exit_code_is(&actual_result, MISSING_ARGUMENT_EXIT_CODE);
+}
+
+#[test]
+fn fail_to_turn_on_only_the_internal_display_when_there_is_none() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::change()
+        .topology(TopologyOption::Internal)
+        .build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result =
+        CommandResultBuilder::invalid_topology_error("There is no internal display to turn on");
+
+    assert_eq!(actual_result, expected_result);
+    assert_that_exit_code_is(&actual_result, INVALID_TOPOLOGY_EXIT_CODE);
+}
@@ -32,6 +32,13 @@ fn get_informations_about_displays_and_speakers() {
         .with_an_alternative_one_named(&alternative_speaker_name_2)
         .build_computer();
 
+    let primary_display = computer.display_named(&primary_display_name);
+    let secondary_display = computer.display_named(&secondary_display_name);
+    let secondary_display_2 = computer.display_named(&secondary_display_name_2);
+    let default_speaker = computer.speaker_named(&default_speaker_name);
+    let alternative_speaker = computer.speaker_named(&alternative_speaker_name);
+    let alternative_speaker_2 = computer.speaker_named(&alternative_speaker_name_2);
+
     let mut application = ApplicationBuilder::new(computer).build();
 
     let args = ArgumentsBuilder::info().displays_and_speakers().build();
@@ -42,12 +49,12 @@ fn get_informations_about_displays_and_speakers() {
     // Assert
     let expected_result = CommandResultBuilder::info()
         .displays_and_speakers()
-        .with_primary_display(&primary_display_name)
-        .with_secondary_display(&secondary_display_name)
-        .with_secondary_display(&secondary_display_name_2)
-        .with_default_speaker(&default_speaker_name)
-        .with_alternative_speaker(&alternative_speaker_name)
-        .with_alternative_speaker(&alternative_speaker_name_2)
+        .with_primary_display(&primary_display)
+        .with_secondary_display(&secondary_display)
+        .with_secondary_display(&secondary_display_2)
+        .with_default_speaker(&default_speaker)
+        .with_alternative_speaker(&alternative_speaker)
+        .with_alternative_speaker(&alternative_speaker_2)
         .build();
 
     assert_eq!(actual_result, expected_result);
@@ -90,6 +97,10 @@ fn get_informations_about_displays_only() {
         .with_a_secondary_named(&secondary_display_name_2)
         .build_computer();
 
+    let primary_display = computer.display_named(&primary_display_name);
+    let secondary_display = computer.display_named(&secondary_display_name);
+    let secondary_display_2 = computer.display_named(&secondary_display_name_2);
+
     let mut application = ApplicationBuilder::new(computer).build();
 
     let args = ArgumentsBuilder::info().displays_only().build();
@@ -100,9 +111,9 @@ fn get_informations_about_displays_only() {
     // Assert
     let expected_result = CommandResultBuilder::info()
         .displays_only()
-        .with_primary_display(&primary_display_name)
-        .with_secondary_display(&secondary_display_name)
-        .with_secondary_display(&secondary_display_name_2)
+        .with_primary_display(&primary_display)
+        .with_secondary_display(&secondary_display)
+        .with_secondary_display(&secondary_display_2)
         .build();
 
     assert_eq!(actual_result, expected_result);
@@ -125,6 +136,10 @@ fn get_informations_about_speakers_only() {
         .with_an_alternative_one_named(&alternative_speaker_name_2)
         .build_computer();
 
+    let default_speaker = computer.speaker_named(&default_speaker_name);
+    let alternative_speaker = computer.speaker_named(&alternative_speaker_name);
+    let alternative_speaker_2 = computer.speaker_named(&alternative_speaker_name_2);
+
     let mut application = ApplicationBuilder::new(computer).build();
 
     let args = ArgumentsBuilder::info().speakers_only().build();
@@ -135,9 +150,9 @@ fn get_informations_about_speakers_only() {
     // Assert
     let expected_result = CommandResultBuilder::info()
         .speakers_only()
-        .with_default_speaker(&default_speaker_name)
-        .with_alternative_speaker(&alternative_speaker_name)
-        .with_alternative_speaker(&alternative_speaker_name_2)
+        .with_default_speaker(&default_speaker)
+        .with_alternative_speaker(&alternative_speaker)
+        .with_alternative_speaker(&alternative_speaker_2)
         .build();
 
     assert_eq!(actual_result, expected_result);
@@ -159,6 +174,9 @@ fn get_informations_about_speakers_only_even_if_there_if_no_default_one() {
         .with_an_alternative_one_named(&alternative_speaker_name_2)
         .build_computer();
 
+    let alternative_speaker = computer.speaker_named(&alternative_speaker_name);
+    let alternative_speaker_2 = computer.speaker_named(&alternative_speaker_name_2);
+
     let mut application = ApplicationBuilder::new(computer).build();
 
     let args = ArgumentsBuilder::info().speakers_only().build();
@@ -169,9 +187,36 @@ fn get_informations_about_speakers_only_even_if_there_if_no_default_one() {
     // Assert
     let expected_result = CommandResultBuilder::info()
         .speakers_only()
-        .with_alternative_speaker(&alternative_speaker_name)
-        .with_alternative_speaker(&alternative_speaker_name_2)
+        .with_alternative_speaker(&alternative_speaker)
+        .with_alternative_speaker(&alternative_speaker_2)
         .build();
 
     assert_eq!(actual_result, expected_result);
 }
+
+#[test]
+fn get_informations_about_backends() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are(2)
+        .build_displays()
+        .with_speakers()
+        .of_which_there_are(2)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::info().backends().build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::info().backends().build();
+
+    assert_eq!(actual_result, expected_result);
+}
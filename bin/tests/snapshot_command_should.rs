@@ -0,0 +1,200 @@
+use convertible_couch::{commands::change::TopologyOption, exit_code::SNAPSHOT_EXIT_CODE};
+use convertible_couch_lib::func;
+use convertible_couch_testing::{
+    arrangements::{
+        builders::{
+            application::ApplicationBuilder, arguments::ArgumentsBuilder,
+            command_result::CommandResultBuilder,
+        },
+        fuzzing::{ComputerBuilder, Fuzzer},
+    },
+    assertions::assert_that_exit_code_is,
+};
+
+#[test]
+fn save_a_snapshot() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .build_displays()
+        .with_speakers()
+        .of_which_there_are_at_least(2)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::snapshot().save("couch").build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::snapshot().saved("couch");
+
+    assert_eq!(actual_result, expected_result);
+}
+
+#[test]
+fn restore_the_displays_and_the_speakers_of_a_snapshot() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+    let (default_speaker_name, alternative_speaker_name) = fuzzer.generate_two_speakers_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_displays()
+        .with_speakers()
+        .of_which_there_are_at_least(2)
+        .whose_default_one_is_named(&default_speaker_name)
+        .with_an_alternative_one_named(&alternative_speaker_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let info_args = ArgumentsBuilder::info().displays_and_speakers().build();
+    let save_args = ArgumentsBuilder::snapshot().save("desktop").build();
+    let change_args = ArgumentsBuilder::change()
+        .displays_and_speakers(
+            &primary_display_name,
+            &secondary_display_name,
+            &default_speaker_name,
+            &alternative_speaker_name,
+        )
+        .build();
+    let restore_args = ArgumentsBuilder::snapshot().restore("desktop").build();
+
+    let initial_info_result = application.execute(&info_args);
+
+    application.execute(&save_args).unwrap();
+    application.execute(&change_args).unwrap();
+
+    // Act
+    let actual_result = application.execute(&restore_args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::snapshot().restored("desktop");
+
+    assert_eq!(actual_result, expected_result);
+    assert_eq!(application.execute(&info_args), initial_info_result);
+}
+
+#[test]
+fn turn_the_displays_of_a_snapshot_back_on() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let info_args = ArgumentsBuilder::info().displays_only().build();
+    let save_args = ArgumentsBuilder::snapshot().save("desktop").build();
+    let only_args = ArgumentsBuilder::change()
+        .only(&secondary_display_name)
+        .build();
+    let restore_args = ArgumentsBuilder::snapshot().restore("desktop").build();
+
+    let initial_info_result = application.execute(&info_args);
+
+    application.execute(&save_args).unwrap();
+    application.execute(&only_args).unwrap();
+
+    // Act
+    let actual_result = application.execute(&restore_args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::snapshot().restored("desktop");
+
+    assert_eq!(actual_result, expected_result);
+    assert_eq!(application.execute(&info_args), initial_info_result);
+}
+
+#[test]
+fn turn_a_saved_set_of_displays_back_on() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let (primary_display_name, secondary_display_name) = fuzzer.generate_two_display_names();
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .whose_primary_is_named(&primary_display_name)
+        .with_a_secondary_named(&secondary_display_name)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let info_args = ArgumentsBuilder::info().displays_only().build();
+    let only_args = ArgumentsBuilder::change()
+        .only(&secondary_display_name)
+        .build();
+    let save_args = ArgumentsBuilder::snapshot().save("couch").build();
+    let extend_args = ArgumentsBuilder::change()
+        .topology(TopologyOption::Extend)
+        .build();
+    let restore_args = ArgumentsBuilder::snapshot().restore("couch").build();
+
+    application.execute(&only_args).unwrap();
+
+    let saved_info_result = application.execute(&info_args);
+
+    application.execute(&save_args).unwrap();
+    application.execute(&extend_args).unwrap();
+
+    // Act
+    let actual_result = application.execute(&restore_args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::snapshot().restored("couch");
+
+    assert_eq!(actual_result, expected_result);
+    assert_eq!(application.execute(&info_args), saved_info_result);
+}
+
+#[test]
+fn fail_to_restore_a_snapshot_which_was_never_saved() {
+    // Arrange
+    let mut fuzzer = Fuzzer::new(func!(), true);
+
+    let computer = fuzzer
+        .generate_computer()
+        .with_displays()
+        .of_which_there_are_at_least(2)
+        .build_displays()
+        .with_speakers()
+        .of_which_there_are_at_least(2)
+        .build_computer();
+
+    let mut application = ApplicationBuilder::new(computer).build();
+
+    let args = ArgumentsBuilder::snapshot().restore("couch").build();
+
+    // Act
+    let actual_result = application.execute(&args);
+
+    // Assert
+    let expected_result = CommandResultBuilder::snapshot_error("Snapshot couch does not exist");
+
+    assert_that_exit_code_is(&actual_result, SNAPSHOT_EXIT_CODE);
+    assert_eq!(actual_result, expected_result);
+}
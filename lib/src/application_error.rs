@@ -1,15 +1,442 @@
-use std::{num::TryFromIntError, string::FromUtf16Error};
+use std::{
+    error::Error,
+    fmt::{self, Display, Formatter},
+    io,
+    num::TryFromIntError,
+    string::{FromUtf8Error, FromUtf16Error},
+    sync::Arc,
+};
 
 use ::log::SetLoggerError;
 use log4rs::config::runtime::ConfigErrors;
+use serde::Serialize;
 use thiserror::Error;
 
-use crate::trace_fn;
+use crate::{
+    device_selector::{SelectableDevice, suggest_device_names},
+    trace_fn,
+};
 
 #[derive(Error, Debug, PartialEq)]
 pub enum ApplicationError {
     #[error("{0}")]
     Custom(String),
+    /// A device given by the user is not among the ones reported, the closest names being
+    /// suggested.
+    #[error("{}, {}", format_invalid_devices(.kind, .role), format_possible_values(.suggestions, .candidates))]
+    DeviceNotFound {
+        kind: DeviceKind,
+        role: DeviceRole,
+        requested: Vec<String>,
+        candidates: Vec<String>,
+        suggestions: Vec<String>,
+    },
+    /// A selector given by the user matches several devices, which are listed with their ids.
+    #[error("{} selector {selector} is ambiguous, candidates are [{}]", format_selected_device(.kind, .role), .candidates.join(", "))]
+    AmbiguousDevice {
+        kind: DeviceKind,
+        role: DeviceRole,
+        selector: String,
+        candidates: Vec<String>,
+    },
+    /// A call to the operating system, to a D-Bus service or to a command line tool failed. The
+    /// code is the one reported by the platform, when there is one.
+    #[error("{api} failed: {message}")]
+    PlatformCall {
+        api: String,
+        code: Option<i32>,
+        message: String,
+        #[source]
+        source: Option<ErrorSource>,
+    },
+    #[error("{message}")]
+    Conversion {
+        message: String,
+        #[source]
+        source: Option<ErrorSource>,
+    },
+    #[error("{message}")]
+    Config {
+        message: String,
+        #[source]
+        source: Option<ErrorSource>,
+    },
+    #[error("{message}")]
+    Logger {
+        message: String,
+        #[source]
+        source: Option<ErrorSource>,
+    },
+    /// A snapshot could not be saved or restored, the devices being left untouched.
+    #[error("{message}")]
+    Snapshot {
+        message: String,
+        #[source]
+        source: Option<ErrorSource>,
+    },
+    /// A change failed after some of the devices were changed, which stay changed.
+    #[error("The {}s were changed but not the {}s: {source}", format_device_kind(.completed), format_device_kind(.failed))]
+    PartialFailure {
+        completed: DeviceKind,
+        failed: DeviceKind,
+        #[source]
+        source: Box<ApplicationError>,
+    },
+    /// A change failed after some of the devices were changed, which were changed back.
+    #[error("The {}s could not be changed so the {}s were changed back: {source}", format_device_kind(.failed), format_device_kind(.rolled_back))]
+    RollbackPerformed {
+        rolled_back: DeviceKind,
+        failed: DeviceKind,
+        #[source]
+        source: Box<ApplicationError>,
+    },
+    /// A change failed after some of the devices were changed, which could not be changed back
+    /// either.
+    #[error("The {}s were changed but not the {}s: {source}, and they could not be changed back: {rollback_error}", format_device_kind(.completed), format_device_kind(.failed))]
+    RollbackFailed {
+        completed: DeviceKind,
+        failed: DeviceKind,
+        #[source]
+        source: Box<ApplicationError>,
+        rollback_error: Box<ApplicationError>,
+    },
+    /// A display does not support the mode asked, the ones it supports being listed.
+    #[error("{display} does not support {requested}, supported modes are [{}]", .supported.join(", "))]
+    UnsupportedMode {
+        display: String,
+        requested: String,
+        supported: Vec<String>,
+    },
+    /// A display setting which cannot be changed, such as the scale on X11 or HDR on a display
+    /// which does not support it.
+    #[error("The {setting} of {display} cannot be set with this backend or on this display")]
+    UnsupportedSetting { display: String, setting: String },
+    /// An argument which the command needs was not given, such as the display to keep turned on
+    /// when turning on only one.
+    #[error("{message}")]
+    MissingArgument { message: String },
+    /// A topology which would leave no display turned on, such as only the internal display on a
+    /// computer without one.
+    #[error("{message}")]
+    InvalidTopology { message: String },
+    /// No backend suits the session, such as an SSH one without a display server, so one has to
+    /// be selected explicitly.
+    #[error("No {} backend is available in this session, select one explicitly", format_device_kind(.kind))]
+    BackendNotDetected { kind: DeviceKind },
+    /// A change accepted by the platform was not applied, the settings observed last which
+    /// differ from the ones applied being reported.
+    #[error("The {} change was not applied after {attempts} checks: {}", format_device_kind(.kind), format_mismatches(.mismatches))]
+    VerificationFailed {
+        kind: DeviceKind,
+        mismatches: Vec<VerificationMismatch>,
+        attempts: u32,
+    },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DeviceKind {
+    Display,
+    Speaker,
+}
+
+/// A setting observed once a change was applied which differs from the value the change set it
+/// to, such as the primary display or its rotation.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct VerificationMismatch {
+    pub setting: String,
+    pub expected: String,
+    pub observed: String,
+}
+
+impl VerificationMismatch {
+    pub fn new(setting: &str, expected: impl Display, observed: impl Display) -> Self {
+        trace_fn!();
+
+        Self {
+            setting: String::from(setting),
+            expected: expected.to_string(),
+            observed: observed.to_string(),
+        }
+    }
+
+    /// The primary display or the default speaker observed is not the one set, or there is
+    /// none.
+    pub fn device(kind: DeviceKind, expected: &str, observed: Option<&str>) -> Self {
+        trace_fn!();
+
+        Self::new(
+            format_changed_setting(&kind),
+            expected,
+            observed.unwrap_or("none"),
+        )
+    }
+}
+
+/// What the device given by the user was meant for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DeviceRole {
+    Desktop,
+    Couch,
+    DesktopAndCouch,
+    /// The device to set as primary or default.
+    Target,
+    Profile,
+}
+
+/// The error a variant was raised from, kept so that the whole chain can be walked. Two sources
+/// are equal when they read the same, the underlying errors rarely being comparable.
+#[derive(Clone, Debug)]
+pub struct ErrorSource(Arc<dyn Error + Send + Sync>);
+
+impl ErrorSource {
+    pub fn new<T: Error + Send + Sync + 'static>(error: T) -> Self {
+        trace_fn!();
+
+        Self(Arc::new(error))
+    }
+}
+
+impl PartialEq for ErrorSource {
+    fn eq(&self, other: &Self) -> bool {
+        trace_fn!();
+
+        self.0.to_string() == other.0.to_string()
+    }
+}
+
+impl Display for ErrorSource {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        trace_fn!();
+
+        self.0.fmt(formatter)
+    }
+}
+
+impl Error for ErrorSource {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        trace_fn!();
+
+        self.0.source()
+    }
+}
+
+impl ApplicationError {
+    pub fn device_not_found(
+        kind: DeviceKind,
+        role: DeviceRole,
+        requested: &[&str],
+        mut candidates: Vec<String>,
+    ) -> Self {
+        trace_fn!();
+
+        candidates.sort();
+
+        let mut suggestions = Vec::new();
+
+        for requested_name in requested {
+            for suggestion in suggest_device_names(requested_name, &candidates) {
+                if !suggestions.contains(&suggestion) {
+                    suggestions.push(suggestion);
+                }
+            }
+        }
+
+        ApplicationError::DeviceNotFound {
+            kind,
+            role,
+            requested: requested
+                .iter()
+                .map(|requested_name| String::from(*requested_name))
+                .collect(),
+            candidates,
+            suggestions,
+        }
+    }
+
+    pub fn ambiguous_device<T: SelectableDevice>(
+        kind: DeviceKind,
+        role: DeviceRole,
+        selector: &str,
+        candidates: &[&T],
+    ) -> Self {
+        trace_fn!();
+
+        let mut candidates = candidates.to_vec();
+        candidates.sort_by(|a, b| (a.name(), a.id()).cmp(&(b.name(), b.id())));
+
+        ApplicationError::AmbiguousDevice {
+            kind,
+            role,
+            selector: String::from(selector),
+            candidates: candidates
+                .iter()
+                .map(|candidate| format!("{} (id:{})", candidate.name(), candidate.id()))
+                .collect(),
+        }
+    }
+
+    pub fn platform_call<T: Error + Send + Sync + 'static>(
+        api: &str,
+        code: Option<i32>,
+        error: T,
+    ) -> Self {
+        trace_fn!();
+
+        ApplicationError::PlatformCall {
+            api: String::from(api),
+            code,
+            message: error.to_string(),
+            source: Some(ErrorSource::new(error)),
+        }
+    }
+
+    pub fn partial_failure(completed: DeviceKind, failed: DeviceKind, error: Self) -> Self {
+        trace_fn!();
+
+        ApplicationError::PartialFailure {
+            completed,
+            failed,
+            source: Box::new(error),
+        }
+    }
+
+    pub fn rollback_performed(rolled_back: DeviceKind, failed: DeviceKind, error: Self) -> Self {
+        trace_fn!();
+
+        ApplicationError::RollbackPerformed {
+            rolled_back,
+            failed,
+            source: Box::new(error),
+        }
+    }
+
+    pub fn rollback_failed(
+        completed: DeviceKind,
+        failed: DeviceKind,
+        error: Self,
+        rollback_error: Self,
+    ) -> Self {
+        trace_fn!();
+
+        ApplicationError::RollbackFailed {
+            completed,
+            failed,
+            source: Box::new(error),
+            rollback_error: Box::new(rollback_error),
+        }
+    }
+
+    /// A snapshot holds the state as its backend applies it, so it cannot be restored by another
+    /// one.
+    pub fn snapshot_of_another_backend(
+        kind: DeviceKind,
+        saved_with: &str,
+        restored_with: &str,
+    ) -> Self {
+        trace_fn!();
+
+        ApplicationError::Snapshot {
+            message: format!(
+                "The {}s snapshot was saved with the {saved_with} backend and cannot be restored with the {restored_with} one",
+                format_device_kind(&kind)
+            ),
+            source: None,
+        }
+    }
+
+    pub fn conversion<T: Error + Send + Sync + 'static>(message: &str, error: T) -> Self {
+        trace_fn!();
+
+        ApplicationError::Conversion {
+            message: String::from(message),
+            source: Some(ErrorSource::new(error)),
+        }
+    }
+}
+
+pub(crate) fn format_device_kind(kind: &DeviceKind) -> &'static str {
+    trace_fn!();
+
+    match kind {
+        DeviceKind::Display => "display",
+        DeviceKind::Speaker => "speaker",
+    }
+}
+
+fn format_changed_setting(kind: &DeviceKind) -> &'static str {
+    trace_fn!();
+
+    match kind {
+        DeviceKind::Display => "primary display",
+        DeviceKind::Speaker => "default speaker",
+    }
+}
+
+fn format_mismatches(mismatches: &[VerificationMismatch]) -> String {
+    trace_fn!();
+
+    mismatches
+        .iter()
+        .map(|mismatch| {
+            format!(
+                "the {} is {} instead of {}",
+                mismatch.setting, mismatch.observed, mismatch.expected
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+fn format_capitalized_device_kind(kind: &DeviceKind) -> &'static str {
+    trace_fn!();
+
+    match kind {
+        DeviceKind::Display => "Display",
+        DeviceKind::Speaker => "Speaker",
+    }
+}
+
+fn format_selected_device(kind: &DeviceKind, role: &DeviceRole) -> String {
+    trace_fn!();
+
+    let kind_name = format_device_kind(kind);
+
+    match role {
+        DeviceRole::Desktop => format!("Desktop {kind_name}"),
+        DeviceRole::Couch => format!("Couch {kind_name}"),
+        DeviceRole::DesktopAndCouch => format!("Desktop or couch {kind_name}"),
+        DeviceRole::Target => String::from(format_capitalized_device_kind(kind)),
+        DeviceRole::Profile => format!("Profile {kind_name}"),
+    }
+}
+
+fn format_invalid_devices(kind: &DeviceKind, role: &DeviceRole) -> String {
+    trace_fn!();
+
+    let kind_name = format_device_kind(kind);
+    let capitalized_kind_name = format_capitalized_device_kind(kind);
+
+    match role {
+        DeviceRole::Desktop => format!("Desktop {kind_name} is invalid"),
+        DeviceRole::Couch => format!("Couch {kind_name} is invalid"),
+        DeviceRole::DesktopAndCouch => format!("Desktop and couch {kind_name}s are invalid"),
+        DeviceRole::Target => format!("{capitalized_kind_name} is invalid"),
+        DeviceRole::Profile => format!("Profile {kind_name} is invalid"),
+    }
+}
+
+fn format_possible_values(suggestions: &[String], possible_values: &[String]) -> String {
+    trace_fn!();
+
+    let possible_values_fragment = possible_values.join(", ");
+
+    if suggestions.is_empty() {
+        return format!("possible values are [{possible_values_fragment}]");
+    }
+
+    let suggestions_fragment = suggestions.join(" or ");
+
+    format!("did you mean {suggestions_fragment}? Possible values are [{possible_values_fragment}]")
 }
 
 impl From<String> for ApplicationError {
@@ -24,7 +451,10 @@ impl From<ConfigErrors> for ApplicationError {
     fn from(value: ConfigErrors) -> Self {
         trace_fn!();
 
-        ApplicationError::Custom(value.to_string())
+        ApplicationError::Logger {
+            message: value.to_string(),
+            source: Some(ErrorSource::new(value)),
+        }
     }
 }
 
@@ -32,39 +462,166 @@ impl From<SetLoggerError> for ApplicationError {
     fn from(value: SetLoggerError) -> Self {
         trace_fn!();
 
-        ApplicationError::Custom(value.to_string())
+        ApplicationError::Logger {
+            message: value.to_string(),
+            source: Some(ErrorSource::new(value)),
+        }
     }
 }
 
 impl From<FromUtf16Error> for ApplicationError {
-    fn from(_: FromUtf16Error) -> Self {
+    fn from(value: FromUtf16Error) -> Self {
+        trace_fn!();
+
+        ApplicationError::conversion("Failed to convert a String from a UTF-16 byte slice", value)
+    }
+}
+
+impl From<FromUtf8Error> for ApplicationError {
+    fn from(value: FromUtf8Error) -> Self {
         trace_fn!();
 
-        ApplicationError::Custom(String::from(
-            "Failed to convert a String from a UTF-16 byte slice",
-        ))
+        ApplicationError::conversion("Failed to convert a String from a UTF-8 byte slice", value)
+    }
+}
+
+impl From<io::Error> for ApplicationError {
+    fn from(value: io::Error) -> Self {
+        trace_fn!();
+
+        let code = value.raw_os_error();
+
+        ApplicationError::platform_call("I/O", code, value)
     }
 }
 
 impl From<TryFromIntError> for ApplicationError {
-    fn from(_: TryFromIntError) -> Self {
+    fn from(value: TryFromIntError) -> Self {
         trace_fn!();
 
-        ApplicationError::Custom(String::from("Failed to convert an int"))
+        ApplicationError::conversion("Failed to convert an int", value)
     }
 }
 
+#[cfg(target_os = "linux")]
+impl From<wayland_client::ConnectError> for ApplicationError {
+    fn from(value: wayland_client::ConnectError) -> Self {
+        trace_fn!();
+
+        ApplicationError::platform_call("Wayland", None, value)
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl From<wayland_client::globals::GlobalError> for ApplicationError {
+    fn from(value: wayland_client::globals::GlobalError) -> Self {
+        trace_fn!();
+
+        ApplicationError::platform_call("Wayland", None, value)
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl From<wayland_client::DispatchError> for ApplicationError {
+    fn from(value: wayland_client::DispatchError) -> Self {
+        trace_fn!();
+
+        ApplicationError::platform_call("Wayland", None, value)
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl From<zbus::Error> for ApplicationError {
+    fn from(value: zbus::Error) -> Self {
+        trace_fn!();
+
+        ApplicationError::platform_call("D-Bus", None, value)
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl From<zbus::zvariant::Error> for ApplicationError {
+    fn from(value: zbus::zvariant::Error) -> Self {
+        trace_fn!();
+
+        ApplicationError::platform_call("D-Bus", None, value)
+    }
+}
+
+#[cfg(target_os = "windows")]
 impl From<windows_core::Error> for ApplicationError {
     fn from(value: windows_core::Error) -> Self {
         trace_fn!();
 
-        ApplicationError::Custom(value.to_string())
+        let code = value.code().0;
+
+        ApplicationError::platform_call("Windows API", Some(code), value)
     }
 }
 
 #[cfg(test)]
 mod should {
-    use crate::application_error::ApplicationError;
+    use std::{error::Error, io};
+
+    use test_case::test_case;
+
+    use crate::application_error::{
+        ApplicationError, DeviceKind, DeviceRole, ErrorSource, VerificationMismatch,
+    };
+
+    #[test_case(&["LG TV SSCR3"] => String::from("Couch display is invalid, did you mean LG TV SSCR2? Possible values are [DELL U2723QE, LG TV SSCR2]"); "when a name is close to a possible value")]
+    #[test_case(&["Epson EF-12"] => String::from("Couch display is invalid, possible values are [DELL U2723QE, LG TV SSCR2]"); "when no name is close to a possible value")]
+    #[test_case(&["dell", "LG TV"] => String::from("Couch display is invalid, did you mean DELL U2723QE or LG TV SSCR2? Possible values are [DELL U2723QE, LG TV SSCR2]"); "when several names are close to possible values")]
+    fn suggest_the_closest_possible_values_of_an_invalid_device(requested: &[&str]) -> String {
+        // Arrange
+        let candidates = vec![String::from("LG TV SSCR2"), String::from("DELL U2723QE")];
+
+        // Act
+        let error = ApplicationError::device_not_found(
+            DeviceKind::Display,
+            DeviceRole::Couch,
+            requested,
+            candidates,
+        );
+
+        // Assert
+        error.to_string()
+    }
+
+    #[test_case(DeviceKind::Display, DeviceRole::Desktop => String::from("Desktop display is invalid, possible values are [LG TV SSCR2]"); "when the desktop display is invalid")]
+    #[test_case(DeviceKind::Speaker, DeviceRole::DesktopAndCouch => String::from("Desktop and couch speakers are invalid, possible values are [LG TV SSCR2]"); "when the desktop and couch speakers are invalid")]
+    #[test_case(DeviceKind::Speaker, DeviceRole::Target => String::from("Speaker is invalid, possible values are [LG TV SSCR2]"); "when the speaker to set is invalid")]
+    #[test_case(DeviceKind::Display, DeviceRole::Profile => String::from("Profile display is invalid, possible values are [LG TV SSCR2]"); "when the display of a profile is invalid")]
+    fn describe_the_role_of_an_invalid_device(kind: DeviceKind, role: DeviceRole) -> String {
+        // Act
+        let error = ApplicationError::device_not_found(
+            kind,
+            role,
+            &["Epson EF-12"],
+            vec![String::from("LG TV SSCR2")],
+        );
+
+        // Assert
+        error.to_string()
+    }
+
+    #[test_case(DeviceKind::Display, vec![VerificationMismatch::device(DeviceKind::Display, "LG TV SSCR2", Some("DELL U2723QE"))] => String::from("The display change was not applied after 3 checks: the primary display is DELL U2723QE instead of LG TV SSCR2"); "when another display is observed")]
+    #[test_case(DeviceKind::Speaker, vec![VerificationMismatch::device(DeviceKind::Speaker, "LG TV SSCR2", None)] => String::from("The speaker change was not applied after 3 checks: the default speaker is none instead of LG TV SSCR2"); "when no speaker is observed")]
+    #[test_case(DeviceKind::Display, vec![VerificationMismatch::new("rotation of LG TV SSCR2", "90°", "0°"), VerificationMismatch::new("scale of LG TV SSCR2", "150%", "100%")] => String::from("The display change was not applied after 3 checks: the rotation of LG TV SSCR2 is 0° instead of 90°, the scale of LG TV SSCR2 is 100% instead of 150%"); "when settings of the display differ")]
+    fn describe_the_settings_observed_when_a_change_is_not_applied(
+        kind: DeviceKind,
+        mismatches: Vec<VerificationMismatch>,
+    ) -> String {
+        // Act
+        let error = ApplicationError::VerificationFailed {
+            kind,
+            mismatches,
+            attempts: 3,
+        };
+
+        // Assert
+        error.to_string()
+    }
 
     #[test]
     fn be_converted_from_a_string() {
@@ -83,6 +640,7 @@ mod should {
         // Arrange
         let bad_data = vec![0xD800];
         let initial_error = String::from_utf16(&bad_data).unwrap_err();
+        let expected_source = String::from_utf16(&bad_data).unwrap_err();
 
         // Act
         let error = ApplicationError::from(initial_error);
@@ -90,12 +648,47 @@ mod should {
         // Assert
         assert_eq!(
             error,
-            ApplicationError::Custom(String::from(
-                "Failed to convert a String from a UTF-16 byte slice"
-            ))
+            ApplicationError::Conversion {
+                message: String::from("Failed to convert a String from a UTF-16 byte slice"),
+                source: Some(ErrorSource::new(expected_source)),
+            }
+        );
+    }
+
+    #[test]
+    fn be_converted_from_a_from_utf8_error() {
+        // Arrange
+        let bad_data = vec![0xFF];
+        let initial_error = String::from_utf8(bad_data).unwrap_err();
+
+        // Act
+        let error = ApplicationError::from(initial_error.clone());
+
+        // Assert
+        assert_eq!(
+            error,
+            ApplicationError::Conversion {
+                message: String::from("Failed to convert a String from a UTF-8 byte slice"),
+                source: Some(ErrorSource::new(initial_error)),
+            }
         );
     }
 
+    #[test]
+    fn be_converted_from_an_io_error() {
+        // Arrange
+        let initial_error = io::Error::from_raw_os_error(2);
+
+        // Act
+        let error = ApplicationError::from(initial_error);
+
+        // Assert
+        assert!(matches!(
+            error,
+            ApplicationError::PlatformCall { code: Some(2), .. }
+        ));
+    }
+
     #[test]
     fn be_converted_from_a_try_from_int_error() {
         // Arrange
@@ -108,7 +701,94 @@ mod should {
         // Assert
         assert_eq!(
             error,
-            ApplicationError::Custom(String::from("Failed to convert an int"))
+            ApplicationError::Conversion {
+                message: String::from("Failed to convert an int"),
+                source: Some(ErrorSource::new(initial_error)),
+            }
+        );
+    }
+
+    #[test]
+    fn report_the_devices_changed_before_a_failure() {
+        // Arrange
+        let initial_error = ApplicationError::Custom(String::from("Something wrong happened"));
+
+        // Act
+        let error = ApplicationError::partial_failure(
+            DeviceKind::Display,
+            DeviceKind::Speaker,
+            initial_error,
+        );
+
+        // Assert
+        assert_eq!(
+            error.to_string(),
+            "The displays were changed but not the speakers: Something wrong happened"
+        );
+        assert_eq!(
+            error.source().map(|source| source.to_string()),
+            Some(String::from("Something wrong happened"))
+        );
+    }
+
+    #[test]
+    fn report_the_devices_changed_back_after_a_failure() {
+        // Arrange
+        let initial_error = ApplicationError::Custom(String::from("Something wrong happened"));
+
+        // Act
+        let error = ApplicationError::rollback_performed(
+            DeviceKind::Display,
+            DeviceKind::Speaker,
+            initial_error,
+        );
+
+        // Assert
+        assert_eq!(
+            error.to_string(),
+            "The speakers could not be changed so the displays were changed back: Something wrong happened"
+        );
+    }
+
+    #[test]
+    fn report_both_the_failure_and_the_failure_of_the_rollback() {
+        // Arrange
+        let initial_error = ApplicationError::Custom(String::from("Something wrong happened"));
+        let rollback_error =
+            ApplicationError::Custom(String::from("Something else wrong happened"));
+
+        // Act
+        let error = ApplicationError::rollback_failed(
+            DeviceKind::Display,
+            DeviceKind::Speaker,
+            initial_error,
+            rollback_error,
+        );
+
+        // Assert
+        assert_eq!(
+            error.to_string(),
+            "The displays were changed but not the speakers: Something wrong happened, and they could not be changed back: Something else wrong happened"
+        );
+        assert_eq!(
+            error.source().map(|source| source.to_string()),
+            Some(String::from("Something wrong happened"))
+        );
+    }
+
+    #[test]
+    fn keep_the_error_it_was_converted_from_as_source() {
+        // Arrange
+        let initial_error = io::Error::new(io::ErrorKind::NotFound, "No such file or directory");
+
+        // Act
+        let error = ApplicationError::from(initial_error);
+
+        // Assert
+        assert_eq!(error.to_string(), "I/O failed: No such file or directory");
+        assert_eq!(
+            error.source().map(|source| source.to_string()),
+            Some(String::from("No such file or directory"))
         );
     }
 }
@@ -0,0 +1,242 @@
+use crate::{
+    application_result::ApplicationResult,
+    backends::{Backend, environment_api::EnvironmentApi, is_in_memory_requested},
+    displays_settings::{
+        DisplaysSettings,
+        in_memory::{
+            in_memory_displays_settings::InMemoryDisplaysSettings,
+            simulated_in_memory_api::SimulatedInMemoryApi,
+        },
+    },
+    trace_fn,
+};
+
+#[cfg(target_os = "windows")]
+use crate::displays_settings::windows::{
+    win_32_based_windows_api::Win32BasedWindowsApi,
+    windows_display_settings::WindowsDisplaySettings,
+};
+
+#[cfg(target_os = "linux")]
+use crate::application_error::{ApplicationError, DeviceKind};
+#[cfg(target_os = "linux")]
+use crate::displays_settings::{
+    kscreen::{
+        kscreen_display_settings::KScreenDisplaySettings,
+        kscreen_doctor_based_kscreen_api::KScreenDoctorBasedKScreenApi,
+    },
+    mutter::{
+        mutter_display_settings::MutterDisplaySettings, zbus_based_mutter_api::ZbusBasedMutterApi,
+    },
+    wlroots::{
+        wayland_based_wlroots_api::WaylandBasedWlrootsApi,
+        wlroots_display_settings::WlrootsDisplaySettings,
+    },
+    x11::{x11_display_settings::X11DisplaySettings, xrandr_based_x11_api::XrandrBasedX11Api},
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DisplaysBackend {
+    InMemory,
+    #[cfg(target_os = "windows")]
+    Windows,
+    #[cfg(target_os = "linux")]
+    X11,
+    #[cfg(target_os = "linux")]
+    Wlroots,
+    #[cfg(target_os = "linux")]
+    Mutter,
+    #[cfg(target_os = "linux")]
+    KScreen,
+}
+
+impl Backend for DisplaysBackend {
+    type Settings = dyn DisplaysSettings;
+
+    fn all() -> &'static [Self] {
+        trace_fn!();
+
+        &[
+            Self::InMemory,
+            #[cfg(target_os = "windows")]
+            Self::Windows,
+            #[cfg(target_os = "linux")]
+            Self::X11,
+            #[cfg(target_os = "linux")]
+            Self::Wlroots,
+            #[cfg(target_os = "linux")]
+            Self::Mutter,
+            #[cfg(target_os = "linux")]
+            Self::KScreen,
+        ]
+    }
+
+    fn name(&self) -> &'static str {
+        trace_fn!();
+
+        match self {
+            Self::InMemory => "in-memory",
+            #[cfg(target_os = "windows")]
+            Self::Windows => "windows",
+            #[cfg(target_os = "linux")]
+            Self::X11 => "x11",
+            #[cfg(target_os = "linux")]
+            Self::Wlroots => "wlroots",
+            #[cfg(target_os = "linux")]
+            Self::Mutter => "mutter",
+            #[cfg(target_os = "linux")]
+            Self::KScreen => "kscreen",
+        }
+    }
+
+    /// On Linux, Wayland sessions are told apart by their desktop since each compositor exposes
+    /// its own configuration protocol, X11 being used when only `DISPLAY` is set. A session with
+    /// neither, such as an SSH one, has no displays backend.
+    fn detect(environment_api: &dyn EnvironmentApi) -> ApplicationResult<Self> {
+        trace_fn!();
+
+        if is_in_memory_requested(environment_api) {
+            return Ok(Self::InMemory);
+        }
+
+        cfg_select! {
+            target_os = "windows" => {
+                Ok(Self::Windows)
+            }
+            target_os = "linux" => {
+                if environment_api.var("WAYLAND_DISPLAY").is_none() {
+                    if environment_api.var("DISPLAY").is_none() {
+                        return Err(ApplicationError::BackendNotDetected {
+                            kind: DeviceKind::Display,
+                        });
+                    }
+
+                    return Ok(Self::X11);
+                }
+
+                let current_desktop = environment_api
+                    .var("XDG_CURRENT_DESKTOP")
+                    .unwrap_or_default()
+                    .to_uppercase();
+                let is_current_desktop =
+                    |desktop: &str| current_desktop.split(':').any(|current| current == desktop);
+
+                if is_current_desktop("GNOME") {
+                    Ok(Self::Mutter)
+                } else if is_current_desktop("KDE") {
+                    Ok(Self::KScreen)
+                } else {
+                    Ok(Self::Wlroots)
+                }
+            }
+            _ => {
+                Ok(Self::InMemory)
+            }
+        }
+    }
+
+    fn create_settings(&self) -> Box<Self::Settings> {
+        trace_fn!();
+
+        match self {
+            Self::InMemory => Box::new(InMemoryDisplaysSettings::new(Box::new(
+                SimulatedInMemoryApi::default(),
+            ))),
+            #[cfg(target_os = "windows")]
+            Self::Windows => Box::new(WindowsDisplaySettings::new(Box::new(Win32BasedWindowsApi))),
+            #[cfg(target_os = "linux")]
+            Self::X11 => Box::new(X11DisplaySettings::new(Box::new(
+                XrandrBasedX11Api::default(),
+            ))),
+            #[cfg(target_os = "linux")]
+            Self::Wlroots => Box::new(WlrootsDisplaySettings::new(Box::new(
+                WaylandBasedWlrootsApi,
+            ))),
+            #[cfg(target_os = "linux")]
+            Self::Mutter => Box::new(MutterDisplaySettings::new(Box::new(
+                ZbusBasedMutterApi::default(),
+            ))),
+            #[cfg(target_os = "linux")]
+            Self::KScreen => Box::new(KScreenDisplaySettings::new(Box::new(
+                KScreenDoctorBasedKScreenApi::default(),
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod should {
+    #[cfg(all(target_os = "linux", not(feature = "in-memory")))]
+    use test_case::test_case;
+
+    #[cfg(all(target_os = "linux", not(feature = "in-memory")))]
+    use crate::application_error::{ApplicationError, DeviceKind};
+    #[cfg(all(target_os = "linux", not(feature = "in-memory")))]
+    use crate::application_result::ApplicationResult;
+    use crate::backends::{
+        Backend, displays_backend::DisplaysBackend, fake_environment_api::FakeEnvironmentApi,
+    };
+
+    #[test]
+    fn detect_the_in_memory_backend_when_it_is_asked_for() {
+        // Arrange
+        let environment_api = FakeEnvironmentApi::default()
+            .with_var("CONVERTIBLE_COUCH_BACKEND", "in-memory")
+            .with_var("WAYLAND_DISPLAY", "wayland-0")
+            .with_var("DISPLAY", ":0");
+
+        // Act
+        let backend = DisplaysBackend::detect(&environment_api);
+
+        // Assert
+        assert_eq!(backend, Ok(DisplaysBackend::InMemory));
+    }
+
+    #[cfg(all(target_os = "linux", not(feature = "in-memory")))]
+    #[test_case(&[("DISPLAY", ":0")] => Ok(DisplaysBackend::X11); "when on x11")]
+    #[test_case(&[("DISPLAY", ":0"), ("XDG_CURRENT_DESKTOP", "GNOME")] => Ok(DisplaysBackend::X11); "when on gnome with x11")]
+    #[test_case(&[] => Err(ApplicationError::BackendNotDetected { kind: DeviceKind::Display }); "when there is no session")]
+    #[test_case(&[("XDG_CURRENT_DESKTOP", "GNOME")] => Err(ApplicationError::BackendNotDetected { kind: DeviceKind::Display }); "when there is a desktop but no session")]
+    #[test_case(&[("WAYLAND_DISPLAY", "wayland-0"), ("XDG_CURRENT_DESKTOP", "sway")] => Ok(DisplaysBackend::Wlroots); "when on sway")]
+    #[test_case(&[("WAYLAND_DISPLAY", "wayland-1"), ("DISPLAY", ":0")] => Ok(DisplaysBackend::Wlroots); "when on wayland with xwayland")]
+    #[test_case(&[("WAYLAND_DISPLAY", "wayland-0"), ("XDG_CURRENT_DESKTOP", "ubuntu:GNOME")] => Ok(DisplaysBackend::Mutter); "when on gnome")]
+    #[test_case(&[("WAYLAND_DISPLAY", "wayland-0"), ("XDG_CURRENT_DESKTOP", "KDE")] => Ok(DisplaysBackend::KScreen); "when on plasma")]
+    fn detect_the_backend_of_the_session(
+        vars: &[(&str, &str)],
+    ) -> ApplicationResult<DisplaysBackend> {
+        // Arrange
+        let environment_api = vars.iter().fold(
+            FakeEnvironmentApi::default(),
+            |environment_api, (key, value)| environment_api.with_var(key, value),
+        );
+
+        // Act
+        DisplaysBackend::detect(&environment_api)
+    }
+
+    #[cfg(all(target_os = "windows", not(feature = "in-memory")))]
+    #[test]
+    fn detect_the_windows_backend() {
+        // Act
+        let backend = DisplaysBackend::detect(&FakeEnvironmentApi::default());
+
+        // Assert
+        assert_eq!(backend, Ok(DisplaysBackend::Windows));
+    }
+
+    #[test]
+    fn provide_a_distinct_name_for_each_backend() {
+        // Act
+        let mut names = DisplaysBackend::all()
+            .iter()
+            .map(|backend| backend.name())
+            .collect::<Vec<&str>>();
+
+        // Assert
+        let backends_count = names.len();
+        names.sort();
+        names.dedup();
+
+        assert_eq!(names.len(), backends_count);
+    }
+}
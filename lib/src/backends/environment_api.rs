@@ -0,0 +1,7 @@
+use std::path::Path;
+
+pub trait EnvironmentApi {
+    fn var(&self, key: &str) -> Option<String>;
+
+    fn path_exists(&self, path: &Path) -> bool;
+}
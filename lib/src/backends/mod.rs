@@ -0,0 +1,133 @@
+use serde::Serialize;
+
+use crate::{
+    application_result::ApplicationResult, backends::environment_api::EnvironmentApi, trace_fn,
+};
+
+pub mod displays_backend;
+pub mod environment_api;
+pub mod speakers_backend;
+pub mod std_based_environment_api;
+
+/// Setting this environment variable to `in-memory` runs the program against a simulated machine.
+pub const BACKEND_ENVIRONMENT_VARIABLE: &str = "CONVERTIBLE_COUCH_BACKEND";
+pub const IN_MEMORY_BACKEND_NAME: &str = "in-memory";
+
+pub trait Backend: Copy + Eq + 'static {
+    type Settings: ?Sized;
+
+    /// The backends which can be selected on the current platform.
+    fn all() -> &'static [Self];
+
+    fn name(&self) -> &'static str;
+
+    fn detect(environment_api: &dyn EnvironmentApi) -> ApplicationResult<Self>;
+
+    fn create_settings(&self) -> Box<Self::Settings>;
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct BackendInfo {
+    pub name: String,
+    pub is_detected: bool,
+    pub is_selected: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct BackendsInfos {
+    pub displays_backends: Vec<BackendInfo>,
+    pub speakers_backends: Vec<BackendInfo>,
+}
+
+/// No backend is flagged as detected when the session offers none.
+pub fn get_backends_infos<T: Backend>(
+    detected_backend: Option<T>,
+    selected_backend: T,
+) -> Vec<BackendInfo> {
+    trace_fn!();
+
+    T::all()
+        .iter()
+        .map(|backend| BackendInfo {
+            name: String::from(backend.name()),
+            is_detected: Some(*backend) == detected_backend,
+            is_selected: *backend == selected_backend,
+        })
+        .collect()
+}
+
+/// The in-memory backend wins over the detection when it is built in or asked for at runtime.
+fn is_in_memory_requested(environment_api: &dyn EnvironmentApi) -> bool {
+    trace_fn!();
+
+    cfg!(feature = "in-memory")
+        || environment_api
+            .var(BACKEND_ENVIRONMENT_VARIABLE)
+            .is_some_and(|backend| backend == IN_MEMORY_BACKEND_NAME)
+}
+
+#[cfg(test)]
+pub(crate) mod fake_environment_api {
+    use std::{
+        collections::{HashMap, HashSet},
+        path::{Path, PathBuf},
+    };
+
+    use crate::backends::environment_api::EnvironmentApi;
+
+    #[derive(Default)]
+    pub struct FakeEnvironmentApi {
+        vars: HashMap<String, String>,
+        paths: HashSet<PathBuf>,
+    }
+
+    impl FakeEnvironmentApi {
+        pub fn with_var(mut self, key: &str, value: &str) -> Self {
+            self.vars.insert(String::from(key), String::from(value));
+
+            self
+        }
+
+        pub fn with_path(mut self, path: &str) -> Self {
+            self.paths.insert(PathBuf::from(path));
+
+            self
+        }
+    }
+
+    impl EnvironmentApi for FakeEnvironmentApi {
+        fn var(&self, key: &str) -> Option<String> {
+            self.vars.get(key).cloned()
+        }
+
+        fn path_exists(&self, path: &Path) -> bool {
+            self.paths.contains(path)
+        }
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use crate::backends::{BackendInfo, displays_backend::DisplaysBackend, get_backends_infos};
+
+    #[test]
+    fn flag_the_detected_and_the_selected_backends() {
+        // Act
+        let backends_infos =
+            get_backends_infos(Some(DisplaysBackend::InMemory), DisplaysBackend::InMemory);
+
+        // Assert
+        assert!(backends_infos.contains(&BackendInfo {
+            name: String::from("in-memory"),
+            is_detected: true,
+            is_selected: true,
+        }));
+        assert_eq!(
+            backends_infos
+                .iter()
+                .filter(|backend_info| backend_info.is_detected || backend_info.is_selected)
+                .count(),
+            1
+        );
+    }
+}
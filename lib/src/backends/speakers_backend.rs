@@ -0,0 +1,182 @@
+use crate::{
+    application_result::ApplicationResult,
+    backends::{Backend, environment_api::EnvironmentApi, is_in_memory_requested},
+    speakers_settings::{
+        SpeakersSettings,
+        in_memory::{
+            in_memory_speakers_settings::InMemorySpeakersSettings,
+            simulated_in_memory_api::SimulatedInMemoryApi,
+        },
+    },
+    trace_fn,
+};
+
+#[cfg(target_os = "windows")]
+use crate::speakers_settings::windows::{
+    win_32_based_windows_api::Win32BasedWindowsApi, windows_sound_settings::WindowsSoundSettings,
+};
+
+#[cfg(target_os = "linux")]
+use std::path::Path;
+
+#[cfg(target_os = "linux")]
+use crate::speakers_settings::pulse_audio::{
+    pactl_based_pulse_audio_api::PactlBasedPulseAudioApi,
+    pulse_audio_speakers_settings::PulseAudioSpeakersSettings,
+};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SpeakersBackend {
+    InMemory,
+    #[cfg(target_os = "windows")]
+    Windows,
+    #[cfg(target_os = "linux")]
+    PulseAudio,
+    #[cfg(target_os = "linux")]
+    PipeWire,
+}
+
+impl Backend for SpeakersBackend {
+    type Settings = dyn SpeakersSettings;
+
+    fn all() -> &'static [Self] {
+        trace_fn!();
+
+        &[
+            Self::InMemory,
+            #[cfg(target_os = "windows")]
+            Self::Windows,
+            #[cfg(target_os = "linux")]
+            Self::PulseAudio,
+            #[cfg(target_os = "linux")]
+            Self::PipeWire,
+        ]
+    }
+
+    fn name(&self) -> &'static str {
+        trace_fn!();
+
+        match self {
+            Self::InMemory => "in-memory",
+            #[cfg(target_os = "windows")]
+            Self::Windows => "windows",
+            #[cfg(target_os = "linux")]
+            Self::PulseAudio => "pulseaudio",
+            #[cfg(target_os = "linux")]
+            Self::PipeWire => "pipewire",
+        }
+    }
+
+    /// On Linux, PipeWire is told apart from PulseAudio by the socket it listens on in the runtime
+    /// directory.
+    fn detect(environment_api: &dyn EnvironmentApi) -> ApplicationResult<Self> {
+        trace_fn!();
+
+        if is_in_memory_requested(environment_api) {
+            return Ok(Self::InMemory);
+        }
+
+        cfg_select! {
+            target_os = "windows" => {
+                Ok(Self::Windows)
+            }
+            target_os = "linux" => {
+                let is_pipewire_running = environment_api
+                    .var("XDG_RUNTIME_DIR")
+                    .is_some_and(|runtime_directory| {
+                        environment_api
+                            .path_exists(&Path::new(&runtime_directory).join("pipewire-0"))
+                    });
+
+                if is_pipewire_running {
+                    Ok(Self::PipeWire)
+                } else {
+                    Ok(Self::PulseAudio)
+                }
+            }
+            _ => {
+                Ok(Self::InMemory)
+            }
+        }
+    }
+
+    /// PipeWire is driven through its PulseAudio compatibility layer.
+    fn create_settings(&self) -> Box<Self::Settings> {
+        trace_fn!();
+
+        match self {
+            Self::InMemory => Box::new(InMemorySpeakersSettings::new(Box::new(
+                SimulatedInMemoryApi::default(),
+            ))),
+            #[cfg(target_os = "windows")]
+            Self::Windows => Box::new(WindowsSoundSettings::new(Box::new(Win32BasedWindowsApi))),
+            #[cfg(target_os = "linux")]
+            Self::PulseAudio | Self::PipeWire => Box::new(PulseAudioSpeakersSettings::new(
+                Box::new(PactlBasedPulseAudioApi::default()),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use crate::backends::{
+        Backend, fake_environment_api::FakeEnvironmentApi, speakers_backend::SpeakersBackend,
+    };
+
+    #[test]
+    fn detect_the_in_memory_backend_when_it_is_asked_for() {
+        // Arrange
+        let environment_api = FakeEnvironmentApi::default()
+            .with_var("CONVERTIBLE_COUCH_BACKEND", "in-memory")
+            .with_var("XDG_RUNTIME_DIR", "/run/user/1000")
+            .with_path("/run/user/1000/pipewire-0");
+
+        // Act
+        let backend = SpeakersBackend::detect(&environment_api);
+
+        // Assert
+        assert_eq!(backend, Ok(SpeakersBackend::InMemory));
+    }
+
+    #[cfg(all(target_os = "linux", not(feature = "in-memory")))]
+    #[test]
+    fn detect_pipewire_when_its_socket_exists() {
+        // Arrange
+        let environment_api = FakeEnvironmentApi::default()
+            .with_var("XDG_RUNTIME_DIR", "/run/user/1000")
+            .with_path("/run/user/1000/pipewire-0")
+            .with_path("/run/user/1000/pulse/native");
+
+        // Act
+        let backend = SpeakersBackend::detect(&environment_api);
+
+        // Assert
+        assert_eq!(backend, Ok(SpeakersBackend::PipeWire));
+    }
+
+    #[cfg(all(target_os = "linux", not(feature = "in-memory")))]
+    #[test]
+    fn detect_pulseaudio_when_there_is_no_pipewire_socket() {
+        // Arrange
+        let environment_api = FakeEnvironmentApi::default()
+            .with_var("XDG_RUNTIME_DIR", "/run/user/1000")
+            .with_path("/run/user/1000/pulse/native");
+
+        // Act
+        let backend = SpeakersBackend::detect(&environment_api);
+
+        // Assert
+        assert_eq!(backend, Ok(SpeakersBackend::PulseAudio));
+    }
+
+    #[cfg(all(target_os = "windows", not(feature = "in-memory")))]
+    #[test]
+    fn detect_the_windows_backend() {
+        // Act
+        let backend = SpeakersBackend::detect(&FakeEnvironmentApi::default());
+
+        // Assert
+        assert_eq!(backend, Ok(SpeakersBackend::Windows));
+    }
+}
@@ -0,0 +1,20 @@
+use std::{env, path::Path};
+
+use crate::{backends::environment_api::EnvironmentApi, trace_fn};
+
+#[derive(Default)]
+pub struct StdBasedEnvironmentApi;
+
+impl EnvironmentApi for StdBasedEnvironmentApi {
+    fn var(&self, key: &str) -> Option<String> {
+        trace_fn!();
+
+        env::var(key).ok()
+    }
+
+    fn path_exists(&self, path: &Path) -> bool {
+        trace_fn!();
+
+        path.exists()
+    }
+}
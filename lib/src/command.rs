@@ -0,0 +1,104 @@
+use std::process::Command;
+
+use crate::{application_error::ApplicationError, application_result::ApplicationResult, trace_fn};
+
+/// Runs a command line tool in the given environment and returns its standard output, a tool
+/// which cannot be run or which fails being reported with its exit code and standard error.
+pub(crate) fn run_command(
+    program: &str,
+    args: &[String],
+    envs: &[(&str, &str)],
+) -> ApplicationResult<String> {
+    trace_fn!();
+
+    let output = Command::new(program)
+        .args(args)
+        .envs(envs.iter().copied())
+        .output()
+        .map_err(|error| {
+            let code = error.raw_os_error();
+
+            ApplicationError::platform_call(program, code, error)
+        })?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let error = ApplicationError::PlatformCall {
+            api: String::from(program),
+            code: output.status.code(),
+            message: String::from(stderr.trim()),
+            source: None,
+        };
+
+        return Err(error);
+    }
+
+    let stdout = String::from_utf8(output.stdout)?;
+
+    Ok(stdout)
+}
+
+#[cfg(test)]
+mod should {
+    use std::{env, fs, os::unix::fs::PermissionsExt, path::PathBuf, process};
+
+    use crate::{application_error::ApplicationError, command::run_command};
+
+    fn fake_program(name: &str, script: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("fake-program-{name}-{}", process::id()));
+
+        fs::write(&path, script).unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755)).unwrap();
+
+        path
+    }
+
+    #[test]
+    fn forward_the_arguments_and_the_environment_and_return_the_standard_output() {
+        // Arrange
+        let program = fake_program("echo", "#!/bin/sh\necho \"$LC_ALL $@\"\n");
+        let args = [String::from("set-default-sink"), String::from("hdmi")];
+
+        // Act
+        let stdout = run_command(program.to_str().unwrap(), &args, &[("LC_ALL", "C")]);
+
+        // Assert
+        assert_eq!(stdout, Ok(String::from("C set-default-sink hdmi\n")));
+    }
+
+    #[test]
+    fn fail_with_the_standard_error_when_the_program_fails() {
+        // Arrange
+        let program = fake_program(
+            "fail",
+            "#!/bin/sh\necho \"Can't open display\" >&2\nexit 1\n",
+        );
+        let program = program.to_str().unwrap();
+
+        // Act
+        let stdout = run_command(program, &[], &[]);
+
+        // Assert
+        assert_eq!(
+            stdout,
+            Err(ApplicationError::PlatformCall {
+                api: String::from(program),
+                code: Some(1),
+                message: String::from("Can't open display"),
+                source: None,
+            })
+        );
+    }
+
+    #[test]
+    fn fail_when_the_program_cannot_be_run() {
+        // Act
+        let stdout = run_command("/nonexistent/xrandr", &[], &[]);
+
+        // Assert
+        assert!(matches!(
+            stdout,
+            Err(ApplicationError::PlatformCall { api, code: Some(2), .. }) if api == "/nonexistent/xrandr"
+        ));
+    }
+}
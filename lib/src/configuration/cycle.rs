@@ -0,0 +1,19 @@
+use serde::Deserialize;
+
+use crate::trace_fn;
+
+/// The displays and speakers to cycle through, in order, when none are given on the command line.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Cycle {
+    pub displays: Vec<String>,
+    pub speakers: Vec<String>,
+}
+
+impl Cycle {
+    pub fn is_empty(&self) -> bool {
+        trace_fn!();
+
+        self.displays.is_empty() && self.speakers.is_empty()
+    }
+}
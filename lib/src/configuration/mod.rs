@@ -0,0 +1,501 @@
+use std::{
+    collections::BTreeMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use log::info;
+use serde::Deserialize;
+
+use crate::{
+    application_error::{ApplicationError, ErrorSource},
+    application_result::ApplicationResult,
+    backends::environment_api::EnvironmentApi,
+    configuration::{cycle::Cycle, profile::Profile, verification::Verification},
+    trace_fn,
+};
+
+pub mod cycle;
+pub mod profile;
+pub mod verification;
+
+const CONFIGURATION_DIRECTORY_NAME: &str = "convertible_couch";
+const CONFIGURATION_FILE_NAME: &str = "config.toml";
+
+#[derive(Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct Configuration {
+    #[serde(default)]
+    pub profiles: BTreeMap<String, Profile>,
+    #[serde(default)]
+    pub cycle: Cycle,
+    #[serde(default)]
+    pub verification: Verification,
+}
+
+impl Configuration {
+    pub fn from_toml(toml: &str) -> ApplicationResult<Self> {
+        trace_fn!();
+
+        toml::from_str::<Configuration>(toml).map_err(|error| ApplicationError::Config {
+            message: format!("Failed to parse the configuration: {error}"),
+            source: Some(ErrorSource::new(error)),
+        })
+    }
+
+    /// Loads the configuration file given on the command line, or the one found at the default
+    /// path. Only the former is required to exist.
+    pub fn load(
+        configuration_path: Option<&Path>,
+        environment_api: &dyn EnvironmentApi,
+    ) -> ApplicationResult<Self> {
+        trace_fn!();
+
+        let (configuration_path, is_required) = match configuration_path {
+            Some(configuration_path) => (PathBuf::from(configuration_path), true),
+            None => match get_default_configuration_path(environment_api) {
+                Some(default_configuration_path) => (default_configuration_path, false),
+                None => return Ok(Self::default()),
+            },
+        };
+
+        info!(
+            "Loading configuration from {}",
+            configuration_path.display()
+        );
+
+        match fs::read_to_string(&configuration_path) {
+            Ok(toml) => Self::from_toml(&toml),
+            Err(error) if error.kind() == io::ErrorKind::NotFound && !is_required => {
+                Ok(Self::default())
+            }
+            Err(error) => Err(ApplicationError::Config {
+                message: format!(
+                    "Failed to read the configuration file {}: {error}",
+                    configuration_path.display()
+                ),
+                source: Some(ErrorSource::new(error)),
+            }),
+        }
+    }
+
+    pub fn get_profile(&self, profile_name: &str) -> ApplicationResult<&Profile> {
+        trace_fn!();
+
+        self.profiles.get(profile_name).ok_or_else(|| {
+            let possible_values_fragment = self
+                .profiles
+                .keys()
+                .map(String::as_str)
+                .collect::<Vec<&str>>()
+                .join(", ");
+
+            ApplicationError::Config {
+                message: format!(
+                    "Profile is invalid, possible values are [{possible_values_fragment}]"
+                ),
+                source: None,
+            }
+        })
+    }
+
+    pub fn get_cycle(&self) -> ApplicationResult<&Cycle> {
+        trace_fn!();
+
+        if self.cycle.is_empty() {
+            return Err(ApplicationError::Config {
+                message: String::from(
+                    "Cycle is empty, give the displays and/or speakers to cycle through or declare them in the configuration file",
+                ),
+                source: None,
+            });
+        }
+
+        Ok(&self.cycle)
+    }
+}
+
+pub fn get_default_configuration_path(environment_api: &dyn EnvironmentApi) -> Option<PathBuf> {
+    trace_fn!();
+
+    get_default_configuration_directory(environment_api)
+        .map(|configuration_directory| configuration_directory.join(CONFIGURATION_FILE_NAME))
+}
+
+/// The configuration lives in `%APPDATA%` on Windows and in the XDG configuration directory
+/// elsewhere.
+pub fn get_default_configuration_directory(
+    environment_api: &dyn EnvironmentApi,
+) -> Option<PathBuf> {
+    trace_fn!();
+
+    let configuration_home = cfg_select! {
+        target_os = "windows" => {
+            environment_api.var("APPDATA").map(PathBuf::from)
+        }
+        _ => {
+            environment_api
+                .var("XDG_CONFIG_HOME")
+                .filter(|xdg_config_home| !xdg_config_home.is_empty())
+                .map(PathBuf::from)
+                .or_else(|| {
+                    environment_api
+                        .var("HOME")
+                        .map(|home| Path::new(&home).join(".config"))
+                })
+        }
+    };
+
+    configuration_home
+        .map(|configuration_home| configuration_home.join(CONFIGURATION_DIRECTORY_NAME))
+}
+
+#[cfg(test)]
+mod should {
+    use std::{collections::BTreeMap, env, fs, path::PathBuf, process};
+
+    use crate::{
+        application_error::ApplicationError,
+        backends::fake_environment_api::FakeEnvironmentApi,
+        configuration::{
+            Configuration, cycle::Cycle, get_default_configuration_path, profile::Profile,
+            verification::Verification,
+        },
+        displays_settings::{DisplayResolution, DisplayRotation},
+    };
+
+    const CONFIGURATION: &str = r#"
+        [profiles.desk]
+        display = "DELL U2723QE"
+        speaker = "Speakers (Realtek(R) Audio)"
+
+        [profiles.couch]
+        display = "LG TV SSCR2"
+        speaker = "LG TV SSCR2"
+    "#;
+
+    fn profile(display: &str, speaker: &str) -> Profile {
+        Profile {
+            display: String::from(display),
+            speaker: String::from(speaker),
+            resolution: None,
+            refresh: None,
+            rotation: None,
+            scale: None,
+            hdr: None,
+        }
+    }
+
+    fn configuration_file(name: &str, toml: &str) -> PathBuf {
+        let path = env::temp_dir().join(format!("convertible-couch-{name}-{}.toml", process::id()));
+
+        fs::write(&path, toml).unwrap();
+
+        path
+    }
+
+    #[test]
+    fn parse_the_profiles() {
+        // Act
+        let configuration = Configuration::from_toml(CONFIGURATION);
+
+        // Assert
+        assert_eq!(
+            configuration,
+            Ok(Configuration {
+                profiles: BTreeMap::from([
+                    (String::from("couch"), profile("LG TV SSCR2", "LG TV SSCR2")),
+                    (
+                        String::from("desk"),
+                        profile("DELL U2723QE", "Speakers (Realtek(R) Audio)")
+                    ),
+                ]),
+                cycle: Cycle::default(),
+                verification: Verification::default(),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_the_verification() {
+        // Act
+        let configuration = Configuration::from_toml("[verification]\nretries = 5\n");
+
+        // Assert
+        assert_eq!(
+            configuration.map(|configuration| configuration.verification),
+            Ok(Verification {
+                retries: 5,
+                ..Verification::default()
+            })
+        );
+    }
+
+    #[test]
+    fn parse_the_cycle() {
+        // Act
+        let configuration = Configuration::from_toml(
+            "[cycle]\ndisplays = [\"DELL U2723QE\", \"LG TV SSCR2\", \"Epson EF-12\"]\n",
+        );
+
+        // Assert
+        assert_eq!(
+            configuration.map(|configuration| configuration.cycle),
+            Ok(Cycle {
+                displays: vec![
+                    String::from("DELL U2723QE"),
+                    String::from("LG TV SSCR2"),
+                    String::from("Epson EF-12"),
+                ],
+                speakers: Vec::new(),
+            })
+        );
+    }
+
+    #[test]
+    fn parse_an_empty_configuration() {
+        // Act
+        let configuration = Configuration::from_toml("");
+
+        // Assert
+        assert_eq!(configuration, Ok(Configuration::default()));
+    }
+
+    #[test]
+    fn fail_to_parse_a_profile_without_a_speaker() {
+        // Act
+        let configuration = Configuration::from_toml("[profiles.projector]\ndisplay = \"Epson\"\n");
+
+        // Assert
+        assert!(configuration.is_err_and(|error| {
+            error
+                .to_string()
+                .starts_with("Failed to parse the configuration")
+        }));
+    }
+
+    #[test]
+    fn parse_the_mode_of_a_profile() {
+        // Act
+        let configuration = Configuration::from_toml(
+            "[profiles.couch]\ndisplay = \"LG TV SSCR2\"\nspeaker = \"LG TV SSCR2\"\nresolution = \"3840x2160\"\nrefresh = 120\n",
+        );
+
+        // Assert
+        assert_eq!(
+            configuration.map(|configuration| configuration.profiles["couch"].clone()),
+            Ok(Profile {
+                resolution: Some(DisplayResolution {
+                    width: 3840,
+                    height: 2160,
+                }),
+                refresh: Some(120),
+                ..profile("LG TV SSCR2", "LG TV SSCR2")
+            })
+        );
+    }
+
+    #[test]
+    fn parse_the_rotation_and_the_scale_of_a_profile() {
+        // Act
+        let configuration = Configuration::from_toml(
+            "[profiles.couch]\ndisplay = \"LG TV SSCR2\"\nspeaker = \"LG TV SSCR2\"\nrotation = 90\nscale = 200\n",
+        );
+
+        // Assert
+        assert_eq!(
+            configuration.map(|configuration| configuration.profiles["couch"].clone()),
+            Ok(Profile {
+                rotation: Some(DisplayRotation::Degrees90),
+                scale: Some(200),
+                ..profile("LG TV SSCR2", "LG TV SSCR2")
+            })
+        );
+    }
+
+    #[test]
+    fn parse_the_hdr_of_a_profile() {
+        // Act
+        let configuration = Configuration::from_toml(
+            "[profiles.couch]\ndisplay = \"LG TV SSCR2\"\nspeaker = \"LG TV SSCR2\"\nhdr = true\n",
+        );
+
+        // Assert
+        assert_eq!(
+            configuration.map(|configuration| configuration.profiles["couch"].clone()),
+            Ok(Profile {
+                hdr: Some(true),
+                ..profile("LG TV SSCR2", "LG TV SSCR2")
+            })
+        );
+    }
+
+    #[test]
+    fn fail_to_parse_a_profile_with_an_invalid_rotation() {
+        // Act
+        let configuration = Configuration::from_toml(
+            "[profiles.couch]\ndisplay = \"LG TV SSCR2\"\nspeaker = \"LG TV SSCR2\"\nrotation = 45\n",
+        );
+
+        // Assert
+        assert!(
+            configuration
+                .is_err_and(|error| error.to_string().contains("45 is not a quarter turn"))
+        );
+    }
+
+    #[test]
+    fn fail_to_parse_a_profile_with_an_invalid_resolution() {
+        // Act
+        let configuration = Configuration::from_toml(
+            "[profiles.couch]\ndisplay = \"LG TV SSCR2\"\nspeaker = \"LG TV SSCR2\"\nresolution = \"4K\"\n",
+        );
+
+        // Assert
+        assert!(configuration.is_err_and(|error| {
+            error
+                .to_string()
+                .contains("4K is not a resolution such as 3840x2160")
+        }));
+    }
+
+    #[test]
+    fn load_the_configuration_file_given() {
+        // Arrange
+        let path = configuration_file("given", CONFIGURATION);
+
+        // Act
+        let configuration = Configuration::load(Some(&path), &FakeEnvironmentApi::default());
+
+        // Assert
+        assert_eq!(
+            configuration.map(|configuration| configuration.profiles.len()),
+            Ok(2)
+        );
+    }
+
+    #[test]
+    fn fail_to_load_a_configuration_file_given_which_does_not_exist() {
+        // Arrange
+        let path = PathBuf::from("/nonexistent/config.toml");
+
+        // Act
+        let configuration = Configuration::load(Some(&path), &FakeEnvironmentApi::default());
+
+        // Assert
+        assert!(configuration.is_err_and(|error| {
+            error
+                .to_string()
+                .starts_with("Failed to read the configuration file /nonexistent/config.toml")
+        }));
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn load_an_empty_configuration_when_there_is_no_default_configuration_file() {
+        // Arrange
+        let environment_api =
+            FakeEnvironmentApi::default().with_var("XDG_CONFIG_HOME", "/nonexistent");
+
+        // Act
+        let configuration = Configuration::load(None, &environment_api);
+
+        // Assert
+        assert_eq!(configuration, Ok(Configuration::default()));
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn prefer_the_xdg_configuration_directory() {
+        // Arrange
+        let environment_api = FakeEnvironmentApi::default()
+            .with_var("XDG_CONFIG_HOME", "/home/user/.xdg")
+            .with_var("HOME", "/home/user");
+
+        // Act
+        let path = get_default_configuration_path(&environment_api);
+
+        // Assert
+        assert_eq!(
+            path,
+            Some(PathBuf::from(
+                "/home/user/.xdg/convertible_couch/config.toml"
+            ))
+        );
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    #[test]
+    fn fall_back_on_the_configuration_directory_of_the_home() {
+        // Arrange
+        let environment_api = FakeEnvironmentApi::default().with_var("HOME", "/home/user");
+
+        // Act
+        let path = get_default_configuration_path(&environment_api);
+
+        // Assert
+        assert_eq!(
+            path,
+            Some(PathBuf::from(
+                "/home/user/.config/convertible_couch/config.toml"
+            ))
+        );
+    }
+
+    #[cfg(target_os = "windows")]
+    #[test]
+    fn use_the_application_data_directory() {
+        // Arrange
+        let environment_api =
+            FakeEnvironmentApi::default().with_var("APPDATA", "C:\\Users\\user\\AppData\\Roaming");
+
+        // Act
+        let path = get_default_configuration_path(&environment_api);
+
+        // Assert
+        assert_eq!(
+            path,
+            Some(PathBuf::from(
+                "C:\\Users\\user\\AppData\\Roaming\\convertible_couch\\config.toml"
+            ))
+        );
+    }
+
+    #[test]
+    fn fail_to_get_a_profile_which_is_not_declared() {
+        // Arrange
+        let configuration = Configuration::from_toml(CONFIGURATION).unwrap();
+
+        // Act
+        let profile = configuration.get_profile("projector");
+
+        // Assert
+        assert_eq!(
+            profile,
+            Err(ApplicationError::Config {
+                message: String::from("Profile is invalid, possible values are [couch, desk]"),
+                source: None,
+            })
+        );
+    }
+
+    #[test]
+    fn fail_to_get_a_cycle_which_is_not_declared() {
+        // Arrange
+        let configuration = Configuration::from_toml(CONFIGURATION).unwrap();
+
+        // Act
+        let cycle = configuration.get_cycle();
+
+        // Assert
+        assert_eq!(
+            cycle,
+            Err(ApplicationError::Config {
+                message: String::from(
+                    "Cycle is empty, give the displays and/or speakers to cycle through or declare them in the configuration file"
+                ),
+                source: None,
+            })
+        );
+    }
+}
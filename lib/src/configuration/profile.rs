@@ -0,0 +1,168 @@
+use serde::{Deserialize, Deserializer, de};
+
+use crate::{
+    application_error::{DeviceKind, DeviceRole},
+    application_result::ApplicationResult,
+    device_selector::{resolve_existing_device, to_id_selector},
+    displays_settings::{
+        DisplayInfo, DisplayModeRequest, DisplayResolution, DisplayRotation,
+        DisplayTransformRequest,
+    },
+    speakers_settings::SpeakerInfo,
+    trace_fn,
+};
+
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct Profile {
+    pub display: String,
+    pub speaker: String,
+    /// The resolution to drive the display at, such as `3840x2160`.
+    #[serde(default, deserialize_with = "deserialize_resolution")]
+    pub resolution: Option<DisplayResolution>,
+    /// The refresh rate in hertz to drive the display at.
+    #[serde(default)]
+    pub refresh: Option<u32>,
+    /// The clockwise rotation in degrees to turn the display to.
+    #[serde(default)]
+    pub rotation: Option<DisplayRotation>,
+    /// The scale in percent of the desktop shown on the display.
+    #[serde(default)]
+    pub scale: Option<u32>,
+    /// Whether to turn HDR on or off on the display.
+    #[serde(default)]
+    pub hdr: Option<bool>,
+}
+
+impl Profile {
+    /// Resolves the selectors of the profile against the devices currently reported, checking
+    /// them since a profile may outlive the devices it was written for. The devices are then
+    /// selected by their ids, which tell apart the ones sharing a name.
+    pub fn resolve(
+        &self,
+        displays_infos: &[DisplayInfo],
+        speakers_infos: &[SpeakerInfo],
+    ) -> ApplicationResult<Profile> {
+        trace_fn!();
+
+        let display = resolve_existing_device(
+            DeviceKind::Display,
+            DeviceRole::Profile,
+            &self.display,
+            displays_infos,
+        )?;
+        let speaker = resolve_existing_device(
+            DeviceKind::Speaker,
+            DeviceRole::Profile,
+            &self.speaker,
+            speakers_infos,
+        )?;
+
+        Ok(Profile {
+            display: to_id_selector(&display.id),
+            speaker: to_id_selector(&speaker.id),
+            resolution: self.resolution,
+            refresh: self.refresh,
+            rotation: self.rotation,
+            scale: self.scale,
+            hdr: self.hdr,
+        })
+    }
+
+    pub fn mode_request(&self) -> DisplayModeRequest {
+        trace_fn!();
+
+        DisplayModeRequest {
+            resolution: self.resolution,
+            refresh_rate_hertz: self.refresh,
+        }
+    }
+
+    pub fn transform_request(&self) -> DisplayTransformRequest {
+        trace_fn!();
+
+        DisplayTransformRequest {
+            rotation: self.rotation,
+            scale_percent: self.scale,
+        }
+    }
+}
+
+fn deserialize_resolution<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<DisplayResolution>, D::Error> {
+    trace_fn!();
+
+    Option::<String>::deserialize(deserializer)?
+        .map(|resolution| resolution.parse().map_err(de::Error::custom))
+        .transpose()
+}
+
+#[cfg(test)]
+mod should {
+    use test_case::test_case;
+
+    use crate::{
+        application_error::{ApplicationError, DeviceKind, DeviceRole},
+        application_result::ApplicationResult,
+        configuration::profile::Profile,
+        displays_settings::DisplayInfo,
+        speakers_settings::SpeakerInfo,
+    };
+
+    fn profile(display: &str, speaker: &str) -> Profile {
+        Profile {
+            display: String::from(display),
+            speaker: String::from(speaker),
+            resolution: None,
+            refresh: None,
+            rotation: None,
+            scale: None,
+            hdr: None,
+        }
+    }
+
+    #[test_case("LG TV SSCR2", "LG TV SSCR2" => Ok(profile("id:HDMI-A-1", "id:{0.0.0.00000000}.{9b61d3e7}")); "when the display and the speaker are reported")]
+    #[test_case("glob:lg*", "id:{0.0.0.00000000}.{9b61d3e7}" => Ok(profile("id:HDMI-A-1", "id:{0.0.0.00000000}.{9b61d3e7}")); "when the display and the speaker are selected")]
+    #[test_case("LG TV SSCR2", "id:{0.0.0.00000000}.{5c3f0a21}" => Err(ApplicationError::DeviceNotFound { kind: DeviceKind::Speaker, role: DeviceRole::Profile, requested: vec![String::from("id:{0.0.0.00000000}.{5c3f0a21}")], candidates: vec![String::from("LG TV SSCR2"), String::from("Speakers")], suggestions: Vec::new() }); "when the id of the speaker is not reported")]
+    #[test_case("Epson EF-12", "LG TV SSCR2" => Err(ApplicationError::DeviceNotFound { kind: DeviceKind::Display, role: DeviceRole::Profile, requested: vec![String::from("Epson EF-12")], candidates: vec![String::from("Internal Display"), String::from("LG TV SSCR2")], suggestions: Vec::new() }); "when the display is not reported")]
+    #[test_case("LG TV SSCR2", "Epson EF-12" => Err(ApplicationError::DeviceNotFound { kind: DeviceKind::Speaker, role: DeviceRole::Profile, requested: vec![String::from("Epson EF-12")], candidates: vec![String::from("LG TV SSCR2"), String::from("Speakers")], suggestions: Vec::new() }); "when the speaker is not reported")]
+    fn resolve_the_profile_against_the_reported_devices(
+        display: &str,
+        speaker: &str,
+    ) -> ApplicationResult<Profile> {
+        // Arrange
+        let profile = profile(display, speaker);
+        let displays_infos = [
+            DisplayInfo {
+                is_primary: true,
+                name: String::from("Internal Display"),
+                id: String::from("eDP-1"),
+                ..Default::default()
+            },
+            DisplayInfo {
+                is_primary: false,
+                name: String::from("LG TV SSCR2"),
+                id: String::from("HDMI-A-1"),
+                ..Default::default()
+            },
+        ];
+        let speakers_infos = [
+            SpeakerInfo {
+                is_default: true,
+                name: String::from("Speakers"),
+                id: String::from("{0.0.0.00000000}.{1a2b3c4d}"),
+                ..Default::default()
+            },
+            SpeakerInfo {
+                is_default: false,
+                name: String::from("LG TV SSCR2"),
+                id: String::from("{0.0.0.00000000}.{9b61d3e7}"),
+                ..Default::default()
+            },
+        ];
+
+        // Act
+        profile.resolve(&displays_infos, &speakers_infos)
+    }
+}
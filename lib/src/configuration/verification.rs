@@ -0,0 +1,135 @@
+use std::{thread, time::Duration};
+
+use log::info;
+use serde::Deserialize;
+
+use crate::{
+    application_error::{ApplicationError, DeviceKind, VerificationMismatch},
+    application_result::ApplicationResult,
+    trace_fn,
+};
+
+const DEFAULT_RETRIES: u32 = 2;
+const DEFAULT_SETTLE_DELAY_MILLISECONDS: u64 = 250;
+
+/// How a change accepted by the platform is checked to be applied, some drivers silently
+/// reverting it.
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct Verification {
+    /// How many more times the devices are queried when the change is not applied yet.
+    pub retries: u32,
+    /// How long the change is given to settle before each query.
+    pub settle_delay_milliseconds: u64,
+}
+
+impl Default for Verification {
+    fn default() -> Self {
+        trace_fn!();
+
+        Self {
+            retries: DEFAULT_RETRIES,
+            settle_delay_milliseconds: DEFAULT_SETTLE_DELAY_MILLISECONDS,
+        }
+    }
+}
+
+impl Verification {
+    /// Observes the devices until none of their settings differs from the ones applied, the
+    /// mismatches observed last being reported when some always do.
+    pub fn verify(
+        &self,
+        kind: DeviceKind,
+        mut observe: impl FnMut() -> ApplicationResult<Vec<VerificationMismatch>>,
+    ) -> ApplicationResult<()> {
+        trace_fn!();
+
+        let attempts = self.retries + 1;
+        let mut mismatches = Vec::new();
+
+        for attempt in 1..=attempts {
+            thread::sleep(Duration::from_millis(self.settle_delay_milliseconds));
+
+            mismatches = observe()?;
+
+            if mismatches.is_empty() {
+                return Ok(());
+            }
+
+            info!("Change not applied yet after check {attempt} of {attempts}");
+        }
+
+        Err(ApplicationError::VerificationFailed {
+            kind,
+            mismatches,
+            attempts,
+        })
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use crate::{
+        application_error::{ApplicationError, DeviceKind, VerificationMismatch},
+        configuration::verification::Verification,
+    };
+
+    const VERIFICATION: Verification = Verification {
+        retries: 2,
+        settle_delay_milliseconds: 0,
+    };
+
+    #[test]
+    fn verify_a_change_applied_once_it_settled() {
+        // Arrange
+        let mut observations = vec![
+            vec![VerificationMismatch::device(
+                DeviceKind::Display,
+                "LG TV SSCR2",
+                Some("DELL U2723QE"),
+            )],
+            vec![VerificationMismatch::device(
+                DeviceKind::Display,
+                "LG TV SSCR2",
+                None,
+            )],
+        ];
+
+        // Act
+        let result = VERIFICATION.verify(DeviceKind::Display, || {
+            Ok(observations.pop().unwrap_or_default())
+        });
+
+        // Assert
+        assert_eq!(result, Ok(()));
+    }
+
+    #[test]
+    fn fail_to_verify_a_change_never_applied() {
+        // Arrange
+        let mut observations_count = 0;
+        let mismatch = VerificationMismatch::device(
+            DeviceKind::Speaker,
+            "LG TV SSCR2",
+            Some("Speakers (Realtek(R) Audio)"),
+        );
+
+        // Act
+        let result = VERIFICATION.verify(DeviceKind::Speaker, || {
+            observations_count += 1;
+
+            Ok(vec![mismatch.clone()])
+        });
+
+        // Assert
+        assert_eq!(
+            result,
+            Err(ApplicationError::VerificationFailed {
+                kind: DeviceKind::Speaker,
+                mismatches: vec![mismatch],
+                attempts: 3,
+            })
+        );
+        assert_eq!(observations_count, 3);
+    }
+}
@@ -0,0 +1,546 @@
+use regex::{Regex, RegexBuilder};
+
+use crate::{
+    application_error::{
+        ApplicationError, DeviceKind, DeviceRole, ErrorSource, format_device_kind,
+    },
+    application_result::ApplicationResult,
+    trace_fn,
+};
+
+const ID_SELECTOR_PREFIX: &str = "id:";
+const GLOB_SELECTOR_PREFIX: &str = "glob:";
+const REGEX_SELECTOR_PREFIX: &str = "re:";
+const MAX_SUGGESTIONS_COUNT: usize = 3;
+/// Beyond a few typos, two names are more likely to be different devices of the same brand.
+const MAX_SUGGESTION_DISTANCE: usize = 3;
+
+/// A device reported by a backend, which can be selected by its name or by its id.
+pub trait SelectableDevice {
+    fn name(&self) -> &str;
+
+    fn id(&self) -> &str;
+}
+
+/// Selects devices by `id:<ID>`, `glob:<PATTERN>`, `re:<REGEX>` or by their name, the names
+/// being matched case-insensitively.
+#[derive(Debug)]
+pub enum DeviceSelector {
+    Name(String),
+    Id(String),
+    Pattern(Regex),
+}
+
+impl DeviceSelector {
+    pub fn parse(selector: &str) -> ApplicationResult<Self> {
+        trace_fn!();
+
+        if let Some(id) = selector.strip_prefix(ID_SELECTOR_PREFIX) {
+            return Ok(Self::Id(String::from(id)));
+        }
+
+        if let Some(glob) = selector.strip_prefix(GLOB_SELECTOR_PREFIX) {
+            return build_pattern(selector, &from_glob_to_regex(glob)).map(Self::Pattern);
+        }
+
+        if let Some(regex) = selector.strip_prefix(REGEX_SELECTOR_PREFIX) {
+            return build_pattern(selector, regex).map(Self::Pattern);
+        }
+
+        Ok(Self::Name(String::from(selector)))
+    }
+
+    pub fn matches(&self, device: &impl SelectableDevice) -> bool {
+        trace_fn!();
+
+        match self {
+            Self::Name(name) => device.name().to_lowercase() == name.to_lowercase(),
+            Self::Id(id) => device.id().eq_ignore_ascii_case(id),
+            Self::Pattern(regex) => regex.is_match(device.name()),
+        }
+    }
+}
+
+/// Resolves a selector to the device it matches, or to none when it matches nothing, for the
+/// caller to report it along with the possible values.
+pub fn resolve_device<'a, T: SelectableDevice>(
+    kind: DeviceKind,
+    role: DeviceRole,
+    selector: &str,
+    devices: &'a [T],
+) -> ApplicationResult<Option<&'a T>> {
+    trace_fn!();
+
+    let device_selector = DeviceSelector::parse(selector)?;
+
+    let mut candidates =
+        select_distinct_devices(devices, |candidate| device_selector.matches(candidate));
+
+    // The exact name wins over the ones differing only by their case.
+    if candidates
+        .iter()
+        .any(|candidate| candidate.name() == selector)
+    {
+        candidates.retain(|candidate| candidate.name() == selector);
+    }
+
+    // Devices sharing a name are told apart by their ids, so only a name or a pattern can match
+    // several of them.
+    match candidates.as_slice() {
+        [] => Ok(None),
+        [candidate] => Ok(Some(*candidate)),
+        _ => Err(ApplicationError::ambiguous_device(
+            kind,
+            role,
+            selector,
+            &candidates,
+        )),
+    }
+}
+
+/// Resolves a selector to the device it matches, a selector which matches nothing being reported
+/// along with the names of the devices.
+pub fn resolve_existing_device<'a, T: SelectableDevice>(
+    kind: DeviceKind,
+    role: DeviceRole,
+    selector: &str,
+    devices: &'a [T],
+) -> ApplicationResult<&'a T> {
+    trace_fn!();
+
+    resolve_device(kind, role, selector, devices)?
+        .ok_or_else(|| device_not_found(kind, role, &[selector], devices))
+}
+
+/// Resolves the selectors of the desktop and couch devices, both being reported at once when
+/// neither matches.
+pub fn resolve_device_pair<'a, T: SelectableDevice>(
+    kind: DeviceKind,
+    desktop_selector: &str,
+    couch_selector: &str,
+    devices: &'a [T],
+) -> ApplicationResult<(&'a T, &'a T)> {
+    trace_fn!();
+
+    let desktop_device = resolve_device(kind, DeviceRole::Desktop, desktop_selector, devices)?;
+    let couch_device = resolve_device(kind, DeviceRole::Couch, couch_selector, devices)?;
+
+    match (desktop_device, couch_device) {
+        (Some(desktop_device), Some(couch_device)) => Ok((desktop_device, couch_device)),
+        (None, None) => Err(device_not_found(
+            kind,
+            DeviceRole::DesktopAndCouch,
+            &[desktop_selector, couch_selector],
+            devices,
+        )),
+        (None, _) => Err(device_not_found(
+            kind,
+            DeviceRole::Desktop,
+            &[desktop_selector],
+            devices,
+        )),
+        (_, None) => Err(device_not_found(
+            kind,
+            DeviceRole::Couch,
+            &[couch_selector],
+            devices,
+        )),
+    }
+}
+
+/// Resolves the selectors of a cycle to the device following the active one, wrapping around, or
+/// preceding it when cycling in reverse. A cycle which does not include the active device starts
+/// over from its first device, or from its last one in reverse. Only the selector of the next
+/// device has to match, the other devices of the cycle may be unplugged.
+pub fn resolve_next_device<'a, T: SelectableDevice>(
+    kind: DeviceKind,
+    selectors: &[String],
+    devices: &'a [T],
+    active_device_id: Option<&str>,
+    reverse: bool,
+) -> ApplicationResult<&'a T> {
+    trace_fn!();
+
+    let cycle_devices = selectors
+        .iter()
+        .map(|selector| resolve_device(kind, DeviceRole::Target, selector, devices))
+        .collect::<ApplicationResult<Vec<Option<&T>>>>()?;
+
+    if cycle_devices.is_empty() {
+        return Err(ApplicationError::Config {
+            message: format!("The {} cycle is empty", format_device_kind(&kind)),
+            source: None,
+        });
+    }
+
+    let active_position = cycle_devices.iter().position(|cycle_device| {
+        cycle_device.is_some_and(|cycle_device| Some(cycle_device.id()) == active_device_id)
+    });
+    let count = cycle_devices.len();
+
+    let next_position = match (active_position, reverse) {
+        (Some(position), false) => (position + 1) % count,
+        (Some(position), true) => (position + count - 1) % count,
+        (None, false) => 0,
+        (None, true) => count - 1,
+    };
+
+    cycle_devices[next_position].ok_or_else(|| {
+        device_not_found(
+            kind,
+            DeviceRole::Target,
+            &[selectors[next_position].as_str()],
+            devices,
+        )
+    })
+}
+
+/// The selector which matches a device by its id, whatever its name.
+pub fn to_id_selector(id: &str) -> String {
+    trace_fn!();
+
+    format!("{ID_SELECTOR_PREFIX}{id}")
+}
+
+/// Suggests the device names closest to a name which matches nothing, by edit distance or because
+/// one contains the other. Selectors other than names get no suggestion.
+pub fn suggest_device_names(selector: &str, device_names: &[String]) -> Vec<String> {
+    trace_fn!();
+
+    let Ok(DeviceSelector::Name(name)) = DeviceSelector::parse(selector) else {
+        return Vec::new();
+    };
+
+    let name = name.to_lowercase();
+    let mut suggestions = device_names
+        .iter()
+        .filter_map(|device_name| {
+            let lowercase_device_name = device_name.to_lowercase();
+            let distance = levenshtein_distance(&name, &lowercase_device_name);
+            let is_contained = name.chars().count() >= 3
+                && (lowercase_device_name.contains(&name) || name.contains(&lowercase_device_name));
+
+            (distance <= MAX_SUGGESTION_DISTANCE || is_contained).then_some((distance, device_name))
+        })
+        .collect::<Vec<(usize, &String)>>();
+    suggestions.sort();
+
+    suggestions
+        .into_iter()
+        .take(MAX_SUGGESTIONS_COUNT)
+        .map(|(_, device_name)| device_name.clone())
+        .collect()
+}
+
+/// The number of single character insertions, deletions or substitutions turning one string into
+/// the other.
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    trace_fn!();
+
+    let b = b.chars().collect::<Vec<char>>();
+    let mut previous_row = (0..=b.len()).collect::<Vec<usize>>();
+
+    for (i, a_character) in a.chars().enumerate() {
+        let mut current_row = vec![i + 1; b.len() + 1];
+
+        for (j, b_character) in b.iter().enumerate() {
+            let substitution_cost = usize::from(a_character != *b_character);
+
+            current_row[j + 1] = (previous_row[j] + substitution_cost)
+                .min(previous_row[j + 1] + 1)
+                .min(current_row[j] + 1);
+        }
+
+        previous_row = current_row;
+    }
+
+    previous_row[b.len()]
+}
+
+/// The devices are told apart by their ids, two speakers both named Speakers being distinct
+/// whereas a device reported twice is not.
+fn select_distinct_devices<T: SelectableDevice>(
+    devices: &[T],
+    predicate: impl Fn(&T) -> bool,
+) -> Vec<&T> {
+    trace_fn!();
+
+    let mut selected_devices = devices
+        .iter()
+        .filter(|device| predicate(device))
+        .collect::<Vec<&T>>();
+    selected_devices.sort_by_key(|device| device.id());
+    selected_devices.dedup_by_key(|device| device.id());
+
+    selected_devices
+}
+
+fn device_not_found<T: SelectableDevice>(
+    kind: DeviceKind,
+    role: DeviceRole,
+    requested: &[&str],
+    devices: &[T],
+) -> ApplicationError {
+    trace_fn!();
+
+    let possible_names = devices
+        .iter()
+        .map(|device| String::from(device.name()))
+        .collect::<Vec<String>>();
+
+    ApplicationError::device_not_found(kind, role, requested, possible_names)
+}
+
+fn build_pattern(selector: &str, regex: &str) -> ApplicationResult<Regex> {
+    trace_fn!();
+
+    RegexBuilder::new(regex)
+        .case_insensitive(true)
+        .build()
+        .map_err(|error| ApplicationError::Config {
+            message: format!("Selector {selector} is invalid: {error}"),
+            source: Some(ErrorSource::new(error)),
+        })
+}
+
+/// Translates a glob, where `*` matches any sequence of characters and `?` a single one, into an
+/// anchored regex.
+fn from_glob_to_regex(glob: &str) -> String {
+    trace_fn!();
+
+    let mut regex = String::from("^");
+
+    for character in glob.chars() {
+        match character {
+            '*' => regex.push_str(".*"),
+            '?' => regex.push('.'),
+            _ => regex.push_str(&regex::escape(&character.to_string())),
+        }
+    }
+
+    regex.push('$');
+
+    regex
+}
+
+#[cfg(test)]
+mod should {
+    use test_case::test_case;
+
+    use crate::{
+        application_error::{ApplicationError, DeviceKind, DeviceRole},
+        application_result::ApplicationResult,
+        device_selector::{
+            SelectableDevice, levenshtein_distance, resolve_device, resolve_device_pair,
+            resolve_existing_device, resolve_next_device, suggest_device_names,
+        },
+    };
+
+    struct Device {
+        name: &'static str,
+        id: &'static str,
+    }
+
+    impl SelectableDevice for Device {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn id(&self) -> &str {
+            self.id
+        }
+    }
+
+    const DEVICES: [Device; 4] = [
+        Device {
+            name: "DELL U2723QE",
+            id: "DP-1",
+        },
+        Device {
+            name: "LG TV SSCR2",
+            id: "HDMI-A-1",
+        },
+        Device {
+            name: "LG TV SSCR2 (2)",
+            id: "HDMI-A-2",
+        },
+        Device {
+            name: "Denon AVR-X1700H",
+            id: "HDMI-A-3",
+        },
+    ];
+
+    #[test_case("DELL U2723QE" => Ok(Some("DP-1")); "when the name is exact")]
+    #[test_case("dell u2723qe" => Ok(Some("DP-1")); "when the name differs by its case")]
+    #[test_case("LG TV SSCR2" => Ok(Some("HDMI-A-1")); "when the name is the prefix of another one")]
+    #[test_case("id:HDMI-A-2" => Ok(Some("HDMI-A-2")); "when the id is given")]
+    #[test_case("id:hdmi-a-3" => Ok(Some("HDMI-A-3")); "when the id differs by its case")]
+    #[test_case("glob:dell*" => Ok(Some("DP-1")); "when the glob matches one device")]
+    #[test_case("glob:LG TV SSCR? (?)" => Ok(Some("HDMI-A-2")); "when the glob matches single characters")]
+    #[test_case("re:^Denon" => Ok(Some("HDMI-A-3")); "when the regex matches one device")]
+    #[test_case("Epson EF-12" => Ok(None); "when the name matches nothing")]
+    #[test_case("id:DP-2" => Ok(None); "when the id matches nothing")]
+    #[test_case("id:DELL U2723QE" => Ok(None); "when the id is the name of a device")]
+    #[test_case("glob:LG*" => Err(ApplicationError::AmbiguousDevice { kind: DeviceKind::Display, role: DeviceRole::Target, selector: String::from("glob:LG*"), candidates: vec![String::from("LG TV SSCR2 (id:HDMI-A-1)"), String::from("LG TV SSCR2 (2) (id:HDMI-A-2)")] }); "when the glob matches several devices")]
+    #[test_case("re:SSCR2" => Err(ApplicationError::AmbiguousDevice { kind: DeviceKind::Display, role: DeviceRole::Target, selector: String::from("re:SSCR2"), candidates: vec![String::from("LG TV SSCR2 (id:HDMI-A-1)"), String::from("LG TV SSCR2 (2) (id:HDMI-A-2)")] }); "when the regex matches several devices")]
+    fn resolve_the_selected_device(selector: &str) -> ApplicationResult<Option<&'static str>> {
+        // Act
+        let device = resolve_device(DeviceKind::Display, DeviceRole::Target, selector, &DEVICES);
+
+        // Assert
+        device.map(|device| device.map(|device| device.id))
+    }
+
+    #[test_case("id:DP-2" => Err(ApplicationError::DeviceNotFound { kind: DeviceKind::Display, role: DeviceRole::Target, requested: vec![String::from("id:DP-2")], candidates: vec![String::from("DELL U2723QE"), String::from("Denon AVR-X1700H"), String::from("LG TV SSCR2"), String::from("LG TV SSCR2 (2)")], suggestions: Vec::new() }); "when the id matches nothing")]
+    #[test_case("Epson EF-12" => Err(ApplicationError::DeviceNotFound { kind: DeviceKind::Display, role: DeviceRole::Target, requested: vec![String::from("Epson EF-12")], candidates: vec![String::from("DELL U2723QE"), String::from("Denon AVR-X1700H"), String::from("LG TV SSCR2"), String::from("LG TV SSCR2 (2)")], suggestions: Vec::new() }); "when the name matches nothing")]
+    #[test_case("id:DP-1" => Ok("DP-1"); "when the id matches a device")]
+    fn report_a_selector_which_matches_no_device(
+        selector: &str,
+    ) -> ApplicationResult<&'static str> {
+        // Act
+        let device =
+            resolve_existing_device(DeviceKind::Display, DeviceRole::Target, selector, &DEVICES);
+
+        // Assert
+        device.map(|device| device.id)
+    }
+
+    #[test_case("DELL U2723QE", "id:HDMI-A-1" => Ok(("DP-1", "HDMI-A-1")); "when both devices match")]
+    #[test_case("DELL U2723QE", "id:DP-2" => Err(ApplicationError::DeviceNotFound { kind: DeviceKind::Display, role: DeviceRole::Couch, requested: vec![String::from("id:DP-2")], candidates: vec![String::from("DELL U2723QE"), String::from("Denon AVR-X1700H"), String::from("LG TV SSCR2"), String::from("LG TV SSCR2 (2)")], suggestions: Vec::new() }); "when the couch device matches nothing")]
+    #[test_case("Epson EF-12", "id:DP-2" => Err(ApplicationError::DeviceNotFound { kind: DeviceKind::Display, role: DeviceRole::DesktopAndCouch, requested: vec![String::from("Epson EF-12"), String::from("id:DP-2")], candidates: vec![String::from("DELL U2723QE"), String::from("Denon AVR-X1700H"), String::from("LG TV SSCR2"), String::from("LG TV SSCR2 (2)")], suggestions: Vec::new() }); "when neither device matches")]
+    fn resolve_the_desktop_and_couch_devices(
+        desktop_selector: &str,
+        couch_selector: &str,
+    ) -> ApplicationResult<(&'static str, &'static str)> {
+        // Act
+        let devices = resolve_device_pair(
+            DeviceKind::Display,
+            desktop_selector,
+            couch_selector,
+            &DEVICES,
+        );
+
+        // Assert
+        devices.map(|(desktop_device, couch_device)| (desktop_device.id, couch_device.id))
+    }
+
+    #[test_case("Speakers" => Err(ApplicationError::AmbiguousDevice { kind: DeviceKind::Speaker, role: DeviceRole::Target, selector: String::from("Speakers"), candidates: vec![String::from("Speakers (id:{0.0.0.00000000}.{1a})"), String::from("Speakers (id:{0.0.0.00000000}.{2b})")] }); "when the name is shared by several devices")]
+    #[test_case("speakers" => Err(ApplicationError::AmbiguousDevice { kind: DeviceKind::Speaker, role: DeviceRole::Target, selector: String::from("speakers"), candidates: vec![String::from("Speakers (id:{0.0.0.00000000}.{1a})"), String::from("Speakers (id:{0.0.0.00000000}.{2b})")] }); "when the name differing by its case is shared by several devices")]
+    #[test_case("id:{0.0.0.00000000}.{2b}" => Ok(Some("{0.0.0.00000000}.{2b}")); "when the id of a device sharing its name is given")]
+    #[test_case("Headphones" => Ok(Some("{0.0.0.00000000}.{3c}")); "when the device is reported twice")]
+    fn resolve_a_device_sharing_its_name(
+        selector: &str,
+    ) -> ApplicationResult<Option<&'static str>> {
+        // Arrange
+        let devices = [
+            Device {
+                name: "Speakers",
+                id: "{0.0.0.00000000}.{2b}",
+            },
+            Device {
+                name: "Speakers",
+                id: "{0.0.0.00000000}.{1a}",
+            },
+            Device {
+                name: "Headphones",
+                id: "{0.0.0.00000000}.{3c}",
+            },
+            Device {
+                name: "Headphones",
+                id: "{0.0.0.00000000}.{3c}",
+            },
+        ];
+
+        // Act
+        let device = resolve_device(DeviceKind::Speaker, DeviceRole::Target, selector, &devices);
+
+        // Assert
+        device.map(|device| device.map(|device| device.id))
+    }
+
+    #[test_case(Some("DP-1"), false => Ok("HDMI-A-1"); "when the active device is followed by another one")]
+    #[test_case(Some("HDMI-A-3"), false => Ok("DP-1"); "when the active device is the last one")]
+    #[test_case(Some("HDMI-A-1"), true => Ok("DP-1"); "when the active device is preceded by another one in reverse")]
+    #[test_case(Some("DP-1"), true => Ok("HDMI-A-3"); "when the active device is the first one in reverse")]
+    #[test_case(Some("HDMI-A-2"), false => Ok("DP-1"); "when the active device is not part of the cycle")]
+    #[test_case(None, true => Ok("HDMI-A-3"); "when there is no active device in reverse")]
+    #[test_case(Some("HDMI-A-3"), true => Err(ApplicationError::DeviceNotFound { kind: DeviceKind::Display, role: DeviceRole::Target, requested: vec![String::from("Epson EF-12")], candidates: vec![String::from("DELL U2723QE"), String::from("Denon AVR-X1700H"), String::from("LG TV SSCR2"), String::from("LG TV SSCR2 (2)")], suggestions: Vec::new() }); "when the next device matches nothing")]
+    fn resolve_the_next_device_of_a_cycle(
+        active_device_id: Option<&str>,
+        reverse: bool,
+    ) -> ApplicationResult<&'static str> {
+        // Arrange
+        let selectors = [
+            String::from("dell u2723qe"),
+            String::from("id:HDMI-A-1"),
+            String::from("Epson EF-12"),
+            String::from("glob:Denon*"),
+        ];
+
+        // Act
+        let device = resolve_next_device(
+            DeviceKind::Display,
+            &selectors,
+            &DEVICES,
+            active_device_id,
+            reverse,
+        );
+
+        // Assert
+        device.map(|device| device.id)
+    }
+
+    #[test]
+    fn fail_to_resolve_the_next_device_of_an_empty_cycle() {
+        // Act
+        let device = resolve_next_device(DeviceKind::Speaker, &[], &DEVICES, None, false);
+
+        // Assert
+        assert_eq!(
+            device.map(|device| device.id),
+            Err(ApplicationError::Config {
+                message: String::from("The speaker cycle is empty"),
+                source: None,
+            })
+        );
+    }
+
+    #[test_case("LG TV SSCR" => vec![String::from("LG TV SSCR2"), String::from("LG TV SSCR2 (2)")]; "when the name is truncated")]
+    #[test_case("Denon AVR-X1600H" => vec![String::from("Denon AVR-X1700H")]; "when the name has a typo")]
+    #[test_case("denon" => vec![String::from("Denon AVR-X1700H")]; "when the name is contained")]
+    #[test_case("Epson EF-12" => Vec::<String>::new(); "when no name is close")]
+    #[test_case("glob:Epson*" => Vec::<String>::new(); "when a selector is given")]
+    fn suggest_the_closest_device_names(name: &str) -> Vec<String> {
+        // Arrange
+        let device_names = DEVICES
+            .iter()
+            .map(|device| String::from(device.name))
+            .collect::<Vec<String>>();
+
+        // Act
+        suggest_device_names(name, &device_names)
+    }
+
+    #[test_case("", "" => 0; "when both strings are empty")]
+    #[test_case("kitten", "sitting" => 3; "when characters are substituted and inserted")]
+    #[test_case("SSCR2", "SSCR" => 1; "when a character is deleted")]
+    fn compute_the_levenshtein_distance(a: &str, b: &str) -> usize {
+        // Act
+        levenshtein_distance(a, b)
+    }
+
+    #[test]
+    fn fail_to_resolve_an_invalid_regex() {
+        // Act
+        let device = resolve_device(DeviceKind::Display, DeviceRole::Target, "re:(", &DEVICES);
+
+        // Assert
+        assert!(matches!(
+            device,
+            Err(ApplicationError::Config {
+                message,
+                source: Some(_),
+            }) if message.starts_with("Selector re:( is invalid: regex parse error")
+        ));
+    }
+}
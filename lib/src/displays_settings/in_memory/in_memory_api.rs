@@ -0,0 +1,68 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    application_result::ApplicationResult,
+    displays_settings::{
+        DisplayAdvancedColor, DisplayConnector, DisplayMode, DisplayResolution, DisplayRotation,
+    },
+    trace_fn,
+};
+
+/// A display of the simulated machine, positioned on the virtual desktop while it is active.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InMemoryDisplay {
+    pub id: String,
+    pub name: String,
+    pub connector: DisplayConnector,
+    pub width: u32,
+    pub height: u32,
+    pub x: i32,
+    pub y: i32,
+    pub refresh_rate_millihertz: u32,
+    pub rotation: DisplayRotation,
+    pub scale_percent: u32,
+    /// Whether the desktop spreads over the display, an inactive one being connected but off.
+    #[serde(default = "is_active_by_default")]
+    pub is_active: bool,
+    /// The modes the display supports besides its current one, which is always supported.
+    #[serde(default)]
+    pub modes: Vec<DisplayMode>,
+    /// Whether the display supports HDR and shows it, left out for the displays simulated
+    /// before HDR could be toggled.
+    #[serde(default)]
+    pub advanced_color: Option<DisplayAdvancedColor>,
+}
+
+impl InMemoryDisplay {
+    pub fn current_mode(&self) -> DisplayMode {
+        trace_fn!();
+
+        DisplayMode {
+            resolution: DisplayResolution {
+                width: self.width,
+                height: self.height,
+            }
+            .rotated(self.rotation),
+            refresh_rate_millihertz: self.refresh_rate_millihertz,
+        }
+    }
+}
+
+/// Snapshots saved before the displays could be turned off only hold active displays.
+fn is_active_by_default() -> bool {
+    trace_fn!();
+
+    true
+}
+
+pub trait InMemoryApi {
+    /// Retrieves every display of the simulated machine.
+    ///
+    /// The active display positioned at the origin of the virtual desktop is the primary one.
+    fn get_displays(&self) -> ApplicationResult<Vec<InMemoryDisplay>>;
+
+    /// Replaces the layout of the simulated machine's displays.
+    ///
+    /// The displays are expected to be given in the order returned by `get_displays`.
+    fn set_displays(&mut self, displays: Vec<InMemoryDisplay>) -> ApplicationResult<()>;
+}
@@ -0,0 +1,361 @@
+use log::info;
+
+use crate::{
+    application_error::{ApplicationError, DeviceKind, DeviceRole},
+    application_result::ApplicationResult,
+    displays_settings::{
+        ConnectedDisplay, DisplayInfo, DisplayMode, DisplayModes, DisplayPosition,
+        DisplayResolution, DisplayRotation, DisplaysSettings, DisplaysSettingsResult,
+        DisplaysSnapshot, DisplaysTopology, ensure_snapshot_displays_are_connected,
+        from_raw_display_name, in_memory::in_memory_api::InMemoryApi,
+    },
+    trace_fn,
+};
+
+pub struct InMemoryDisplaysSettings {
+    in_memory_api: Box<dyn InMemoryApi>,
+}
+
+impl InMemoryDisplaysSettings {
+    pub fn new(displays_settings_api: Box<dyn InMemoryApi>) -> Self {
+        trace_fn!();
+
+        Self {
+            in_memory_api: displays_settings_api,
+        }
+    }
+}
+
+impl DisplaysSettings for InMemoryDisplaysSettings {
+    fn change_primary_display(
+        &mut self,
+        desktop_display_id: &str,
+        couch_display_id: &str,
+    ) -> ApplicationResult<DisplaysSettingsResult> {
+        trace_fn!();
+        info!("Changing primary display");
+
+        let mut displays = self.in_memory_api.get_displays()?;
+
+        let mut new_position = (0, 0);
+        let mut new_primary_display_name = String::default();
+        let mut desktop_display_id_is_valid = false;
+        let mut couch_display_id_is_valid = false;
+        let mut possible_ids = Vec::with_capacity(displays.len());
+
+        for display in displays.iter().filter(|display| display.is_active) {
+            possible_ids.push(display.id.clone());
+
+            if display.id != desktop_display_id && display.id != couch_display_id {
+                continue;
+            }
+
+            if display.id == desktop_display_id {
+                desktop_display_id_is_valid = true;
+            }
+
+            if display.id == couch_display_id {
+                couch_display_id_is_valid = true;
+            }
+
+            if display.x != 0 || display.y != 0 {
+                new_position = (display.x, display.y);
+                new_primary_display_name = from_raw_display_name(&display.name);
+            }
+        }
+
+        let invalid_params = match (desktop_display_id_is_valid, couch_display_id_is_valid) {
+            (false, false) => Some((
+                DeviceRole::DesktopAndCouch,
+                vec![desktop_display_id, couch_display_id],
+            )),
+            (false, _) => Some((DeviceRole::Desktop, vec![desktop_display_id])),
+            (_, false) => Some((DeviceRole::Couch, vec![couch_display_id])),
+            _ => None,
+        };
+
+        if let Some((invalid_role, invalid_ids)) = invalid_params {
+            let error = ApplicationError::device_not_found(
+                DeviceKind::Display,
+                invalid_role,
+                &invalid_ids,
+                possible_ids,
+            );
+
+            return Err(error);
+        }
+
+        for display in displays.iter_mut().filter(|display| display.is_active) {
+            display.x -= new_position.0;
+            display.y -= new_position.1;
+        }
+
+        self.in_memory_api.set_displays(displays)?;
+
+        Ok(DisplaysSettingsResult {
+            new_primary_display: new_primary_display_name,
+            new_mode: None,
+            new_rotation: None,
+            new_scale_percent: None,
+            new_hdr_enabled: None,
+        })
+    }
+
+    fn get_displays_infos(&mut self) -> ApplicationResult<Vec<DisplayInfo>> {
+        trace_fn!();
+        info!("Getting displays informations");
+
+        let mut displays_infos = self
+            .in_memory_api
+            .get_displays()?
+            .iter()
+            .filter(|display| display.is_active)
+            .map(|display| DisplayInfo {
+                is_primary: display.x == 0 && display.y == 0,
+                name: from_raw_display_name(&display.name),
+                id: display.id.clone(),
+                connector: display.connector,
+                resolution: DisplayResolution {
+                    width: display.width,
+                    height: display.height,
+                },
+                position: DisplayPosition {
+                    x: display.x,
+                    y: display.y,
+                },
+                refresh_rate_millihertz: Some(display.refresh_rate_millihertz),
+                rotation: display.rotation,
+                scale_percent: Some(display.scale_percent),
+                advanced_color: display.advanced_color,
+            })
+            .collect::<Vec<DisplayInfo>>();
+
+        displays_infos.sort();
+
+        Ok(displays_infos)
+    }
+
+    fn save_snapshot(&mut self) -> ApplicationResult<DisplaysSnapshot> {
+        trace_fn!();
+        info!("Saving displays snapshot");
+
+        let displays = self.in_memory_api.get_displays()?;
+
+        Ok(DisplaysSnapshot::InMemory { displays })
+    }
+
+    fn restore_snapshot(&mut self, snapshot: &DisplaysSnapshot) -> ApplicationResult<()> {
+        trace_fn!();
+        info!("Restoring displays snapshot");
+
+        let DisplaysSnapshot::InMemory {
+            displays: snapshot_displays,
+        } = snapshot
+        else {
+            return Err(ApplicationError::snapshot_of_another_backend(
+                DeviceKind::Display,
+                snapshot.backend_name(),
+                "in-memory",
+            ));
+        };
+
+        let displays = self.in_memory_api.get_displays()?;
+        let displays_ids = displays
+            .iter()
+            .map(|display| display.id.as_str())
+            .collect::<Vec<&str>>();
+
+        ensure_snapshot_displays_are_connected(
+            snapshot_displays.iter().map(|display| display.id.as_str()),
+            &displays_ids,
+        )?;
+
+        let restored_displays = displays
+            .iter()
+            .map(|display| {
+                snapshot_displays
+                    .iter()
+                    .find(|snapshot_display| snapshot_display.id == display.id)
+                    .unwrap_or(display)
+                    .clone()
+            })
+            .collect();
+
+        self.in_memory_api.set_displays(restored_displays)
+    }
+
+    fn get_connected_displays(&mut self) -> ApplicationResult<Vec<ConnectedDisplay>> {
+        trace_fn!();
+        info!("Getting connected displays");
+
+        Ok(self
+            .in_memory_api
+            .get_displays()?
+            .iter()
+            .map(|display| ConnectedDisplay {
+                name: from_raw_display_name(&display.name),
+                id: display.id.clone(),
+                connector: display.connector,
+                is_active: display.is_active,
+                is_primary: display.is_active && display.x == 0 && display.y == 0,
+            })
+            .collect())
+    }
+
+    fn apply_topology(
+        &mut self,
+        topology: &DisplaysTopology,
+        active_displays: &[ConnectedDisplay],
+    ) -> ApplicationResult<()> {
+        trace_fn!();
+        info!("Applying displays topology");
+
+        let mut displays = self.in_memory_api.get_displays()?;
+        let mut x = 0;
+
+        for display in &mut displays {
+            display.is_active = false;
+        }
+
+        for active_display in active_displays {
+            let Some(display) = displays
+                .iter_mut()
+                .find(|display| display.id == active_display.id)
+            else {
+                continue;
+            };
+
+            display.is_active = true;
+            display.x = x;
+            display.y = 0;
+
+            if *topology != DisplaysTopology::Clone {
+                x += i32::try_from(display.width)?;
+            }
+        }
+
+        self.in_memory_api.set_displays(displays)
+    }
+
+    fn get_displays_modes(&mut self) -> ApplicationResult<Vec<DisplayModes>> {
+        trace_fn!();
+        info!("Getting displays modes");
+
+        Ok(self
+            .in_memory_api
+            .get_displays()?
+            .iter()
+            .filter(|display| display.is_active)
+            .map(|display| {
+                let current_mode = display.current_mode();
+                let mut modes = display.modes.clone();
+
+                if !modes.contains(&current_mode) {
+                    modes.push(current_mode);
+                }
+
+                DisplayModes {
+                    name: from_raw_display_name(&display.name),
+                    id: display.id.clone(),
+                    current_mode: Some(current_mode),
+                    modes,
+                }
+            })
+            .collect())
+    }
+
+    fn apply_display_mode(
+        &mut self,
+        display_modes: &DisplayModes,
+        mode: &DisplayMode,
+    ) -> ApplicationResult<()> {
+        trace_fn!();
+        info!("Applying display mode");
+
+        let mut displays = self.in_memory_api.get_displays()?;
+
+        for display in &mut displays {
+            if display.id != display_modes.id {
+                continue;
+            }
+
+            let resolution = mode.resolution.rotated(display.rotation);
+
+            display.width = resolution.width;
+            display.height = resolution.height;
+            display.refresh_rate_millihertz = mode.refresh_rate_millihertz;
+        }
+
+        self.in_memory_api.set_displays(displays)
+    }
+
+    fn apply_display_rotation(
+        &mut self,
+        display_info: &DisplayInfo,
+        rotation: DisplayRotation,
+    ) -> ApplicationResult<()> {
+        trace_fn!();
+        info!("Applying display rotation");
+
+        let mut displays = self.in_memory_api.get_displays()?;
+
+        for display in &mut displays {
+            if display.id != display_info.id {
+                continue;
+            }
+
+            let resolution = DisplayResolution {
+                width: display.width,
+                height: display.height,
+            }
+            .rotated(display.rotation)
+            .rotated(rotation);
+
+            display.width = resolution.width;
+            display.height = resolution.height;
+            display.rotation = rotation;
+        }
+
+        self.in_memory_api.set_displays(displays)
+    }
+
+    fn apply_display_scale(
+        &mut self,
+        display_info: &DisplayInfo,
+        scale_percent: u32,
+    ) -> ApplicationResult<()> {
+        trace_fn!();
+        info!("Applying display scale");
+
+        let mut displays = self.in_memory_api.get_displays()?;
+
+        for display in &mut displays {
+            if display.id == display_info.id {
+                display.scale_percent = scale_percent;
+            }
+        }
+
+        self.in_memory_api.set_displays(displays)
+    }
+
+    fn apply_display_hdr(
+        &mut self,
+        display_info: &DisplayInfo,
+        is_hdr_enabled: bool,
+    ) -> ApplicationResult<()> {
+        trace_fn!();
+        info!("Applying display HDR");
+
+        let mut displays = self.in_memory_api.get_displays()?;
+
+        for display in &mut displays {
+            if display.id == display_info.id
+                && let Some(advanced_color) = &mut display.advanced_color
+            {
+                advanced_color.is_hdr_enabled = is_hdr_enabled;
+            }
+        }
+
+        self.in_memory_api.set_displays(displays)
+    }
+}
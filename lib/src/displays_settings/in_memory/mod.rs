@@ -0,0 +1,3 @@
+pub mod in_memory_api;
+pub mod in_memory_displays_settings;
+pub mod simulated_in_memory_api;
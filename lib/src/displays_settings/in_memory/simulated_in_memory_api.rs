@@ -0,0 +1,120 @@
+use crate::{
+    application_result::ApplicationResult,
+    displays_settings::{
+        DisplayAdvancedColor, DisplayColorEncoding, DisplayConnector, DisplayMode,
+        DisplayResolution, DisplayRotation,
+        in_memory::in_memory_api::{InMemoryApi, InMemoryDisplay},
+    },
+    trace_fn,
+};
+
+pub struct SimulatedInMemoryApi {
+    displays: Vec<InMemoryDisplay>,
+}
+
+impl SimulatedInMemoryApi {
+    pub fn new(displays: Vec<InMemoryDisplay>) -> Self {
+        trace_fn!();
+
+        Self { displays }
+    }
+}
+
+impl Default for SimulatedInMemoryApi {
+    fn default() -> Self {
+        trace_fn!();
+
+        Self::new(vec![
+            InMemoryDisplay {
+                id: String::from("in-memory-1"),
+                name: String::from("Desktop Monitor"),
+                connector: DisplayConnector::DisplayPort,
+                width: 2560,
+                height: 1440,
+                x: 0,
+                y: 0,
+                refresh_rate_millihertz: 165000,
+                rotation: DisplayRotation::Degrees0,
+                scale_percent: 100,
+                is_active: true,
+                modes: vec![
+                    mode(2560, 1440, 165000),
+                    mode(2560, 1440, 144000),
+                    mode(2560, 1440, 60000),
+                    mode(1920, 1080, 60000),
+                ],
+                advanced_color: Some(DisplayAdvancedColor {
+                    is_hdr_supported: false,
+                    is_hdr_enabled: false,
+                    bits_per_color_channel: Some(8),
+                    color_encoding: DisplayColorEncoding::Rgb,
+                }),
+            },
+            InMemoryDisplay {
+                id: String::from("in-memory-2"),
+                name: String::from("Living Room TV"),
+                connector: DisplayConnector::Hdmi,
+                width: 3840,
+                height: 2160,
+                x: 2560,
+                y: 0,
+                refresh_rate_millihertz: 60000,
+                rotation: DisplayRotation::Degrees0,
+                scale_percent: 200,
+                is_active: true,
+                modes: vec![
+                    mode(3840, 2160, 60000),
+                    mode(3840, 2160, 30000),
+                    mode(1920, 1080, 120000),
+                    mode(1920, 1080, 60000),
+                ],
+                advanced_color: Some(DisplayAdvancedColor {
+                    is_hdr_supported: true,
+                    is_hdr_enabled: false,
+                    bits_per_color_channel: Some(10),
+                    color_encoding: DisplayColorEncoding::Rgb,
+                }),
+            },
+            InMemoryDisplay {
+                id: String::from("in-memory-3"),
+                name: String::from("Projector"),
+                connector: DisplayConnector::Hdmi,
+                width: 1920,
+                height: 1080,
+                x: 0,
+                y: 0,
+                refresh_rate_millihertz: 60000,
+                rotation: DisplayRotation::Degrees0,
+                scale_percent: 100,
+                is_active: false,
+                modes: vec![mode(1920, 1080, 60000), mode(1280, 720, 60000)],
+                advanced_color: None,
+            },
+        ])
+    }
+}
+
+fn mode(width: u32, height: u32, refresh_rate_millihertz: u32) -> DisplayMode {
+    trace_fn!();
+
+    DisplayMode {
+        resolution: DisplayResolution { width, height },
+        refresh_rate_millihertz,
+    }
+}
+
+impl InMemoryApi for SimulatedInMemoryApi {
+    fn get_displays(&self) -> ApplicationResult<Vec<InMemoryDisplay>> {
+        trace_fn!();
+
+        Ok(self.displays.clone())
+    }
+
+    fn set_displays(&mut self, displays: Vec<InMemoryDisplay>) -> ApplicationResult<()> {
+        trace_fn!();
+
+        self.displays = displays;
+
+        Ok(())
+    }
+}
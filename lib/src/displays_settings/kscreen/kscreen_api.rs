@@ -0,0 +1,9 @@
+use crate::application_result::ApplicationResult;
+
+pub trait KScreenApi {
+    /// Runs `kscreen-doctor` against the KScreen daemon of the current Plasma session.
+    ///
+    /// This is a thin wrapper around the `kscreen-doctor` executable: the arguments are
+    /// forwarded as is and its standard output is returned without being parsed.
+    fn kscreen_doctor(&mut self, args: &[String]) -> ApplicationResult<String>;
+}
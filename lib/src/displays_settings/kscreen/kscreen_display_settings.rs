@@ -0,0 +1,937 @@
+use log::info;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    application_error::{ApplicationError, DeviceKind, DeviceRole},
+    application_result::ApplicationResult,
+    displays_settings::{
+        ConnectedDisplay, DisplayInfo, DisplayMode, DisplayModes, DisplayPosition,
+        DisplayResolution, DisplayRotation, DisplaysSettings, DisplaysSettingsResult,
+        DisplaysSnapshot, DisplaysTopology, ensure_snapshot_displays_are_connected,
+        from_connector_name, from_monitor_and_connector_names, from_refresh_rate_in_hertz,
+        from_scale_factor, kscreen::kscreen_api::KScreenApi, unsupported_mode,
+    },
+    trace_fn,
+};
+
+/// Plasma 6 replaced the primary flag with priorities, the primary output having the first one.
+const PRIMARY_OUTPUT_PRIORITY: u32 = 1;
+
+pub struct KScreenDisplaySettings {
+    kscreen_api: Box<dyn KScreenApi>,
+}
+
+/// The state of an output as `kscreen-doctor` applies it.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct KScreenOutputSnapshot {
+    pub name: String,
+    pub is_primary: bool,
+    pub position: DisplayPosition,
+    pub current_mode_id: Option<String>,
+    /// A `KScreen::Output::Rotation` flag.
+    pub rotation: u32,
+    pub scale: Option<f64>,
+}
+
+#[derive(Deserialize)]
+struct KScreenConfig {
+    outputs: Vec<KScreenOutput>,
+}
+
+#[derive(Clone, Copy, Default, Deserialize)]
+struct KScreenPosition {
+    x: i32,
+    y: i32,
+}
+
+#[derive(Clone, Copy, Default, Deserialize)]
+struct KScreenSize {
+    width: u32,
+    height: u32,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct KScreenMode {
+    id: String,
+    refresh_rate: f64,
+    size: KScreenSize,
+}
+
+impl KScreenMode {
+    fn to_display_mode(&self) -> Option<DisplayMode> {
+        trace_fn!();
+
+        Some(DisplayMode {
+            resolution: DisplayResolution {
+                width: self.size.width,
+                height: self.size.height,
+            },
+            refresh_rate_millihertz: from_refresh_rate_in_hertz(self.refresh_rate)?,
+        })
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct KScreenOutput {
+    name: String,
+    #[serde(default)]
+    model: Option<String>,
+    #[serde(default)]
+    connected: bool,
+    #[serde(default)]
+    enabled: bool,
+    #[serde(default)]
+    primary: bool,
+    #[serde(default)]
+    priority: u32,
+    #[serde(default)]
+    pos: KScreenPosition,
+    #[serde(default)]
+    current_mode_id: Option<String>,
+    #[serde(default)]
+    modes: Vec<KScreenMode>,
+    /// The modes KScreen picks from when a disabled output is enabled.
+    #[serde(default)]
+    preferred_modes: Vec<String>,
+    /// A `KScreen::Output::Rotation` flag, `None` being `1`.
+    #[serde(default)]
+    rotation: u32,
+    #[serde(default)]
+    scale: Option<f64>,
+}
+
+impl KScreenOutput {
+    fn is_primary(&self) -> bool {
+        trace_fn!();
+
+        self.primary || self.priority == PRIMARY_OUTPUT_PRIORITY
+    }
+
+    fn display_name(&self) -> String {
+        trace_fn!();
+
+        from_monitor_and_connector_names(self.model.clone(), &self.name)
+    }
+
+    fn current_mode(&self) -> Option<&KScreenMode> {
+        trace_fn!();
+
+        self.modes
+            .iter()
+            .find(|mode| Some(&mode.id) == self.current_mode_id.as_ref())
+    }
+
+    /// A disabled output has no current mode and is enabled with its first preferred one.
+    fn turned_on_mode(&self) -> Option<&KScreenMode> {
+        trace_fn!();
+
+        self.current_mode().or_else(|| {
+            self.modes
+                .iter()
+                .find(|mode| self.preferred_modes.first() == Some(&mode.id))
+        })
+    }
+
+    /// The width of the output in the coordinates of the desktop, once rotated and scaled.
+    fn logical_width(&self) -> i32 {
+        trace_fn!();
+
+        let size = self
+            .turned_on_mode()
+            .map(|mode| mode.size)
+            .unwrap_or_default();
+        let width = match self.display_rotation() {
+            DisplayRotation::Degrees90 | DisplayRotation::Degrees270 => size.height,
+            DisplayRotation::Degrees0 | DisplayRotation::Degrees180 => size.width,
+        };
+
+        (f64::from(width) / self.scale.unwrap_or(1.0)).round() as i32
+    }
+
+    /// `Left` and `Right` are the sides the top of the panel is turned to.
+    fn display_rotation(&self) -> DisplayRotation {
+        trace_fn!();
+
+        match self.rotation {
+            2 => DisplayRotation::Degrees270,
+            4 => DisplayRotation::Degrees180,
+            8 => DisplayRotation::Degrees90,
+            _ => DisplayRotation::Degrees0,
+        }
+    }
+
+    /// The modes are the ones of the panel, so their size is swapped when the output is rotated
+    /// by a quarter turn.
+    fn to_display_info(&self) -> DisplayInfo {
+        trace_fn!();
+
+        let current_mode = self.current_mode();
+        let rotation = self.display_rotation();
+        let size = current_mode.map(|mode| mode.size).unwrap_or_default();
+        let (width, height) = match rotation {
+            DisplayRotation::Degrees90 | DisplayRotation::Degrees270 => (size.height, size.width),
+            DisplayRotation::Degrees0 | DisplayRotation::Degrees180 => (size.width, size.height),
+        };
+
+        DisplayInfo {
+            is_primary: self.is_primary(),
+            name: self.display_name(),
+            id: self.name.clone(),
+            connector: from_connector_name(&self.name),
+            resolution: DisplayResolution { width, height },
+            position: DisplayPosition {
+                x: self.pos.x,
+                y: self.pos.y,
+            },
+            refresh_rate_millihertz: current_mode
+                .and_then(|mode| from_refresh_rate_in_hertz(mode.refresh_rate)),
+            rotation,
+            scale_percent: self.scale.and_then(from_scale_factor),
+            advanced_color: None,
+        }
+    }
+}
+
+impl KScreenDisplaySettings {
+    pub fn new(displays_settings_api: Box<dyn KScreenApi>) -> Self {
+        trace_fn!();
+
+        Self {
+            kscreen_api: displays_settings_api,
+        }
+    }
+
+    /// Only the outputs that are connected and enabled are returned, just like the active paths
+    /// on Windows.
+    fn get_outputs(&mut self) -> ApplicationResult<Vec<KScreenOutput>> {
+        trace_fn!();
+
+        Ok(self
+            .get_connected_outputs()?
+            .into_iter()
+            .filter(|output| output.enabled)
+            .collect())
+    }
+
+    fn get_connected_outputs(&mut self) -> ApplicationResult<Vec<KScreenOutput>> {
+        trace_fn!();
+
+        let json = self.kscreen_api.kscreen_doctor(&[String::from("-j")])?;
+        let config = serde_json::from_str::<KScreenConfig>(&json).map_err(|error| {
+            ApplicationError::conversion(
+                &format!("Failed to parse the KScreen configuration: {error}"),
+                error,
+            )
+        })?;
+
+        Ok(config
+            .outputs
+            .into_iter()
+            .filter(|output| output.connected)
+            .collect())
+    }
+}
+
+impl DisplaysSettings for KScreenDisplaySettings {
+    fn change_primary_display(
+        &mut self,
+        desktop_display_id: &str,
+        couch_display_id: &str,
+    ) -> ApplicationResult<DisplaysSettingsResult> {
+        trace_fn!();
+        info!("Changing primary display");
+
+        let outputs = self.get_outputs()?;
+
+        let mut new_primary_output_name = String::default();
+        let mut new_primary_display_name = String::default();
+        let mut desktop_display_id_is_valid = false;
+        let mut couch_display_id_is_valid = false;
+        let mut possible_ids = Vec::with_capacity(outputs.len());
+
+        // The outputs are told apart by their names, which are their ids.
+        for output in &outputs {
+            possible_ids.push(output.name.clone());
+
+            if output.name != desktop_display_id && output.name != couch_display_id {
+                continue;
+            }
+
+            if output.name == desktop_display_id {
+                desktop_display_id_is_valid = true;
+            }
+
+            if output.name == couch_display_id {
+                couch_display_id_is_valid = true;
+            }
+
+            if !output.is_primary() {
+                new_primary_output_name = output.name.clone();
+                new_primary_display_name = output.display_name();
+            }
+        }
+
+        let invalid_params = match (desktop_display_id_is_valid, couch_display_id_is_valid) {
+            (false, false) => Some((
+                DeviceRole::DesktopAndCouch,
+                vec![desktop_display_id, couch_display_id],
+            )),
+            (false, _) => Some((DeviceRole::Desktop, vec![desktop_display_id])),
+            (_, false) => Some((DeviceRole::Couch, vec![couch_display_id])),
+            _ => None,
+        };
+
+        if let Some((invalid_role, invalid_ids)) = invalid_params {
+            let error = ApplicationError::device_not_found(
+                DeviceKind::Display,
+                invalid_role,
+                &invalid_ids,
+                possible_ids,
+            );
+
+            return Err(error);
+        }
+
+        self.kscreen_api
+            .kscreen_doctor(&[format!("output.{new_primary_output_name}.primary")])?;
+
+        Ok(DisplaysSettingsResult {
+            new_primary_display: new_primary_display_name,
+            new_mode: None,
+            new_rotation: None,
+            new_scale_percent: None,
+            new_hdr_enabled: None,
+        })
+    }
+
+    fn get_displays_infos(&mut self) -> ApplicationResult<Vec<DisplayInfo>> {
+        trace_fn!();
+        info!("Getting displays informations");
+
+        let mut displays_infos = self
+            .get_outputs()?
+            .iter()
+            .map(KScreenOutput::to_display_info)
+            .collect::<Vec<DisplayInfo>>();
+
+        displays_infos.sort();
+
+        Ok(displays_infos)
+    }
+
+    /// Only the primary flag is set, KDE keeping the layout of the outputs.
+    fn moves_primary_display_to_origin(&self) -> bool {
+        trace_fn!();
+
+        false
+    }
+
+    fn save_snapshot(&mut self) -> ApplicationResult<DisplaysSnapshot> {
+        trace_fn!();
+        info!("Saving displays snapshot");
+
+        let outputs = self
+            .get_outputs()?
+            .into_iter()
+            .map(|output| KScreenOutputSnapshot {
+                is_primary: output.is_primary(),
+                name: output.name,
+                position: DisplayPosition {
+                    x: output.pos.x,
+                    y: output.pos.y,
+                },
+                current_mode_id: output.current_mode_id,
+                rotation: output.rotation,
+                scale: output.scale,
+            })
+            .collect();
+
+        Ok(DisplaysSnapshot::KScreen { outputs })
+    }
+
+    fn restore_snapshot(&mut self, snapshot: &DisplaysSnapshot) -> ApplicationResult<()> {
+        trace_fn!();
+        info!("Restoring displays snapshot");
+
+        let DisplaysSnapshot::KScreen {
+            outputs: snapshot_outputs,
+        } = snapshot
+        else {
+            return Err(ApplicationError::snapshot_of_another_backend(
+                DeviceKind::Display,
+                snapshot.backend_name(),
+                "kscreen",
+            ));
+        };
+
+        let outputs = self.get_connected_outputs()?;
+        let outputs_names = outputs
+            .iter()
+            .map(|output| output.name.as_str())
+            .collect::<Vec<&str>>();
+
+        ensure_snapshot_displays_are_connected(
+            snapshot_outputs.iter().map(|output| output.name.as_str()),
+            &outputs_names,
+        )?;
+
+        let mut args = Vec::with_capacity(outputs.len() * 6);
+
+        for output in snapshot_outputs {
+            let name = &output.name;
+
+            args.push(format!("output.{name}.enable"));
+
+            if let Some(current_mode_id) = &output.current_mode_id {
+                args.push(format!("output.{name}.mode.{current_mode_id}"));
+            }
+
+            args.push(format!(
+                "output.{name}.position.{},{}",
+                output.position.x, output.position.y
+            ));
+            args.push(format!(
+                "output.{name}.rotation.{}",
+                match output.rotation {
+                    2 => "left",
+                    4 => "inverted",
+                    8 => "right",
+                    _ => "none",
+                }
+            ));
+
+            if let Some(scale) = output.scale {
+                args.push(format!("output.{name}.scale.{scale}"));
+            }
+
+            if output.is_primary {
+                args.push(format!("output.{name}.primary"));
+            }
+        }
+
+        push_disabled_outputs(
+            &mut args,
+            &outputs,
+            snapshot_outputs.iter().map(|output| output.name.as_str()),
+        );
+
+        self.kscreen_api.kscreen_doctor(&args)?;
+
+        Ok(())
+    }
+
+    fn get_connected_displays(&mut self) -> ApplicationResult<Vec<ConnectedDisplay>> {
+        trace_fn!();
+        info!("Getting connected displays");
+
+        Ok(self
+            .get_connected_outputs()?
+            .iter()
+            .map(|output| ConnectedDisplay {
+                name: output.display_name(),
+                id: output.name.clone(),
+                connector: from_connector_name(&output.name),
+                is_active: output.enabled,
+                is_primary: output.enabled && output.is_primary(),
+            })
+            .collect())
+    }
+
+    /// The enabled outputs keep their mode, rotation and scale, KScreen picking the preferred
+    /// mode of the ones it turns on.
+    fn apply_topology(
+        &mut self,
+        topology: &DisplaysTopology,
+        active_displays: &[ConnectedDisplay],
+    ) -> ApplicationResult<()> {
+        trace_fn!();
+        info!("Applying displays topology");
+
+        let outputs = self.get_connected_outputs()?;
+        let mut args = Vec::with_capacity(outputs.len() * 3);
+        let mut x = 0;
+
+        for display in active_displays {
+            let Some(output) = outputs.iter().find(|output| output.name == display.id) else {
+                continue;
+            };
+            let name = &output.name;
+            let is_first = args.is_empty();
+
+            args.push(format!("output.{name}.enable"));
+            args.push(format!("output.{name}.position.{x},0"));
+
+            if is_first {
+                args.push(format!("output.{name}.primary"));
+            }
+
+            if *topology != DisplaysTopology::Clone {
+                x += output.logical_width();
+            }
+        }
+
+        push_disabled_outputs(
+            &mut args,
+            &outputs,
+            active_displays.iter().map(|display| display.id.as_str()),
+        );
+
+        self.kscreen_api.kscreen_doctor(&args)?;
+
+        Ok(())
+    }
+
+    fn get_displays_modes(&mut self) -> ApplicationResult<Vec<DisplayModes>> {
+        trace_fn!();
+        info!("Getting displays modes");
+
+        Ok(self
+            .get_outputs()?
+            .iter()
+            .map(|output| DisplayModes {
+                name: output.display_name(),
+                id: output.name.clone(),
+                current_mode: output.current_mode().and_then(KScreenMode::to_display_mode),
+                modes: output
+                    .modes
+                    .iter()
+                    .filter_map(KScreenMode::to_display_mode)
+                    .collect(),
+            })
+            .collect())
+    }
+
+    /// KScreen lays the outputs out again once the size of one of them changes.
+    fn apply_display_mode(
+        &mut self,
+        display_modes: &DisplayModes,
+        mode: &DisplayMode,
+    ) -> ApplicationResult<()> {
+        trace_fn!();
+        info!("Applying display mode");
+
+        let outputs = self.get_outputs()?;
+        let Some(mode_id) = outputs
+            .iter()
+            .find(|output| output.name == display_modes.id)
+            .and_then(|output| {
+                output
+                    .modes
+                    .iter()
+                    .find(|output_mode| output_mode.to_display_mode() == Some(*mode))
+            })
+            .map(|output_mode| output_mode.id.clone())
+        else {
+            return Err(unsupported_mode(display_modes, mode));
+        };
+
+        self.kscreen_api
+            .kscreen_doctor(&[format!("output.{}.mode.{mode_id}", display_modes.id)])?;
+
+        Ok(())
+    }
+
+    /// KScreen lays the outputs out again once the size of one of them changes.
+    fn apply_display_rotation(
+        &mut self,
+        display_info: &DisplayInfo,
+        rotation: DisplayRotation,
+    ) -> ApplicationResult<()> {
+        trace_fn!();
+        info!("Applying display rotation");
+
+        self.kscreen_api.kscreen_doctor(&[format!(
+            "output.{}.rotation.{}",
+            display_info.id,
+            to_kscreen_rotation(rotation)
+        )])?;
+
+        Ok(())
+    }
+
+    fn apply_display_scale(
+        &mut self,
+        display_info: &DisplayInfo,
+        scale_percent: u32,
+    ) -> ApplicationResult<()> {
+        trace_fn!();
+        info!("Applying display scale");
+
+        self.kscreen_api.kscreen_doctor(&[format!(
+            "output.{}.scale.{}",
+            display_info.id,
+            f64::from(scale_percent) / 100.0
+        )])?;
+
+        Ok(())
+    }
+}
+
+fn to_kscreen_rotation(rotation: DisplayRotation) -> &'static str {
+    trace_fn!();
+
+    match rotation {
+        DisplayRotation::Degrees0 => "none",
+        DisplayRotation::Degrees90 => "right",
+        DisplayRotation::Degrees180 => "inverted",
+        DisplayRotation::Degrees270 => "left",
+    }
+}
+
+/// Disables the connected outputs which are not part of the ones kept enabled.
+fn push_disabled_outputs<'a>(
+    args: &mut Vec<String>,
+    outputs: &[KScreenOutput],
+    enabled_outputs_names: impl Iterator<Item = &'a str>,
+) {
+    trace_fn!();
+
+    let enabled_outputs_names = enabled_outputs_names.collect::<Vec<&str>>();
+
+    for output in outputs {
+        if !enabled_outputs_names.contains(&output.name.as_str()) {
+            args.push(format!("output.{}.disable", output.name));
+        }
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use std::{
+        env, fs,
+        os::unix::fs::PermissionsExt,
+        path::PathBuf,
+        process,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    use test_case::test_case;
+
+    use crate::{
+        application_error::{ApplicationError, DeviceKind, DeviceRole},
+        application_result::ApplicationResult,
+        displays_settings::{
+            DisplayConnector, DisplayInfo, DisplayMode, DisplayModeRequest, DisplayPosition,
+            DisplayResolution, DisplayRotation, DisplayTransformRequest, DisplaysSettings,
+            DisplaysSettingsResult, DisplaysTopology,
+            kscreen::{
+                kscreen_display_settings::KScreenDisplaySettings,
+                kscreen_doctor_based_kscreen_api::KScreenDoctorBasedKScreenApi,
+            },
+        },
+    };
+
+    /// Output of `kscreen-doctor -j` on Plasma 6, with only a few modes.
+    const PLASMA_6_CONFIG: &str = r#"{
+        "features": 31,
+        "outputs": [
+            {
+                "connected": true,
+                "currentModeId": "1",
+                "enabled": true,
+                "id": 1,
+                "modes": [
+                    { "id": "1", "name": "1920x1080@60", "refreshRate": 60.008, "size": { "height": 1080, "width": 1920 } },
+                    { "id": "2", "name": "1280x720@60", "refreshRate": 59.855, "size": { "height": 720, "width": 1280 } }
+                ],
+                "name": "eDP-1",
+                "pos": { "x": 0, "y": 0 },
+                "priority": 1,
+                "rotation": 1,
+                "scale": 1.25,
+                "type": 7
+            },
+            {
+                "connected": true,
+                "currentModeId": "3",
+                "enabled": true,
+                "id": 2,
+                "model": "LG TV SSCR2",
+                "modes": [
+                    { "id": "3", "name": "3840x2160@60", "refreshRate": 60, "size": { "height": 2160, "width": 3840 } },
+                    { "id": "4", "name": "1920x1080@120", "refreshRate": 119.88, "size": { "height": 1080, "width": 1920 } }
+                ],
+                "name": "HDMI-A-1",
+                "pos": { "x": 1536, "y": 0 },
+                "priority": 2,
+                "rotation": 2,
+                "scale": 2,
+                "type": 11
+            },
+            {
+                "connected": false,
+                "enabled": false,
+                "id": 3,
+                "name": "DP-1",
+                "pos": { "x": 0, "y": 0 },
+                "priority": 0,
+                "type": 14
+            }
+        ],
+        "screen": { "currentSize": { "height": 1080, "width": 3456 }, "id": 0 }
+    }"#;
+
+    /// Output of `kscreen-doctor -j` on Plasma 5, without the modes nor the geometry.
+    const PLASMA_5_CONFIG: &str = r#"{
+        "outputs": [
+            { "connected": true, "enabled": true, "id": 1, "name": "eDP-1", "primary": false },
+            { "connected": true, "enabled": true, "id": 2, "name": "HDMI-A-1", "primary": true }
+        ]
+    }"#;
+
+    static FAKES_COUNT: AtomicUsize = AtomicUsize::new(0);
+
+    /// Writes a fake `kscreen-doctor` replaying `config` for `-j` and recording any other call.
+    fn fake_kscreen_doctor(config: &str) -> (KScreenDisplaySettings, PathBuf) {
+        let name = FAKES_COUNT.fetch_add(1, Ordering::Relaxed);
+        let directory = env::temp_dir();
+        let program = directory.join(format!("fake-kscreen-doctor-{name}-{}", process::id()));
+        let calls = directory.join(format!(
+            "fake-kscreen-doctor-{name}-{}.calls",
+            process::id()
+        ));
+        let script = format!(
+            "#!/bin/sh\nif [ \"$1\" = \"-j\" ]; then\ncat <<'JSON'\n{config}\nJSON\nelse\necho \"$@\" >> {}\nfi\n",
+            calls.display()
+        );
+
+        fs::write(&program, script).unwrap();
+        fs::set_permissions(&program, fs::Permissions::from_mode(0o755)).unwrap();
+        let _ = fs::remove_file(&calls);
+
+        let kscreen_api = KScreenDoctorBasedKScreenApi::new(program.to_str().unwrap());
+
+        (KScreenDisplaySettings::new(Box::new(kscreen_api)), calls)
+    }
+
+    #[test]
+    fn get_the_enabled_outputs_with_their_current_mode() {
+        // Arrange
+        let (mut kscreen_display_settings, _) = fake_kscreen_doctor(PLASMA_6_CONFIG);
+
+        // Act
+        let displays_infos = kscreen_display_settings.get_displays_infos();
+
+        // Assert
+        assert_eq!(
+            displays_infos,
+            Ok(vec![
+                DisplayInfo {
+                    is_primary: true,
+                    name: String::from("Internal Display"),
+                    id: String::from("eDP-1"),
+                    connector: DisplayConnector::Internal,
+                    resolution: DisplayResolution {
+                        width: 1920,
+                        height: 1080,
+                    },
+                    position: DisplayPosition { x: 0, y: 0 },
+                    refresh_rate_millihertz: Some(60008),
+                    rotation: DisplayRotation::Degrees0,
+                    scale_percent: Some(125),
+                    advanced_color: None,
+                },
+                DisplayInfo {
+                    is_primary: false,
+                    name: String::from("LG TV SSCR2"),
+                    id: String::from("HDMI-A-1"),
+                    connector: DisplayConnector::Hdmi,
+                    resolution: DisplayResolution {
+                        width: 2160,
+                        height: 3840,
+                    },
+                    position: DisplayPosition { x: 1536, y: 0 },
+                    refresh_rate_millihertz: Some(60000),
+                    rotation: DisplayRotation::Degrees270,
+                    scale_percent: Some(200),
+                    advanced_color: None,
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn get_the_enabled_outputs_with_a_primary_flag() {
+        // Arrange
+        let (mut kscreen_display_settings, _) = fake_kscreen_doctor(PLASMA_5_CONFIG);
+
+        // Act
+        let displays_infos = kscreen_display_settings.get_displays_infos();
+
+        // Assert
+        assert_eq!(
+            displays_infos,
+            Ok(vec![
+                DisplayInfo {
+                    is_primary: true,
+                    name: String::from("HDMI-A-1"),
+                    id: String::from("HDMI-A-1"),
+                    connector: DisplayConnector::Hdmi,
+                    ..Default::default()
+                },
+                DisplayInfo {
+                    is_primary: false,
+                    name: String::from("Internal Display"),
+                    id: String::from("eDP-1"),
+                    connector: DisplayConnector::Internal,
+                    ..Default::default()
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn set_the_couch_output_as_primary() {
+        // Arrange
+        let (mut kscreen_display_settings, calls) = fake_kscreen_doctor(PLASMA_6_CONFIG);
+
+        // Act
+        let result =
+            kscreen_display_settings.swap_primary_display("Internal Display", "LG TV SSCR2");
+
+        // Assert
+        assert_eq!(
+            result,
+            Ok(DisplaysSettingsResult {
+                new_primary_display: String::from("LG TV SSCR2"),
+                new_mode: None,
+                new_rotation: None,
+                new_scale_percent: None,
+                new_hdr_enabled: None,
+            })
+        );
+        assert_eq!(
+            fs::read_to_string(calls).unwrap(),
+            "output.HDMI-A-1.primary\n"
+        );
+    }
+
+    #[test]
+    fn set_the_mode_of_the_output_by_its_id() {
+        // Arrange
+        let (mut kscreen_display_settings, calls) = fake_kscreen_doctor(PLASMA_6_CONFIG);
+        let mode_request = DisplayModeRequest {
+            resolution: Some(DisplayResolution {
+                width: 1920,
+                height: 1080,
+            }),
+            refresh_rate_hertz: Some(120),
+        };
+
+        // Act
+        let result = kscreen_display_settings.set_display_mode("LG TV SSCR2", &mode_request);
+
+        // Assert
+        assert_eq!(
+            result,
+            Ok(DisplayMode {
+                resolution: DisplayResolution {
+                    width: 1920,
+                    height: 1080,
+                },
+                refresh_rate_millihertz: 119880,
+            })
+        );
+        assert_eq!(
+            fs::read_to_string(calls).unwrap(),
+            "output.HDMI-A-1.mode.4\n"
+        );
+    }
+
+    #[test]
+    fn set_the_rotation_and_the_scale_of_the_output_by_its_id() {
+        // Arrange
+        let (mut kscreen_display_settings, calls) = fake_kscreen_doctor(PLASMA_6_CONFIG);
+        let transform_request = DisplayTransformRequest {
+            rotation: Some(DisplayRotation::Degrees0),
+            scale_percent: Some(150),
+        };
+
+        // Act
+        let result =
+            kscreen_display_settings.set_display_transform("LG TV SSCR2", &transform_request);
+
+        // Assert
+        assert_eq!(result, Ok(()));
+        assert_eq!(
+            fs::read_to_string(calls).unwrap(),
+            "output.HDMI-A-1.rotation.none\noutput.HDMI-A-1.scale.1.5\n"
+        );
+    }
+
+    #[test]
+    fn restore_the_mode_the_position_the_rotation_and_the_scale_of_the_outputs_of_a_snapshot() {
+        // Arrange
+        let (mut kscreen_display_settings, calls) = fake_kscreen_doctor(PLASMA_6_CONFIG);
+        let snapshot = kscreen_display_settings.save_snapshot().unwrap();
+
+        // Act
+        let result = kscreen_display_settings.restore_snapshot(&snapshot);
+
+        // Assert
+        assert_eq!(result, Ok(()));
+        assert_eq!(
+            fs::read_to_string(calls).unwrap(),
+            "output.eDP-1.enable output.eDP-1.mode.1 output.eDP-1.position.0,0 \
+             output.eDP-1.rotation.none output.eDP-1.scale.1.25 output.eDP-1.primary \
+             output.HDMI-A-1.enable output.HDMI-A-1.mode.3 output.HDMI-A-1.position.1536,0 \
+             output.HDMI-A-1.rotation.left output.HDMI-A-1.scale.2\n"
+        );
+    }
+
+    #[test_case(DisplaysTopology::Extend => "output.eDP-1.enable output.eDP-1.position.0,0 output.eDP-1.primary output.HDMI-A-1.enable output.HDMI-A-1.position.1536,0\n"; "when the desktop is extended")]
+    #[test_case(DisplaysTopology::Clone => "output.eDP-1.enable output.eDP-1.position.0,0 output.eDP-1.primary output.HDMI-A-1.enable output.HDMI-A-1.position.0,0\n"; "when the desktop is cloned")]
+    #[test_case(DisplaysTopology::External => "output.HDMI-A-1.enable output.HDMI-A-1.position.0,0 output.HDMI-A-1.primary output.eDP-1.disable\n"; "when only the external outputs are kept")]
+    fn enable_the_outputs_of_a_topology_and_disable_the_other_ones(
+        topology: DisplaysTopology,
+    ) -> String {
+        // Arrange
+        let (mut kscreen_display_settings, calls) = fake_kscreen_doctor(PLASMA_6_CONFIG);
+
+        // Act
+        let result = kscreen_display_settings.set_topology(&topology);
+
+        // Assert
+        assert!(result.is_ok());
+
+        fs::read_to_string(calls).unwrap()
+    }
+
+    #[test_case("Unknown Monitor", "LG TV SSCR2" => Err(ApplicationError::DeviceNotFound { kind: DeviceKind::Display, role: DeviceRole::Desktop, requested: vec![String::from("Unknown Monitor")], candidates: vec![String::from("Internal Display"), String::from("LG TV SSCR2")], suggestions: Vec::new() }); "when the desktop display is invalid")]
+    #[test_case("Internal Display", "DP-1" => Err(ApplicationError::DeviceNotFound { kind: DeviceKind::Display, role: DeviceRole::Couch, requested: vec![String::from("DP-1")], candidates: vec![String::from("Internal Display"), String::from("LG TV SSCR2")], suggestions: Vec::new() }); "when the couch display is disconnected")]
+    #[test_case("Unknown Monitor", "Unknown TV" => Err(ApplicationError::DeviceNotFound { kind: DeviceKind::Display, role: DeviceRole::DesktopAndCouch, requested: vec![String::from("Unknown Monitor"), String::from("Unknown TV")], candidates: vec![String::from("Internal Display"), String::from("LG TV SSCR2")], suggestions: Vec::new() }); "when both displays are invalid")]
+    fn fail_to_change_the_primary_display(
+        desktop_display_name: &str,
+        couch_display_name: &str,
+    ) -> ApplicationResult<DisplaysSettingsResult> {
+        // Arrange
+        let (mut kscreen_display_settings, calls) = fake_kscreen_doctor(PLASMA_6_CONFIG);
+
+        // Act
+        let result =
+            kscreen_display_settings.swap_primary_display(desktop_display_name, couch_display_name);
+
+        // Assert
+        assert!(!calls.exists());
+
+        result
+    }
+
+    #[test]
+    fn fail_when_the_configuration_cannot_be_parsed() {
+        // Arrange
+        let (mut kscreen_display_settings, _) = fake_kscreen_doctor("not json");
+
+        // Act
+        let result = kscreen_display_settings.get_displays_infos();
+
+        // Assert
+        assert!(result.is_err_and(|error| {
+            error
+                .to_string()
+                .starts_with("Failed to parse the KScreen configuration")
+        }));
+    }
+}
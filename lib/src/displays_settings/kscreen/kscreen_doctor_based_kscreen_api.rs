@@ -0,0 +1,34 @@
+use crate::{
+    application_result::ApplicationResult, command::run_command,
+    displays_settings::kscreen::kscreen_api::KScreenApi, trace_fn,
+};
+
+pub struct KScreenDoctorBasedKScreenApi {
+    program: String,
+}
+
+impl KScreenDoctorBasedKScreenApi {
+    pub fn new(program: &str) -> Self {
+        trace_fn!();
+
+        Self {
+            program: String::from(program),
+        }
+    }
+}
+
+impl Default for KScreenDoctorBasedKScreenApi {
+    fn default() -> Self {
+        trace_fn!();
+
+        Self::new("kscreen-doctor")
+    }
+}
+
+impl KScreenApi for KScreenDoctorBasedKScreenApi {
+    fn kscreen_doctor(&mut self, args: &[String]) -> ApplicationResult<String> {
+        trace_fn!();
+
+        run_command(&self.program, args, &[])
+    }
+}
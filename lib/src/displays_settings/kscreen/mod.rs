@@ -0,0 +1,3 @@
+pub mod kscreen_api;
+pub mod kscreen_display_settings;
+pub mod kscreen_doctor_based_kscreen_api;
@@ -1,17 +1,364 @@
-use std::cmp::Ordering;
+use std::{
+    cmp::Ordering,
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+};
 
+use serde::{Deserialize, Serialize};
+
+use crate::application_error::{ApplicationError, DeviceKind, DeviceRole, VerificationMismatch};
 use crate::application_result::ApplicationResult;
+use crate::configuration::verification::Verification;
+use crate::device_selector::{
+    SelectableDevice, resolve_device_pair, resolve_existing_device, resolve_next_device,
+    to_id_selector,
+};
+use crate::displays_settings::in_memory::in_memory_api::InMemoryDisplay;
+#[cfg(target_os = "windows")]
+use crate::displays_settings::windows::windows_display_settings::WindowsPathSnapshot;
+#[cfg(target_os = "linux")]
+use crate::displays_settings::{
+    kscreen::kscreen_display_settings::KScreenOutputSnapshot, mutter::mutter_api::MutterState,
+    wlroots::wlroots_api::WlrootsHead, x11::x11_display_settings::X11OutputSnapshot,
+};
 use crate::trace_fn;
 
-#[derive(Debug, PartialEq, Eq)]
+/// The mode, the rotation, the scale and the HDR state are only reported when the change sets
+/// them.
+#[derive(Debug, PartialEq, Eq, Serialize)]
 pub struct DisplaysSettingsResult {
     pub new_primary_display: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_mode: Option<DisplayMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_rotation: Option<DisplayRotation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_scale_percent: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_hdr_enabled: Option<bool>,
+}
+
+/// What a change of primary display would do, the displays being reported as they would be once
+/// changed.
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct DisplaysPlan {
+    pub new_primary_display: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_mode: Option<DisplayMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_rotation: Option<DisplayRotation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_scale_percent: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_hdr_enabled: Option<bool>,
+    pub displays: Vec<DisplayInfo>,
+}
+
+impl DisplaysPlan {
+    /// Reports the new primary display as it would be once driven at the given mode.
+    pub fn with_mode(mut self, mode: DisplayMode) -> Self {
+        trace_fn!();
+
+        for display in &mut self.displays {
+            if display.name == self.new_primary_display {
+                display.resolution = mode.resolution.rotated(display.rotation);
+                display.refresh_rate_millihertz = Some(mode.refresh_rate_millihertz);
+            }
+        }
+
+        self.new_mode = Some(mode);
+
+        self
+    }
+
+    /// Reports the new primary display as it would be once rotated and scaled, its resolution on
+    /// the desktop following its new rotation.
+    pub fn with_transform(mut self, transform_request: &DisplayTransformRequest) -> Self {
+        trace_fn!();
+
+        for display in &mut self.displays {
+            if display.name == self.new_primary_display {
+                if let Some(rotation) = transform_request.rotation {
+                    display.resolution = display
+                        .resolution
+                        .rotated(display.rotation)
+                        .rotated(rotation);
+                    display.rotation = rotation;
+                }
+
+                if let Some(scale_percent) = transform_request.scale_percent {
+                    display.scale_percent = Some(scale_percent);
+                }
+            }
+        }
+
+        self.new_rotation = transform_request.rotation;
+        self.new_scale_percent = transform_request.scale_percent;
+
+        self
+    }
+
+    /// Reports the new primary display with HDR turned on or off.
+    pub fn with_hdr(mut self, is_hdr_enabled: bool) -> Self {
+        trace_fn!();
+
+        for display in &mut self.displays {
+            if display.name == self.new_primary_display
+                && let Some(advanced_color) = &mut display.advanced_color
+            {
+                advanced_color.is_hdr_enabled = is_hdr_enabled;
+            }
+        }
+
+        self.new_hdr_enabled = Some(is_hdr_enabled);
+
+        self
+    }
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct DisplayResolution {
+    pub width: u32,
+    pub height: u32,
+}
+
+impl DisplayResolution {
+    /// Swaps the width and the height for a quarter turn, which goes both from the resolution of
+    /// a panel to the one it takes on the desktop and back.
+    pub fn rotated(self, rotation: DisplayRotation) -> Self {
+        trace_fn!();
+
+        match rotation {
+            DisplayRotation::Degrees90 | DisplayRotation::Degrees270 => DisplayResolution {
+                width: self.height,
+                height: self.width,
+            },
+            DisplayRotation::Degrees0 | DisplayRotation::Degrees180 => self,
+        }
+    }
+}
+
+impl Display for DisplayResolution {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        trace_fn!();
+
+        write!(formatter, "{}x{}", self.width, self.height)
+    }
+}
+
+/// Reads a resolution written as `3840x2160`.
+impl FromStr for DisplayResolution {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        trace_fn!();
+
+        let invalid_resolution_error = || format!("{value} is not a resolution such as 3840x2160");
+
+        let (width, height) = value
+            .split_once(['x', 'X'])
+            .ok_or_else(invalid_resolution_error)?;
+        let width = width
+            .trim()
+            .parse::<u32>()
+            .map_err(|_| invalid_resolution_error())?;
+        let height = height
+            .trim()
+            .parse::<u32>()
+            .map_err(|_| invalid_resolution_error())?;
+
+        if width == 0 || height == 0 {
+            return Err(invalid_resolution_error());
+        }
+
+        Ok(DisplayResolution { width, height })
+    }
+}
+
+/// A mode a display can be driven at. Its resolution is the one of the panel, before any
+/// rotation, so that a mode reads the same whichever way the display is turned.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct DisplayMode {
+    pub resolution: DisplayResolution,
+    pub refresh_rate_millihertz: u32,
+}
+
+/// Written as `3840x2160@60`, or `1920x1080@59.94` for the refresh rates which are not whole
+/// numbers.
+impl Display for DisplayMode {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        trace_fn!();
+
+        write!(
+            formatter,
+            "{}@{}",
+            self.resolution,
+            format_refresh_rate(self.refresh_rate_millihertz)
+        )
+    }
+}
+
+/// The mode asked for a display, the resolution defaulting to its current one and the refresh
+/// rate to the current or highest one of that resolution.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DisplayModeRequest {
+    pub resolution: Option<DisplayResolution>,
+    pub refresh_rate_hertz: Option<u32>,
+}
+
+impl DisplayModeRequest {
+    /// Neither the resolution nor the refresh rate is asked, the mode being left untouched.
+    pub fn is_empty(&self) -> bool {
+        trace_fn!();
+
+        self.resolution.is_none() && self.refresh_rate_hertz.is_none()
+    }
+}
+
+/// The rotation and the scale asked for a display, the ones left out being kept.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct DisplayTransformRequest {
+    pub rotation: Option<DisplayRotation>,
+    pub scale_percent: Option<u32>,
+}
+
+impl DisplayTransformRequest {
+    /// Neither the rotation nor the scale is asked, the display being left untouched.
+    pub fn is_empty(&self) -> bool {
+        trace_fn!();
+
+        self.rotation.is_none() && self.scale_percent.is_none()
+    }
+}
+
+/// The modes an active display supports, along with the one it is driven at.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DisplayModes {
+    pub name: String,
+    pub id: String,
+    pub current_mode: Option<DisplayMode>,
+    pub modes: Vec<DisplayMode>,
+}
+
+impl SelectableDevice for DisplayModes {
+    fn name(&self) -> &str {
+        trace_fn!();
+
+        &self.name
+    }
+
+    fn id(&self) -> &str {
+        trace_fn!();
+
+        &self.id
+    }
+}
+
+/// The position of the top left corner of a display on the virtual desktop.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DisplayPosition {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Display for DisplayPosition {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        trace_fn!();
+
+        write!(formatter, "({}, {})", self.x, self.y)
+    }
+}
+
+/// The clockwise rotation of a display, serialized as degrees.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(into = "u16", try_from = "u16")]
+pub enum DisplayRotation {
+    #[default]
+    Degrees0,
+    Degrees90,
+    Degrees180,
+    Degrees270,
+}
+
+impl From<DisplayRotation> for u16 {
+    fn from(value: DisplayRotation) -> Self {
+        trace_fn!();
+
+        match value {
+            DisplayRotation::Degrees0 => 0,
+            DisplayRotation::Degrees90 => 90,
+            DisplayRotation::Degrees180 => 180,
+            DisplayRotation::Degrees270 => 270,
+        }
+    }
+}
+
+impl TryFrom<u16> for DisplayRotation {
+    type Error = String;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        trace_fn!();
+
+        match value {
+            0 => Ok(DisplayRotation::Degrees0),
+            90 => Ok(DisplayRotation::Degrees90),
+            180 => Ok(DisplayRotation::Degrees180),
+            270 => Ok(DisplayRotation::Degrees270),
+            _ => Err(format!("{value} is not a quarter turn")),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DisplayConnector {
+    Internal,
+    Hdmi,
+    DisplayPort,
+    Dvi,
+    Vga,
+    Other,
+    #[default]
+    Unknown,
+}
+
+/// How the colours sent to a display are encoded.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DisplayColorEncoding {
+    Rgb,
+    Ycbcr444,
+    Ycbcr422,
+    Ycbcr420,
+    Intensity,
+    #[default]
+    Unknown,
+}
+
+/// Whether a display supports HDR and shows it, along with the colours it is sent.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DisplayAdvancedColor {
+    pub is_hdr_supported: bool,
+    pub is_hdr_enabled: bool,
+    pub bits_per_color_channel: Option<u32>,
+    pub color_encoding: DisplayColorEncoding,
+}
+
+/// The resolution is the one the display takes on the virtual desktop, so once rotated. The
+/// refresh rate, the scale and the advanced colour are optional since some backends cannot
+/// report them.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
 pub struct DisplayInfo {
     pub is_primary: bool,
     pub name: String,
+    pub id: String,
+    pub connector: DisplayConnector,
+    pub resolution: DisplayResolution,
+    pub position: DisplayPosition,
+    pub refresh_rate_millihertz: Option<u32>,
+    pub rotation: DisplayRotation,
+    pub scale_percent: Option<u32>,
+    pub advanced_color: Option<DisplayAdvancedColor>,
 }
 
 impl Ord for DisplayInfo {
@@ -34,24 +381,1127 @@ impl PartialOrd for DisplayInfo {
     }
 }
 
-pub trait DisplaysSettings {
-    fn new(displays_settings_api: Box<dyn CurrentDisplaysSettingsApiTrait>) -> Self;
+impl SelectableDevice for DisplayInfo {
+    fn name(&self) -> &str {
+        trace_fn!();
 
+        &self.name
+    }
+
+    fn id(&self) -> &str {
+        trace_fn!();
+
+        &self.id
+    }
+}
+
+/// A display plugged into the machine, whether or not the desktop spreads over it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ConnectedDisplay {
+    pub name: String,
+    pub id: String,
+    pub connector: DisplayConnector,
+    pub is_active: bool,
+    pub is_primary: bool,
+}
+
+impl Ord for ConnectedDisplay {
+    fn cmp(&self, other: &Self) -> Ordering {
+        trace_fn!();
+
+        other
+            .is_primary
+            .cmp(&self.is_primary)
+            .then(self.name.cmp(&other.name))
+    }
+}
+
+#[allow(clippy::non_canonical_partial_ord_impl)]
+impl PartialOrd for ConnectedDisplay {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        trace_fn!();
+
+        Some(self.cmp(other))
+    }
+}
+
+impl SelectableDevice for ConnectedDisplay {
+    fn name(&self) -> &str {
+        trace_fn!();
+
+        &self.name
+    }
+
+    fn id(&self) -> &str {
+        trace_fn!();
+
+        &self.id
+    }
+}
+
+/// Which of the connected displays the desktop spreads over, and how.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DisplaysTopology {
+    /// Every connected display extends the desktop.
+    Extend,
+    /// Every connected display shows the same desktop.
+    Clone,
+    /// Only the internal displays are kept, the external ones being turned off.
+    Internal,
+    /// Only the external displays are kept, the internal ones being turned off.
+    External,
+    /// Only the display matching the selector is kept, every other one being turned off.
+    Only(String),
+}
+
+/// The displays the desktop spreads over once the topology is applied, the primary one first.
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct DisplaysTopologyResult {
+    pub active_displays: Vec<String>,
+    pub is_cloned: bool,
+}
+
+/// The state of the displays as their backend applies it, so that it can be restored exactly.
+/// A snapshot can only be restored by the backend which saved it.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "backend")]
+pub enum DisplaysSnapshot {
+    #[serde(rename = "in-memory")]
+    InMemory { displays: Vec<InMemoryDisplay> },
+    #[cfg(target_os = "windows")]
+    #[serde(rename = "windows")]
+    Windows { paths: Vec<WindowsPathSnapshot> },
+    #[cfg(target_os = "linux")]
+    #[serde(rename = "x11")]
+    X11 { outputs: Vec<X11OutputSnapshot> },
+    #[cfg(target_os = "linux")]
+    #[serde(rename = "wlroots")]
+    Wlroots { heads: Vec<WlrootsHead> },
+    #[cfg(target_os = "linux")]
+    #[serde(rename = "mutter")]
+    Mutter { state: MutterState },
+    #[cfg(target_os = "linux")]
+    #[serde(rename = "kscreen")]
+    KScreen { outputs: Vec<KScreenOutputSnapshot> },
+}
+
+impl DisplaysSnapshot {
+    pub fn backend_name(&self) -> &'static str {
+        trace_fn!();
+
+        match self {
+            DisplaysSnapshot::InMemory { .. } => "in-memory",
+            #[cfg(target_os = "windows")]
+            DisplaysSnapshot::Windows { .. } => "windows",
+            #[cfg(target_os = "linux")]
+            DisplaysSnapshot::X11 { .. } => "x11",
+            #[cfg(target_os = "linux")]
+            DisplaysSnapshot::Wlroots { .. } => "wlroots",
+            #[cfg(target_os = "linux")]
+            DisplaysSnapshot::Mutter { .. } => "mutter",
+            #[cfg(target_os = "linux")]
+            DisplaysSnapshot::KScreen { .. } => "kscreen",
+        }
+    }
+}
+
+pub trait DisplaysSettings {
+    /// Toggles the primary display between the desktop and couch displays, given by their ids
+    /// since two displays may share a name.
     fn change_primary_display(
         &mut self,
-        desktop_display_name: &str,
-        couch_display_name: &str,
+        desktop_display_id: &str,
+        couch_display_id: &str,
     ) -> ApplicationResult<DisplaysSettingsResult>;
 
     fn get_displays_infos(&mut self) -> ApplicationResult<Vec<DisplayInfo>>;
+
+    fn save_snapshot(&mut self) -> ApplicationResult<DisplaysSnapshot>;
+
+    fn restore_snapshot(&mut self, snapshot: &DisplaysSnapshot) -> ApplicationResult<()>;
+
+    /// Retrieves every connected display, including the ones the desktop does not spread over.
+    fn get_connected_displays(&mut self) -> ApplicationResult<Vec<ConnectedDisplay>>;
+
+    /// Turns on the given displays and turns off every other one, the first display becoming
+    /// the primary one and the others being laid out from left to right, or all showing the
+    /// same desktop when the topology is a clone.
+    fn apply_topology(
+        &mut self,
+        topology: &DisplaysTopology,
+        active_displays: &[ConnectedDisplay],
+    ) -> ApplicationResult<()>;
+
+    /// Retrieves the modes supported by each display the desktop spreads over.
+    fn get_displays_modes(&mut self) -> ApplicationResult<Vec<DisplayModes>>;
+
+    /// Drives a display at one of the modes it reported.
+    fn apply_display_mode(
+        &mut self,
+        display_modes: &DisplayModes,
+        mode: &DisplayMode,
+    ) -> ApplicationResult<()>;
+
+    /// Turns a display clockwise, its resolution on the desktop following the new rotation.
+    fn apply_display_rotation(
+        &mut self,
+        display_info: &DisplayInfo,
+        rotation: DisplayRotation,
+    ) -> ApplicationResult<()>;
+
+    /// Checks that a display offers the scale asked. Backends which accept any scale they can
+    /// report keep this default.
+    fn check_display_scale(
+        &mut self,
+        _display_info: &DisplayInfo,
+        _scale_percent: u32,
+    ) -> ApplicationResult<()> {
+        trace_fn!();
+
+        Ok(())
+    }
+
+    /// Scales the desktop shown on a display. Backends which cannot report the scale cannot set
+    /// it either, so they keep this default.
+    fn apply_display_scale(
+        &mut self,
+        display_info: &DisplayInfo,
+        _scale_percent: u32,
+    ) -> ApplicationResult<()> {
+        trace_fn!();
+
+        Err(unsupported_setting(display_info, "scale"))
+    }
+
+    /// Turns HDR on or off on a display. Backends which cannot report the advanced colour cannot
+    /// toggle HDR either, so they keep this default.
+    fn apply_display_hdr(
+        &mut self,
+        display_info: &DisplayInfo,
+        _is_hdr_enabled: bool,
+    ) -> ApplicationResult<()> {
+        trace_fn!();
+
+        Err(unsupported_setting(display_info, "HDR"))
+    }
+
+    /// Whether changing the primary display moves every display so that the new primary one
+    /// sits at the origin of the desktop, rather than only moving the primary flag.
+    fn moves_primary_display_to_origin(&self) -> bool {
+        trace_fn!();
+
+        true
+    }
+
+    /// Swaps the desktop and couch displays, once their selectors are resolved against the
+    /// displays reported.
+    fn swap_primary_display(
+        &mut self,
+        desktop_display_selector: &str,
+        couch_display_selector: &str,
+    ) -> ApplicationResult<DisplaysSettingsResult> {
+        trace_fn!();
+
+        let displays_infos = self.get_displays_infos()?;
+        let (desktop_display, couch_display) = resolve_device_pair(
+            DeviceKind::Display,
+            desktop_display_selector,
+            couch_display_selector,
+            &displays_infos,
+        )?;
+
+        self.change_primary_display(&desktop_display.id, &couch_display.id)
+    }
+
+    /// Sets a display as primary whether or not it already is, by toggling between it and the
+    /// current primary display.
+    fn set_primary_display(
+        &mut self,
+        display_selector: &str,
+    ) -> ApplicationResult<DisplaysSettingsResult> {
+        trace_fn!();
+
+        let displays_infos = self.get_displays_infos()?;
+        let (display, toggled_display) =
+            resolve_primary_display_toggle(display_selector, &displays_infos)?;
+
+        match toggled_display {
+            Some(toggled_display) => self.change_primary_display(&toggled_display.id, &display.id),
+            None => Ok(DisplaysSettingsResult {
+                new_primary_display: display.name.clone(),
+                new_mode: None,
+                new_rotation: None,
+                new_scale_percent: None,
+                new_hdr_enabled: None,
+            }),
+        }
+    }
+
+    /// Sets as primary the display following the current primary one in a cycle.
+    fn cycle_primary_display(
+        &mut self,
+        display_selectors: &[String],
+        reverse: bool,
+    ) -> ApplicationResult<DisplaysSettingsResult> {
+        trace_fn!();
+
+        let displays_infos = self.get_displays_infos()?;
+        let display = resolve_next_primary_display(display_selectors, &displays_infos, reverse)?;
+
+        self.set_primary_display(&to_id_selector(&display.id))
+    }
+
+    /// Turns on the displays of a topology and turns off the other ones.
+    fn set_topology(
+        &mut self,
+        topology: &DisplaysTopology,
+    ) -> ApplicationResult<DisplaysTopologyResult> {
+        trace_fn!();
+
+        let connected_displays = self.get_connected_displays()?;
+        let active_displays = select_topology_displays(topology, connected_displays)?;
+
+        self.apply_topology(topology, &active_displays)?;
+
+        Ok(to_topology_result(topology, &active_displays))
+    }
+
+    /// Drives a display at the mode asked, which is checked against the ones it supports.
+    fn set_display_mode(
+        &mut self,
+        display_selector: &str,
+        mode_request: &DisplayModeRequest,
+    ) -> ApplicationResult<DisplayMode> {
+        trace_fn!();
+
+        let displays_modes = self.get_displays_modes()?;
+        let display_modes = find_display_modes(display_selector, &displays_modes)?;
+        let mode = select_display_mode(display_modes, mode_request)?;
+
+        if display_modes.current_mode != Some(mode) {
+            self.apply_display_mode(display_modes, &mode)?;
+        }
+
+        Ok(mode)
+    }
+
+    /// Computes the mode a display would be driven at, without changing it.
+    fn plan_display_mode(
+        &mut self,
+        display_selector: &str,
+        mode_request: &DisplayModeRequest,
+    ) -> ApplicationResult<DisplayMode> {
+        trace_fn!();
+
+        let displays_modes = self.get_displays_modes()?;
+        let display_modes = find_display_modes(display_selector, &displays_modes)?;
+
+        select_display_mode(display_modes, mode_request)
+    }
+
+    /// Rotates and scales a display, the settings it already has being left untouched.
+    fn set_display_transform(
+        &mut self,
+        display_selector: &str,
+        transform_request: &DisplayTransformRequest,
+    ) -> ApplicationResult<()> {
+        trace_fn!();
+
+        let displays_infos = self.get_displays_infos()?;
+        let display_info = find_display_info(display_selector, &displays_infos)?;
+
+        check_display_transform(display_info, transform_request)?;
+
+        if let Some(scale_percent) = transform_request.scale_percent {
+            self.check_display_scale(display_info, scale_percent)?;
+        }
+
+        if let Some(rotation) = transform_request
+            .rotation
+            .filter(|rotation| *rotation != display_info.rotation)
+        {
+            self.apply_display_rotation(display_info, rotation)?;
+        }
+
+        if let Some(scale_percent) = transform_request
+            .scale_percent
+            .filter(|scale_percent| display_info.scale_percent != Some(*scale_percent))
+        {
+            self.apply_display_scale(display_info, scale_percent)?;
+        }
+
+        Ok(())
+    }
+
+    /// Checks that a display could be rotated and scaled as asked, without changing it.
+    fn plan_display_transform(
+        &mut self,
+        display_selector: &str,
+        transform_request: &DisplayTransformRequest,
+    ) -> ApplicationResult<()> {
+        trace_fn!();
+
+        let displays_infos = self.get_displays_infos()?;
+        let display_info = find_display_info(display_selector, &displays_infos)?;
+
+        check_display_transform(display_info, transform_request)?;
+
+        match transform_request.scale_percent {
+            Some(scale_percent) => self.check_display_scale(display_info, scale_percent),
+            None => Ok(()),
+        }
+    }
+
+    /// Turns HDR on or off on a display, leaving it untouched when it already is.
+    fn set_display_hdr(
+        &mut self,
+        display_selector: &str,
+        is_hdr_enabled: bool,
+    ) -> ApplicationResult<()> {
+        trace_fn!();
+
+        let displays_infos = self.get_displays_infos()?;
+        let display_info = find_display_info(display_selector, &displays_infos)?;
+        let advanced_color = check_display_hdr(display_info)?;
+
+        if advanced_color.is_hdr_enabled != is_hdr_enabled {
+            self.apply_display_hdr(display_info, is_hdr_enabled)?;
+        }
+
+        Ok(())
+    }
+
+    /// Checks that HDR could be turned on or off on a display, without changing it.
+    fn plan_display_hdr(&mut self, display_selector: &str) -> ApplicationResult<()> {
+        trace_fn!();
+
+        let displays_infos = self.get_displays_infos()?;
+        let display_info = find_display_info(display_selector, &displays_infos)?;
+
+        check_display_hdr(display_info).map(|_| ())
+    }
+
+    /// Computes which displays a topology would turn on, without changing them.
+    fn plan_topology(
+        &mut self,
+        topology: &DisplaysTopology,
+    ) -> ApplicationResult<DisplaysTopologyResult> {
+        trace_fn!();
+
+        let connected_displays = self.get_connected_displays()?;
+        let active_displays = select_topology_displays(topology, connected_displays)?;
+
+        Ok(to_topology_result(topology, &active_displays))
+    }
+
+    /// Queries the displays until the new primary display is reported where the plan put it,
+    /// with the mode, the rotation, the scale and the HDR state it set, since a change may be
+    /// accepted without being applied.
+    fn verify_displays(
+        &mut self,
+        displays_plan: &DisplaysPlan,
+        verification: &Verification,
+    ) -> ApplicationResult<()> {
+        trace_fn!();
+
+        verification.verify(DeviceKind::Display, || {
+            let displays_infos = self.get_displays_infos()?;
+            let displays_modes = if displays_plan.new_mode.is_some() {
+                self.get_displays_modes()?
+            } else {
+                Vec::new()
+            };
+
+            Ok(find_displays_mismatches(
+                displays_plan,
+                &displays_infos,
+                &displays_modes,
+            ))
+        })
+    }
+
+    /// Queries the displays until the ones of the topology are the only ones turned on, showing
+    /// the same desktop when it is a clone.
+    fn verify_topology(
+        &mut self,
+        topology_result: &DisplaysTopologyResult,
+        verification: &Verification,
+    ) -> ApplicationResult<()> {
+        trace_fn!();
+
+        verification.verify(DeviceKind::Display, || {
+            let displays_infos = self.get_displays_infos()?;
+
+            Ok(find_topology_mismatches(topology_result, &displays_infos))
+        })
+    }
+
+    /// Computes what swapping the desktop and couch displays would do, without changing them.
+    fn plan_swap_primary_display(
+        &mut self,
+        desktop_display_selector: &str,
+        couch_display_selector: &str,
+    ) -> ApplicationResult<DisplaysPlan> {
+        trace_fn!();
+
+        let displays_infos = self.get_displays_infos()?;
+        let (desktop_display, couch_display) = resolve_device_pair(
+            DeviceKind::Display,
+            desktop_display_selector,
+            couch_display_selector,
+            &displays_infos,
+        )?;
+
+        Ok(plan_primary_display_change(
+            &displays_infos,
+            desktop_display,
+            couch_display,
+            self.moves_primary_display_to_origin(),
+        ))
+    }
+
+    /// Computes what cycling to the next display would do, without changing it.
+    fn plan_cycle_primary_display(
+        &mut self,
+        display_selectors: &[String],
+        reverse: bool,
+    ) -> ApplicationResult<DisplaysPlan> {
+        trace_fn!();
+
+        let displays_infos = self.get_displays_infos()?;
+        let display = resolve_next_primary_display(display_selectors, &displays_infos, reverse)?;
+
+        self.plan_set_primary_display(&to_id_selector(&display.id))
+    }
+
+    /// Computes what setting a display as primary would do, without changing it.
+    fn plan_set_primary_display(
+        &mut self,
+        display_selector: &str,
+    ) -> ApplicationResult<DisplaysPlan> {
+        trace_fn!();
+
+        let displays_infos = self.get_displays_infos()?;
+        let (display, toggled_display) =
+            resolve_primary_display_toggle(display_selector, &displays_infos)?;
+
+        let moves_primary_display_to_origin =
+            toggled_display.is_some() && self.moves_primary_display_to_origin();
+
+        Ok(plan_primary_display_change(
+            &displays_infos,
+            toggled_display.unwrap_or(display),
+            display,
+            moves_primary_display_to_origin,
+        ))
+    }
+}
+
+/// The displays are ordered the way they are laid out, the current primary display first so that
+/// it stays primary when it is kept.
+fn select_topology_displays(
+    topology: &DisplaysTopology,
+    connected_displays: Vec<ConnectedDisplay>,
+) -> ApplicationResult<Vec<ConnectedDisplay>> {
+    trace_fn!();
+
+    let mut active_displays = match topology {
+        DisplaysTopology::Extend | DisplaysTopology::Clone => connected_displays,
+        DisplaysTopology::Internal | DisplaysTopology::External => {
+            let is_internal = *topology == DisplaysTopology::Internal;
+
+            connected_displays
+                .into_iter()
+                .filter(|display| (display.connector == DisplayConnector::Internal) == is_internal)
+                .collect()
+        }
+        DisplaysTopology::Only(display_selector) => {
+            let display = resolve_existing_device(
+                DeviceKind::Display,
+                DeviceRole::Target,
+                display_selector,
+                &connected_displays,
+            )?;
+
+            vec![display.clone()]
+        }
+    };
+
+    if active_displays.is_empty() {
+        let kind = match topology {
+            DisplaysTopology::Internal => "internal",
+            DisplaysTopology::External => "external",
+            _ => "connected",
+        };
+
+        return Err(ApplicationError::InvalidTopology {
+            message: format!("There is no {kind} display to turn on"),
+        });
+    }
+
+    active_displays.sort();
+
+    Ok(active_displays)
+}
+
+fn to_topology_result(
+    topology: &DisplaysTopology,
+    active_displays: &[ConnectedDisplay],
+) -> DisplaysTopologyResult {
+    trace_fn!();
+
+    DisplaysTopologyResult {
+        active_displays: active_displays
+            .iter()
+            .map(|display| display.name.clone())
+            .collect(),
+        is_cloned: *topology == DisplaysTopology::Clone,
+    }
+}
+
+fn resolve_next_primary_display<'a>(
+    display_selectors: &[String],
+    displays_infos: &'a [DisplayInfo],
+    reverse: bool,
+) -> ApplicationResult<&'a DisplayInfo> {
+    trace_fn!();
+
+    let primary_display_id = displays_infos
+        .iter()
+        .find(|display_info| display_info.is_primary)
+        .map(|display_info| display_info.id.as_str());
+
+    resolve_next_device(
+        DeviceKind::Display,
+        display_selectors,
+        displays_infos,
+        primary_display_id,
+        reverse,
+    )
+}
+
+/// Resolves the display to set as primary along with the one to toggle from, which is the
+/// current primary display, or none when the display already is the primary one.
+fn resolve_primary_display_toggle<'a>(
+    display_selector: &str,
+    displays_infos: &'a [DisplayInfo],
+) -> ApplicationResult<(&'a DisplayInfo, Option<&'a DisplayInfo>)> {
+    trace_fn!();
+
+    let display = resolve_existing_device(
+        DeviceKind::Display,
+        DeviceRole::Target,
+        display_selector,
+        displays_infos,
+    )?;
+
+    let toggled_display = match displays_infos
+        .iter()
+        .find(|display_info| display_info.is_primary)
+    {
+        Some(primary_display) if primary_display.id == display.id => None,
+        Some(primary_display) => Some(primary_display),
+        None => Some(display),
+    };
+
+    Ok((display, toggled_display))
+}
+
+/// The couch display becomes primary when the desktop one is, the desktop display otherwise,
+/// the same way the backends toggle between them.
+fn plan_primary_display_change(
+    displays_infos: &[DisplayInfo],
+    desktop_display: &DisplayInfo,
+    couch_display: &DisplayInfo,
+    moves_primary_display_to_origin: bool,
+) -> DisplaysPlan {
+    trace_fn!();
+
+    let new_primary_display = if desktop_display.is_primary {
+        couch_display
+    } else {
+        desktop_display
+    };
+
+    let origin = if moves_primary_display_to_origin {
+        new_primary_display.position
+    } else {
+        DisplayPosition::default()
+    };
+
+    let mut displays = displays_infos
+        .iter()
+        .map(|display_info| DisplayInfo {
+            is_primary: display_info.id == new_primary_display.id,
+            position: DisplayPosition {
+                x: display_info.position.x - origin.x,
+                y: display_info.position.y - origin.y,
+            },
+            ..display_info.clone()
+        })
+        .collect::<Vec<DisplayInfo>>();
+
+    displays.sort();
+
+    DisplaysPlan {
+        new_primary_display: new_primary_display.name.clone(),
+        new_mode: None,
+        new_rotation: None,
+        new_scale_percent: None,
+        new_hdr_enabled: None,
+        displays,
+    }
+}
+
+/// Only the settings of the new primary display which the change set are compared, the other
+/// displays being left as the platform lays them out.
+fn find_displays_mismatches(
+    displays_plan: &DisplaysPlan,
+    displays_infos: &[DisplayInfo],
+    displays_modes: &[DisplayModes],
+) -> Vec<VerificationMismatch> {
+    trace_fn!();
+
+    let display_name = &displays_plan.new_primary_display;
+    let observed_display = displays_infos
+        .iter()
+        .find(|display_info| display_info.is_primary);
+
+    let Some(observed_display) = observed_display.filter(|display| display.name == *display_name)
+    else {
+        return vec![VerificationMismatch::device(
+            DeviceKind::Display,
+            display_name,
+            observed_display.map(|display| display.name.as_str()),
+        )];
+    };
+
+    let mut mismatches = Vec::new();
+
+    // Every display is moved when the new primary one is re-based at the origin.
+    for planned_display in &displays_plan.displays {
+        if let Some(observed_display) = displays_infos
+            .iter()
+            .find(|display_info| display_info.name == planned_display.name)
+            && observed_display.position != planned_display.position
+        {
+            mismatches.push(VerificationMismatch::new(
+                &format!("position of {}", planned_display.name),
+                planned_display.position,
+                observed_display.position,
+            ));
+        }
+    }
+
+    if let Some(mode) = displays_plan.new_mode {
+        let observed_mode = displays_modes
+            .iter()
+            .find(|display_modes| display_modes.name == *display_name)
+            .and_then(|display_modes| display_modes.current_mode);
+
+        if observed_mode != Some(mode) {
+            mismatches.push(VerificationMismatch::new(
+                &format!("mode of {display_name}"),
+                mode,
+                observed_mode.map_or_else(|| String::from("unknown"), |mode| mode.to_string()),
+            ));
+        }
+    }
+
+    if let Some(rotation) = displays_plan.new_rotation
+        && observed_display.rotation != rotation
+    {
+        mismatches.push(VerificationMismatch::new(
+            &format!("rotation of {display_name}"),
+            format!("{}°", u16::from(rotation)),
+            format!("{}°", u16::from(observed_display.rotation)),
+        ));
+    }
+
+    if let Some(scale_percent) = displays_plan.new_scale_percent
+        && observed_display.scale_percent != Some(scale_percent)
+    {
+        mismatches.push(VerificationMismatch::new(
+            &format!("scale of {display_name}"),
+            format!("{scale_percent}%"),
+            observed_display
+                .scale_percent
+                .map_or_else(|| String::from("unknown"), |scale| format!("{scale}%")),
+        ));
+    }
+
+    if let Some(is_hdr_enabled) = displays_plan.new_hdr_enabled {
+        let observed_hdr_enabled = observed_display
+            .advanced_color
+            .map(|advanced_color| advanced_color.is_hdr_enabled);
+
+        if observed_hdr_enabled != Some(is_hdr_enabled) {
+            mismatches.push(VerificationMismatch::new(
+                &format!("HDR of {display_name}"),
+                format_on_off(is_hdr_enabled),
+                observed_hdr_enabled.map_or("unknown", format_on_off),
+            ));
+        }
+    }
+
+    mismatches
+}
+
+/// The displays turned on are compared whatever their order, the platform choosing which one
+/// ends up primary.
+fn find_topology_mismatches(
+    topology_result: &DisplaysTopologyResult,
+    displays_infos: &[DisplayInfo],
+) -> Vec<VerificationMismatch> {
+    trace_fn!();
+
+    let mut expected_displays = topology_result.active_displays.clone();
+    let mut observed_displays = displays_infos
+        .iter()
+        .map(|display_info| display_info.name.clone())
+        .collect::<Vec<String>>();
+
+    expected_displays.sort();
+    observed_displays.sort();
+
+    let mut mismatches = Vec::new();
+
+    if observed_displays != expected_displays {
+        mismatches.push(VerificationMismatch::new(
+            "displays turned on",
+            format!("[{}]", expected_displays.join(", ")),
+            format!("[{}]", observed_displays.join(", ")),
+        ));
+    }
+
+    let is_cloned = displays_infos
+        .windows(2)
+        .all(|displays| displays[0].position == displays[1].position);
+
+    // A single display reads the same whether it is cloned or extended.
+    if displays_infos.len() > 1 && is_cloned != topology_result.is_cloned {
+        mismatches.push(VerificationMismatch::new(
+            "layout",
+            format_layout(topology_result.is_cloned),
+            format_layout(is_cloned),
+        ));
+    }
+
+    mismatches
+}
+
+fn format_layout(is_cloned: bool) -> &'static str {
+    trace_fn!();
+
+    if is_cloned { "cloned" } else { "extended" }
+}
+
+fn format_on_off(is_on: bool) -> &'static str {
+    trace_fn!();
+
+    if is_on { "on" } else { "off" }
+}
+
+fn find_display_info<'a>(
+    display_selector: &str,
+    displays_infos: &'a [DisplayInfo],
+) -> ApplicationResult<&'a DisplayInfo> {
+    trace_fn!();
+
+    resolve_existing_device(
+        DeviceKind::Display,
+        DeviceRole::Target,
+        display_selector,
+        displays_infos,
+    )
+}
+
+/// A display whose scale is not reported cannot be scaled, so that a change is refused before
+/// anything is changed.
+fn check_display_transform(
+    display_info: &DisplayInfo,
+    transform_request: &DisplayTransformRequest,
+) -> ApplicationResult<()> {
+    trace_fn!();
+
+    if transform_request.scale_percent.is_some() && display_info.scale_percent.is_none() {
+        return Err(unsupported_setting(display_info, "scale"));
+    }
+
+    Ok(())
+}
+
+/// HDR can only be toggled on a display which reports supporting it, whether the backend cannot
+/// report the advanced colour or the display cannot show HDR.
+fn check_display_hdr(display_info: &DisplayInfo) -> ApplicationResult<DisplayAdvancedColor> {
+    trace_fn!();
+
+    display_info
+        .advanced_color
+        .filter(|advanced_color| advanced_color.is_hdr_supported)
+        .ok_or_else(|| unsupported_setting(display_info, "HDR"))
+}
+
+fn unsupported_setting(display_info: &DisplayInfo, setting: &str) -> ApplicationError {
+    trace_fn!();
+
+    ApplicationError::UnsupportedSetting {
+        display: display_info.name.clone(),
+        setting: String::from(setting),
+    }
+}
+
+/// A mode which the backend does not offer for the display, although the display reported it.
+#[cfg(target_os = "linux")]
+fn unsupported_mode(display_modes: &DisplayModes, mode: &DisplayMode) -> ApplicationError {
+    trace_fn!();
+
+    ApplicationError::UnsupportedMode {
+        display: display_modes.name.clone(),
+        requested: mode.to_string(),
+        supported: display_modes
+            .modes
+            .iter()
+            .map(DisplayMode::to_string)
+            .collect(),
+    }
+}
+
+fn find_display_modes<'a>(
+    display_selector: &str,
+    displays_modes: &'a [DisplayModes],
+) -> ApplicationResult<&'a DisplayModes> {
+    trace_fn!();
+
+    resolve_existing_device(
+        DeviceKind::Display,
+        DeviceRole::Target,
+        display_selector,
+        displays_modes,
+    )
+}
+
+/// A refresh rate given in hertz matches the modes which round to it, such as 59.94 for 60, the
+/// closest one being picked. Without one, the current mode is kept when it has the resolution
+/// asked, the highest refresh rate of that resolution being picked otherwise.
+fn select_display_mode(
+    display_modes: &DisplayModes,
+    mode_request: &DisplayModeRequest,
+) -> ApplicationResult<DisplayMode> {
+    trace_fn!();
+
+    let resolution = mode_request.resolution.or(display_modes
+        .current_mode
+        .map(|current_mode| current_mode.resolution));
+
+    let candidates = display_modes
+        .modes
+        .iter()
+        .filter(|mode| resolution.is_none_or(|resolution| mode.resolution == resolution))
+        .filter(|mode| {
+            mode_request
+                .refresh_rate_hertz
+                .is_none_or(|refresh_rate_hertz| {
+                    (mode.refresh_rate_millihertz + 500) / 1000 == refresh_rate_hertz
+                })
+        })
+        .copied()
+        .collect::<Vec<DisplayMode>>();
+
+    let selected_mode = display_modes
+        .current_mode
+        .filter(|current_mode| candidates.contains(current_mode))
+        .or_else(|| match mode_request.refresh_rate_hertz {
+            Some(refresh_rate_hertz) => candidates.into_iter().min_by_key(|mode| {
+                mode.refresh_rate_millihertz
+                    .abs_diff(refresh_rate_hertz.saturating_mul(1000))
+            }),
+            None => candidates
+                .into_iter()
+                .max_by_key(|mode| mode.refresh_rate_millihertz),
+        });
+
+    selected_mode.ok_or_else(|| {
+        let mut supported_modes = display_modes.modes.clone();
+
+        supported_modes.sort_by(|mode, other_mode| other_mode.cmp(mode));
+        supported_modes.dedup();
+
+        ApplicationError::UnsupportedMode {
+            display: display_modes.name.clone(),
+            requested: format_mode_request(resolution, mode_request.refresh_rate_hertz),
+            supported: supported_modes.iter().map(DisplayMode::to_string).collect(),
+        }
+    })
+}
+
+fn format_mode_request(
+    resolution: Option<DisplayResolution>,
+    refresh_rate_hertz: Option<u32>,
+) -> String {
+    trace_fn!();
+
+    match (resolution, refresh_rate_hertz) {
+        (Some(resolution), Some(refresh_rate_hertz)) => {
+            format!("{resolution}@{refresh_rate_hertz}")
+        }
+        (Some(resolution), None) => resolution.to_string(),
+        (None, Some(refresh_rate_hertz)) => format!("{refresh_rate_hertz} Hz"),
+        (None, None) => String::from("current mode"),
+    }
+}
+
+fn format_refresh_rate(refresh_rate_millihertz: u32) -> String {
+    trace_fn!();
+
+    if refresh_rate_millihertz.is_multiple_of(1000) {
+        return (refresh_rate_millihertz / 1000).to_string();
+    }
+
+    format!("{:.2}", f64::from(refresh_rate_millihertz) / 1000.0)
+        .trim_end_matches('0')
+        .to_string()
+}
+
+pub const INTERNAL_DISPLAY_NAME: &str = "Internal Display";
+
+/// The layout of the displays depends on each of them, so a snapshot is not restored at all when
+/// one of its displays is not connected anymore.
+fn ensure_snapshot_displays_are_connected<'a>(
+    snapshot_displays_ids: impl IntoIterator<Item = &'a str>,
+    connected_displays_ids: &[&str],
+) -> ApplicationResult<()> {
+    trace_fn!();
+
+    match snapshot_displays_ids
+        .into_iter()
+        .find(|display_id| !connected_displays_ids.contains(display_id))
+    {
+        Some(display_id) => Err(ApplicationError::Snapshot {
+            message: format!("The display {display_id} of the snapshot is not connected anymore"),
+            source: None,
+        }),
+        None => Ok(()),
+    }
+}
+
+/// Displays without a friendly name are the internal panels of laptops.
+fn from_raw_display_name(raw_display_name: &str) -> String {
+    trace_fn!();
+
+    let display_name = if raw_display_name.is_empty() {
+        INTERNAL_DISPLAY_NAME
+    } else {
+        raw_display_name
+    };
+
+    String::from(display_name)
+}
+
+#[cfg(target_os = "linux")]
+const INTERNAL_CONNECTORS_PREFIXES: [&str; 3] = ["eDP", "LVDS", "DSI"];
+
+/// Reads the kind of connector from the name given by the kernel, such as `HDMI-A-1` or `DP-2`.
+#[cfg(target_os = "linux")]
+fn from_connector_name(connector_name: &str) -> DisplayConnector {
+    trace_fn!();
+
+    if INTERNAL_CONNECTORS_PREFIXES
+        .iter()
+        .any(|prefix| connector_name.starts_with(prefix))
+    {
+        return DisplayConnector::Internal;
+    }
+
+    match connector_name.split('-').next().unwrap_or_default() {
+        "HDMI" => DisplayConnector::Hdmi,
+        "DP" | "DisplayPort" => DisplayConnector::DisplayPort,
+        "DVI" => DisplayConnector::Dvi,
+        "VGA" => DisplayConnector::Vga,
+        _ => DisplayConnector::Other,
+    }
 }
 
-cfg_select! {
-    target_os = "windows" => {
-        pub mod windows;
-        pub use windows::windows_display_settings::WindowsDisplaySettings as CurrentDisplaysSettings;
-        pub use windows::win_32_based_windows_api::Win32BasedWindowsApi as CurrentDisplaysSettingsApi;
-        pub use windows::windows_api::WindowsApi as CurrentDisplaysSettingsApiTrait;
-        pub const INTERNAL_DISPLAY_NAME: &str = "Internal Display";
+/// Reads the rotation from a `wl_output` transform, which is counter-clockwise and whose flipped
+/// variants keep the rotation of their unflipped counterpart.
+#[cfg(target_os = "linux")]
+fn from_wl_output_transform(transform: u32) -> DisplayRotation {
+    trace_fn!();
+
+    match transform % 4 {
+        1 => DisplayRotation::Degrees270,
+        2 => DisplayRotation::Degrees180,
+        3 => DisplayRotation::Degrees90,
+        _ => DisplayRotation::Degrees0,
     }
 }
+
+/// Writes a rotation as a `wl_output` transform, the current transform being kept flipped when
+/// it is.
+#[cfg(target_os = "linux")]
+fn to_wl_output_transform(rotation: DisplayRotation, current_transform: u32) -> u32 {
+    trace_fn!();
+
+    let transform = match rotation {
+        DisplayRotation::Degrees0 => 0,
+        DisplayRotation::Degrees90 => 3,
+        DisplayRotation::Degrees180 => 2,
+        DisplayRotation::Degrees270 => 1,
+    };
+
+    (current_transform & 4) | transform
+}
+
+#[cfg(target_os = "linux")]
+fn from_refresh_rate_in_hertz(refresh_rate: f64) -> Option<u32> {
+    trace_fn!();
+
+    let refresh_rate_millihertz = (refresh_rate * 1000.0).round();
+
+    (refresh_rate_millihertz >= 1.0 && refresh_rate_millihertz <= f64::from(u32::MAX))
+        .then_some(refresh_rate_millihertz as u32)
+}
+
+#[cfg(target_os = "linux")]
+fn from_scale_factor(scale: f64) -> Option<u32> {
+    trace_fn!();
+
+    let scale_percent = (scale * 100.0).round();
+
+    (scale_percent >= 1.0 && scale_percent <= f64::from(u32::MAX)).then_some(scale_percent as u32)
+}
+
+/// Outputs without a monitor name are named after their connector, unless they are the internal
+/// panels of laptops.
+#[cfg(target_os = "linux")]
+fn from_monitor_and_connector_names(monitor_name: Option<String>, connector_name: &str) -> String {
+    trace_fn!();
+
+    match monitor_name.filter(|monitor_name| !monitor_name.is_empty()) {
+        Some(monitor_name) => monitor_name,
+        None if INTERNAL_CONNECTORS_PREFIXES
+            .iter()
+            .any(|prefix| connector_name.starts_with(prefix)) =>
+        {
+            from_raw_display_name("")
+        }
+        None => String::from(connector_name),
+    }
+}
+
+pub mod in_memory;
+
+#[cfg(target_os = "windows")]
+pub mod windows;
+
+#[cfg(target_os = "linux")]
+pub mod kscreen;
+
+#[cfg(target_os = "linux")]
+pub mod mutter;
+
+#[cfg(target_os = "linux")]
+pub mod wlroots;
+
+#[cfg(target_os = "linux")]
+pub mod x11;
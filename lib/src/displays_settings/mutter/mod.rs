@@ -0,0 +1,3 @@
+pub mod mutter_api;
+pub mod mutter_display_settings;
+pub mod zbus_based_mutter_api;
@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+
+use crate::application_result::ApplicationResult;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MutterMode {
+    pub id: String,
+    pub width: i32,
+    pub height: i32,
+    pub refresh_rate: f64,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MutterMonitor {
+    pub connector: String,
+    pub product: String,
+    pub current_mode_id: Option<String>,
+    /// The mode a monitor which is not part of any logical monitor is turned on with.
+    #[serde(default)]
+    pub preferred_mode_id: Option<String>,
+    pub modes: Vec<MutterMode>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MutterLogicalMonitor {
+    pub x: i32,
+    pub y: i32,
+    pub scale: f64,
+    pub transform: u32,
+    pub is_primary: bool,
+    pub connectors: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MutterState {
+    pub serial: u32,
+    pub monitors: Vec<MutterMonitor>,
+    pub logical_monitors: Vec<MutterLogicalMonitor>,
+}
+
+pub trait MutterApi {
+    /// Calls `GetCurrentState` on the `org.gnome.Mutter.DisplayConfig` interface.
+    fn get_current_state(&mut self) -> ApplicationResult<MutterState>;
+
+    /// Calls `ApplyMonitorsConfig` on the `org.gnome.Mutter.DisplayConfig` interface.
+    ///
+    /// Every monitor of the logical monitors keeps its current mode, Mutter rejecting the
+    /// configuration when `serial` is not the one of its current state. The configuration is
+    /// persisted, as the other backends persist theirs.
+    fn apply_monitors_config(&mut self, state: &MutterState) -> ApplicationResult<()>;
+}
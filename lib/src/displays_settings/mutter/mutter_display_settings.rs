@@ -0,0 +1,1056 @@
+use log::info;
+
+use crate::{
+    application_error::{ApplicationError, DeviceKind, DeviceRole},
+    application_result::ApplicationResult,
+    displays_settings::{
+        ConnectedDisplay, DisplayInfo, DisplayMode, DisplayModes, DisplayPosition,
+        DisplayResolution, DisplayRotation, DisplaysSettings, DisplaysSettingsResult,
+        DisplaysSnapshot, DisplaysTopology, ensure_snapshot_displays_are_connected,
+        from_connector_name, from_monitor_and_connector_names, from_refresh_rate_in_hertz,
+        from_scale_factor, from_wl_output_transform,
+        mutter::mutter_api::{
+            MutterApi, MutterLogicalMonitor, MutterMode, MutterMonitor, MutterState,
+        },
+        to_wl_output_transform, unsupported_mode, unsupported_setting,
+    },
+    trace_fn,
+};
+
+pub struct MutterDisplaySettings {
+    mutter_api: Box<dyn MutterApi>,
+}
+
+struct MutterDisplay {
+    connector: String,
+    name: String,
+    is_primary: bool,
+    resolution: DisplayResolution,
+    position: DisplayPosition,
+    refresh_rate_millihertz: Option<u32>,
+    rotation: DisplayRotation,
+    scale_percent: Option<u32>,
+}
+
+impl MutterDisplaySettings {
+    pub fn new(displays_settings_api: Box<dyn MutterApi>) -> Self {
+        trace_fn!();
+
+        Self {
+            mutter_api: displays_settings_api,
+        }
+    }
+
+    /// Mutter rejects logical monitors which overlap or leave a gap between them, so the ones on
+    /// the right of the changed logical monitor are moved by as much as its width changes.
+    fn apply_logical_monitor_change(
+        &mut self,
+        display_info: &DisplayInfo,
+        setting: &str,
+        change: impl FnOnce(&mut MutterLogicalMonitor),
+    ) -> ApplicationResult<()> {
+        trace_fn!();
+
+        let mut state = self.mutter_api.get_current_state()?;
+
+        let Some(current_mode) = state
+            .monitors
+            .iter()
+            .find(|monitor| monitor.connector == display_info.id)
+            .and_then(find_turned_on_mode)
+        else {
+            return Err(ApplicationError::device_not_found(
+                DeviceKind::Display,
+                DeviceRole::Target,
+                &[&display_info.name],
+                get_monitors_names(&state),
+            ));
+        };
+
+        let Some(logical_monitor) = state
+            .logical_monitors
+            .iter_mut()
+            .find(|logical_monitor| logical_monitor.connectors.contains(&display_info.id))
+        else {
+            return Err(unsupported_setting(display_info, setting));
+        };
+
+        let current_width = logical_width(&current_mode, logical_monitor);
+
+        change(logical_monitor);
+
+        let width_delta = logical_width(&current_mode, logical_monitor) - current_width;
+        let right_edge = logical_monitor.x + current_width;
+
+        for other_logical_monitor in &mut state.logical_monitors {
+            if other_logical_monitor.x >= right_edge {
+                other_logical_monitor.x += width_delta;
+            }
+        }
+
+        self.mutter_api.apply_monitors_config(&state)
+    }
+}
+
+impl DisplaysSettings for MutterDisplaySettings {
+    fn change_primary_display(
+        &mut self,
+        desktop_display_id: &str,
+        couch_display_id: &str,
+    ) -> ApplicationResult<DisplaysSettingsResult> {
+        trace_fn!();
+        info!("Changing primary display");
+
+        let mut state = self.mutter_api.get_current_state()?;
+        let displays = active_displays(&state);
+
+        let mut new_primary_connector = String::default();
+        let mut new_primary_display_name = String::default();
+        let mut desktop_display_id_is_valid = false;
+        let mut couch_display_id_is_valid = false;
+        let mut possible_ids = Vec::with_capacity(displays.len());
+
+        // The displays are told apart by their connectors, which are their ids.
+        for display in displays {
+            possible_ids.push(display.connector.clone());
+
+            if display.connector != desktop_display_id && display.connector != couch_display_id {
+                continue;
+            }
+
+            if display.connector == desktop_display_id {
+                desktop_display_id_is_valid = true;
+            }
+
+            if display.connector == couch_display_id {
+                couch_display_id_is_valid = true;
+            }
+
+            if !display.is_primary {
+                new_primary_connector = display.connector;
+                new_primary_display_name = display.name;
+            }
+        }
+
+        let invalid_params = match (desktop_display_id_is_valid, couch_display_id_is_valid) {
+            (false, false) => Some((
+                DeviceRole::DesktopAndCouch,
+                vec![desktop_display_id, couch_display_id],
+            )),
+            (false, _) => Some((DeviceRole::Desktop, vec![desktop_display_id])),
+            (_, false) => Some((DeviceRole::Couch, vec![couch_display_id])),
+            _ => None,
+        };
+
+        if let Some((invalid_role, invalid_ids)) = invalid_params {
+            let error = ApplicationError::device_not_found(
+                DeviceKind::Display,
+                invalid_role,
+                &invalid_ids,
+                possible_ids,
+            );
+
+            return Err(error);
+        }
+
+        // Mutter rejects layouts which are not anchored at (0,0), so unlike on Windows the
+        // positions are kept and only the primary flag moves.
+        for logical_monitor in &mut state.logical_monitors {
+            logical_monitor.is_primary =
+                logical_monitor.connectors.contains(&new_primary_connector);
+        }
+
+        self.mutter_api.apply_monitors_config(&state)?;
+
+        Ok(DisplaysSettingsResult {
+            new_primary_display: new_primary_display_name,
+            new_mode: None,
+            new_rotation: None,
+            new_scale_percent: None,
+            new_hdr_enabled: None,
+        })
+    }
+
+    fn get_displays_infos(&mut self) -> ApplicationResult<Vec<DisplayInfo>> {
+        trace_fn!();
+        info!("Getting displays informations");
+
+        let state = self.mutter_api.get_current_state()?;
+
+        let mut displays_infos = active_displays(&state)
+            .into_iter()
+            .map(|display| DisplayInfo {
+                is_primary: display.is_primary,
+                name: display.name,
+                id: display.connector.clone(),
+                connector: from_connector_name(&display.connector),
+                resolution: display.resolution,
+                position: display.position,
+                refresh_rate_millihertz: display.refresh_rate_millihertz,
+                rotation: display.rotation,
+                scale_percent: display.scale_percent,
+                advanced_color: None,
+            })
+            .collect::<Vec<DisplayInfo>>();
+
+        displays_infos.sort();
+
+        Ok(displays_infos)
+    }
+
+    /// The layout is kept, only the primary flag moving from a logical monitor to another.
+    fn moves_primary_display_to_origin(&self) -> bool {
+        trace_fn!();
+
+        false
+    }
+
+    fn save_snapshot(&mut self) -> ApplicationResult<DisplaysSnapshot> {
+        trace_fn!();
+        info!("Saving displays snapshot");
+
+        let state = self.mutter_api.get_current_state()?;
+
+        Ok(DisplaysSnapshot::Mutter { state })
+    }
+
+    /// The logical monitors of the snapshot are applied as they were, each monitor getting back
+    /// its mode, against the serial of the current state.
+    fn restore_snapshot(&mut self, snapshot: &DisplaysSnapshot) -> ApplicationResult<()> {
+        trace_fn!();
+        info!("Restoring displays snapshot");
+
+        let DisplaysSnapshot::Mutter {
+            state: snapshot_state,
+        } = snapshot
+        else {
+            return Err(ApplicationError::snapshot_of_another_backend(
+                DeviceKind::Display,
+                snapshot.backend_name(),
+                "mutter",
+            ));
+        };
+
+        let mut state = self.mutter_api.get_current_state()?;
+        let connectors = state
+            .monitors
+            .iter()
+            .map(|monitor| monitor.connector.as_str())
+            .collect::<Vec<&str>>();
+
+        ensure_snapshot_displays_are_connected(
+            snapshot_state
+                .logical_monitors
+                .iter()
+                .flat_map(|logical_monitor| logical_monitor.connectors.iter())
+                .map(String::as_str),
+            &connectors,
+        )?;
+
+        for monitor in &mut state.monitors {
+            if let Some(snapshot_monitor) = snapshot_state
+                .monitors
+                .iter()
+                .find(|snapshot_monitor| snapshot_monitor.connector == monitor.connector)
+            {
+                monitor.current_mode_id = snapshot_monitor.current_mode_id.clone();
+            }
+        }
+
+        state.logical_monitors = snapshot_state.logical_monitors.clone();
+
+        self.mutter_api.apply_monitors_config(&state)
+    }
+
+    fn get_connected_displays(&mut self) -> ApplicationResult<Vec<ConnectedDisplay>> {
+        trace_fn!();
+        info!("Getting connected displays");
+
+        let state = self.mutter_api.get_current_state()?;
+
+        Ok(state
+            .monitors
+            .iter()
+            .map(|monitor| {
+                let logical_monitor = find_logical_monitor(&state, &monitor.connector);
+
+                ConnectedDisplay {
+                    name: from_monitor_and_connector_names(
+                        Some(monitor.product.clone()),
+                        &monitor.connector,
+                    ),
+                    id: monitor.connector.clone(),
+                    connector: from_connector_name(&monitor.connector),
+                    is_active: logical_monitor.is_some(),
+                    is_primary: logical_monitor
+                        .is_some_and(|logical_monitor| logical_monitor.is_primary),
+                }
+            })
+            .collect())
+    }
+
+    /// Each monitor gets its own logical monitor when the desktop is extended, whereas cloned
+    /// monitors share a single one, which requires them to have a mode of the same size.
+    fn apply_topology(
+        &mut self,
+        topology: &DisplaysTopology,
+        active_displays: &[ConnectedDisplay],
+    ) -> ApplicationResult<()> {
+        trace_fn!();
+        info!("Applying displays topology");
+
+        let mut state = self.mutter_api.get_current_state()?;
+        let mut logical_monitors =
+            Vec::<MutterLogicalMonitor>::with_capacity(active_displays.len());
+        let mut cloned_mode_size = None;
+        let mut x = 0;
+
+        for display in active_displays {
+            let (scale, transform) = find_logical_monitor(&state, &display.id)
+                .map(|logical_monitor| (logical_monitor.scale, logical_monitor.transform))
+                .unwrap_or((1.0, 0));
+
+            let Some(monitor) = state
+                .monitors
+                .iter_mut()
+                .find(|monitor| monitor.connector == display.id)
+            else {
+                continue;
+            };
+
+            let mode =
+                match cloned_mode_size {
+                    Some(cloned_mode_size) => find_mode_of_size(monitor, cloned_mode_size)
+                        .ok_or_else(|| ApplicationError::InvalidTopology {
+                            message: format!(
+                                "{} has no mode of the size of the other displays to clone them",
+                                display.name
+                            ),
+                        })?,
+                    None => find_turned_on_mode(monitor).ok_or_else(|| {
+                        ApplicationError::InvalidTopology {
+                            message: format!("{} has no mode to be turned on with", display.name),
+                        }
+                    })?,
+                };
+
+            monitor.current_mode_id = Some(mode.id.clone());
+
+            if *topology == DisplaysTopology::Clone {
+                cloned_mode_size = Some((mode.width, mode.height));
+
+                match logical_monitors.first_mut() {
+                    Some(logical_monitor) => logical_monitor.connectors.push(display.id.clone()),
+                    None => logical_monitors.push(MutterLogicalMonitor {
+                        x: 0,
+                        y: 0,
+                        scale,
+                        transform,
+                        is_primary: true,
+                        connectors: vec![display.id.clone()],
+                    }),
+                }
+
+                continue;
+            }
+
+            let logical_monitor = MutterLogicalMonitor {
+                x,
+                y: 0,
+                scale,
+                transform,
+                is_primary: logical_monitors.is_empty(),
+                connectors: vec![display.id.clone()],
+            };
+
+            x += logical_width(&mode, &logical_monitor);
+
+            logical_monitors.push(logical_monitor);
+        }
+
+        state.logical_monitors = logical_monitors;
+
+        self.mutter_api.apply_monitors_config(&state)
+    }
+
+    fn get_displays_modes(&mut self) -> ApplicationResult<Vec<DisplayModes>> {
+        trace_fn!();
+        info!("Getting displays modes");
+
+        let state = self.mutter_api.get_current_state()?;
+
+        Ok(state
+            .monitors
+            .iter()
+            .filter(|monitor| find_logical_monitor(&state, &monitor.connector).is_some())
+            .map(|monitor| DisplayModes {
+                name: from_monitor_and_connector_names(
+                    Some(monitor.product.clone()),
+                    &monitor.connector,
+                ),
+                id: monitor.connector.clone(),
+                current_mode: monitor
+                    .modes
+                    .iter()
+                    .find(|mode| Some(&mode.id) == monitor.current_mode_id.as_ref())
+                    .and_then(to_display_mode),
+                modes: monitor.modes.iter().filter_map(to_display_mode).collect(),
+            })
+            .collect())
+    }
+
+    /// Mutter rejects logical monitors which overlap or leave a gap between them, so the ones on
+    /// the right of the monitor are moved by as much as its width changes.
+    fn apply_display_mode(
+        &mut self,
+        display_modes: &DisplayModes,
+        mode: &DisplayMode,
+    ) -> ApplicationResult<()> {
+        trace_fn!();
+        info!("Applying display mode");
+
+        let mut state = self.mutter_api.get_current_state()?;
+
+        let Some(logical_monitor) = find_logical_monitor(&state, &display_modes.id).cloned() else {
+            return Err(ApplicationError::UnsupportedSetting {
+                display: display_modes.name.clone(),
+                setting: String::from("mode"),
+            });
+        };
+
+        let monitors_names = get_monitors_names(&state);
+        let Some(monitor) = state
+            .monitors
+            .iter_mut()
+            .find(|monitor| monitor.connector == display_modes.id)
+        else {
+            return Err(ApplicationError::device_not_found(
+                DeviceKind::Display,
+                DeviceRole::Target,
+                &[&display_modes.name],
+                monitors_names,
+            ));
+        };
+
+        let Some(new_mode) = monitor
+            .modes
+            .iter()
+            .find(|monitor_mode| to_display_mode(monitor_mode) == Some(*mode))
+            .cloned()
+        else {
+            return Err(unsupported_mode(display_modes, mode));
+        };
+
+        let current_width = find_turned_on_mode(monitor)
+            .map(|current_mode| logical_width(&current_mode, &logical_monitor))
+            .unwrap_or_default();
+        let width_delta = logical_width(&new_mode, &logical_monitor) - current_width;
+
+        monitor.current_mode_id = Some(new_mode.id);
+
+        for other_logical_monitor in &mut state.logical_monitors {
+            if other_logical_monitor.x >= logical_monitor.x + current_width {
+                other_logical_monitor.x += width_delta;
+            }
+        }
+
+        self.mutter_api.apply_monitors_config(&state)
+    }
+
+    fn apply_display_rotation(
+        &mut self,
+        display_info: &DisplayInfo,
+        rotation: DisplayRotation,
+    ) -> ApplicationResult<()> {
+        trace_fn!();
+        info!("Applying display rotation");
+
+        self.apply_logical_monitor_change(display_info, "rotation", |logical_monitor| {
+            logical_monitor.transform = to_wl_output_transform(rotation, logical_monitor.transform);
+        })
+    }
+
+    /// Mutter only accepts the scales the mode supports, rejecting the configuration otherwise.
+    fn apply_display_scale(
+        &mut self,
+        display_info: &DisplayInfo,
+        scale_percent: u32,
+    ) -> ApplicationResult<()> {
+        trace_fn!();
+        info!("Applying display scale");
+
+        self.apply_logical_monitor_change(display_info, "scale", |logical_monitor| {
+            logical_monitor.scale = f64::from(scale_percent) / 100.0;
+        })
+    }
+}
+
+/// The width a mode takes on the desktop, once rotated and scaled by its logical monitor.
+fn logical_width(mode: &MutterMode, logical_monitor: &MutterLogicalMonitor) -> i32 {
+    trace_fn!();
+
+    let width = match from_wl_output_transform(logical_monitor.transform) {
+        DisplayRotation::Degrees90 | DisplayRotation::Degrees270 => mode.height,
+        DisplayRotation::Degrees0 | DisplayRotation::Degrees180 => mode.width,
+    };
+
+    (f64::from(width) / logical_monitor.scale).round() as i32
+}
+
+fn to_display_mode(mode: &MutterMode) -> Option<DisplayMode> {
+    trace_fn!();
+
+    Some(DisplayMode {
+        resolution: DisplayResolution {
+            width: u32::try_from(mode.width).ok().filter(|width| *width > 0)?,
+            height: u32::try_from(mode.height)
+                .ok()
+                .filter(|height| *height > 0)?,
+        },
+        refresh_rate_millihertz: from_refresh_rate_in_hertz(mode.refresh_rate)?,
+    })
+}
+
+fn find_logical_monitor<'a>(
+    state: &'a MutterState,
+    connector: &str,
+) -> Option<&'a MutterLogicalMonitor> {
+    trace_fn!();
+
+    state.logical_monitors.iter().find(|logical_monitor| {
+        logical_monitor
+            .connectors
+            .iter()
+            .any(|logical_monitor_connector| logical_monitor_connector == connector)
+    })
+}
+
+fn get_monitors_names(state: &MutterState) -> Vec<String> {
+    trace_fn!();
+
+    state
+        .monitors
+        .iter()
+        .map(|monitor| {
+            from_monitor_and_connector_names(Some(monitor.product.clone()), &monitor.connector)
+        })
+        .collect()
+}
+
+/// A monitor is turned on with its current mode, or with its preferred one when it has none.
+fn find_turned_on_mode(monitor: &MutterMonitor) -> Option<MutterMode> {
+    trace_fn!();
+
+    let mode_id = monitor
+        .current_mode_id
+        .as_ref()
+        .or(monitor.preferred_mode_id.as_ref());
+
+    monitor
+        .modes
+        .iter()
+        .find(|mode| Some(&mode.id) == mode_id)
+        .or(monitor.modes.first())
+        .cloned()
+}
+
+/// The turned on mode is kept when it already has the expected size.
+fn find_mode_of_size(monitor: &MutterMonitor, (width, height): (i32, i32)) -> Option<MutterMode> {
+    trace_fn!();
+
+    find_turned_on_mode(monitor)
+        .filter(|mode| mode.width == width && mode.height == height)
+        .or_else(|| {
+            monitor
+                .modes
+                .iter()
+                .find(|mode| mode.width == width && mode.height == height)
+                .cloned()
+        })
+}
+
+/// Only the monitors which are part of a logical monitor are active, the primary flag, the
+/// position, the rotation and the scale being the ones of their logical monitor.
+fn active_displays(state: &MutterState) -> Vec<MutterDisplay> {
+    trace_fn!();
+
+    state
+        .monitors
+        .iter()
+        .filter_map(|monitor| {
+            let logical_monitor = state
+                .logical_monitors
+                .iter()
+                .find(|logical_monitor| logical_monitor.connectors.contains(&monitor.connector))?;
+
+            let current_mode = monitor
+                .modes
+                .iter()
+                .find(|mode| Some(&mode.id) == monitor.current_mode_id.as_ref());
+            let rotation = from_wl_output_transform(logical_monitor.transform);
+            let (width, height) = current_mode
+                .map(|mode| {
+                    let width = u32::try_from(mode.width).unwrap_or_default();
+                    let height = u32::try_from(mode.height).unwrap_or_default();
+
+                    match rotation {
+                        DisplayRotation::Degrees90 | DisplayRotation::Degrees270 => (height, width),
+                        DisplayRotation::Degrees0 | DisplayRotation::Degrees180 => (width, height),
+                    }
+                })
+                .unwrap_or_default();
+
+            Some(MutterDisplay {
+                connector: monitor.connector.clone(),
+                name: from_monitor_and_connector_names(
+                    Some(monitor.product.clone()),
+                    &monitor.connector,
+                ),
+                is_primary: logical_monitor.is_primary,
+                resolution: DisplayResolution { width, height },
+                position: DisplayPosition {
+                    x: logical_monitor.x,
+                    y: logical_monitor.y,
+                },
+                refresh_rate_millihertz: current_mode
+                    .and_then(|mode| from_refresh_rate_in_hertz(mode.refresh_rate)),
+                rotation,
+                scale_percent: from_scale_factor(logical_monitor.scale),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod should {
+    use std::{cell::RefCell, rc::Rc};
+
+    use test_case::test_case;
+
+    use crate::{
+        application_error::{ApplicationError, DeviceKind, DeviceRole},
+        application_result::ApplicationResult,
+        displays_settings::{
+            DisplayConnector, DisplayInfo, DisplayMode, DisplayModeRequest, DisplayPosition,
+            DisplayResolution, DisplayRotation, DisplayTransformRequest, DisplaysSettings,
+            DisplaysSettingsResult, DisplaysTopology, DisplaysTopologyResult,
+            mutter::{
+                mutter_api::{
+                    MutterApi, MutterLogicalMonitor, MutterMode, MutterMonitor, MutterState,
+                },
+                mutter_display_settings::MutterDisplaySettings,
+            },
+        },
+    };
+
+    struct FakeMutterApi {
+        state: Rc<RefCell<MutterState>>,
+    }
+
+    impl MutterApi for FakeMutterApi {
+        fn get_current_state(&mut self) -> ApplicationResult<MutterState> {
+            Ok(self.state.borrow().clone())
+        }
+
+        fn apply_monitors_config(&mut self, state: &MutterState) -> ApplicationResult<()> {
+            *self.state.borrow_mut() = state.clone();
+
+            Ok(())
+        }
+    }
+
+    fn monitor(connector: &str, product: &str, current_mode_id: Option<&str>) -> MutterMonitor {
+        MutterMonitor {
+            connector: String::from(connector),
+            product: String::from(product),
+            current_mode_id: current_mode_id.map(String::from),
+            preferred_mode_id: Some(String::from("3840x2160@60.000")),
+            modes: vec![
+                MutterMode {
+                    id: String::from("1920x1080@60.020"),
+                    width: 1920,
+                    height: 1080,
+                    refresh_rate: 60.02,
+                },
+                MutterMode {
+                    id: String::from("3840x2160@60.000"),
+                    width: 3840,
+                    height: 2160,
+                    refresh_rate: 60.0,
+                },
+            ],
+        }
+    }
+
+    fn logical_monitor(x: i32, is_primary: bool, connector: &str) -> MutterLogicalMonitor {
+        MutterLogicalMonitor {
+            x,
+            y: 0,
+            scale: 1.0,
+            transform: 0,
+            is_primary,
+            connectors: vec![String::from(connector)],
+        }
+    }
+
+    fn display_info(
+        name: &str,
+        is_primary: bool,
+        connector: &str,
+        display_connector: DisplayConnector,
+        (width, height): (u32, u32),
+        x: i32,
+        refresh_rate_millihertz: u32,
+    ) -> DisplayInfo {
+        DisplayInfo {
+            is_primary,
+            name: String::from(name),
+            id: String::from(connector),
+            connector: display_connector,
+            resolution: DisplayResolution { width, height },
+            position: DisplayPosition { x, y: 0 },
+            refresh_rate_millihertz: Some(refresh_rate_millihertz),
+            rotation: DisplayRotation::Degrees0,
+            scale_percent: Some(100),
+            advanced_color: None,
+        }
+    }
+
+    fn mutter_display_settings() -> (MutterDisplaySettings, Rc<RefCell<MutterState>>) {
+        let state = Rc::new(RefCell::new(MutterState {
+            serial: 42,
+            monitors: vec![
+                monitor("eDP-1", "", Some("1920x1080@60.020")),
+                monitor("HDMI-1", "LG TV SSCR2", Some("3840x2160@60.000")),
+                monitor("DP-1", "DELL U2723QE", None),
+            ],
+            logical_monitors: vec![
+                logical_monitor(0, true, "eDP-1"),
+                logical_monitor(1920, false, "HDMI-1"),
+            ],
+        }));
+        let mutter_api = FakeMutterApi {
+            state: Rc::clone(&state),
+        };
+
+        (MutterDisplaySettings::new(Box::new(mutter_api)), state)
+    }
+
+    #[test]
+    fn get_the_monitors_of_the_logical_monitors() {
+        // Arrange
+        let (mut mutter_display_settings, _) = mutter_display_settings();
+
+        // Act
+        let displays_infos = mutter_display_settings.get_displays_infos();
+
+        // Assert
+        assert_eq!(
+            displays_infos,
+            Ok(vec![
+                display_info(
+                    "Internal Display",
+                    true,
+                    "eDP-1",
+                    DisplayConnector::Internal,
+                    (1920, 1080),
+                    0,
+                    60020
+                ),
+                display_info(
+                    "LG TV SSCR2",
+                    false,
+                    "HDMI-1",
+                    DisplayConnector::Hdmi,
+                    (3840, 2160),
+                    1920,
+                    60000
+                ),
+            ])
+        );
+    }
+
+    #[test_case(0, 1.0 => (DisplayResolution { width: 3840, height: 2160 }, DisplayRotation::Degrees0, Some(100)); "when the logical monitor is not transformed")]
+    #[test_case(1, 2.0 => (DisplayResolution { width: 2160, height: 3840 }, DisplayRotation::Degrees270, Some(200)); "when the logical monitor is rotated counter clockwise")]
+    #[test_case(2, 1.5 => (DisplayResolution { width: 3840, height: 2160 }, DisplayRotation::Degrees180, Some(150)); "when the logical monitor is upside down")]
+    #[test_case(7, 1.25 => (DisplayResolution { width: 2160, height: 3840 }, DisplayRotation::Degrees90, Some(125)); "when the logical monitor is flipped and rotated clockwise")]
+    fn get_the_transform_and_the_scale_of_the_logical_monitors(
+        transform: u32,
+        scale: f64,
+    ) -> (DisplayResolution, DisplayRotation, Option<u32>) {
+        // Arrange
+        let (mut mutter_display_settings, state) = mutter_display_settings();
+
+        state.borrow_mut().logical_monitors[1].transform = transform;
+        state.borrow_mut().logical_monitors[1].scale = scale;
+
+        // Act
+        let displays_infos = mutter_display_settings.get_displays_infos().unwrap();
+
+        // Assert
+        (
+            displays_infos[1].resolution,
+            displays_infos[1].rotation,
+            displays_infos[1].scale_percent,
+        )
+    }
+
+    #[test]
+    fn move_the_primary_flag_to_the_couch_logical_monitor() {
+        // Arrange
+        let (mut mutter_display_settings, state) = mutter_display_settings();
+
+        // Act
+        let result =
+            mutter_display_settings.swap_primary_display("Internal Display", "LG TV SSCR2");
+
+        // Assert
+        assert_eq!(
+            result,
+            Ok(DisplaysSettingsResult {
+                new_primary_display: String::from("LG TV SSCR2"),
+                new_mode: None,
+                new_rotation: None,
+                new_scale_percent: None,
+                new_hdr_enabled: None,
+            })
+        );
+        assert_eq!(
+            state.borrow().logical_monitors,
+            vec![
+                logical_monitor(0, false, "eDP-1"),
+                logical_monitor(1920, true, "HDMI-1"),
+            ]
+        );
+    }
+
+    #[test]
+    fn plan_the_move_of_the_primary_flag_without_moving_the_logical_monitors() {
+        // Arrange
+        let (mut mutter_display_settings, state) = mutter_display_settings();
+        let expected_state = state.borrow().clone();
+
+        // Act
+        let plan =
+            mutter_display_settings.plan_swap_primary_display("Internal Display", "LG TV SSCR2");
+
+        // Assert
+        let plan = plan.unwrap();
+
+        assert_eq!(plan.new_primary_display, "LG TV SSCR2");
+        assert_eq!(
+            plan.displays
+                .iter()
+                .map(|display_info| (
+                    display_info.name.as_str(),
+                    display_info.is_primary,
+                    display_info.position
+                ))
+                .collect::<Vec<(&str, bool, DisplayPosition)>>(),
+            vec![
+                ("LG TV SSCR2", true, DisplayPosition { x: 1920, y: 0 }),
+                ("Internal Display", false, DisplayPosition { x: 0, y: 0 }),
+            ]
+        );
+        assert_eq!(*state.borrow(), expected_state);
+    }
+
+    #[test]
+    fn drive_the_monitor_at_the_mode_asked_and_move_the_logical_monitors_on_its_right() {
+        // Arrange
+        let (mut mutter_display_settings, state) = mutter_display_settings();
+        let mode_request = DisplayModeRequest {
+            resolution: Some(DisplayResolution {
+                width: 3840,
+                height: 2160,
+            }),
+            refresh_rate_hertz: None,
+        };
+
+        // Act
+        let result = mutter_display_settings.set_display_mode("Internal Display", &mode_request);
+
+        // Assert
+        assert_eq!(
+            result,
+            Ok(DisplayMode {
+                resolution: DisplayResolution {
+                    width: 3840,
+                    height: 2160,
+                },
+                refresh_rate_millihertz: 60000,
+            })
+        );
+        assert_eq!(
+            state.borrow().monitors[0].current_mode_id,
+            Some(String::from("3840x2160@60.000"))
+        );
+        assert_eq!(
+            state.borrow().logical_monitors,
+            vec![
+                logical_monitor(0, true, "eDP-1"),
+                logical_monitor(3840, false, "HDMI-1"),
+            ]
+        );
+    }
+
+    #[test]
+    fn rotate_and_scale_the_logical_monitor_and_move_the_logical_monitors_on_its_right() {
+        // Arrange
+        let (mut mutter_display_settings, state) = mutter_display_settings();
+        let transform_request = DisplayTransformRequest {
+            rotation: Some(DisplayRotation::Degrees90),
+            scale_percent: Some(200),
+        };
+
+        // Act
+        let result =
+            mutter_display_settings.set_display_transform("Internal Display", &transform_request);
+
+        // Assert
+        assert_eq!(result, Ok(()));
+        assert_eq!(
+            state.borrow().logical_monitors,
+            vec![
+                MutterLogicalMonitor {
+                    scale: 2.0,
+                    transform: 3,
+                    ..logical_monitor(0, true, "eDP-1")
+                },
+                logical_monitor(540, false, "HDMI-1"),
+            ]
+        );
+    }
+
+    #[test]
+    fn turn_on_the_only_monitor_kept_with_its_preferred_mode() {
+        // Arrange
+        let (mut mutter_display_settings, state) = mutter_display_settings();
+
+        // Act
+        let result = mutter_display_settings
+            .set_topology(&DisplaysTopology::Only(String::from("DELL U2723QE")));
+
+        // Assert
+        assert_eq!(
+            result,
+            Ok(DisplaysTopologyResult {
+                active_displays: vec![String::from("DELL U2723QE")],
+                is_cloned: false,
+            })
+        );
+        assert_eq!(
+            state.borrow().monitors[2].current_mode_id,
+            Some(String::from("3840x2160@60.000"))
+        );
+        assert_eq!(
+            state.borrow().logical_monitors,
+            vec![logical_monitor(0, true, "DP-1")]
+        );
+    }
+
+    #[test]
+    fn give_each_monitor_its_own_logical_monitor_when_the_desktop_is_extended() {
+        // Arrange
+        let (mut mutter_display_settings, state) = mutter_display_settings();
+
+        // Act
+        let result = mutter_display_settings.set_topology(&DisplaysTopology::Extend);
+
+        // Assert
+        assert!(result.is_ok());
+        assert_eq!(
+            state.borrow().logical_monitors,
+            vec![
+                logical_monitor(0, true, "eDP-1"),
+                logical_monitor(1920, false, "DP-1"),
+                logical_monitor(5760, false, "HDMI-1"),
+            ]
+        );
+    }
+
+    #[test]
+    fn put_every_monitor_in_a_single_logical_monitor_when_the_desktop_is_cloned() {
+        // Arrange
+        let (mut mutter_display_settings, state) = mutter_display_settings();
+
+        // Act
+        let result = mutter_display_settings.set_topology(&DisplaysTopology::Clone);
+
+        // Assert
+        assert_eq!(
+            result,
+            Ok(DisplaysTopologyResult {
+                active_displays: vec![
+                    String::from("Internal Display"),
+                    String::from("DELL U2723QE"),
+                    String::from("LG TV SSCR2"),
+                ],
+                is_cloned: true,
+            })
+        );
+        assert_eq!(
+            state
+                .borrow()
+                .monitors
+                .iter()
+                .map(|monitor| monitor.current_mode_id.as_deref())
+                .collect::<Vec<Option<&str>>>(),
+            vec![Some("1920x1080@60.020"); 3]
+        );
+        assert_eq!(
+            state.borrow().logical_monitors,
+            vec![MutterLogicalMonitor {
+                connectors: vec![
+                    String::from("eDP-1"),
+                    String::from("DP-1"),
+                    String::from("HDMI-1"),
+                ],
+                ..logical_monitor(0, true, "eDP-1")
+            }]
+        );
+    }
+
+    #[test]
+    fn restore_the_logical_monitors_and_the_modes_of_a_snapshot() {
+        // Arrange
+        let (mut mutter_display_settings, state) = mutter_display_settings();
+        let snapshot = mutter_display_settings.save_snapshot().unwrap();
+        let expected_state = MutterState {
+            serial: 43,
+            ..state.borrow().clone()
+        };
+
+        {
+            let mut state = state.borrow_mut();
+
+            state.serial = 43;
+            state.monitors[1].current_mode_id = Some(String::from("1920x1080@60.020"));
+            state.logical_monitors = vec![
+                logical_monitor(0, true, "HDMI-1"),
+                logical_monitor(1920, false, "eDP-1"),
+            ];
+        }
+
+        // Act
+        let result = mutter_display_settings.restore_snapshot(&snapshot);
+
+        // Assert
+        assert_eq!(result, Ok(()));
+        assert_eq!(*state.borrow(), expected_state);
+    }
+
+    #[test_case("Unknown Monitor", "LG TV SSCR2" => Err(ApplicationError::DeviceNotFound { kind: DeviceKind::Display, role: DeviceRole::Desktop, requested: vec![String::from("Unknown Monitor")], candidates: vec![String::from("Internal Display"), String::from("LG TV SSCR2")], suggestions: Vec::new() }); "when the desktop display is invalid")]
+    #[test_case("Internal Display", "DELL U2723QE" => Err(ApplicationError::DeviceNotFound { kind: DeviceKind::Display, role: DeviceRole::Couch, requested: vec![String::from("DELL U2723QE")], candidates: vec![String::from("Internal Display"), String::from("LG TV SSCR2")], suggestions: Vec::new() }); "when the couch display is disabled")]
+    #[test_case("Unknown Monitor", "Unknown TV" => Err(ApplicationError::DeviceNotFound { kind: DeviceKind::Display, role: DeviceRole::DesktopAndCouch, requested: vec![String::from("Unknown Monitor"), String::from("Unknown TV")], candidates: vec![String::from("Internal Display"), String::from("LG TV SSCR2")], suggestions: Vec::new() }); "when both displays are invalid")]
+    fn fail_to_change_the_primary_display(
+        desktop_display_name: &str,
+        couch_display_name: &str,
+    ) -> ApplicationResult<DisplaysSettingsResult> {
+        // Arrange
+        let (mut mutter_display_settings, _) = mutter_display_settings();
+
+        // Act
+        mutter_display_settings.swap_primary_display(desktop_display_name, couch_display_name)
+    }
+}
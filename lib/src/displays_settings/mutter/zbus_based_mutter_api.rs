@@ -0,0 +1,426 @@
+use std::collections::HashMap;
+
+use zbus::{blocking::Connection, zvariant::OwnedValue};
+
+use crate::{
+    application_result::ApplicationResult,
+    displays_settings::mutter::mutter_api::{
+        MutterApi, MutterLogicalMonitor, MutterMode, MutterMonitor, MutterState,
+    },
+    trace_fn,
+};
+
+const DISPLAY_CONFIG_DESTINATION: &str = "org.gnome.Mutter.DisplayConfig";
+const DISPLAY_CONFIG_PATH: &str = "/org/gnome/Mutter/DisplayConfig";
+const DISPLAY_CONFIG_INTERFACE: &str = "org.gnome.Mutter.DisplayConfig";
+/// The `method` of `ApplyMonitorsConfig` which applies a configuration and saves it in
+/// `monitors.xml`.
+const PERSISTENT_CONFIG_METHOD: u32 = 2;
+
+type Properties = HashMap<String, OwnedValue>;
+type MonitorSpec = (String, String, String, String);
+type Mode = (String, i32, i32, f64, f64, Vec<f64>, Properties);
+type Monitor = (MonitorSpec, Vec<Mode>, Properties);
+type LogicalMonitor = (i32, i32, f64, u32, bool, Vec<MonitorSpec>, Properties);
+type CurrentState = (u32, Vec<Monitor>, Vec<LogicalMonitor>, Properties);
+type MonitorConfig = (String, String, Properties);
+type LogicalMonitorConfig = (i32, i32, f64, u32, bool, Vec<MonitorConfig>);
+
+#[derive(Default)]
+pub struct ZbusBasedMutterApi {
+    connection: Option<Connection>,
+}
+
+impl ZbusBasedMutterApi {
+    pub fn new(connection: Connection) -> Self {
+        trace_fn!();
+
+        Self {
+            connection: Some(connection),
+        }
+    }
+
+    /// Connects to the session bus on first use.
+    fn connection(&mut self) -> ApplicationResult<&Connection> {
+        trace_fn!();
+
+        let connection = match self.connection.take() {
+            Some(connection) => connection,
+            None => Connection::session()?,
+        };
+
+        Ok(self.connection.insert(connection))
+    }
+}
+
+impl MutterApi for ZbusBasedMutterApi {
+    fn get_current_state(&mut self) -> ApplicationResult<MutterState> {
+        trace_fn!();
+
+        let reply = self.connection()?.call_method(
+            Some(DISPLAY_CONFIG_DESTINATION),
+            DISPLAY_CONFIG_PATH,
+            Some(DISPLAY_CONFIG_INTERFACE),
+            "GetCurrentState",
+            &(),
+        )?;
+        let (serial, monitors, logical_monitors, _) = reply.body().deserialize::<CurrentState>()?;
+
+        let monitors = monitors
+            .into_iter()
+            .map(|((connector, _, product, _), modes, _)| MutterMonitor {
+                connector,
+                product,
+                current_mode_id: modes
+                    .iter()
+                    .find(|(_, _, _, _, _, _, properties)| is_set(properties, "is-current"))
+                    .map(|(mode_id, _, _, _, _, _, _)| mode_id.clone()),
+                preferred_mode_id: modes
+                    .iter()
+                    .find(|(_, _, _, _, _, _, properties)| is_set(properties, "is-preferred"))
+                    .map(|(mode_id, _, _, _, _, _, _)| mode_id.clone()),
+                modes: modes
+                    .into_iter()
+                    .map(|(id, width, height, refresh_rate, _, _, _)| MutterMode {
+                        id,
+                        width,
+                        height,
+                        refresh_rate,
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        let logical_monitors = logical_monitors
+            .into_iter()
+            .map(
+                |(x, y, scale, transform, is_primary, monitors_specs, _)| MutterLogicalMonitor {
+                    x,
+                    y,
+                    scale,
+                    transform,
+                    is_primary,
+                    connectors: monitors_specs
+                        .into_iter()
+                        .map(|(connector, _, _, _)| connector)
+                        .collect(),
+                },
+            )
+            .collect();
+
+        Ok(MutterState {
+            serial,
+            monitors,
+            logical_monitors,
+        })
+    }
+
+    fn apply_monitors_config(&mut self, state: &MutterState) -> ApplicationResult<()> {
+        trace_fn!();
+
+        let logical_monitors_configs = state
+            .logical_monitors
+            .iter()
+            .map(|logical_monitor| {
+                let monitors_configs = logical_monitor
+                    .connectors
+                    .iter()
+                    .filter_map(|connector| {
+                        state
+                            .monitors
+                            .iter()
+                            .find(|monitor| monitor.connector == *connector)
+                            .and_then(|monitor| monitor.current_mode_id.clone())
+                            .map(|mode_id| (connector.clone(), mode_id, Properties::new()))
+                    })
+                    .collect();
+
+                (
+                    logical_monitor.x,
+                    logical_monitor.y,
+                    logical_monitor.scale,
+                    logical_monitor.transform,
+                    logical_monitor.is_primary,
+                    monitors_configs,
+                )
+            })
+            .collect::<Vec<LogicalMonitorConfig>>();
+
+        self.connection()?.call_method(
+            Some(DISPLAY_CONFIG_DESTINATION),
+            DISPLAY_CONFIG_PATH,
+            Some(DISPLAY_CONFIG_INTERFACE),
+            "ApplyMonitorsConfig",
+            &(
+                state.serial,
+                PERSISTENT_CONFIG_METHOD,
+                logical_monitors_configs,
+                Properties::new(),
+            ),
+        )?;
+
+        Ok(())
+    }
+}
+
+fn is_set(properties: &Properties, key: &str) -> bool {
+    trace_fn!();
+
+    properties
+        .get(key)
+        .and_then(|value| bool::try_from(value).ok())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod should {
+    use std::{
+        os::unix::net::UnixStream,
+        sync::{Arc, Mutex},
+        thread,
+    };
+
+    use zbus::{
+        Guid,
+        blocking::{Connection, connection::Builder},
+        interface,
+        zvariant::{OwnedValue, Value},
+    };
+
+    use crate::displays_settings::mutter::{
+        mutter_api::{MutterApi, MutterLogicalMonitor, MutterMode, MutterMonitor, MutterState},
+        zbus_based_mutter_api::{
+            CurrentState, DISPLAY_CONFIG_PATH, LogicalMonitorConfig, Mode, Properties,
+            ZbusBasedMutterApi,
+        },
+    };
+
+    type AppliedMonitorsConfig = (u32, u32, Vec<LogicalMonitorConfig>);
+
+    struct StandInDisplayConfig {
+        applied_monitors_config: Arc<Mutex<Option<AppliedMonitorsConfig>>>,
+    }
+
+    #[interface(name = "org.gnome.Mutter.DisplayConfig")]
+    impl StandInDisplayConfig {
+        fn get_current_state(&self) -> CurrentState {
+            let edp = (
+                String::from("eDP-1"),
+                String::from("BOE"),
+                String::new(),
+                String::from("0x00000000"),
+            );
+            let hdmi = (
+                String::from("HDMI-1"),
+                String::from("GSM"),
+                String::from("LG TV SSCR2"),
+                String::from("0x01010101"),
+            );
+
+            (
+                7,
+                vec![
+                    (
+                        edp.clone(),
+                        vec![mode("1920x1080@60.020", 1920, 1080, 60.02, true)],
+                        Properties::new(),
+                    ),
+                    (
+                        hdmi.clone(),
+                        vec![
+                            mode("1920x1080@60.000", 1920, 1080, 60.0, false),
+                            mode("3840x2160@60.000", 3840, 2160, 60.0, true),
+                        ],
+                        Properties::new(),
+                    ),
+                ],
+                vec![
+                    (0, 0, 1.0, 0, true, vec![edp], Properties::new()),
+                    (1920, 0, 2.0, 0, false, vec![hdmi], Properties::new()),
+                ],
+                Properties::new(),
+            )
+        }
+
+        fn apply_monitors_config(
+            &self,
+            serial: u32,
+            method: u32,
+            logical_monitors: Vec<LogicalMonitorConfig>,
+            _properties: Properties,
+        ) {
+            *self.applied_monitors_config.lock().unwrap() =
+                Some((serial, method, logical_monitors));
+        }
+    }
+
+    fn mode(mode_id: &str, width: i32, height: i32, refresh_rate: f64, is_current: bool) -> Mode {
+        let mut properties = Properties::new();
+
+        if is_current {
+            properties.insert(
+                String::from("is-current"),
+                OwnedValue::try_from(Value::from(true)).unwrap(),
+            );
+        }
+
+        (
+            String::from(mode_id),
+            width,
+            height,
+            refresh_rate,
+            1.0,
+            vec![1.0],
+            properties,
+        )
+    }
+
+    /// Serves the stand-in service on one end of a socket pair and returns a connection to it
+    /// along with the server side, which must be kept alive.
+    fn connect_to_stand_in(
+        applied_monitors_config: Arc<Mutex<Option<AppliedMonitorsConfig>>>,
+    ) -> (Connection, Connection) {
+        let (server_stream, client_stream) = UnixStream::pair().unwrap();
+        let stand_in = StandInDisplayConfig {
+            applied_monitors_config,
+        };
+
+        let server = thread::spawn(move || {
+            Builder::async_io_unix_stream(server_stream)
+                .server(Guid::generate())
+                .unwrap()
+                .p2p()
+                .serve_at(DISPLAY_CONFIG_PATH, stand_in)
+                .unwrap()
+                .build()
+                .unwrap()
+        });
+        let client = Builder::async_io_unix_stream(client_stream)
+            .p2p()
+            .build()
+            .unwrap();
+
+        (client, server.join().unwrap())
+    }
+
+    #[test]
+    fn get_the_current_state_from_the_display_config_service() {
+        // Arrange
+        let (client, _server) = connect_to_stand_in(Arc::new(Mutex::new(None)));
+        let mut mutter_api = ZbusBasedMutterApi::new(client);
+
+        // Act
+        let state = mutter_api.get_current_state();
+
+        // Assert
+        assert_eq!(
+            state,
+            Ok(MutterState {
+                serial: 7,
+                monitors: vec![
+                    MutterMonitor {
+                        connector: String::from("eDP-1"),
+                        product: String::new(),
+                        current_mode_id: Some(String::from("1920x1080@60.020")),
+                        preferred_mode_id: None,
+                        modes: vec![MutterMode {
+                            id: String::from("1920x1080@60.020"),
+                            width: 1920,
+                            height: 1080,
+                            refresh_rate: 60.02,
+                        }],
+                    },
+                    MutterMonitor {
+                        connector: String::from("HDMI-1"),
+                        product: String::from("LG TV SSCR2"),
+                        current_mode_id: Some(String::from("3840x2160@60.000")),
+                        preferred_mode_id: None,
+                        modes: vec![
+                            MutterMode {
+                                id: String::from("1920x1080@60.000"),
+                                width: 1920,
+                                height: 1080,
+                                refresh_rate: 60.0,
+                            },
+                            MutterMode {
+                                id: String::from("3840x2160@60.000"),
+                                width: 3840,
+                                height: 2160,
+                                refresh_rate: 60.0,
+                            },
+                        ],
+                    },
+                ],
+                logical_monitors: vec![
+                    MutterLogicalMonitor {
+                        x: 0,
+                        y: 0,
+                        scale: 1.0,
+                        transform: 0,
+                        is_primary: true,
+                        connectors: vec![String::from("eDP-1")],
+                    },
+                    MutterLogicalMonitor {
+                        x: 1920,
+                        y: 0,
+                        scale: 2.0,
+                        transform: 0,
+                        is_primary: false,
+                        connectors: vec![String::from("HDMI-1")],
+                    },
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn apply_the_monitors_config_with_the_current_modes() {
+        // Arrange
+        let applied_monitors_config = Arc::new(Mutex::new(None));
+        let (client, _server) = connect_to_stand_in(Arc::clone(&applied_monitors_config));
+        let mut mutter_api = ZbusBasedMutterApi::new(client);
+        let mut state = mutter_api.get_current_state().unwrap();
+
+        state.logical_monitors[0].is_primary = false;
+        state.logical_monitors[1].is_primary = true;
+
+        // Act
+        let result = mutter_api.apply_monitors_config(&state);
+
+        // Assert
+        assert_eq!(result, Ok(()));
+        assert_eq!(
+            *applied_monitors_config.lock().unwrap(),
+            Some((
+                7,
+                2,
+                vec![
+                    (
+                        0,
+                        0,
+                        1.0,
+                        0,
+                        false,
+                        vec![(
+                            String::from("eDP-1"),
+                            String::from("1920x1080@60.020"),
+                            Properties::new()
+                        )]
+                    ),
+                    (
+                        1920,
+                        0,
+                        2.0,
+                        0,
+                        true,
+                        vec![(
+                            String::from("HDMI-1"),
+                            String::from("3840x2160@60.000"),
+                            Properties::new()
+                        )]
+                    ),
+                ]
+            ))
+        );
+    }
+}
@@ -1,14 +1,21 @@
 use windows::Win32::{
     Devices::Display::{
         DISPLAYCONFIG_DEVICE_INFO_HEADER, DISPLAYCONFIG_MODE_INFO, DISPLAYCONFIG_PATH_INFO,
-        DISPLAYCONFIG_TOPOLOGY_ID, DisplayConfigGetDeviceInfo, GetDisplayConfigBufferSizes,
-        QUERY_DISPLAY_CONFIG_FLAGS, QueryDisplayConfig, SET_DISPLAY_CONFIG_FLAGS, SetDisplayConfig,
+        DISPLAYCONFIG_TOPOLOGY_ID, DisplayConfigGetDeviceInfo, DisplayConfigSetDeviceInfo,
+        GetDisplayConfigBufferSizes, QUERY_DISPLAY_CONFIG_FLAGS, QueryDisplayConfig,
+        SET_DISPLAY_CONFIG_FLAGS, SetDisplayConfig,
     },
     Foundation::WIN32_ERROR,
+    Graphics::Gdi::{
+        CDS_TYPE, ChangeDisplaySettingsExW, DEVMODEW, DISP_CHANGE, ENUM_DISPLAY_SETTINGS_MODE,
+        EnumDisplaySettingsW,
+    },
 };
+use windows_core::{BOOL, PCWSTR};
 
 use crate::{displays_settings::windows::windows_api::WindowsApi, trace_fn};
 
+#[derive(Default)]
 pub struct Win32BasedWindowsApi;
 
 impl WindowsApi for Win32BasedWindowsApi {
@@ -57,6 +64,15 @@ impl WindowsApi for Win32BasedWindowsApi {
         unsafe { DisplayConfigGetDeviceInfo(requestpacket) }
     }
 
+    unsafe fn display_config_set_device_info(
+        &mut self,
+        setpacket: *const DISPLAYCONFIG_DEVICE_INFO_HEADER,
+    ) -> i32 {
+        trace_fn!();
+
+        unsafe { DisplayConfigSetDeviceInfo(setpacket) }
+    }
+
     unsafe fn set_display_config(
         &mut self,
         patharray: Option<&[DISPLAYCONFIG_PATH_INFO]>,
@@ -67,4 +83,26 @@ impl WindowsApi for Win32BasedWindowsApi {
 
         unsafe { SetDisplayConfig(patharray, modeinfoarray, flags) }
     }
+
+    unsafe fn enum_display_settings(
+        &self,
+        lpszdevicename: PCWSTR,
+        imodenum: ENUM_DISPLAY_SETTINGS_MODE,
+        lpdevmode: *mut DEVMODEW,
+    ) -> BOOL {
+        trace_fn!();
+
+        unsafe { EnumDisplaySettingsW(lpszdevicename, imodenum, lpdevmode) }
+    }
+
+    unsafe fn change_display_settings_ex(
+        &mut self,
+        lpszdevicename: PCWSTR,
+        lpdevmode: Option<*const DEVMODEW>,
+        dwflags: CDS_TYPE,
+    ) -> DISP_CHANGE {
+        trace_fn!();
+
+        unsafe { ChangeDisplaySettingsExW(lpszdevicename, lpdevmode, None, dwflags, None) }
+    }
 }
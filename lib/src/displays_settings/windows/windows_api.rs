@@ -1,10 +1,42 @@
 use windows::Win32::{
     Devices::Display::{
-        DISPLAYCONFIG_DEVICE_INFO_HEADER, DISPLAYCONFIG_MODE_INFO, DISPLAYCONFIG_PATH_INFO,
-        DISPLAYCONFIG_TOPOLOGY_ID, QUERY_DISPLAY_CONFIG_FLAGS, SET_DISPLAY_CONFIG_FLAGS,
+        DISPLAYCONFIG_DEVICE_INFO_HEADER, DISPLAYCONFIG_DEVICE_INFO_TYPE, DISPLAYCONFIG_MODE_INFO,
+        DISPLAYCONFIG_PATH_INFO, DISPLAYCONFIG_TOPOLOGY_ID, QUERY_DISPLAY_CONFIG_FLAGS,
+        SET_DISPLAY_CONFIG_FLAGS,
     },
     Foundation::WIN32_ERROR,
+    Graphics::Gdi::{CDS_TYPE, DEVMODEW, DISP_CHANGE, ENUM_DISPLAY_SETTINGS_MODE},
 };
+use windows_core::{BOOL, PCWSTR};
+
+/// The undocumented packet type Windows Settings reads the scale of a display source with.
+pub const DISPLAYCONFIG_DEVICE_INFO_GET_DPI_SCALE: DISPLAYCONFIG_DEVICE_INFO_TYPE =
+    DISPLAYCONFIG_DEVICE_INFO_TYPE(-3);
+/// The undocumented packet type Windows Settings sets the scale of a display source with.
+pub const DISPLAYCONFIG_DEVICE_INFO_SET_DPI_SCALE: DISPLAYCONFIG_DEVICE_INFO_TYPE =
+    DISPLAYCONFIG_DEVICE_INFO_TYPE(-4);
+/// The scales Windows offers, a display being offered the ones from 100% up to a maximum.
+pub const DPI_SCALES_PERCENTS: [u32; 12] =
+    [100, 125, 150, 175, 200, 225, 250, 300, 350, 400, 450, 500];
+
+/// The scale of a display source, in steps of `DPI_SCALES_PERCENTS` relative to the scale
+/// Windows recommends for the display, the minimum step being the one of 100%.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DisplayConfigGetDpiScale {
+    pub header: DISPLAYCONFIG_DEVICE_INFO_HEADER,
+    pub min_scale_step: i32,
+    pub current_scale_step: i32,
+    pub max_scale_step: i32,
+}
+
+/// The scale to set a display source to, in steps relative to the recommended one.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DisplayConfigSetDpiScale {
+    pub header: DISPLAYCONFIG_DEVICE_INFO_HEADER,
+    pub scale_step: i32,
+}
 
 pub trait WindowsApi {
     /// Retrieves the required buffer sizes for display configuration queries.
@@ -72,6 +104,22 @@ pub trait WindowsApi {
         requestpacket: *mut DISPLAYCONFIG_DEVICE_INFO_HEADER,
     ) -> i32;
 
+    /// Sets a property of a display configuration device, such as its advanced colour state.
+    ///
+    /// This is a thin wrapper around the Win32 `DisplayConfigSetDeviceInfo` API.
+    ///
+    /// # Safety
+    /// - `setpacket` must be a **valid, non-null, properly aligned** pointer to a
+    ///   `DISPLAYCONFIG_DEVICE_INFO_HEADER` heading a packet of the type it declares.
+    /// - The pointed structure must have its `size` and `type` fields correctly set
+    ///   according to the Win32 API contract.
+    /// - The memory must remain valid for the duration of the call.
+    /// - Violating any of these requirements results in **undefined behavior**.
+    unsafe fn display_config_set_device_info(
+        &mut self,
+        setpacket: *const DISPLAYCONFIG_DEVICE_INFO_HEADER,
+    ) -> i32;
+
     /// Sets the display configuration for the system.
     ///
     /// This is a thin wrapper around the Win32 `SetDisplayConfig` API and directly
@@ -100,4 +148,40 @@ pub trait WindowsApi {
         modeinfoarray: Option<&[DISPLAYCONFIG_MODE_INFO]>,
         flags: SET_DISPLAY_CONFIG_FLAGS,
     ) -> i32;
+
+    /// Retrieves one of the graphics modes of a display device, or its current one.
+    ///
+    /// This is a thin wrapper around the Win32 `EnumDisplaySettingsW` API.
+    ///
+    /// # Safety
+    /// - `lpszdevicename` must point to a **valid, null-terminated** UTF-16 string which remains
+    ///   valid for the duration of the call.
+    /// - `lpdevmode` must be a **valid, non-null, properly aligned, writable** pointer to a
+    ///   `DEVMODEW` whose `dmSize` field is set.
+    /// - The memory must not be aliased mutably elsewhere while this function executes.
+    /// - Violating any of these requirements results in **undefined behavior**.
+    unsafe fn enum_display_settings(
+        &self,
+        lpszdevicename: PCWSTR,
+        imodenum: ENUM_DISPLAY_SETTINGS_MODE,
+        lpdevmode: *mut DEVMODEW,
+    ) -> BOOL;
+
+    /// Changes the graphics mode of a display device.
+    ///
+    /// This is a thin wrapper around the Win32 `ChangeDisplaySettingsExW` API, without a window
+    /// nor video parameters.
+    ///
+    /// # Safety
+    /// - `lpszdevicename` must point to a **valid, null-terminated** UTF-16 string which remains
+    ///   valid for the duration of the call.
+    /// - If `lpdevmode` is `Some(ptr)`, `ptr` must be a **valid, properly aligned** pointer to an
+    ///   initialized `DEVMODEW` whose `dmSize` and `dmFields` fields are set.
+    /// - Violating any of these requirements results in **undefined behavior**.
+    unsafe fn change_display_settings_ex(
+        &mut self,
+        lpszdevicename: PCWSTR,
+        lpdevmode: Option<*const DEVMODEW>,
+        dwflags: CDS_TYPE,
+    ) -> DISP_CHANGE;
 }
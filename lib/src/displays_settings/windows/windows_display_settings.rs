@@ -1,57 +1,116 @@
 use crate::application_result::ApplicationResult;
-use crate::displays_settings::windows::windows_api::WindowsApi;
+use crate::displays_settings::windows::windows_api::{
+    DISPLAYCONFIG_DEVICE_INFO_GET_DPI_SCALE, DISPLAYCONFIG_DEVICE_INFO_SET_DPI_SCALE,
+    DPI_SCALES_PERCENTS, DisplayConfigGetDpiScale, DisplayConfigSetDpiScale, WindowsApi,
+};
 use crate::{
-    application_error::ApplicationError,
+    application_error::{ApplicationError, DeviceKind, DeviceRole},
     displays_settings::{
-        DisplayInfo, DisplaysSettings, DisplaysSettingsResult, INTERNAL_DISPLAY_NAME,
+        ConnectedDisplay, DisplayAdvancedColor, DisplayColorEncoding, DisplayConnector,
+        DisplayInfo, DisplayMode, DisplayModes, DisplayPosition, DisplayResolution,
+        DisplayRotation, DisplaysSettings, DisplaysSettingsResult, DisplaysSnapshot,
+        DisplaysTopology, from_raw_display_name, unsupported_setting,
     },
     trace_fn,
 };
 use log::info;
+use serde::{Deserialize, Serialize};
 use std::{fmt::Debug, mem};
 use windows::Win32::{
     Devices::Display::{
-        DISPLAYCONFIG_DEVICE_INFO_GET_TARGET_NAME, DISPLAYCONFIG_DEVICE_INFO_HEADER,
-        DISPLAYCONFIG_MODE_INFO, DISPLAYCONFIG_PATH_INFO, DISPLAYCONFIG_TARGET_DEVICE_NAME,
-        QDC_ONLY_ACTIVE_PATHS, SDC_ALLOW_CHANGES, SDC_APPLY, SDC_SAVE_TO_DATABASE,
-        SDC_USE_SUPPLIED_DISPLAY_CONFIG,
+        DISPLAYCONFIG_DEVICE_INFO_GET_ADVANCED_COLOR_INFO,
+        DISPLAYCONFIG_DEVICE_INFO_GET_SOURCE_NAME, DISPLAYCONFIG_DEVICE_INFO_GET_TARGET_NAME,
+        DISPLAYCONFIG_DEVICE_INFO_HEADER, DISPLAYCONFIG_DEVICE_INFO_SET_ADVANCED_COLOR_STATE,
+        DISPLAYCONFIG_GET_ADVANCED_COLOR_INFO, DISPLAYCONFIG_MODE_INFO, DISPLAYCONFIG_MODE_INFO_0,
+        DISPLAYCONFIG_MODE_INFO_TYPE_SOURCE, DISPLAYCONFIG_OUTPUT_TECHNOLOGY_DISPLAYPORT_EMBEDDED,
+        DISPLAYCONFIG_OUTPUT_TECHNOLOGY_DISPLAYPORT_EXTERNAL, DISPLAYCONFIG_OUTPUT_TECHNOLOGY_DVI,
+        DISPLAYCONFIG_OUTPUT_TECHNOLOGY_HD15, DISPLAYCONFIG_OUTPUT_TECHNOLOGY_HDMI,
+        DISPLAYCONFIG_OUTPUT_TECHNOLOGY_INTERNAL, DISPLAYCONFIG_OUTPUT_TECHNOLOGY_LVDS,
+        DISPLAYCONFIG_PATH_INFO, DISPLAYCONFIG_PATH_SOURCE_INFO, DISPLAYCONFIG_PATH_TARGET_INFO,
+        DISPLAYCONFIG_PIXELFORMAT, DISPLAYCONFIG_RATIONAL, DISPLAYCONFIG_ROTATION,
+        DISPLAYCONFIG_ROTATION_IDENTITY, DISPLAYCONFIG_ROTATION_ROTATE90,
+        DISPLAYCONFIG_ROTATION_ROTATE180, DISPLAYCONFIG_ROTATION_ROTATE270, DISPLAYCONFIG_SCALING,
+        DISPLAYCONFIG_SCANLINE_ORDERING, DISPLAYCONFIG_SET_ADVANCED_COLOR_STATE,
+        DISPLAYCONFIG_SET_ADVANCED_COLOR_STATE_0, DISPLAYCONFIG_SOURCE_DEVICE_NAME,
+        DISPLAYCONFIG_SOURCE_MODE, DISPLAYCONFIG_TARGET_DEVICE_NAME, QDC_ALL_PATHS,
+        QDC_ONLY_ACTIVE_PATHS, QUERY_DISPLAY_CONFIG_FLAGS, SDC_ALLOW_CHANGES, SDC_APPLY,
+        SDC_SAVE_TO_DATABASE, SDC_TOPOLOGY_CLONE, SDC_TOPOLOGY_EXTEND,
+        SDC_USE_SUPPLIED_DISPLAY_CONFIG, SET_DISPLAY_CONFIG_FLAGS,
     },
     Foundation::{ERROR_INSUFFICIENT_BUFFER, POINTL, WIN32_ERROR},
+    Graphics::Gdi::{
+        CDS_UPDATEREGISTRY, DEVMODEW, DISP_CHANGE_SUCCESSFUL, DISPLAYCONFIG_COLOR_ENCODING,
+        DISPLAYCONFIG_COLOR_ENCODING_INTENSITY, DISPLAYCONFIG_COLOR_ENCODING_RGB,
+        DISPLAYCONFIG_COLOR_ENCODING_YCBCR420, DISPLAYCONFIG_COLOR_ENCODING_YCBCR422,
+        DISPLAYCONFIG_COLOR_ENCODING_YCBCR444, DISPLAYCONFIG_PATH_ACTIVE,
+        DISPLAYCONFIG_PATH_MODE_IDX_INVALID, DM_DISPLAYFREQUENCY, DM_PELSHEIGHT, DM_PELSWIDTH,
+        ENUM_CURRENT_SETTINGS, ENUM_DISPLAY_SETTINGS_MODE,
+    },
 };
+use windows_core::PCWSTR;
+
+/// The flags `SetDisplayConfig` applies and persists a configuration of the caller with.
+const SUPPLIED_DISPLAY_CONFIG_FLAGS: SET_DISPLAY_CONFIG_FLAGS = SET_DISPLAY_CONFIG_FLAGS(
+    SDC_APPLY.0 | SDC_USE_SUPPLIED_DISPLAY_CONFIG.0 | SDC_ALLOW_CHANGES.0 | SDC_SAVE_TO_DATABASE.0,
+);
+
+/// The state of an active path as `SetDisplayConfig` applies it. The adapters are left out
+/// since their LUIDs change on reboot, the paths being found back by their target and source ids.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WindowsPathSnapshot {
+    pub target_id: u32,
+    pub source_id: u32,
+    pub resolution: DisplayResolution,
+    pub position: DisplayPosition,
+    pub pixel_format: i32,
+    pub refresh_rate_numerator: u32,
+    pub refresh_rate_denominator: u32,
+    pub rotation: DisplayRotation,
+    pub scaling: i32,
+    pub scan_line_ordering: i32,
+}
 
 pub struct WindowsDisplaySettings {
     windows_api: Box<dyn WindowsApi>,
 }
 
 impl DisplaysSettings for WindowsDisplaySettings {
-    fn new(displays_settings_api: Box<dyn WindowsApi>) -> Self {
-        trace_fn!();
-
-        Self {
-            windows_api: displays_settings_api,
-        }
-    }
-
     fn change_primary_display(
         &mut self,
-        desktop_display_name: &str,
-        couch_display_name: &str,
+        desktop_display_id: &str,
+        couch_display_id: &str,
     ) -> ApplicationResult<DisplaysSettingsResult> {
         trace_fn!();
         info!("Changing primary display");
 
-        let (patharray, mut modeinfoarray) = self.query_display_config()?;
+        let (patharray, mut modeinfoarray) = self.query_display_config(QDC_ONLY_ACTIVE_PATHS)?;
 
         let mut new_position = POINTL { x: 0, y: 0 };
         let mut new_primary_monitor_name = String::default();
-        let mut desktop_display_name_is_valid = false;
-        let mut couch_display_name_is_valid = false;
-        let mut possible_names = Vec::new();
+        let mut desktop_display_id_is_valid = false;
+        let mut couch_display_id_is_valid = false;
+        let mut possible_ids = Vec::new();
 
         let size_of_displayconfig_target_device_name =
             size_of::<DISPLAYCONFIG_TARGET_DEVICE_NAME, u32>();
 
         for path in &patharray {
+            let display_id = from_target_info_to_id(&path.targetInfo);
+
+            possible_ids.push(display_id.clone());
+
+            if display_id != desktop_display_id && display_id != couch_display_id {
+                continue;
+            }
+
+            if display_id == desktop_display_id {
+                desktop_display_id_is_valid = true;
+            }
+
+            if display_id == couch_display_id {
+                couch_display_id_is_valid = true;
+            }
+
             let mut target_name = DISPLAYCONFIG_TARGET_DEVICE_NAME {
                 header: DISPLAYCONFIG_DEVICE_INFO_HEADER {
                     r#type: DISPLAYCONFIG_DEVICE_INFO_GET_TARGET_NAME,
@@ -80,44 +139,29 @@ impl DisplaysSettings for WindowsDisplaySettings {
             let display_friendly_device_name =
                 from_raw_display_name(&raw_display_friendly_device_name);
 
-            possible_names.push(display_friendly_device_name.clone());
-
-            if display_friendly_device_name != desktop_display_name
-                && display_friendly_device_name != couch_display_name
-            {
-                continue;
-            }
-
-            if display_friendly_device_name == desktop_display_name {
-                desktop_display_name_is_valid = true;
-            }
-
-            if display_friendly_device_name == couch_display_name {
-                couch_display_name_is_valid = true;
-            }
-
             if position.x != 0 || position.y != 0 {
                 new_position = position;
                 new_primary_monitor_name = display_friendly_device_name;
             }
         }
 
-        let invalid_params_error_message =
-            match (desktop_display_name_is_valid, couch_display_name_is_valid) {
-                (false, false) => Some("Desktop and couch displays are invalid"),
-                (false, _) => Some("Desktop display is invalid"),
-                (_, false) => Some("Couch display is invalid"),
-                _ => None,
-            };
-
-        if let Some(invalid_params_error_message_fragment) = invalid_params_error_message {
-            possible_names.sort();
-            let possible_values_fragment = possible_names.join(", ");
+        let invalid_params = match (desktop_display_id_is_valid, couch_display_id_is_valid) {
+            (false, false) => Some((
+                DeviceRole::DesktopAndCouch,
+                vec![desktop_display_id, couch_display_id],
+            )),
+            (false, _) => Some((DeviceRole::Desktop, vec![desktop_display_id])),
+            (_, false) => Some((DeviceRole::Couch, vec![couch_display_id])),
+            _ => None,
+        };
 
-            let error_message = format!(
-                "{invalid_params_error_message_fragment}, possible values are [{possible_values_fragment}]"
+        if let Some((invalid_role, invalid_ids)) = invalid_params {
+            let error = ApplicationError::device_not_found(
+                DeviceKind::Display,
+                invalid_role,
+                &invalid_ids,
+                possible_ids,
             );
-            let error = ApplicationError::Custom(error_message);
 
             return Err(error);
         }
@@ -130,22 +174,18 @@ impl DisplaysSettings for WindowsDisplaySettings {
             unsafe { mode_info.Anonymous.sourceMode.position.y -= new_position.y };
         }
 
-        let set_display_config_result_code = unsafe {
-            self.windows_api.set_display_config(
-                Some(&patharray),
-                Some(&modeinfoarray),
-                SDC_APPLY
-                    | SDC_USE_SUPPLIED_DISPLAY_CONFIG
-                    | SDC_ALLOW_CHANGES
-                    | SDC_SAVE_TO_DATABASE,
-            )
-        };
-        let set_display_config_result = WIN32_ERROR(set_display_config_result_code.try_into()?);
-
-        set_display_config_result.ok()?;
+        self.set_display_config(
+            Some(&patharray),
+            Some(&modeinfoarray),
+            SUPPLIED_DISPLAY_CONFIG_FLAGS,
+        )?;
 
         Ok(DisplaysSettingsResult {
             new_primary_display: new_primary_monitor_name,
+            new_mode: None,
+            new_rotation: None,
+            new_scale_percent: None,
+            new_hdr_enabled: None,
         })
     }
 
@@ -153,7 +193,7 @@ impl DisplaysSettings for WindowsDisplaySettings {
         trace_fn!();
         info!("Getting displays informations");
 
-        let (patharray, modeinfoarray) = self.query_display_config()?;
+        let (patharray, modeinfoarray) = self.query_display_config(QDC_ONLY_ACTIVE_PATHS)?;
         let mut displays_info = Vec::new();
         let size_of_displayconfig_target_device_name =
             size_of::<DISPLAYCONFIG_TARGET_DEVICE_NAME, u32>();
@@ -179,8 +219,12 @@ impl DisplaysSettings for WindowsDisplaySettings {
             display_config_get_device_info_result.ok()?;
 
             let source_mode_info_idx = unsafe { path.sourceInfo.Anonymous.modeInfoIdx };
-            let source_mode = &modeinfoarray[source_mode_info_idx as usize];
-            let position = unsafe { source_mode.Anonymous.sourceMode.position };
+            let source_mode = unsafe {
+                modeinfoarray[source_mode_info_idx as usize]
+                    .Anonymous
+                    .sourceMode
+            };
+            let position = source_mode.position;
 
             let raw_display_friendly_device_name =
                 from_utf16_trimed(&target_name.monitorFriendlyDeviceName)?;
@@ -188,8 +232,22 @@ impl DisplaysSettings for WindowsDisplaySettings {
                 from_raw_display_name(&raw_display_friendly_device_name);
 
             displays_info.push(DisplayInfo {
-                name: display_friendly_device_name.clone(),
                 is_primary: position.x == 0 && position.y == 0,
+                name: display_friendly_device_name.clone(),
+                id: from_target_info_to_id(&path.targetInfo),
+                connector: from_target_info_to_connector(&path.targetInfo),
+                resolution: DisplayResolution {
+                    width: source_mode.width,
+                    height: source_mode.height,
+                },
+                position: DisplayPosition {
+                    x: position.x,
+                    y: position.y,
+                },
+                refresh_rate_millihertz: from_target_info_to_refresh_rate(&path.targetInfo),
+                rotation: from_target_info_to_rotation(&path.targetInfo),
+                scale_percent: self.get_scale_percent(&path.sourceInfo),
+                advanced_color: self.get_advanced_color(&path.targetInfo),
             });
         }
 
@@ -197,11 +255,462 @@ impl DisplaysSettings for WindowsDisplaySettings {
 
         Ok(displays_info)
     }
+
+    fn save_snapshot(&mut self) -> ApplicationResult<DisplaysSnapshot> {
+        trace_fn!();
+        info!("Saving displays snapshot");
+
+        let (patharray, modeinfoarray) = self.query_display_config(QDC_ONLY_ACTIVE_PATHS)?;
+        let paths = patharray
+            .iter()
+            .map(|path| {
+                let source_mode_info_idx = unsafe { path.sourceInfo.Anonymous.modeInfoIdx };
+                let source_mode = unsafe {
+                    modeinfoarray[source_mode_info_idx as usize]
+                        .Anonymous
+                        .sourceMode
+                };
+
+                WindowsPathSnapshot {
+                    target_id: path.targetInfo.id,
+                    source_id: path.sourceInfo.id,
+                    resolution: DisplayResolution {
+                        width: source_mode.width,
+                        height: source_mode.height,
+                    },
+                    position: DisplayPosition {
+                        x: source_mode.position.x,
+                        y: source_mode.position.y,
+                    },
+                    pixel_format: source_mode.pixelFormat.0,
+                    refresh_rate_numerator: path.targetInfo.refreshRate.Numerator,
+                    refresh_rate_denominator: path.targetInfo.refreshRate.Denominator,
+                    rotation: from_target_info_to_rotation(&path.targetInfo),
+                    scaling: path.targetInfo.scaling.0,
+                    scan_line_ordering: path.targetInfo.scanLineOrdering.0,
+                }
+            })
+            .collect();
+
+        Ok(DisplaysSnapshot::Windows { paths })
+    }
+
+    /// The paths are looked up among the current ones to get the LUIDs of their adapters, the
+    /// cloned paths sharing the mode of their source. Windows picks the target modes by itself.
+    fn restore_snapshot(&mut self, snapshot: &DisplaysSnapshot) -> ApplicationResult<()> {
+        trace_fn!();
+        info!("Restoring displays snapshot");
+
+        let DisplaysSnapshot::Windows { paths } = snapshot else {
+            return Err(ApplicationError::snapshot_of_another_backend(
+                DeviceKind::Display,
+                snapshot.backend_name(),
+                "windows",
+            ));
+        };
+
+        let (current_patharray, _) = self.query_display_config(QDC_ALL_PATHS)?;
+        let mut patharray = Vec::<DISPLAYCONFIG_PATH_INFO>::with_capacity(paths.len());
+        let mut modeinfoarray = Vec::<DISPLAYCONFIG_MODE_INFO>::with_capacity(paths.len());
+
+        for path_snapshot in paths {
+            let Some(mut path) = current_patharray
+                .iter()
+                .find(|path| {
+                    path.targetInfo.id == path_snapshot.target_id
+                        && path.sourceInfo.id == path_snapshot.source_id
+                })
+                .copied()
+            else {
+                return Err(ApplicationError::Snapshot {
+                    message: format!(
+                        "The display {} of the snapshot is not connected anymore",
+                        path_snapshot.target_id
+                    ),
+                    source: None,
+                });
+            };
+
+            let source_mode_info_idx = match modeinfoarray.iter().position(|mode_info| {
+                mode_info.adapterId == path.sourceInfo.adapterId
+                    && mode_info.id == path.sourceInfo.id
+            }) {
+                Some(source_mode_info_idx) => source_mode_info_idx,
+                None => {
+                    modeinfoarray.push(DISPLAYCONFIG_MODE_INFO {
+                        infoType: DISPLAYCONFIG_MODE_INFO_TYPE_SOURCE,
+                        id: path.sourceInfo.id,
+                        adapterId: path.sourceInfo.adapterId,
+                        Anonymous: DISPLAYCONFIG_MODE_INFO_0 {
+                            sourceMode: DISPLAYCONFIG_SOURCE_MODE {
+                                width: path_snapshot.resolution.width,
+                                height: path_snapshot.resolution.height,
+                                pixelFormat: DISPLAYCONFIG_PIXELFORMAT(path_snapshot.pixel_format),
+                                position: POINTL {
+                                    x: path_snapshot.position.x,
+                                    y: path_snapshot.position.y,
+                                },
+                            },
+                        },
+                    });
+
+                    modeinfoarray.len() - 1
+                }
+            };
+
+            path.flags |= DISPLAYCONFIG_PATH_ACTIVE;
+            path.sourceInfo.Anonymous.modeInfoIdx = source_mode_info_idx.try_into()?;
+            path.targetInfo.Anonymous.modeInfoIdx = DISPLAYCONFIG_PATH_MODE_IDX_INVALID;
+            path.targetInfo.refreshRate = DISPLAYCONFIG_RATIONAL {
+                Numerator: path_snapshot.refresh_rate_numerator,
+                Denominator: path_snapshot.refresh_rate_denominator,
+            };
+            path.targetInfo.rotation = to_target_info_rotation(path_snapshot.rotation);
+            path.targetInfo.scaling = DISPLAYCONFIG_SCALING(path_snapshot.scaling);
+            path.targetInfo.scanLineOrdering =
+                DISPLAYCONFIG_SCANLINE_ORDERING(path_snapshot.scan_line_ordering);
+
+            patharray.push(path);
+        }
+
+        self.set_display_config(
+            Some(&patharray),
+            Some(&modeinfoarray),
+            SUPPLIED_DISPLAY_CONFIG_FLAGS,
+        )
+    }
+
+    /// Every target is reachable from several sources, so only the first path of each target
+    /// is kept.
+    fn get_connected_displays(&mut self) -> ApplicationResult<Vec<ConnectedDisplay>> {
+        trace_fn!();
+        info!("Getting connected displays");
+
+        let (patharray, modeinfoarray) = self.query_display_config(QDC_ALL_PATHS)?;
+        let mut connected_displays = Vec::<ConnectedDisplay>::new();
+
+        for path in &patharray {
+            let id = from_target_info_to_id(&path.targetInfo);
+            let is_active = is_active_path(path);
+
+            if !path.targetInfo.targetAvailable.as_bool()
+                || (!is_active && connected_displays.iter().any(|display| display.id == id))
+            {
+                continue;
+            }
+
+            let source_mode_info_idx = unsafe { path.sourceInfo.Anonymous.modeInfoIdx };
+            let is_primary = is_active
+                && modeinfoarray
+                    .get(source_mode_info_idx as usize)
+                    .is_some_and(|source_mode| {
+                        let position = unsafe { source_mode.Anonymous.sourceMode.position };
+
+                        position.x == 0 && position.y == 0
+                    });
+
+            connected_displays.retain(|display| display.id != id);
+            connected_displays.push(ConnectedDisplay {
+                name: self.get_display_name(&path.targetInfo)?,
+                id,
+                connector: from_target_info_to_connector(&path.targetInfo),
+                is_active,
+                is_primary,
+            });
+        }
+
+        Ok(connected_displays)
+    }
+
+    /// Windows lays the displays out by itself: the extended and cloned topologies are the ones
+    /// of `Win + P`, whereas the other ones supply the paths to activate without their modes.
+    fn apply_topology(
+        &mut self,
+        topology: &DisplaysTopology,
+        active_displays: &[ConnectedDisplay],
+    ) -> ApplicationResult<()> {
+        trace_fn!();
+        info!("Applying displays topology");
+
+        match topology {
+            DisplaysTopology::Extend => {
+                return self.set_display_config(None, None, SDC_APPLY | SDC_TOPOLOGY_EXTEND);
+            }
+            DisplaysTopology::Clone => {
+                return self.set_display_config(None, None, SDC_APPLY | SDC_TOPOLOGY_CLONE);
+            }
+            DisplaysTopology::Internal | DisplaysTopology::External | DisplaysTopology::Only(_) => {
+            }
+        }
+
+        let (patharray, _) = self.query_display_config(QDC_ALL_PATHS)?;
+        let mut active_patharray =
+            Vec::<DISPLAYCONFIG_PATH_INFO>::with_capacity(active_displays.len());
+
+        for display in active_displays {
+            let mut paths = patharray
+                .iter()
+                .filter(|path| from_target_info_to_id(&path.targetInfo) == display.id)
+                .filter(|path| {
+                    !active_patharray.iter().any(|active_path| {
+                        active_path.sourceInfo.adapterId == path.sourceInfo.adapterId
+                            && active_path.sourceInfo.id == path.sourceInfo.id
+                    })
+                })
+                .collect::<Vec<&DISPLAYCONFIG_PATH_INFO>>();
+
+            paths.sort_by_key(|path| !is_active_path(path));
+
+            let Some(mut path) = paths.first().map(|path| **path) else {
+                return Err(ApplicationError::InvalidTopology {
+                    message: format!(
+                        "There is no display source left to turn {} on",
+                        display.name
+                    ),
+                });
+            };
+
+            path.flags |= DISPLAYCONFIG_PATH_ACTIVE;
+            path.sourceInfo.Anonymous.modeInfoIdx = DISPLAYCONFIG_PATH_MODE_IDX_INVALID;
+            path.targetInfo.Anonymous.modeInfoIdx = DISPLAYCONFIG_PATH_MODE_IDX_INVALID;
+
+            active_patharray.push(path);
+        }
+
+        self.set_display_config(Some(&active_patharray), None, SUPPLIED_DISPLAY_CONFIG_FLAGS)
+    }
+
+    /// The modes are the ones of the GDI device of the display source, Windows rounding their
+    /// refresh rates down to whole numbers.
+    fn get_displays_modes(&mut self) -> ApplicationResult<Vec<DisplayModes>> {
+        trace_fn!();
+        info!("Getting displays modes");
+
+        let (patharray, _) = self.query_display_config(QDC_ONLY_ACTIVE_PATHS)?;
+        let mut displays_modes = Vec::with_capacity(patharray.len());
+
+        for path in &patharray {
+            let device_name = self.get_source_device_name(&path.sourceInfo)?;
+            let rotation = from_target_info_to_rotation(&path.targetInfo);
+            let current_mode = self
+                .enum_display_settings(&device_name, ENUM_CURRENT_SETTINGS)
+                .map(|devmode| from_devmode_to_display_mode(&devmode, rotation));
+            let mut modes = Vec::new();
+            let mut mode_number = 0;
+
+            while let Some(devmode) =
+                self.enum_display_settings(&device_name, ENUM_DISPLAY_SETTINGS_MODE(mode_number))
+            {
+                let mode = from_devmode_to_display_mode(&devmode, rotation);
+
+                if !modes.contains(&mode) {
+                    modes.push(mode);
+                }
+
+                mode_number += 1;
+            }
+
+            displays_modes.push(DisplayModes {
+                name: self.get_display_name(&path.targetInfo)?,
+                id: from_target_info_to_id(&path.targetInfo),
+                current_mode,
+                modes,
+            });
+        }
+
+        Ok(displays_modes)
+    }
+
+    fn apply_display_mode(
+        &mut self,
+        display_modes: &DisplayModes,
+        mode: &DisplayMode,
+    ) -> ApplicationResult<()> {
+        trace_fn!();
+        info!("Applying display mode");
+
+        let (patharray, _) = self.query_display_config(QDC_ONLY_ACTIVE_PATHS)?;
+
+        let Some(path) = patharray
+            .iter()
+            .find(|path| from_target_info_to_id(&path.targetInfo) == display_modes.id)
+        else {
+            return Err(ApplicationError::UnsupportedSetting {
+                display: display_modes.name.clone(),
+                setting: String::from("mode"),
+            });
+        };
+
+        let device_name = self.get_source_device_name(&path.sourceInfo)?;
+        let resolution = mode
+            .resolution
+            .rotated(from_target_info_to_rotation(&path.targetInfo));
+        let devmode = DEVMODEW {
+            dmSize: size_of::<DEVMODEW, u16>(),
+            dmFields: DM_PELSWIDTH | DM_PELSHEIGHT | DM_DISPLAYFREQUENCY,
+            dmPelsWidth: resolution.width,
+            dmPelsHeight: resolution.height,
+            dmDisplayFrequency: mode.refresh_rate_millihertz / 1000,
+            ..Default::default()
+        };
+
+        let change_display_settings_result = unsafe {
+            self.windows_api.change_display_settings_ex(
+                PCWSTR(device_name.as_ptr()),
+                Some(&devmode),
+                CDS_UPDATEREGISTRY,
+            )
+        };
+
+        if change_display_settings_result != DISP_CHANGE_SUCCESSFUL {
+            return Err(ApplicationError::PlatformCall {
+                api: String::from("ChangeDisplaySettingsExW"),
+                code: Some(change_display_settings_result.0),
+                message: format!("Failed to drive {} at {mode}", display_modes.name),
+                source: None,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// The source mode takes the size of the rotated display, so its width and height are
+    /// swapped for a quarter turn.
+    fn apply_display_rotation(
+        &mut self,
+        display_info: &DisplayInfo,
+        rotation: DisplayRotation,
+    ) -> ApplicationResult<()> {
+        trace_fn!();
+        info!("Applying display rotation");
+
+        let (mut patharray, mut modeinfoarray) =
+            self.query_display_config(QDC_ONLY_ACTIVE_PATHS)?;
+
+        let Some(path) = patharray
+            .iter_mut()
+            .find(|path| from_target_info_to_id(&path.targetInfo) == display_info.id)
+        else {
+            return Err(unsupported_setting(display_info, "rotation"));
+        };
+
+        let source_mode_info_idx = unsafe { path.sourceInfo.Anonymous.modeInfoIdx };
+        let source_mode = unsafe {
+            &mut modeinfoarray[source_mode_info_idx as usize]
+                .Anonymous
+                .sourceMode
+        };
+        let resolution = DisplayResolution {
+            width: source_mode.width,
+            height: source_mode.height,
+        }
+        .rotated(from_target_info_to_rotation(&path.targetInfo))
+        .rotated(rotation);
+
+        source_mode.width = resolution.width;
+        source_mode.height = resolution.height;
+        path.targetInfo.rotation = to_target_info_rotation(rotation);
+
+        self.set_display_config(
+            Some(&patharray),
+            Some(&modeinfoarray),
+            SUPPLIED_DISPLAY_CONFIG_FLAGS,
+        )
+    }
+
+    fn check_display_scale(
+        &mut self,
+        display_info: &DisplayInfo,
+        scale_percent: u32,
+    ) -> ApplicationResult<()> {
+        trace_fn!();
+
+        self.find_scale_step(display_info, scale_percent)
+            .map(|_| ())
+    }
+
+    fn apply_display_scale(
+        &mut self,
+        display_info: &DisplayInfo,
+        scale_percent: u32,
+    ) -> ApplicationResult<()> {
+        trace_fn!();
+        info!("Applying display scale");
+
+        let (source_info, scale_step) = self.find_scale_step(display_info, scale_percent)?;
+        let set_dpi_scale = DisplayConfigSetDpiScale {
+            header: DISPLAYCONFIG_DEVICE_INFO_HEADER {
+                r#type: DISPLAYCONFIG_DEVICE_INFO_SET_DPI_SCALE,
+                size: size_of::<DisplayConfigSetDpiScale, u32>(),
+                adapterId: source_info.adapterId,
+                id: source_info.id,
+            },
+            scale_step,
+        };
+
+        let display_config_set_device_info_result_code = unsafe {
+            self.windows_api
+                .display_config_set_device_info(&set_dpi_scale.header)
+        };
+
+        WIN32_ERROR(display_config_set_device_info_result_code.try_into()?).ok()?;
+
+        Ok(())
+    }
+
+    /// Windows turns HDR on or off through the advanced colour state of the display target.
+    fn apply_display_hdr(
+        &mut self,
+        display_info: &DisplayInfo,
+        is_hdr_enabled: bool,
+    ) -> ApplicationResult<()> {
+        trace_fn!();
+        info!("Applying display HDR");
+
+        let (patharray, _) = self.query_display_config(QDC_ONLY_ACTIVE_PATHS)?;
+
+        let Some(path) = patharray
+            .iter()
+            .find(|path| from_target_info_to_id(&path.targetInfo) == display_info.id)
+        else {
+            return Err(unsupported_setting(display_info, "HDR"));
+        };
+
+        let advanced_color_state = DISPLAYCONFIG_SET_ADVANCED_COLOR_STATE {
+            header: DISPLAYCONFIG_DEVICE_INFO_HEADER {
+                r#type: DISPLAYCONFIG_DEVICE_INFO_SET_ADVANCED_COLOR_STATE,
+                size: size_of::<DISPLAYCONFIG_SET_ADVANCED_COLOR_STATE, u32>(),
+                adapterId: path.targetInfo.adapterId,
+                id: path.targetInfo.id,
+            },
+            Anonymous: DISPLAYCONFIG_SET_ADVANCED_COLOR_STATE_0 {
+                value: u32::from(is_hdr_enabled),
+            },
+        };
+
+        let display_config_set_device_info_result_code = unsafe {
+            self.windows_api
+                .display_config_set_device_info(&advanced_color_state.header)
+        };
+
+        WIN32_ERROR(display_config_set_device_info_result_code.try_into()?).ok()?;
+
+        Ok(())
+    }
 }
 
 impl WindowsDisplaySettings {
+    pub fn new(displays_settings_api: Box<dyn WindowsApi>) -> Self {
+        trace_fn!();
+
+        Self {
+            windows_api: displays_settings_api,
+        }
+    }
+
     fn query_display_config(
         &mut self,
+        flags: QUERY_DISPLAY_CONFIG_FLAGS,
     ) -> ApplicationResult<(Vec<DISPLAYCONFIG_PATH_INFO>, Vec<DISPLAYCONFIG_MODE_INFO>)> {
         trace_fn!();
 
@@ -215,7 +724,7 @@ impl WindowsDisplaySettings {
 
             unsafe {
                 self.windows_api.get_display_config_buffer_sizes(
-                    QDC_ONLY_ACTIVE_PATHS,
+                    flags,
                     &mut numpatharrayelements,
                     &mut nummodeinfoarrayelements,
                 )
@@ -233,7 +742,7 @@ impl WindowsDisplaySettings {
 
             query_display_config_result = unsafe {
                 self.windows_api.query_display_config(
-                    QDC_ONLY_ACTIVE_PATHS,
+                    flags,
                     &mut numpatharrayelements,
                     patharray.as_mut_ptr(),
                     &mut nummodeinfoarrayelements,
@@ -261,23 +770,216 @@ impl WindowsDisplaySettings {
         Ok((patharray, modeinfoarray))
     }
 
-    fn is_not_an_insufficient_buffer_error(&self, error: &WIN32_ERROR) -> bool {
+    fn set_display_config(
+        &mut self,
+        patharray: Option<&[DISPLAYCONFIG_PATH_INFO]>,
+        modeinfoarray: Option<&[DISPLAYCONFIG_MODE_INFO]>,
+        flags: SET_DISPLAY_CONFIG_FLAGS,
+    ) -> ApplicationResult<()> {
         trace_fn!();
 
-        *error != ERROR_INSUFFICIENT_BUFFER
+        let set_display_config_result_code = unsafe {
+            self.windows_api
+                .set_display_config(patharray, modeinfoarray, flags)
+        };
+        let set_display_config_result = WIN32_ERROR(set_display_config_result_code.try_into()?);
+
+        set_display_config_result.ok()?;
+
+        Ok(())
     }
-}
 
-fn from_raw_display_name(raw_display_name: &str) -> String {
-    trace_fn!();
+    fn get_display_name(
+        &self,
+        target_info: &DISPLAYCONFIG_PATH_TARGET_INFO,
+    ) -> ApplicationResult<String> {
+        trace_fn!();
+
+        let mut target_name = DISPLAYCONFIG_TARGET_DEVICE_NAME {
+            header: DISPLAYCONFIG_DEVICE_INFO_HEADER {
+                r#type: DISPLAYCONFIG_DEVICE_INFO_GET_TARGET_NAME,
+                size: size_of::<DISPLAYCONFIG_TARGET_DEVICE_NAME, u32>(),
+                adapterId: target_info.adapterId,
+                id: target_info.id,
+            },
+            ..Default::default()
+        };
+
+        let display_config_get_device_info_result_code = unsafe {
+            self.windows_api
+                .display_config_get_device_info(&mut target_name.header)
+        };
+
+        WIN32_ERROR(display_config_get_device_info_result_code.try_into()?).ok()?;
+
+        let raw_display_friendly_device_name =
+            from_utf16_trimed(&target_name.monitorFriendlyDeviceName)?;
+
+        Ok(from_raw_display_name(&raw_display_friendly_device_name))
+    }
+
+    /// The advanced colour is only reported since Windows 10 1709, so a target which cannot
+    /// report it is taken as not supporting it rather than failing.
+    fn get_advanced_color(
+        &self,
+        target_info: &DISPLAYCONFIG_PATH_TARGET_INFO,
+    ) -> Option<DisplayAdvancedColor> {
+        trace_fn!();
+
+        let mut advanced_color_info = DISPLAYCONFIG_GET_ADVANCED_COLOR_INFO {
+            header: DISPLAYCONFIG_DEVICE_INFO_HEADER {
+                r#type: DISPLAYCONFIG_DEVICE_INFO_GET_ADVANCED_COLOR_INFO,
+                size: size_of::<DISPLAYCONFIG_GET_ADVANCED_COLOR_INFO, u32>(),
+                adapterId: target_info.adapterId,
+                id: target_info.id,
+            },
+            ..Default::default()
+        };
+
+        let display_config_get_device_info_result_code = unsafe {
+            self.windows_api
+                .display_config_get_device_info(&mut advanced_color_info.header)
+        };
+
+        u32::try_from(display_config_get_device_info_result_code)
+            .ok()
+            .and_then(|result_code| WIN32_ERROR(result_code).ok().ok())?;
+
+        let value = unsafe { advanced_color_info.Anonymous.value };
+
+        Some(DisplayAdvancedColor {
+            is_hdr_supported: value & 0b1 != 0,
+            is_hdr_enabled: value & 0b10 != 0,
+            bits_per_color_channel: Some(advanced_color_info.bitsPerColorChannel)
+                .filter(|bits_per_color_channel| *bits_per_color_channel > 0),
+            color_encoding: from_color_encoding(advanced_color_info.colorEncoding),
+        })
+    }
+
+    /// Windows only offers the scales of `DPI_SCALES_PERCENTS` up to a maximum for each
+    /// display, any other one being refused.
+    fn find_scale_step(
+        &mut self,
+        display_info: &DisplayInfo,
+        scale_percent: u32,
+    ) -> ApplicationResult<(DISPLAYCONFIG_PATH_SOURCE_INFO, i32)> {
+        trace_fn!();
+
+        let (patharray, _) = self.query_display_config(QDC_ONLY_ACTIVE_PATHS)?;
+
+        let Some(path) = patharray
+            .iter()
+            .find(|path| from_target_info_to_id(&path.targetInfo) == display_info.id)
+        else {
+            return Err(unsupported_setting(display_info, "scale"));
+        };
+
+        let dpi_scale = self.get_dpi_scale(&path.sourceInfo)?;
+
+        DPI_SCALES_PERCENTS
+            .iter()
+            .position(|dpi_scale_percent| *dpi_scale_percent == scale_percent)
+            .and_then(|index| i32::try_from(index).ok())
+            .map(|index| dpi_scale.min_scale_step + index)
+            .filter(|scale_step| *scale_step <= dpi_scale.max_scale_step)
+            .map(|scale_step| (path.sourceInfo, scale_step))
+            .ok_or_else(|| unsupported_setting(display_info, "scale"))
+    }
+
+    fn get_dpi_scale(
+        &self,
+        source_info: &DISPLAYCONFIG_PATH_SOURCE_INFO,
+    ) -> ApplicationResult<DisplayConfigGetDpiScale> {
+        trace_fn!();
+
+        let mut dpi_scale = DisplayConfigGetDpiScale {
+            header: DISPLAYCONFIG_DEVICE_INFO_HEADER {
+                r#type: DISPLAYCONFIG_DEVICE_INFO_GET_DPI_SCALE,
+                size: size_of::<DisplayConfigGetDpiScale, u32>(),
+                adapterId: source_info.adapterId,
+                id: source_info.id,
+            },
+            ..Default::default()
+        };
+
+        let display_config_get_device_info_result_code = unsafe {
+            self.windows_api
+                .display_config_get_device_info(&mut dpi_scale.header)
+        };
+
+        WIN32_ERROR(display_config_get_device_info_result_code.try_into()?).ok()?;
+
+        Ok(dpi_scale)
+    }
+
+    /// The scale is read through a packet Windows does not document, so a source which cannot
+    /// report it is taken as not having one rather than failing.
+    fn get_scale_percent(&self, source_info: &DISPLAYCONFIG_PATH_SOURCE_INFO) -> Option<u32> {
+        trace_fn!();
+
+        let dpi_scale = self.get_dpi_scale(source_info).ok()?;
+        let index = dpi_scale.current_scale_step - dpi_scale.min_scale_step;
+
+        DPI_SCALES_PERCENTS
+            .get(usize::try_from(index).ok()?)
+            .copied()
+    }
+
+    /// The GDI device name, such as `\\.\DISPLAY1`, is null-terminated.
+    fn get_source_device_name(
+        &self,
+        source_info: &DISPLAYCONFIG_PATH_SOURCE_INFO,
+    ) -> ApplicationResult<[u16; 32]> {
+        trace_fn!();
+
+        let mut source_name = DISPLAYCONFIG_SOURCE_DEVICE_NAME {
+            header: DISPLAYCONFIG_DEVICE_INFO_HEADER {
+                r#type: DISPLAYCONFIG_DEVICE_INFO_GET_SOURCE_NAME,
+                size: size_of::<DISPLAYCONFIG_SOURCE_DEVICE_NAME, u32>(),
+                adapterId: source_info.adapterId,
+                id: source_info.id,
+            },
+            ..Default::default()
+        };
+
+        let display_config_get_device_info_result_code = unsafe {
+            self.windows_api
+                .display_config_get_device_info(&mut source_name.header)
+        };
 
-    let display_name = if raw_display_name.is_empty() {
-        INTERNAL_DISPLAY_NAME
-    } else {
-        raw_display_name
-    };
+        WIN32_ERROR(display_config_get_device_info_result_code.try_into()?).ok()?;
 
-    String::from(display_name)
+        Ok(source_name.viewGdiDeviceName)
+    }
+
+    fn enum_display_settings(
+        &self,
+        device_name: &[u16; 32],
+        mode_number: ENUM_DISPLAY_SETTINGS_MODE,
+    ) -> Option<DEVMODEW> {
+        trace_fn!();
+
+        let mut devmode = DEVMODEW {
+            dmSize: size_of::<DEVMODEW, u16>(),
+            ..Default::default()
+        };
+
+        let is_found = unsafe {
+            self.windows_api.enum_display_settings(
+                PCWSTR(device_name.as_ptr()),
+                mode_number,
+                &mut devmode,
+            )
+        };
+
+        is_found.as_bool().then_some(devmode)
+    }
+
+    fn is_not_an_insufficient_buffer_error(&self, error: &WIN32_ERROR) -> bool {
+        trace_fn!();
+
+        *error != ERROR_INSUFFICIENT_BUFFER
+    }
 }
 
 fn size_of<T1, T2: TryFrom<usize>>() -> T2
@@ -290,6 +992,97 @@ where
     T2::try_from(size).unwrap()
 }
 
+fn is_active_path(path: &DISPLAYCONFIG_PATH_INFO) -> bool {
+    trace_fn!();
+
+    path.flags & DISPLAYCONFIG_PATH_ACTIVE != 0
+}
+
+/// The target id is only unique on its adapter, whose LUID is stable until the next reboot.
+fn from_target_info_to_id(target_info: &DISPLAYCONFIG_PATH_TARGET_INFO) -> String {
+    trace_fn!();
+
+    format!(
+        "{:08X}{:08X}:{}",
+        target_info.adapterId.HighPart as u32, target_info.adapterId.LowPart, target_info.id
+    )
+}
+
+fn from_target_info_to_connector(target_info: &DISPLAYCONFIG_PATH_TARGET_INFO) -> DisplayConnector {
+    trace_fn!();
+
+    match target_info.outputTechnology {
+        DISPLAYCONFIG_OUTPUT_TECHNOLOGY_HDMI => DisplayConnector::Hdmi,
+        DISPLAYCONFIG_OUTPUT_TECHNOLOGY_DISPLAYPORT_EXTERNAL => DisplayConnector::DisplayPort,
+        DISPLAYCONFIG_OUTPUT_TECHNOLOGY_INTERNAL
+        | DISPLAYCONFIG_OUTPUT_TECHNOLOGY_DISPLAYPORT_EMBEDDED
+        | DISPLAYCONFIG_OUTPUT_TECHNOLOGY_LVDS => DisplayConnector::Internal,
+        DISPLAYCONFIG_OUTPUT_TECHNOLOGY_DVI => DisplayConnector::Dvi,
+        DISPLAYCONFIG_OUTPUT_TECHNOLOGY_HD15 => DisplayConnector::Vga,
+        _ => DisplayConnector::Other,
+    }
+}
+
+fn from_target_info_to_refresh_rate(target_info: &DISPLAYCONFIG_PATH_TARGET_INFO) -> Option<u32> {
+    trace_fn!();
+
+    let refresh_rate = target_info.refreshRate;
+
+    (refresh_rate.Denominator != 0)
+        .then(|| u64::from(refresh_rate.Numerator) * 1000 / u64::from(refresh_rate.Denominator))
+        .and_then(|refresh_rate_millihertz| u32::try_from(refresh_rate_millihertz).ok())
+        .filter(|refresh_rate_millihertz| *refresh_rate_millihertz > 0)
+}
+
+/// The resolution of the mode is the one of the display before its rotation.
+fn from_devmode_to_display_mode(devmode: &DEVMODEW, rotation: DisplayRotation) -> DisplayMode {
+    trace_fn!();
+
+    DisplayMode {
+        resolution: DisplayResolution {
+            width: devmode.dmPelsWidth,
+            height: devmode.dmPelsHeight,
+        }
+        .rotated(rotation),
+        refresh_rate_millihertz: devmode.dmDisplayFrequency * 1000,
+    }
+}
+
+fn from_target_info_to_rotation(target_info: &DISPLAYCONFIG_PATH_TARGET_INFO) -> DisplayRotation {
+    trace_fn!();
+
+    match target_info.rotation {
+        DISPLAYCONFIG_ROTATION_ROTATE90 => DisplayRotation::Degrees90,
+        DISPLAYCONFIG_ROTATION_ROTATE180 => DisplayRotation::Degrees180,
+        DISPLAYCONFIG_ROTATION_ROTATE270 => DisplayRotation::Degrees270,
+        _ => DisplayRotation::Degrees0,
+    }
+}
+
+fn to_target_info_rotation(rotation: DisplayRotation) -> DISPLAYCONFIG_ROTATION {
+    trace_fn!();
+
+    match rotation {
+        DisplayRotation::Degrees0 => DISPLAYCONFIG_ROTATION_IDENTITY,
+        DisplayRotation::Degrees90 => DISPLAYCONFIG_ROTATION_ROTATE90,
+        DisplayRotation::Degrees180 => DISPLAYCONFIG_ROTATION_ROTATE180,
+        DisplayRotation::Degrees270 => DISPLAYCONFIG_ROTATION_ROTATE270,
+    }
+}
+
+fn from_color_encoding(color_encoding: DISPLAYCONFIG_COLOR_ENCODING) -> DisplayColorEncoding {
+    trace_fn!();
+
+    match color_encoding {
+        DISPLAYCONFIG_COLOR_ENCODING_RGB => DisplayColorEncoding::Rgb,
+        DISPLAYCONFIG_COLOR_ENCODING_YCBCR444 => DisplayColorEncoding::Ycbcr444,
+        DISPLAYCONFIG_COLOR_ENCODING_YCBCR422 => DisplayColorEncoding::Ycbcr422,
+        DISPLAYCONFIG_COLOR_ENCODING_YCBCR420 => DisplayColorEncoding::Ycbcr420,
+        DISPLAYCONFIG_COLOR_ENCODING_INTENSITY => DisplayColorEncoding::Intensity,
+        _ => DisplayColorEncoding::Unknown,
+    }
+}
+
 fn from_utf16_trimed(bytes: &[u16]) -> ApplicationResult<String> {
     trace_fn!();
 
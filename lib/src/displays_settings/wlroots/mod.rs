@@ -0,0 +1,3 @@
+pub mod wayland_based_wlroots_api;
+pub mod wlroots_api;
+pub mod wlroots_display_settings;
@@ -0,0 +1,380 @@
+use wayland_client::{
+    Connection, Dispatch, EventQueue, QueueHandle, WEnum, event_created_child,
+    globals::{GlobalListContents, registry_queue_init},
+    protocol::{
+        wl_output::Transform,
+        wl_registry::{self, WlRegistry},
+    },
+};
+use wayland_protocols_wlr::output_management::v1::client::{
+    zwlr_output_configuration_head_v1::{self, ZwlrOutputConfigurationHeadV1},
+    zwlr_output_configuration_v1::{self, ZwlrOutputConfigurationV1},
+    zwlr_output_head_v1::{self, ZwlrOutputHeadV1},
+    zwlr_output_manager_v1::{self, ZwlrOutputManagerV1},
+    zwlr_output_mode_v1::{self, ZwlrOutputModeV1},
+};
+
+use crate::{
+    application_error::{ApplicationError, ErrorSource},
+    application_result::ApplicationResult,
+    displays_settings::wlroots::wlroots_api::{WlrootsApi, WlrootsHead, WlrootsMode},
+    trace_fn,
+};
+
+#[derive(Default)]
+pub struct WaylandBasedWlrootsApi;
+
+#[derive(Default)]
+struct OutputManagerState {
+    serial: Option<u32>,
+    heads: Vec<(ZwlrOutputHeadV1, WlrootsHead)>,
+    /// The modes along with the head which advertised them.
+    modes: Vec<(ZwlrOutputHeadV1, ZwlrOutputModeV1, WlrootsMode)>,
+    current_modes: Vec<(ZwlrOutputHeadV1, ZwlrOutputModeV1)>,
+    preferred_modes: Vec<ZwlrOutputModeV1>,
+    configuration_result: Option<Result<(), &'static str>>,
+}
+
+impl OutputManagerState {
+    /// The current mode of a head is advertised before its size and refresh rate, so it is only
+    /// resolved once every event has been dispatched.
+    fn into_heads(self) -> Vec<WlrootsHead> {
+        trace_fn!();
+
+        self.heads
+            .iter()
+            .map(|(head_proxy, head)| WlrootsHead {
+                current_mode: self.current_mode(head_proxy),
+                preferred_mode: self.preferred_mode(head_proxy),
+                modes: self.head_modes(head_proxy),
+                ..head.clone()
+            })
+            .collect()
+    }
+
+    fn preferred_mode(&self, head_proxy: &ZwlrOutputHeadV1) -> Option<WlrootsMode> {
+        trace_fn!();
+
+        self.modes
+            .iter()
+            .find(|(mode_head_proxy, mode_proxy, _)| {
+                mode_head_proxy == head_proxy && self.preferred_modes.contains(mode_proxy)
+            })
+            .map(|(_, _, mode)| *mode)
+    }
+
+    fn head_modes(&self, head_proxy: &ZwlrOutputHeadV1) -> Vec<WlrootsMode> {
+        trace_fn!();
+
+        self.modes
+            .iter()
+            .filter(|(mode_head_proxy, _, _)| mode_head_proxy == head_proxy)
+            .map(|(_, _, mode)| *mode)
+            .collect()
+    }
+
+    fn current_mode(&self, head_proxy: &ZwlrOutputHeadV1) -> Option<WlrootsMode> {
+        trace_fn!();
+
+        self.current_modes
+            .iter()
+            .find(|(current_mode_head_proxy, _)| current_mode_head_proxy == head_proxy)
+            .and_then(|(_, current_mode_proxy)| {
+                self.modes
+                    .iter()
+                    .find(|(_, mode_proxy, _)| mode_proxy == current_mode_proxy)
+            })
+            .map(|(_, _, mode)| *mode)
+    }
+
+    fn find_mode(
+        &self,
+        head_proxy: &ZwlrOutputHeadV1,
+        mode: WlrootsMode,
+    ) -> Option<&ZwlrOutputModeV1> {
+        trace_fn!();
+
+        self.modes
+            .iter()
+            .find(|(mode_head_proxy, _, head_mode)| {
+                mode_head_proxy == head_proxy && *head_mode == mode
+            })
+            .map(|(_, mode_proxy, _)| mode_proxy)
+    }
+}
+
+struct OutputManager {
+    manager: ZwlrOutputManagerV1,
+    queue: EventQueue<OutputManagerState>,
+    state: OutputManagerState,
+}
+
+impl OutputManager {
+    /// Binds the output manager and waits for the compositor to advertise the current heads.
+    fn connect() -> ApplicationResult<Self> {
+        trace_fn!();
+
+        let connection = Connection::connect_to_env()?;
+        let (globals, mut queue) = registry_queue_init::<OutputManagerState>(&connection)?;
+        let queue_handle = queue.handle();
+        let manager = globals
+            .bind::<ZwlrOutputManagerV1, _, _>(&queue_handle, 1..=4, ())
+            .map_err(|error| ApplicationError::PlatformCall {
+                api: String::from("Wayland"),
+                code: None,
+                message: String::from(
+                    "The compositor does not support the wlr-output-management protocol",
+                ),
+                source: Some(ErrorSource::new(error)),
+            })?;
+        let mut state = OutputManagerState::default();
+
+        while state.serial.is_none() {
+            queue.blocking_dispatch(&mut state)?;
+        }
+
+        Ok(Self {
+            manager,
+            queue,
+            state,
+        })
+    }
+}
+
+impl WlrootsApi for WaylandBasedWlrootsApi {
+    fn get_heads(&mut self) -> ApplicationResult<Vec<WlrootsHead>> {
+        trace_fn!();
+
+        let output_manager = OutputManager::connect()?;
+
+        Ok(output_manager.state.into_heads())
+    }
+
+    fn apply_heads(&mut self, heads: &[WlrootsHead]) -> ApplicationResult<()> {
+        trace_fn!();
+
+        let mut output_manager = OutputManager::connect()?;
+        let queue_handle = output_manager.queue.handle();
+        let serial = output_manager.state.serial.unwrap_or_default();
+        let configuration = output_manager
+            .manager
+            .create_configuration(serial, &queue_handle, ());
+
+        for (head_proxy, current_head) in &output_manager.state.heads {
+            let new_head = heads
+                .iter()
+                .find(|head| head.name == current_head.name)
+                .unwrap_or(current_head);
+
+            if new_head.is_enabled {
+                let configuration_head = configuration.enable_head(head_proxy, &queue_handle, ());
+
+                configuration_head.set_position(new_head.x, new_head.y);
+
+                if let Some(new_mode) = new_head.current_mode
+                    && output_manager.state.current_mode(head_proxy) != Some(new_mode)
+                    && let Some(mode_proxy) = output_manager.state.find_mode(head_proxy, new_mode)
+                {
+                    configuration_head.set_mode(mode_proxy);
+                }
+
+                if new_head.transform != current_head.transform
+                    && let Ok(transform) = Transform::try_from(new_head.transform)
+                {
+                    configuration_head.set_transform(transform);
+                }
+
+                if new_head.scale != current_head.scale {
+                    configuration_head.set_scale(new_head.scale);
+                }
+            } else {
+                configuration.disable_head(head_proxy);
+            }
+        }
+
+        configuration.apply();
+
+        let configuration_result = loop {
+            if let Some(configuration_result) = output_manager.state.configuration_result {
+                break configuration_result;
+            }
+
+            output_manager
+                .queue
+                .blocking_dispatch(&mut output_manager.state)?;
+        };
+
+        configuration.destroy();
+
+        configuration_result.map_err(|error| ApplicationError::PlatformCall {
+            api: String::from("Wayland"),
+            code: None,
+            message: String::from(error),
+            source: None,
+        })
+    }
+}
+
+impl Dispatch<WlRegistry, GlobalListContents> for OutputManagerState {
+    fn event(
+        _: &mut Self,
+        _: &WlRegistry,
+        _: wl_registry::Event,
+        _: &GlobalListContents,
+        _: &Connection,
+        _: &QueueHandle<Self>,
+    ) {
+    }
+}
+
+impl Dispatch<ZwlrOutputManagerV1, ()> for OutputManagerState {
+    fn event(
+        state: &mut Self,
+        _: &ZwlrOutputManagerV1,
+        event: zwlr_output_manager_v1::Event,
+        _: &(),
+        _: &Connection,
+        _: &QueueHandle<Self>,
+    ) {
+        trace_fn!();
+
+        match event {
+            zwlr_output_manager_v1::Event::Head { head } => {
+                state.heads.push((head, WlrootsHead::default()));
+            }
+            zwlr_output_manager_v1::Event::Done { serial } => state.serial = Some(serial),
+            _ => {}
+        }
+    }
+
+    event_created_child!(OutputManagerState, ZwlrOutputManagerV1, [
+        zwlr_output_manager_v1::EVT_HEAD_OPCODE => (ZwlrOutputHeadV1, ()),
+    ]);
+}
+
+impl Dispatch<ZwlrOutputHeadV1, ()> for OutputManagerState {
+    fn event(
+        state: &mut Self,
+        proxy: &ZwlrOutputHeadV1,
+        event: zwlr_output_head_v1::Event,
+        _: &(),
+        _: &Connection,
+        _: &QueueHandle<Self>,
+    ) {
+        trace_fn!();
+
+        let Some((_, head)) = state
+            .heads
+            .iter_mut()
+            .find(|(head_proxy, _)| head_proxy == proxy)
+        else {
+            return;
+        };
+
+        match event {
+            zwlr_output_head_v1::Event::Name { name } => head.name = name,
+            zwlr_output_head_v1::Event::Model { model } => head.model = model,
+            zwlr_output_head_v1::Event::Enabled { enabled } => head.is_enabled = enabled != 0,
+            zwlr_output_head_v1::Event::Position { x, y } => {
+                head.x = x;
+                head.y = y;
+            }
+            zwlr_output_head_v1::Event::Transform {
+                transform: WEnum::Value(transform),
+            } => head.transform = u32::from(transform),
+            zwlr_output_head_v1::Event::Scale { scale } => head.scale = scale,
+            zwlr_output_head_v1::Event::Mode { mode } => {
+                state
+                    .modes
+                    .push((proxy.clone(), mode, WlrootsMode::default()));
+            }
+            zwlr_output_head_v1::Event::CurrentMode { mode } => {
+                state
+                    .current_modes
+                    .retain(|(head_proxy, _)| head_proxy != proxy);
+                state.current_modes.push((proxy.clone(), mode));
+            }
+            zwlr_output_head_v1::Event::Finished => {
+                state.heads.retain(|(head_proxy, _)| head_proxy != proxy);
+            }
+            _ => {}
+        }
+    }
+
+    event_created_child!(OutputManagerState, ZwlrOutputHeadV1, [
+        zwlr_output_head_v1::EVT_MODE_OPCODE => (ZwlrOutputModeV1, ()),
+    ]);
+}
+
+impl Dispatch<ZwlrOutputModeV1, ()> for OutputManagerState {
+    fn event(
+        state: &mut Self,
+        proxy: &ZwlrOutputModeV1,
+        event: zwlr_output_mode_v1::Event,
+        _: &(),
+        _: &Connection,
+        _: &QueueHandle<Self>,
+    ) {
+        trace_fn!();
+
+        let Some((_, _, mode)) = state
+            .modes
+            .iter_mut()
+            .find(|(_, mode_proxy, _)| mode_proxy == proxy)
+        else {
+            return;
+        };
+
+        match event {
+            zwlr_output_mode_v1::Event::Size { width, height } => {
+                mode.width = width;
+                mode.height = height;
+            }
+            zwlr_output_mode_v1::Event::Refresh { refresh } => mode.refresh_rate = refresh,
+            zwlr_output_mode_v1::Event::Preferred => state.preferred_modes.push(proxy.clone()),
+            zwlr_output_mode_v1::Event::Finished => {
+                state.modes.retain(|(_, mode_proxy, _)| mode_proxy != proxy);
+                state
+                    .preferred_modes
+                    .retain(|preferred_mode_proxy| preferred_mode_proxy != proxy);
+            }
+            _ => {}
+        }
+    }
+}
+
+impl Dispatch<ZwlrOutputConfigurationV1, ()> for OutputManagerState {
+    fn event(
+        state: &mut Self,
+        _: &ZwlrOutputConfigurationV1,
+        event: zwlr_output_configuration_v1::Event,
+        _: &(),
+        _: &Connection,
+        _: &QueueHandle<Self>,
+    ) {
+        trace_fn!();
+
+        state.configuration_result = match event {
+            zwlr_output_configuration_v1::Event::Succeeded => Some(Ok(())),
+            zwlr_output_configuration_v1::Event::Failed => Some(Err(
+                "The compositor failed to apply the output configuration",
+            )),
+            zwlr_output_configuration_v1::Event::Cancelled => Some(Err(
+                "The output configuration was cancelled because the heads changed",
+            )),
+            _ => None,
+        };
+    }
+}
+
+impl Dispatch<ZwlrOutputConfigurationHeadV1, ()> for OutputManagerState {
+    fn event(
+        _: &mut Self,
+        _: &ZwlrOutputConfigurationHeadV1,
+        _: zwlr_output_configuration_head_v1::Event,
+        _: &(),
+        _: &Connection,
+        _: &QueueHandle<Self>,
+    ) {
+    }
+}
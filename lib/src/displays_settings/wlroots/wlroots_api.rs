@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+use crate::application_result::ApplicationResult;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WlrootsMode {
+    pub width: i32,
+    pub height: i32,
+    /// The refresh rate in mHz, zero when the compositor does not know it.
+    pub refresh_rate: i32,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct WlrootsHead {
+    pub name: String,
+    pub model: String,
+    pub is_enabled: bool,
+    pub x: i32,
+    pub y: i32,
+    pub current_mode: Option<WlrootsMode>,
+    /// The mode a disabled head is turned on with, it has no current mode.
+    #[serde(default)]
+    pub preferred_mode: Option<WlrootsMode>,
+    /// Every mode the head advertises.
+    #[serde(default)]
+    pub modes: Vec<WlrootsMode>,
+    /// A `wl_output` transform.
+    pub transform: u32,
+    pub scale: f64,
+}
+
+pub trait WlrootsApi {
+    /// Enumerates the heads advertised by the `zwlr_output_manager_v1` global of the compositor.
+    fn get_heads(&mut self) -> ApplicationResult<Vec<WlrootsHead>>;
+
+    /// Applies the position of every enabled head in a single output configuration, along with
+    /// its mode, its transform and its scale when they differ from the current ones.
+    ///
+    /// Heads are matched by name against the ones enumerated by `get_heads`, the configuration
+    /// is cancelled by the compositor when they changed in the meantime.
+    fn apply_heads(&mut self, heads: &[WlrootsHead]) -> ApplicationResult<()>;
+}
@@ -0,0 +1,742 @@
+use log::info;
+
+use crate::{
+    application_error::{ApplicationError, DeviceKind, DeviceRole},
+    application_result::ApplicationResult,
+    displays_settings::{
+        ConnectedDisplay, DisplayInfo, DisplayMode, DisplayModes, DisplayPosition,
+        DisplayResolution, DisplayRotation, DisplaysSettings, DisplaysSettingsResult,
+        DisplaysSnapshot, DisplaysTopology, ensure_snapshot_displays_are_connected,
+        from_connector_name, from_monitor_and_connector_names, from_scale_factor,
+        from_wl_output_transform, to_wl_output_transform,
+        wlroots::wlroots_api::{WlrootsApi, WlrootsHead, WlrootsMode},
+    },
+    trace_fn,
+};
+
+pub struct WlrootsDisplaySettings {
+    wlroots_api: Box<dyn WlrootsApi>,
+}
+
+impl WlrootsDisplaySettings {
+    pub fn new(displays_settings_api: Box<dyn WlrootsApi>) -> Self {
+        trace_fn!();
+
+        Self {
+            wlroots_api: displays_settings_api,
+        }
+    }
+}
+
+impl DisplaysSettings for WlrootsDisplaySettings {
+    fn change_primary_display(
+        &mut self,
+        desktop_display_id: &str,
+        couch_display_id: &str,
+    ) -> ApplicationResult<DisplaysSettingsResult> {
+        trace_fn!();
+        info!("Changing primary display");
+
+        let mut heads = self.wlroots_api.get_heads()?;
+
+        let mut new_position = (0, 0);
+        let mut new_primary_display_name = String::default();
+        let mut desktop_display_id_is_valid = false;
+        let mut couch_display_id_is_valid = false;
+        let mut possible_ids = Vec::with_capacity(heads.len());
+
+        // The heads are told apart by their connector names, which are their ids.
+        for head in heads.iter().filter(|head| head.is_enabled) {
+            possible_ids.push(head.name.clone());
+
+            if head.name != desktop_display_id && head.name != couch_display_id {
+                continue;
+            }
+
+            if head.name == desktop_display_id {
+                desktop_display_id_is_valid = true;
+            }
+
+            if head.name == couch_display_id {
+                couch_display_id_is_valid = true;
+            }
+
+            if head.x != 0 || head.y != 0 {
+                new_position = (head.x, head.y);
+                new_primary_display_name =
+                    from_monitor_and_connector_names(Some(head.model.clone()), &head.name);
+            }
+        }
+
+        let invalid_params = match (desktop_display_id_is_valid, couch_display_id_is_valid) {
+            (false, false) => Some((
+                DeviceRole::DesktopAndCouch,
+                vec![desktop_display_id, couch_display_id],
+            )),
+            (false, _) => Some((DeviceRole::Desktop, vec![desktop_display_id])),
+            (_, false) => Some((DeviceRole::Couch, vec![couch_display_id])),
+            _ => None,
+        };
+
+        if let Some((invalid_role, invalid_ids)) = invalid_params {
+            let error = ApplicationError::device_not_found(
+                DeviceKind::Display,
+                invalid_role,
+                &invalid_ids,
+                possible_ids,
+            );
+
+            return Err(error);
+        }
+
+        for head in heads.iter_mut().filter(|head| head.is_enabled) {
+            head.x -= new_position.0;
+            head.y -= new_position.1;
+        }
+
+        self.wlroots_api.apply_heads(&heads)?;
+
+        Ok(DisplaysSettingsResult {
+            new_primary_display: new_primary_display_name,
+            new_mode: None,
+            new_rotation: None,
+            new_scale_percent: None,
+            new_hdr_enabled: None,
+        })
+    }
+
+    fn get_displays_infos(&mut self) -> ApplicationResult<Vec<DisplayInfo>> {
+        trace_fn!();
+        info!("Getting displays informations");
+
+        let mut displays_infos = self
+            .wlroots_api
+            .get_heads()?
+            .into_iter()
+            .filter(|head| head.is_enabled)
+            .map(to_display_info)
+            .collect::<Vec<DisplayInfo>>();
+
+        displays_infos.sort();
+
+        Ok(displays_infos)
+    }
+
+    fn save_snapshot(&mut self) -> ApplicationResult<DisplaysSnapshot> {
+        trace_fn!();
+        info!("Saving displays snapshot");
+
+        let heads = self.wlroots_api.get_heads()?;
+
+        Ok(DisplaysSnapshot::Wlroots { heads })
+    }
+
+    /// The heads which were disabled when the snapshot was saved are disabled again, the ones
+    /// which were unplugged since then are left as they are.
+    fn restore_snapshot(&mut self, snapshot: &DisplaysSnapshot) -> ApplicationResult<()> {
+        trace_fn!();
+        info!("Restoring displays snapshot");
+
+        let DisplaysSnapshot::Wlroots {
+            heads: snapshot_heads,
+        } = snapshot
+        else {
+            return Err(ApplicationError::snapshot_of_another_backend(
+                DeviceKind::Display,
+                snapshot.backend_name(),
+                "wlroots",
+            ));
+        };
+
+        let heads = self.wlroots_api.get_heads()?;
+        let heads_names = heads
+            .iter()
+            .map(|head| head.name.as_str())
+            .collect::<Vec<&str>>();
+
+        ensure_snapshot_displays_are_connected(
+            snapshot_heads
+                .iter()
+                .filter(|head| head.is_enabled)
+                .map(|head| head.name.as_str()),
+            &heads_names,
+        )?;
+
+        self.wlroots_api.apply_heads(snapshot_heads)
+    }
+
+    fn get_connected_displays(&mut self) -> ApplicationResult<Vec<ConnectedDisplay>> {
+        trace_fn!();
+        info!("Getting connected displays");
+
+        Ok(self
+            .wlroots_api
+            .get_heads()?
+            .into_iter()
+            .map(|head| ConnectedDisplay {
+                name: from_monitor_and_connector_names(Some(head.model), &head.name),
+                connector: from_connector_name(&head.name),
+                is_active: head.is_enabled,
+                is_primary: head.is_enabled && head.x == 0 && head.y == 0,
+                id: head.name,
+            })
+            .collect())
+    }
+
+    /// The heads turned on without a current mode get their preferred one. Since wlroots has no
+    /// notion of mirroring, cloned heads all sit at the origin of the desktop.
+    fn apply_topology(
+        &mut self,
+        topology: &DisplaysTopology,
+        active_displays: &[ConnectedDisplay],
+    ) -> ApplicationResult<()> {
+        trace_fn!();
+        info!("Applying displays topology");
+
+        let mut heads = self.wlroots_api.get_heads()?;
+        let mut x = 0;
+
+        for head in &mut heads {
+            head.is_enabled = false;
+        }
+
+        for display in active_displays {
+            let Some(head) = heads.iter_mut().find(|head| head.name == display.id) else {
+                continue;
+            };
+
+            head.is_enabled = true;
+            head.current_mode = head.current_mode.or(head.preferred_mode);
+            head.x = x;
+            head.y = 0;
+
+            if *topology != DisplaysTopology::Clone {
+                x += logical_width(head);
+            }
+        }
+
+        self.wlroots_api.apply_heads(&heads)
+    }
+
+    fn get_displays_modes(&mut self) -> ApplicationResult<Vec<DisplayModes>> {
+        trace_fn!();
+        info!("Getting displays modes");
+
+        Ok(self
+            .wlroots_api
+            .get_heads()?
+            .into_iter()
+            .filter(|head| head.is_enabled)
+            .map(|head| DisplayModes {
+                name: from_monitor_and_connector_names(Some(head.model), &head.name),
+                current_mode: head.current_mode.and_then(to_display_mode),
+                modes: head.modes.into_iter().filter_map(to_display_mode).collect(),
+                id: head.name,
+            })
+            .collect())
+    }
+
+    /// The other heads are applied as they are, so that they keep their position.
+    fn apply_display_mode(
+        &mut self,
+        display_modes: &DisplayModes,
+        mode: &DisplayMode,
+    ) -> ApplicationResult<()> {
+        trace_fn!();
+        info!("Applying display mode");
+
+        let mut heads = self.wlroots_api.get_heads()?;
+
+        for head in &mut heads {
+            if head.name != display_modes.id {
+                continue;
+            }
+
+            head.current_mode = head
+                .modes
+                .iter()
+                .find(|head_mode| to_display_mode(**head_mode) == Some(*mode))
+                .copied()
+                .or(head.current_mode);
+        }
+
+        self.wlroots_api.apply_heads(&heads)
+    }
+
+    fn apply_display_rotation(
+        &mut self,
+        display_info: &DisplayInfo,
+        rotation: DisplayRotation,
+    ) -> ApplicationResult<()> {
+        trace_fn!();
+        info!("Applying display rotation");
+
+        let mut heads = self.wlroots_api.get_heads()?;
+
+        for head in &mut heads {
+            if head.name == display_info.id {
+                head.transform = to_wl_output_transform(rotation, head.transform);
+            }
+        }
+
+        self.wlroots_api.apply_heads(&heads)
+    }
+
+    fn apply_display_scale(
+        &mut self,
+        display_info: &DisplayInfo,
+        scale_percent: u32,
+    ) -> ApplicationResult<()> {
+        trace_fn!();
+        info!("Applying display scale");
+
+        let mut heads = self.wlroots_api.get_heads()?;
+
+        for head in &mut heads {
+            if head.name == display_info.id {
+                head.scale = f64::from(scale_percent) / 100.0;
+            }
+        }
+
+        self.wlroots_api.apply_heads(&heads)
+    }
+}
+
+/// The modes whose size or refresh rate is unknown cannot be asked for.
+fn to_display_mode(mode: WlrootsMode) -> Option<DisplayMode> {
+    trace_fn!();
+
+    Some(DisplayMode {
+        resolution: DisplayResolution {
+            width: u32::try_from(mode.width).ok().filter(|width| *width > 0)?,
+            height: u32::try_from(mode.height)
+                .ok()
+                .filter(|height| *height > 0)?,
+        },
+        refresh_rate_millihertz: u32::try_from(mode.refresh_rate)
+            .ok()
+            .filter(|refresh_rate| *refresh_rate > 0)?,
+    })
+}
+
+/// The width a head takes on the desktop, once rotated and scaled.
+fn logical_width(head: &WlrootsHead) -> i32 {
+    trace_fn!();
+
+    let current_mode = head.current_mode.unwrap_or_default();
+    let width = match from_wl_output_transform(head.transform) {
+        DisplayRotation::Degrees90 | DisplayRotation::Degrees270 => current_mode.height,
+        DisplayRotation::Degrees0 | DisplayRotation::Degrees180 => current_mode.width,
+    };
+
+    if head.scale > 0.0 {
+        (f64::from(width) / head.scale).round() as i32
+    } else {
+        width
+    }
+}
+
+/// The mode of a head is the one of the panel, so its size is swapped when the head is rotated
+/// by a quarter turn.
+fn to_display_info(head: WlrootsHead) -> DisplayInfo {
+    trace_fn!();
+
+    let rotation = from_wl_output_transform(head.transform);
+    let current_mode = head.current_mode.unwrap_or_default();
+    let width = u32::try_from(current_mode.width).unwrap_or_default();
+    let height = u32::try_from(current_mode.height).unwrap_or_default();
+    let (width, height) = match rotation {
+        DisplayRotation::Degrees90 | DisplayRotation::Degrees270 => (height, width),
+        DisplayRotation::Degrees0 | DisplayRotation::Degrees180 => (width, height),
+    };
+
+    DisplayInfo {
+        is_primary: head.x == 0 && head.y == 0,
+        name: from_monitor_and_connector_names(Some(head.model), &head.name),
+        connector: from_connector_name(&head.name),
+        id: head.name,
+        resolution: DisplayResolution { width, height },
+        position: DisplayPosition {
+            x: head.x,
+            y: head.y,
+        },
+        refresh_rate_millihertz: u32::try_from(current_mode.refresh_rate)
+            .ok()
+            .filter(|refresh_rate| *refresh_rate > 0),
+        rotation,
+        scale_percent: from_scale_factor(head.scale),
+        advanced_color: None,
+    }
+}
+
+#[cfg(test)]
+mod should {
+    use std::{cell::RefCell, rc::Rc};
+
+    use test_case::test_case;
+
+    use crate::{
+        application_error::{ApplicationError, DeviceKind, DeviceRole},
+        application_result::ApplicationResult,
+        displays_settings::{
+            DisplayConnector, DisplayInfo, DisplayMode, DisplayModeRequest, DisplayPosition,
+            DisplayResolution, DisplayRotation, DisplayTransformRequest, DisplaysSettings,
+            DisplaysSettingsResult, DisplaysSnapshot, DisplaysTopology, DisplaysTopologyResult,
+            wlroots::{
+                wlroots_api::{WlrootsApi, WlrootsHead, WlrootsMode},
+                wlroots_display_settings::WlrootsDisplaySettings,
+            },
+        },
+    };
+
+    const MODE: WlrootsMode = WlrootsMode {
+        width: 1920,
+        height: 1080,
+        refresh_rate: 60000,
+    };
+
+    const TV_MODE: WlrootsMode = WlrootsMode {
+        width: 3840,
+        height: 2160,
+        refresh_rate: 59940,
+    };
+
+    struct FakeWlrootsApi {
+        heads: Rc<RefCell<Vec<WlrootsHead>>>,
+    }
+
+    impl WlrootsApi for FakeWlrootsApi {
+        fn get_heads(&mut self) -> ApplicationResult<Vec<WlrootsHead>> {
+            Ok(self.heads.borrow().clone())
+        }
+
+        fn apply_heads(&mut self, heads: &[WlrootsHead]) -> ApplicationResult<()> {
+            *self.heads.borrow_mut() = heads.to_vec();
+
+            Ok(())
+        }
+    }
+
+    fn head(name: &str, model: &str, is_enabled: bool, x: i32, y: i32) -> WlrootsHead {
+        WlrootsHead {
+            name: String::from(name),
+            model: String::from(model),
+            is_enabled,
+            x,
+            y,
+            current_mode: is_enabled.then_some(MODE),
+            preferred_mode: Some(MODE),
+            modes: vec![MODE, TV_MODE],
+            transform: 0,
+            scale: 1.0,
+        }
+    }
+
+    fn wlroots_display_settings() -> (WlrootsDisplaySettings, Rc<RefCell<Vec<WlrootsHead>>>) {
+        let heads = Rc::new(RefCell::new(vec![
+            head("eDP-1", "", true, 0, 0),
+            head("HDMI-A-1", "LG TV SSCR2", true, 1920, 0),
+            head("DP-1", "DELL U2723QE", false, 0, 0),
+        ]));
+        let wlroots_api = FakeWlrootsApi {
+            heads: Rc::clone(&heads),
+        };
+
+        (WlrootsDisplaySettings::new(Box::new(wlroots_api)), heads)
+    }
+
+    #[test]
+    fn get_the_enabled_heads_with_their_monitor_names() {
+        // Arrange
+        let (mut wlroots_display_settings, _) = wlroots_display_settings();
+
+        // Act
+        let displays_infos = wlroots_display_settings.get_displays_infos();
+
+        // Assert
+        assert_eq!(
+            displays_infos,
+            Ok(vec![
+                DisplayInfo {
+                    is_primary: true,
+                    name: String::from("Internal Display"),
+                    id: String::from("eDP-1"),
+                    connector: DisplayConnector::Internal,
+                    resolution: DisplayResolution {
+                        width: 1920,
+                        height: 1080,
+                    },
+                    position: DisplayPosition { x: 0, y: 0 },
+                    refresh_rate_millihertz: Some(60000),
+                    rotation: DisplayRotation::Degrees0,
+                    scale_percent: Some(100),
+                    advanced_color: None,
+                },
+                DisplayInfo {
+                    is_primary: false,
+                    name: String::from("LG TV SSCR2"),
+                    id: String::from("HDMI-A-1"),
+                    connector: DisplayConnector::Hdmi,
+                    resolution: DisplayResolution {
+                        width: 1920,
+                        height: 1080,
+                    },
+                    position: DisplayPosition { x: 1920, y: 0 },
+                    refresh_rate_millihertz: Some(60000),
+                    rotation: DisplayRotation::Degrees0,
+                    scale_percent: Some(100),
+                    advanced_color: None,
+                },
+            ])
+        );
+    }
+
+    #[test_case(1, 2.0 => (DisplayResolution { width: 1080, height: 1920 }, DisplayRotation::Degrees270, Some(200)); "when the head is rotated counter clockwise")]
+    #[test_case(2, 1.0 => (DisplayResolution { width: 1920, height: 1080 }, DisplayRotation::Degrees180, Some(100)); "when the head is upside down")]
+    #[test_case(3, 1.5 => (DisplayResolution { width: 1080, height: 1920 }, DisplayRotation::Degrees90, Some(150)); "when the head is rotated clockwise")]
+    fn get_the_transform_and_the_scale_of_the_heads(
+        transform: u32,
+        scale: f64,
+    ) -> (DisplayResolution, DisplayRotation, Option<u32>) {
+        // Arrange
+        let (mut wlroots_display_settings, heads) = wlroots_display_settings();
+        heads.borrow_mut()[1].transform = transform;
+        heads.borrow_mut()[1].scale = scale;
+
+        // Act
+        let displays_infos = wlroots_display_settings.get_displays_infos().unwrap();
+
+        // Assert
+        (
+            displays_infos[1].resolution,
+            displays_infos[1].rotation,
+            displays_infos[1].scale_percent,
+        )
+    }
+
+    #[test]
+    fn move_the_couch_head_to_the_origin_and_re_base_the_other_heads() {
+        // Arrange
+        let (mut wlroots_display_settings, heads) = wlroots_display_settings();
+
+        // Act
+        let result =
+            wlroots_display_settings.swap_primary_display("Internal Display", "LG TV SSCR2");
+
+        // Assert
+        assert_eq!(
+            result,
+            Ok(DisplaysSettingsResult {
+                new_primary_display: String::from("LG TV SSCR2"),
+                new_mode: None,
+                new_rotation: None,
+                new_scale_percent: None,
+                new_hdr_enabled: None,
+            })
+        );
+        assert_eq!(
+            *heads.borrow(),
+            vec![
+                head("eDP-1", "", true, -1920, 0),
+                head("HDMI-A-1", "LG TV SSCR2", true, 0, 0),
+                head("DP-1", "DELL U2723QE", false, 0, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn turn_on_the_only_head_kept_with_its_preferred_mode_and_turn_off_the_other_heads() {
+        // Arrange
+        let (mut wlroots_display_settings, heads) = wlroots_display_settings();
+
+        // Act
+        let result = wlroots_display_settings
+            .set_topology(&DisplaysTopology::Only(String::from("DELL U2723QE")));
+
+        // Assert
+        assert_eq!(
+            result,
+            Ok(DisplaysTopologyResult {
+                active_displays: vec![String::from("DELL U2723QE")],
+                is_cloned: false,
+            })
+        );
+        assert_eq!(
+            *heads.borrow(),
+            vec![
+                WlrootsHead {
+                    current_mode: Some(MODE),
+                    ..head("eDP-1", "", false, 0, 0)
+                },
+                WlrootsHead {
+                    current_mode: Some(MODE),
+                    ..head("HDMI-A-1", "LG TV SSCR2", false, 1920, 0)
+                },
+                head("DP-1", "DELL U2723QE", true, 0, 0),
+            ]
+        );
+    }
+
+    #[test_case(DisplaysTopology::Extend => vec![(String::from("eDP-1"), 0), (String::from("HDMI-A-1"), 3200), (String::from("DP-1"), 1280)]; "when the desktop is extended")]
+    #[test_case(DisplaysTopology::Clone => vec![(String::from("eDP-1"), 0), (String::from("HDMI-A-1"), 0), (String::from("DP-1"), 0)]; "when the desktop is cloned")]
+    fn lay_out_the_heads_of_a_topology(topology: DisplaysTopology) -> Vec<(String, i32)> {
+        // Arrange
+        let (mut wlroots_display_settings, heads) = wlroots_display_settings();
+        heads.borrow_mut()[0].scale = 1.5;
+
+        // Act
+        wlroots_display_settings.set_topology(&topology).unwrap();
+
+        // Assert
+        heads
+            .borrow()
+            .clone()
+            .into_iter()
+            .inspect(|head| assert!(head.is_enabled))
+            .map(|head| (head.name, head.x))
+            .collect()
+    }
+
+    #[test]
+    fn restore_the_heads_of_a_snapshot() {
+        // Arrange
+        let (mut wlroots_display_settings, heads) = wlroots_display_settings();
+        let snapshot = wlroots_display_settings.save_snapshot().unwrap();
+        wlroots_display_settings
+            .swap_primary_display("Internal Display", "LG TV SSCR2")
+            .unwrap();
+
+        // Act
+        let result = wlroots_display_settings.restore_snapshot(&snapshot);
+
+        // Assert
+        assert_eq!(result, Ok(()));
+        assert_eq!(
+            *heads.borrow(),
+            vec![
+                head("eDP-1", "", true, 0, 0),
+                head("HDMI-A-1", "LG TV SSCR2", true, 1920, 0),
+                head("DP-1", "DELL U2723QE", false, 0, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn fail_to_restore_a_snapshot_whose_heads_are_not_connected_anymore() {
+        // Arrange
+        let (mut wlroots_display_settings, heads) = wlroots_display_settings();
+        let snapshot = DisplaysSnapshot::Wlroots {
+            heads: vec![head("DP-2", "Epson EF-12", true, 0, 0)],
+        };
+
+        // Act
+        let result = wlroots_display_settings.restore_snapshot(&snapshot);
+
+        // Assert
+        assert_eq!(
+            result,
+            Err(ApplicationError::Snapshot {
+                message: String::from("The display DP-2 of the snapshot is not connected anymore"),
+                source: None,
+            })
+        );
+        assert_eq!(
+            heads.borrow()[1],
+            head("HDMI-A-1", "LG TV SSCR2", true, 1920, 0)
+        );
+    }
+
+    #[test]
+    fn drive_the_head_at_the_mode_whose_refresh_rate_rounds_to_the_one_asked() {
+        // Arrange
+        let (mut wlroots_display_settings, heads) = wlroots_display_settings();
+        let mode_request = DisplayModeRequest {
+            resolution: Some(DisplayResolution {
+                width: 3840,
+                height: 2160,
+            }),
+            refresh_rate_hertz: Some(60),
+        };
+
+        // Act
+        let result = wlroots_display_settings.set_display_mode("LG TV SSCR2", &mode_request);
+
+        // Assert
+        assert_eq!(
+            result,
+            Ok(DisplayMode {
+                resolution: DisplayResolution {
+                    width: 3840,
+                    height: 2160,
+                },
+                refresh_rate_millihertz: 59940,
+            })
+        );
+        assert_eq!(heads.borrow()[1].current_mode, Some(TV_MODE));
+        assert_eq!(heads.borrow()[0].current_mode, Some(MODE));
+    }
+
+    #[test]
+    fn rotate_and_scale_the_head_keeping_the_other_heads_as_they_are() {
+        // Arrange
+        let (mut wlroots_display_settings, heads) = wlroots_display_settings();
+        let transform_request = DisplayTransformRequest {
+            rotation: Some(DisplayRotation::Degrees90),
+            scale_percent: Some(150),
+        };
+
+        // Act
+        let result =
+            wlroots_display_settings.set_display_transform("LG TV SSCR2", &transform_request);
+
+        // Assert
+        assert_eq!(result, Ok(()));
+        assert_eq!(heads.borrow()[1].transform, 3);
+        assert_eq!(heads.borrow()[1].scale, 1.5);
+        assert_eq!(heads.borrow()[0], head("eDP-1", "", true, 0, 0));
+    }
+
+    #[test]
+    fn fail_to_drive_the_head_at_a_mode_it_does_not_support() {
+        // Arrange
+        let (mut wlroots_display_settings, heads) = wlroots_display_settings();
+        let mode_request = DisplayModeRequest {
+            resolution: Some(DisplayResolution {
+                width: 3840,
+                height: 2160,
+            }),
+            refresh_rate_hertz: Some(144),
+        };
+
+        // Act
+        let result = wlroots_display_settings.set_display_mode("LG TV SSCR2", &mode_request);
+
+        // Assert
+        assert_eq!(
+            result,
+            Err(ApplicationError::UnsupportedMode {
+                display: String::from("LG TV SSCR2"),
+                requested: String::from("3840x2160@144"),
+                supported: vec![
+                    String::from("3840x2160@59.94"),
+                    String::from("1920x1080@60")
+                ],
+            })
+        );
+        assert_eq!(heads.borrow()[1].current_mode, Some(MODE));
+    }
+
+    #[test_case("Unknown Monitor", "LG TV SSCR2" => Err(ApplicationError::DeviceNotFound { kind: DeviceKind::Display, role: DeviceRole::Desktop, requested: vec![String::from("Unknown Monitor")], candidates: vec![String::from("Internal Display"), String::from("LG TV SSCR2")], suggestions: Vec::new() }); "when the desktop display is invalid")]
+    #[test_case("Internal Display", "DELL U2723QE" => Err(ApplicationError::DeviceNotFound { kind: DeviceKind::Display, role: DeviceRole::Couch, requested: vec![String::from("DELL U2723QE")], candidates: vec![String::from("Internal Display"), String::from("LG TV SSCR2")], suggestions: Vec::new() }); "when the couch display is disabled")]
+    #[test_case("Unknown Monitor", "Unknown TV" => Err(ApplicationError::DeviceNotFound { kind: DeviceKind::Display, role: DeviceRole::DesktopAndCouch, requested: vec![String::from("Unknown Monitor"), String::from("Unknown TV")], candidates: vec![String::from("Internal Display"), String::from("LG TV SSCR2")], suggestions: Vec::new() }); "when both displays are invalid")]
+    fn fail_to_change_the_primary_display(
+        desktop_display_name: &str,
+        couch_display_name: &str,
+    ) -> ApplicationResult<DisplaysSettingsResult> {
+        // Arrange
+        let (mut wlroots_display_settings, _) = wlroots_display_settings();
+
+        // Act
+        wlroots_display_settings.swap_primary_display(desktop_display_name, couch_display_name)
+    }
+}
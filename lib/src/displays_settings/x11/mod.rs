@@ -0,0 +1,3 @@
+pub mod x11_api;
+pub mod x11_display_settings;
+pub mod xrandr_based_x11_api;
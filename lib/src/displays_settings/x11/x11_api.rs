@@ -0,0 +1,9 @@
+use crate::application_result::ApplicationResult;
+
+pub trait X11Api {
+    /// Runs `xrandr` against the current X display.
+    ///
+    /// This is a thin wrapper around the `xrandr` executable: the arguments are forwarded as is
+    /// and its standard output is returned without being parsed.
+    fn xrandr(&mut self, args: &[String]) -> ApplicationResult<String>;
+}
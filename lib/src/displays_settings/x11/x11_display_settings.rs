@@ -0,0 +1,1085 @@
+use log::info;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    application_error::{ApplicationError, DeviceKind, DeviceRole},
+    application_result::ApplicationResult,
+    displays_settings::{
+        ConnectedDisplay, DisplayInfo, DisplayMode, DisplayModes, DisplayPosition,
+        DisplayResolution, DisplayRotation, DisplaysSettings, DisplaysSettingsResult,
+        DisplaysSnapshot, DisplaysTopology, ensure_snapshot_displays_are_connected,
+        from_connector_name, from_monitor_and_connector_names, from_refresh_rate_in_hertz,
+        x11::x11_api::X11Api,
+    },
+    trace_fn,
+};
+
+const EDID_DESCRIPTORS_OFFSETS: [usize; 4] = [54, 72, 90, 108];
+const EDID_DESCRIPTOR_LENGTH: usize = 18;
+const EDID_MONITOR_NAME_TAG: u8 = 0xFC;
+
+pub struct X11DisplaySettings {
+    x11_api: Box<dyn X11Api>,
+}
+
+#[derive(Debug, PartialEq)]
+struct XrandrOutput {
+    name: String,
+    display_name: String,
+    is_active: bool,
+    is_primary: bool,
+    width: u32,
+    height: u32,
+    x: i32,
+    y: i32,
+    refresh_rate_millihertz: Option<u32>,
+    rotation: DisplayRotation,
+    current_mode: Option<DisplayMode>,
+    modes: Vec<DisplayMode>,
+}
+
+/// The state of an output as `xrandr` applies it, the resolution being the one of its mode so
+/// before the rotation.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct X11OutputSnapshot {
+    pub name: String,
+    pub is_primary: bool,
+    pub mode: DisplayResolution,
+    pub position: DisplayPosition,
+    pub refresh_rate_millihertz: Option<u32>,
+    pub rotation: DisplayRotation,
+}
+
+impl X11DisplaySettings {
+    pub fn new(displays_settings_api: Box<dyn X11Api>) -> Self {
+        trace_fn!();
+
+        Self {
+            x11_api: displays_settings_api,
+        }
+    }
+
+    fn query_connected_outputs(&mut self) -> ApplicationResult<Vec<XrandrOutput>> {
+        trace_fn!();
+
+        let query = self.x11_api.xrandr(&[String::from("--props")])?;
+
+        Ok(parse_xrandr_query(&query))
+    }
+
+    /// Only the outputs driven by a CRTC are returned, just like the active paths on Windows.
+    fn query_outputs(&mut self) -> ApplicationResult<Vec<XrandrOutput>> {
+        trace_fn!();
+
+        Ok(self
+            .query_connected_outputs()?
+            .into_iter()
+            .filter(|output| output.is_active)
+            .collect())
+    }
+}
+
+impl DisplaysSettings for X11DisplaySettings {
+    fn change_primary_display(
+        &mut self,
+        desktop_display_id: &str,
+        couch_display_id: &str,
+    ) -> ApplicationResult<DisplaysSettingsResult> {
+        trace_fn!();
+        info!("Changing primary display");
+
+        let outputs = self.query_outputs()?;
+
+        let mut new_position = (0, 0);
+        let mut new_primary_output_name = String::default();
+        let mut new_primary_display_name = String::default();
+        let mut desktop_display_id_is_valid = false;
+        let mut couch_display_id_is_valid = false;
+        let mut possible_ids = Vec::with_capacity(outputs.len());
+
+        // The outputs are told apart by their names, which are their ids.
+        for output in &outputs {
+            possible_ids.push(output.name.clone());
+
+            if output.name != desktop_display_id && output.name != couch_display_id {
+                continue;
+            }
+
+            if output.name == desktop_display_id {
+                desktop_display_id_is_valid = true;
+            }
+
+            if output.name == couch_display_id {
+                couch_display_id_is_valid = true;
+            }
+
+            if !output.is_primary {
+                new_position = (output.x, output.y);
+                new_primary_output_name = output.name.clone();
+                new_primary_display_name = output.display_name.clone();
+            }
+        }
+
+        let invalid_params = match (desktop_display_id_is_valid, couch_display_id_is_valid) {
+            (false, false) => Some((
+                DeviceRole::DesktopAndCouch,
+                vec![desktop_display_id, couch_display_id],
+            )),
+            (false, _) => Some((DeviceRole::Desktop, vec![desktop_display_id])),
+            (_, false) => Some((DeviceRole::Couch, vec![couch_display_id])),
+            _ => None,
+        };
+
+        if let Some((invalid_role, invalid_ids)) = invalid_params {
+            let error = ApplicationError::device_not_found(
+                DeviceKind::Display,
+                invalid_role,
+                &invalid_ids,
+                possible_ids,
+            );
+
+            return Err(error);
+        }
+
+        let mut args = Vec::with_capacity(outputs.len() * 5);
+
+        for output in &outputs {
+            args.push(String::from("--output"));
+            args.push(output.name.clone());
+            args.push(String::from("--pos"));
+            args.push(format!(
+                "{}x{}",
+                output.x - new_position.0,
+                output.y - new_position.1
+            ));
+
+            if output.name == new_primary_output_name {
+                args.push(String::from("--primary"));
+            }
+        }
+
+        self.x11_api.xrandr(&args)?;
+
+        Ok(DisplaysSettingsResult {
+            new_primary_display: new_primary_display_name,
+            new_mode: None,
+            new_rotation: None,
+            new_scale_percent: None,
+            new_hdr_enabled: None,
+        })
+    }
+
+    fn get_displays_infos(&mut self) -> ApplicationResult<Vec<DisplayInfo>> {
+        trace_fn!();
+        info!("Getting displays informations");
+
+        let mut displays_infos = self
+            .query_outputs()?
+            .into_iter()
+            .map(|output| DisplayInfo {
+                is_primary: output.is_primary,
+                name: output.display_name,
+                connector: from_connector_name(&output.name),
+                id: output.name,
+                resolution: DisplayResolution {
+                    width: output.width,
+                    height: output.height,
+                },
+                position: DisplayPosition {
+                    x: output.x,
+                    y: output.y,
+                },
+                refresh_rate_millihertz: output.refresh_rate_millihertz,
+                rotation: output.rotation,
+                scale_percent: None,
+                advanced_color: None,
+            })
+            .collect::<Vec<DisplayInfo>>();
+
+        displays_infos.sort();
+
+        Ok(displays_infos)
+    }
+
+    fn save_snapshot(&mut self) -> ApplicationResult<DisplaysSnapshot> {
+        trace_fn!();
+        info!("Saving displays snapshot");
+
+        let outputs = self
+            .query_outputs()?
+            .into_iter()
+            .map(|output| {
+                let (width, height) = match output.rotation {
+                    DisplayRotation::Degrees90 | DisplayRotation::Degrees270 => {
+                        (output.height, output.width)
+                    }
+                    DisplayRotation::Degrees0 | DisplayRotation::Degrees180 => {
+                        (output.width, output.height)
+                    }
+                };
+
+                X11OutputSnapshot {
+                    name: output.name,
+                    is_primary: output.is_primary,
+                    mode: DisplayResolution { width, height },
+                    position: DisplayPosition {
+                        x: output.x,
+                        y: output.y,
+                    },
+                    refresh_rate_millihertz: output.refresh_rate_millihertz,
+                    rotation: output.rotation,
+                }
+            })
+            .collect();
+
+        Ok(DisplaysSnapshot::X11 { outputs })
+    }
+
+    fn restore_snapshot(&mut self, snapshot: &DisplaysSnapshot) -> ApplicationResult<()> {
+        trace_fn!();
+        info!("Restoring displays snapshot");
+
+        let DisplaysSnapshot::X11 {
+            outputs: snapshot_outputs,
+        } = snapshot
+        else {
+            return Err(ApplicationError::snapshot_of_another_backend(
+                DeviceKind::Display,
+                snapshot.backend_name(),
+                "x11",
+            ));
+        };
+
+        let outputs = self.query_connected_outputs()?;
+        let outputs_names = outputs
+            .iter()
+            .map(|output| output.name.as_str())
+            .collect::<Vec<&str>>();
+
+        ensure_snapshot_displays_are_connected(
+            snapshot_outputs.iter().map(|output| output.name.as_str()),
+            &outputs_names,
+        )?;
+
+        let mut args = Vec::with_capacity(outputs.len() * 11);
+
+        for output in snapshot_outputs {
+            args.push(String::from("--output"));
+            args.push(output.name.clone());
+            args.push(String::from("--mode"));
+            args.push(format!("{}x{}", output.mode.width, output.mode.height));
+
+            if let Some(refresh_rate_millihertz) = output.refresh_rate_millihertz {
+                args.push(String::from("--rate"));
+                args.push(to_xrandr_rate(refresh_rate_millihertz));
+            }
+
+            args.push(String::from("--rotate"));
+            args.push(String::from(to_xrandr_rotation(output.rotation)));
+            args.push(String::from("--pos"));
+            args.push(format!("{}x{}", output.position.x, output.position.y));
+
+            if output.is_primary {
+                args.push(String::from("--primary"));
+            }
+        }
+
+        push_turned_off_outputs(
+            &mut args,
+            &outputs,
+            snapshot_outputs.iter().map(|output| output.name.as_str()),
+        );
+
+        self.x11_api.xrandr(&args)?;
+
+        Ok(())
+    }
+
+    fn get_connected_displays(&mut self) -> ApplicationResult<Vec<ConnectedDisplay>> {
+        trace_fn!();
+        info!("Getting connected displays");
+
+        Ok(self
+            .query_connected_outputs()?
+            .into_iter()
+            .map(|output| ConnectedDisplay {
+                name: output.display_name,
+                connector: from_connector_name(&output.name),
+                id: output.name,
+                is_active: output.is_active,
+                is_primary: output.is_primary,
+            })
+            .collect())
+    }
+
+    /// The outputs turned on get their preferred mode, each one being placed to the right of the
+    /// previous one or at the same position as the primary one.
+    fn apply_topology(
+        &mut self,
+        topology: &DisplaysTopology,
+        active_displays: &[ConnectedDisplay],
+    ) -> ApplicationResult<()> {
+        trace_fn!();
+        info!("Applying displays topology");
+
+        let outputs = self.query_connected_outputs()?;
+        let mut args = Vec::with_capacity(outputs.len() * 5);
+        let mut previous_output_name = None;
+
+        for display in active_displays {
+            args.push(String::from("--output"));
+            args.push(display.id.clone());
+            args.push(String::from("--auto"));
+
+            match (previous_output_name, topology) {
+                (None, _) => {
+                    args.push(String::from("--pos"));
+                    args.push(String::from("0x0"));
+                    args.push(String::from("--primary"));
+                }
+                (Some(_), DisplaysTopology::Clone) => {
+                    args.push(String::from("--same-as"));
+                    args.push(active_displays[0].id.clone());
+                }
+                (Some(previous_output_name), _) => {
+                    args.push(String::from("--right-of"));
+                    args.push(String::from(previous_output_name));
+                }
+            }
+
+            previous_output_name = Some(display.id.as_str());
+        }
+
+        push_turned_off_outputs(
+            &mut args,
+            &outputs,
+            active_displays.iter().map(|display| display.id.as_str()),
+        );
+
+        self.x11_api.xrandr(&args)?;
+
+        Ok(())
+    }
+
+    fn get_displays_modes(&mut self) -> ApplicationResult<Vec<DisplayModes>> {
+        trace_fn!();
+        info!("Getting displays modes");
+
+        Ok(self
+            .query_outputs()?
+            .into_iter()
+            .map(|output| DisplayModes {
+                name: output.display_name,
+                id: output.name,
+                current_mode: output.current_mode,
+                modes: output.modes,
+            })
+            .collect())
+    }
+
+    fn apply_display_mode(
+        &mut self,
+        display_modes: &DisplayModes,
+        mode: &DisplayMode,
+    ) -> ApplicationResult<()> {
+        trace_fn!();
+        info!("Applying display mode");
+
+        self.x11_api.xrandr(&[
+            String::from("--output"),
+            display_modes.id.clone(),
+            String::from("--mode"),
+            mode.resolution.to_string(),
+            String::from("--rate"),
+            to_xrandr_rate(mode.refresh_rate_millihertz),
+        ])?;
+
+        Ok(())
+    }
+
+    fn apply_display_rotation(
+        &mut self,
+        display_info: &DisplayInfo,
+        rotation: DisplayRotation,
+    ) -> ApplicationResult<()> {
+        trace_fn!();
+        info!("Applying display rotation");
+
+        self.x11_api.xrandr(&[
+            String::from("--output"),
+            display_info.id.clone(),
+            String::from("--rotate"),
+            String::from(to_xrandr_rotation(rotation)),
+        ])?;
+
+        Ok(())
+    }
+}
+
+fn to_xrandr_rotation(rotation: DisplayRotation) -> &'static str {
+    trace_fn!();
+
+    match rotation {
+        DisplayRotation::Degrees0 => "normal",
+        DisplayRotation::Degrees90 => "right",
+        DisplayRotation::Degrees180 => "inverted",
+        DisplayRotation::Degrees270 => "left",
+    }
+}
+
+fn to_xrandr_rate(refresh_rate_millihertz: u32) -> String {
+    trace_fn!();
+
+    format!(
+        "{}.{:03}",
+        refresh_rate_millihertz / 1000,
+        refresh_rate_millihertz % 1000
+    )
+}
+
+/// Turns off the connected outputs which are not among the ones kept on.
+fn push_turned_off_outputs<'a>(
+    args: &mut Vec<String>,
+    outputs: &[XrandrOutput],
+    kept_outputs_names: impl Iterator<Item = &'a str>,
+) {
+    trace_fn!();
+
+    let kept_outputs_names = kept_outputs_names.collect::<Vec<&str>>();
+
+    for output in outputs
+        .iter()
+        .filter(|output| !kept_outputs_names.contains(&output.name.as_str()))
+    {
+        args.push(String::from("--output"));
+        args.push(output.name.clone());
+        args.push(String::from("--off"));
+    }
+}
+
+/// Only the outputs that are connected are returned, the ones which are not driven by a CRTC
+/// having neither a geometry nor a refresh rate.
+fn parse_xrandr_query(query: &str) -> Vec<XrandrOutput> {
+    trace_fn!();
+
+    let mut outputs = Vec::new();
+    let mut lines = query.lines().peekable();
+
+    while let Some(line) = lines.next() {
+        if line.starts_with(char::is_whitespace) || line.starts_with("Screen ") {
+            continue;
+        }
+
+        let mut edid = String::new();
+        let mut is_reading_edid = false;
+        let mut current_mode = None;
+        let mut modes = Vec::new();
+
+        while let Some(property_line) = lines.next_if(|line| line.starts_with(char::is_whitespace))
+        {
+            let property_line = property_line.trim();
+
+            if property_line == "EDID:" {
+                is_reading_edid = true;
+            } else if is_reading_edid && property_line.bytes().all(|b| b.is_ascii_hexdigit()) {
+                edid.push_str(property_line);
+            } else {
+                is_reading_edid = false;
+
+                let (line_modes, line_current_mode) = parse_modes(property_line);
+
+                modes.extend(line_modes);
+                current_mode = current_mode.or(line_current_mode);
+            }
+        }
+
+        let mut fields = line.split_whitespace();
+
+        let (Some(name), Some("connected")) = (fields.next(), fields.next()) else {
+            continue;
+        };
+
+        let fields = fields.collect::<Vec<&str>>();
+        let is_primary = fields.first() == Some(&"primary");
+
+        let geometry_index = fields
+            .iter()
+            .position(|field| parse_geometry(field).is_some());
+        let (width, height, x, y) = geometry_index
+            .and_then(|geometry_index| parse_geometry(fields[geometry_index]))
+            .unwrap_or_default();
+        let rotation =
+            match geometry_index.and_then(|geometry_index| fields.get(geometry_index + 1)) {
+                Some(&"left") => DisplayRotation::Degrees270,
+                Some(&"inverted") => DisplayRotation::Degrees180,
+                Some(&"right") => DisplayRotation::Degrees90,
+                _ => DisplayRotation::Degrees0,
+            };
+
+        let monitor_name = decode_hex(&edid).and_then(|edid| edid_monitor_name(&edid));
+
+        outputs.push(XrandrOutput {
+            name: String::from(name),
+            display_name: from_monitor_and_connector_names(monitor_name, name),
+            is_active: geometry_index.is_some(),
+            is_primary,
+            width,
+            height,
+            x,
+            y,
+            refresh_rate_millihertz: current_mode
+                .map(|current_mode: DisplayMode| current_mode.refresh_rate_millihertz),
+            rotation,
+            current_mode,
+            modes,
+        });
+    }
+
+    outputs
+}
+
+/// Parses the `<width>x<height>+<x>+<y>` geometry of an output, the size being the rotated one.
+fn parse_geometry(field: &str) -> Option<(u32, u32, i32, i32)> {
+    trace_fn!();
+
+    let mut parts = field.split('+');
+    let (width, height) = parts.next()?.split_once('x')?;
+
+    let width = width.parse::<u32>().ok()?;
+    let height = height.parse::<u32>().ok()?;
+
+    let x = parts.next()?.parse::<i32>().ok()?;
+    let y = parts.next()?.parse::<i32>().ok()?;
+
+    if parts.next().is_some() {
+        return None;
+    }
+
+    Some((width, height, x, y))
+}
+
+/// Reads the modes of a mode line such as `1920x1080 60.02*+ 59.97`, along with the current one
+/// flagged with a `*`. The lines which are not mode lines have no mode.
+fn parse_modes(mode_line: &str) -> (Vec<DisplayMode>, Option<DisplayMode>) {
+    trace_fn!();
+
+    let mut fields = mode_line.split_whitespace();
+    let Some(resolution) = fields
+        .next()
+        .and_then(|field| field.parse::<DisplayResolution>().ok())
+    else {
+        return (Vec::new(), None);
+    };
+
+    let mut modes = Vec::new();
+    let mut current_mode = None;
+
+    for rate in fields {
+        let Some(refresh_rate_millihertz) = rate
+            .trim_end_matches(['*', '+'])
+            .parse::<f64>()
+            .ok()
+            .and_then(from_refresh_rate_in_hertz)
+        else {
+            continue;
+        };
+
+        let mode = DisplayMode {
+            resolution,
+            refresh_rate_millihertz,
+        };
+
+        if rate.contains('*') {
+            current_mode = Some(mode);
+        }
+
+        modes.push(mode);
+    }
+
+    (modes, current_mode)
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    trace_fn!();
+
+    if hex.is_empty() || !hex.len().is_multiple_of(2) {
+        return None;
+    }
+
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+        .collect()
+}
+
+/// Reads the monitor name from the display descriptors of an EDID base block.
+fn edid_monitor_name(edid: &[u8]) -> Option<String> {
+    trace_fn!();
+
+    EDID_DESCRIPTORS_OFFSETS.iter().find_map(|offset| {
+        let descriptor = edid.get(*offset..*offset + EDID_DESCRIPTOR_LENGTH)?;
+
+        if descriptor[..3] != [0, 0, 0] || descriptor[3] != EDID_MONITOR_NAME_TAG {
+            return None;
+        }
+
+        let monitor_name = descriptor[5..]
+            .iter()
+            .take_while(|byte| **byte != b'\n')
+            .map(|byte| char::from(*byte))
+            .collect::<String>();
+
+        Some(String::from(monitor_name.trim_end()))
+    })
+}
+
+#[cfg(test)]
+mod should {
+    use std::{cell::RefCell, rc::Rc};
+
+    use test_case::test_case;
+
+    use crate::{
+        application_error::{ApplicationError, DeviceKind, DeviceRole},
+        application_result::ApplicationResult,
+        displays_settings::{
+            ConnectedDisplay, DisplayConnector, DisplayInfo, DisplayModeRequest, DisplayPosition,
+            DisplayResolution, DisplayRotation, DisplayTransformRequest, DisplaysSettings,
+            DisplaysSettingsResult, DisplaysSnapshot, DisplaysTopology,
+            x11::{x11_api::X11Api, x11_display_settings::X11DisplaySettings},
+        },
+    };
+
+    struct FakeX11Api {
+        query: String,
+        calls: Rc<RefCell<Vec<Vec<String>>>>,
+    }
+
+    impl X11Api for FakeX11Api {
+        fn xrandr(&mut self, args: &[String]) -> ApplicationResult<String> {
+            self.calls.borrow_mut().push(args.to_vec());
+
+            Ok(self.query.clone())
+        }
+    }
+
+    fn edid(monitor_name: &str) -> String {
+        let mut edid = [0u8; 128];
+
+        edid[..8].copy_from_slice(&[0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00]);
+        edid[72 + 3] = 0xFC;
+
+        let mut name = monitor_name.as_bytes().to_vec();
+        name.push(b'\n');
+        name.resize(13, b' ');
+        edid[72 + 5..72 + 18].copy_from_slice(&name);
+
+        edid.chunks(16)
+            .map(|line| {
+                let hex = line
+                    .iter()
+                    .map(|byte| format!("{byte:02x}"))
+                    .collect::<String>();
+
+                format!("\t\t{hex}\n")
+            })
+            .collect()
+    }
+
+    fn xrandr_query() -> String {
+        format!(
+            "Screen 0: minimum 320 x 200, current 5760 x 2160, maximum 16384 x 16384\n\
+             eDP-1 connected primary 1920x1080+0+0 (normal left inverted right x axis y axis) 344mm x 194mm\n\
+             \tEDID: \n\
+             {}\
+             \tscaling mode: Full aspect \n\
+             \t\tsupported: Full, Center, Full aspect\n\
+             \x20  1920x1080     60.02*+  59.97    50.01  \n\
+             HDMI-1 connected 3840x2160+1920+0 (normal left inverted right x axis y axis) 1600mm x 900mm\n\
+             \tEDID: \n\
+             {}\
+             \tmax bpc: 12 \n\
+             \x20  3840x2160     60.00*+  30.00    25.00  \n\
+             DP-1 connected (normal left inverted right x axis y axis)\n\
+             \tEDID: \n\
+             {}\
+             \x20  2560x1440     59.95 +\n\
+             DP-2 disconnected (normal left inverted right x axis y axis)\n",
+            edid(""),
+            edid("LG TV SSCR2"),
+            edid("DELL U2723QE"),
+        )
+    }
+
+    fn x11_display_settings(query: String) -> (X11DisplaySettings, Rc<RefCell<Vec<Vec<String>>>>) {
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let x11_api = FakeX11Api {
+            query,
+            calls: Rc::clone(&calls),
+        };
+
+        (X11DisplaySettings::new(Box::new(x11_api)), calls)
+    }
+
+    #[test]
+    fn get_the_active_outputs_with_their_monitor_names() {
+        // Arrange
+        let (mut x11_display_settings, _) = x11_display_settings(xrandr_query());
+
+        // Act
+        let displays_infos = x11_display_settings.get_displays_infos();
+
+        // Assert
+        assert_eq!(
+            displays_infos,
+            Ok(vec![
+                DisplayInfo {
+                    name: String::from("Internal Display"),
+                    is_primary: true,
+                    id: String::from("eDP-1"),
+                    connector: DisplayConnector::Internal,
+                    resolution: DisplayResolution {
+                        width: 1920,
+                        height: 1080,
+                    },
+                    position: DisplayPosition { x: 0, y: 0 },
+                    refresh_rate_millihertz: Some(60020),
+                    rotation: DisplayRotation::Degrees0,
+                    scale_percent: None,
+                    advanced_color: None,
+                },
+                DisplayInfo {
+                    name: String::from("LG TV SSCR2"),
+                    is_primary: false,
+                    id: String::from("HDMI-1"),
+                    connector: DisplayConnector::Hdmi,
+                    resolution: DisplayResolution {
+                        width: 3840,
+                        height: 2160,
+                    },
+                    position: DisplayPosition { x: 1920, y: 0 },
+                    refresh_rate_millihertz: Some(60000),
+                    rotation: DisplayRotation::Degrees0,
+                    scale_percent: None,
+                    advanced_color: None,
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn name_outputs_without_edid_after_their_connector() {
+        // Arrange
+        let query = String::from(
+            "Screen 0: minimum 1 x 1, current 1280 x 1024, maximum 1280 x 1024\n\
+             screen connected primary 1280x1024+0+0 0mm x 0mm\n\
+             \x20  1280x1024      0.00* \n",
+        );
+        let (mut x11_display_settings, _) = x11_display_settings(query);
+
+        // Act
+        let displays_infos = x11_display_settings.get_displays_infos();
+
+        // Assert
+        assert_eq!(
+            displays_infos,
+            Ok(vec![DisplayInfo {
+                name: String::from("screen"),
+                is_primary: true,
+                id: String::from("screen"),
+                connector: DisplayConnector::Other,
+                resolution: DisplayResolution {
+                    width: 1280,
+                    height: 1024,
+                },
+                position: DisplayPosition { x: 0, y: 0 },
+                refresh_rate_millihertz: None,
+                rotation: DisplayRotation::Degrees0,
+                scale_percent: None,
+                advanced_color: None,
+            }])
+        );
+    }
+
+    #[test_case("normal" => DisplayRotation::Degrees0; "when the output is not rotated")]
+    #[test_case("left" => DisplayRotation::Degrees270; "when the output is rotated left")]
+    #[test_case("inverted" => DisplayRotation::Degrees180; "when the output is inverted")]
+    #[test_case("right" => DisplayRotation::Degrees90; "when the output is rotated right")]
+    fn get_the_rotation_of_the_outputs(rotation: &str) -> DisplayRotation {
+        // Arrange
+        let query = format!(
+            "Screen 0: minimum 320 x 200, current 1080 x 1920, maximum 16384 x 16384\n\
+             DP-1 connected primary 1080x1920+0+0 {rotation} (normal left inverted right x axis y axis) 597mm x 336mm\n\
+             \x20  1920x1080     59.95*+\n"
+        );
+        let (mut x11_display_settings, _) = x11_display_settings(query);
+
+        // Act
+        let displays_infos = x11_display_settings.get_displays_infos().unwrap();
+
+        // Assert
+        displays_infos[0].rotation
+    }
+
+    #[test]
+    fn move_the_primary_output_to_the_origin_and_re_base_the_other_outputs() {
+        // Arrange
+        let (mut x11_display_settings, calls) = x11_display_settings(xrandr_query());
+
+        // Act
+        let result = x11_display_settings.swap_primary_display("Internal Display", "LG TV SSCR2");
+
+        // Assert
+        assert_eq!(
+            result,
+            Ok(DisplaysSettingsResult {
+                new_primary_display: String::from("LG TV SSCR2"),
+                new_mode: None,
+                new_rotation: None,
+                new_scale_percent: None,
+                new_hdr_enabled: None,
+            })
+        );
+        assert_eq!(
+            calls.borrow().last(),
+            Some(&vec![
+                String::from("--output"),
+                String::from("eDP-1"),
+                String::from("--pos"),
+                String::from("-1920x0"),
+                String::from("--output"),
+                String::from("HDMI-1"),
+                String::from("--pos"),
+                String::from("0x0"),
+                String::from("--primary"),
+            ])
+        );
+    }
+
+    #[test]
+    fn get_the_connected_outputs_including_the_ones_turned_off() {
+        // Arrange
+        let (mut x11_display_settings, _) = x11_display_settings(xrandr_query());
+
+        // Act
+        let connected_displays = x11_display_settings.get_connected_displays();
+
+        // Assert
+        assert_eq!(
+            connected_displays,
+            Ok(vec![
+                ConnectedDisplay {
+                    name: String::from("Internal Display"),
+                    id: String::from("eDP-1"),
+                    connector: DisplayConnector::Internal,
+                    is_active: true,
+                    is_primary: true,
+                },
+                ConnectedDisplay {
+                    name: String::from("LG TV SSCR2"),
+                    id: String::from("HDMI-1"),
+                    connector: DisplayConnector::Hdmi,
+                    is_active: true,
+                    is_primary: false,
+                },
+                ConnectedDisplay {
+                    name: String::from("DELL U2723QE"),
+                    id: String::from("DP-1"),
+                    connector: DisplayConnector::DisplayPort,
+                    is_active: false,
+                    is_primary: false,
+                },
+            ])
+        );
+    }
+
+    #[test_case(DisplaysTopology::Only(String::from("DELL U2723QE")) => vec!["--output", "DP-1", "--auto", "--pos", "0x0", "--primary", "--output", "eDP-1", "--off", "--output", "HDMI-1", "--off"]; "when only one output is kept")]
+    #[test_case(DisplaysTopology::External => vec!["--output", "DP-1", "--auto", "--pos", "0x0", "--primary", "--output", "HDMI-1", "--auto", "--right-of", "DP-1", "--output", "eDP-1", "--off"]; "when only the external outputs are kept")]
+    #[test_case(DisplaysTopology::Extend => vec!["--output", "eDP-1", "--auto", "--pos", "0x0", "--primary", "--output", "DP-1", "--auto", "--right-of", "eDP-1", "--output", "HDMI-1", "--auto", "--right-of", "DP-1"]; "when the desktop is extended")]
+    #[test_case(DisplaysTopology::Clone => vec!["--output", "eDP-1", "--auto", "--pos", "0x0", "--primary", "--output", "DP-1", "--auto", "--same-as", "eDP-1", "--output", "HDMI-1", "--auto", "--same-as", "eDP-1"]; "when the desktop is cloned")]
+    fn turn_the_outputs_of_a_topology_on_and_the_other_ones_off(
+        topology: DisplaysTopology,
+    ) -> Vec<String> {
+        // Arrange
+        let (mut x11_display_settings, calls) = x11_display_settings(xrandr_query());
+
+        // Act
+        x11_display_settings.set_topology(&topology).unwrap();
+
+        // Assert
+        calls.borrow().last().unwrap().clone()
+    }
+
+    #[test_case("Internal Display", Some(50) => vec!["--output", "eDP-1", "--mode", "1920x1080", "--rate", "50.010"]; "when the refresh rate rounds to the one asked")]
+    #[test_case("LG TV SSCR2", Some(25) => vec!["--output", "HDMI-1", "--mode", "3840x2160", "--rate", "25.000"]; "when the refresh rate is the one asked")]
+    #[test_case("Internal Display", None => vec!["--props"]; "when the current mode is kept")]
+    fn set_the_mode_and_the_rate_of_the_output(
+        display_name: &str,
+        refresh_rate_hertz: Option<u32>,
+    ) -> Vec<String> {
+        // Arrange
+        let (mut x11_display_settings, calls) = x11_display_settings(xrandr_query());
+        let mode_request = DisplayModeRequest {
+            resolution: None,
+            refresh_rate_hertz,
+        };
+
+        // Act
+        x11_display_settings
+            .set_display_mode(display_name, &mode_request)
+            .unwrap();
+
+        // Assert
+        calls.borrow().last().unwrap().clone()
+    }
+
+    #[test_case(DisplayRotation::Degrees90 => vec!["--output", "HDMI-1", "--rotate", "right"]; "when the output is rotated right")]
+    #[test_case(DisplayRotation::Degrees270 => vec!["--output", "HDMI-1", "--rotate", "left"]; "when the output is rotated left")]
+    #[test_case(DisplayRotation::Degrees0 => vec!["--props"]; "when the rotation is kept")]
+    fn rotate_the_output(rotation: DisplayRotation) -> Vec<String> {
+        // Arrange
+        let (mut x11_display_settings, calls) = x11_display_settings(xrandr_query());
+        let transform_request = DisplayTransformRequest {
+            rotation: Some(rotation),
+            scale_percent: None,
+        };
+
+        // Act
+        x11_display_settings
+            .set_display_transform("LG TV SSCR2", &transform_request)
+            .unwrap();
+
+        // Assert
+        calls.borrow().last().unwrap().clone()
+    }
+
+    #[test]
+    fn fail_to_scale_an_output() {
+        // Arrange
+        let (mut x11_display_settings, calls) = x11_display_settings(xrandr_query());
+        let transform_request = DisplayTransformRequest {
+            rotation: None,
+            scale_percent: Some(200),
+        };
+
+        // Act
+        let result = x11_display_settings.set_display_transform("LG TV SSCR2", &transform_request);
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(ApplicationError::UnsupportedSetting { display, setting })
+                if display == "LG TV SSCR2" && setting == "scale"
+        ));
+        assert_eq!(
+            calls.borrow().last().unwrap(),
+            &vec![String::from("--props")]
+        );
+    }
+
+    #[test]
+    fn fail_to_turn_hdr_on_on_an_output() {
+        // Arrange
+        let (mut x11_display_settings, calls) = x11_display_settings(xrandr_query());
+
+        // Act
+        let result = x11_display_settings.set_display_hdr("LG TV SSCR2", true);
+
+        // Assert
+        assert!(matches!(
+            result,
+            Err(ApplicationError::UnsupportedSetting { display, setting })
+                if display == "LG TV SSCR2" && setting == "HDR"
+        ));
+        assert_eq!(
+            calls.borrow().last().unwrap(),
+            &vec![String::from("--props")]
+        );
+    }
+
+    #[test]
+    fn restore_the_mode_the_rotation_and_the_position_of_the_outputs_of_a_snapshot() {
+        // Arrange
+        let (mut x11_display_settings, calls) = x11_display_settings(xrandr_query());
+        let snapshot = x11_display_settings.save_snapshot().unwrap();
+
+        // Act
+        let result = x11_display_settings.restore_snapshot(&snapshot);
+
+        // Assert
+        assert_eq!(result, Ok(()));
+        assert_eq!(
+            calls.borrow().last(),
+            Some(&vec![
+                String::from("--output"),
+                String::from("eDP-1"),
+                String::from("--mode"),
+                String::from("1920x1080"),
+                String::from("--rate"),
+                String::from("60.020"),
+                String::from("--rotate"),
+                String::from("normal"),
+                String::from("--pos"),
+                String::from("0x0"),
+                String::from("--primary"),
+                String::from("--output"),
+                String::from("HDMI-1"),
+                String::from("--mode"),
+                String::from("3840x2160"),
+                String::from("--rate"),
+                String::from("60.000"),
+                String::from("--rotate"),
+                String::from("normal"),
+                String::from("--pos"),
+                String::from("1920x0"),
+                String::from("--output"),
+                String::from("DP-1"),
+                String::from("--off"),
+            ])
+        );
+    }
+
+    #[test]
+    fn fail_to_restore_a_snapshot_saved_with_another_backend() {
+        // Arrange
+        let (mut x11_display_settings, calls) = x11_display_settings(xrandr_query());
+        let snapshot = DisplaysSnapshot::InMemory {
+            displays: Vec::new(),
+        };
+
+        // Act
+        let result = x11_display_settings.restore_snapshot(&snapshot);
+
+        // Assert
+        assert_eq!(
+            result,
+            Err(ApplicationError::snapshot_of_another_backend(
+                DeviceKind::Display,
+                "in-memory",
+                "x11"
+            ))
+        );
+        assert!(calls.borrow().is_empty());
+    }
+
+    #[test_case("Unknown Monitor", "LG TV SSCR2" => Err(ApplicationError::DeviceNotFound { kind: DeviceKind::Display, role: DeviceRole::Desktop, requested: vec![String::from("Unknown Monitor")], candidates: vec![String::from("Internal Display"), String::from("LG TV SSCR2")], suggestions: Vec::new() }); "when the desktop display is invalid")]
+    #[test_case("Internal Display", "Unknown TV" => Err(ApplicationError::DeviceNotFound { kind: DeviceKind::Display, role: DeviceRole::Couch, requested: vec![String::from("Unknown TV")], candidates: vec![String::from("Internal Display"), String::from("LG TV SSCR2")], suggestions: Vec::new() }); "when the couch display is invalid")]
+    #[test_case("Unknown Monitor", "Unknown TV" => Err(ApplicationError::DeviceNotFound { kind: DeviceKind::Display, role: DeviceRole::DesktopAndCouch, requested: vec![String::from("Unknown Monitor"), String::from("Unknown TV")], candidates: vec![String::from("Internal Display"), String::from("LG TV SSCR2")], suggestions: Vec::new() }); "when both displays are invalid")]
+    #[test_case("Internal Display", "LG TV SSCR" => Err(ApplicationError::DeviceNotFound { kind: DeviceKind::Display, role: DeviceRole::Couch, requested: vec![String::from("LG TV SSCR")], candidates: vec![String::from("Internal Display"), String::from("LG TV SSCR2")], suggestions: vec![String::from("LG TV SSCR2")] }); "when the couch display is misspelled")]
+    fn fail_to_change_the_primary_display(
+        desktop_display_name: &str,
+        couch_display_name: &str,
+    ) -> ApplicationResult<DisplaysSettingsResult> {
+        // Arrange
+        let (mut x11_display_settings, calls) = x11_display_settings(xrandr_query());
+
+        // Act
+        let result =
+            x11_display_settings.swap_primary_display(desktop_display_name, couch_display_name);
+
+        // Assert
+        assert_eq!(calls.borrow().len(), 1);
+
+        result
+    }
+}
@@ -0,0 +1,34 @@
+use crate::{
+    application_result::ApplicationResult, command::run_command,
+    displays_settings::x11::x11_api::X11Api, trace_fn,
+};
+
+pub struct XrandrBasedX11Api {
+    program: String,
+}
+
+impl XrandrBasedX11Api {
+    pub fn new(program: &str) -> Self {
+        trace_fn!();
+
+        Self {
+            program: String::from(program),
+        }
+    }
+}
+
+impl Default for XrandrBasedX11Api {
+    fn default() -> Self {
+        trace_fn!();
+
+        Self::new("xrandr")
+    }
+}
+
+impl X11Api for XrandrBasedX11Api {
+    fn xrandr(&mut self, args: &[String]) -> ApplicationResult<String> {
+        trace_fn!();
+
+        run_command(&self.program, args, &[])
+    }
+}
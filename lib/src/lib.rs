@@ -1,5 +1,11 @@
 pub mod application_error;
 pub mod application_result;
+pub mod backends;
+#[cfg(target_os = "linux")]
+pub(crate) mod command;
+pub mod configuration;
+pub mod device_selector;
 pub mod displays_settings;
 pub mod log;
+pub mod snapshot;
 pub mod speakers_settings;
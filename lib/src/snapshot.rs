@@ -0,0 +1,288 @@
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use log::info;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    application_error::{ApplicationError, ErrorSource},
+    application_result::ApplicationResult,
+    backends::environment_api::EnvironmentApi,
+    configuration::get_default_configuration_directory,
+    displays_settings::DisplaysSnapshot,
+    speakers_settings::SpeakersSnapshot,
+    trace_fn,
+};
+
+/// Bumped whenever the layout of a snapshot changes, older snapshots being rejected rather than
+/// restored partially.
+pub const SNAPSHOT_VERSION: u32 = 1;
+
+const SNAPSHOTS_DIRECTORY_NAME: &str = "snapshots";
+const SNAPSHOT_FILE_EXTENSION: &str = "toml";
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Snapshot {
+    pub version: u32,
+    pub displays: DisplaysSnapshot,
+    pub speakers: SpeakersSnapshot,
+}
+
+#[derive(Deserialize)]
+struct SnapshotVersion {
+    version: u32,
+}
+
+impl Snapshot {
+    pub fn new(displays: DisplaysSnapshot, speakers: SpeakersSnapshot) -> Self {
+        trace_fn!();
+
+        Self {
+            version: SNAPSHOT_VERSION,
+            displays,
+            speakers,
+        }
+    }
+
+    /// The version is checked first so that a snapshot written by another version of the
+    /// program is reported as such instead of as a parsing error.
+    pub fn from_toml(toml: &str) -> ApplicationResult<Self> {
+        trace_fn!();
+
+        let SnapshotVersion { version } =
+            toml::from_str::<SnapshotVersion>(toml).map_err(parsing_error)?;
+
+        if version != SNAPSHOT_VERSION {
+            return Err(ApplicationError::Snapshot {
+                message: format!(
+                    "The snapshot has the version {version} whereas only the version {SNAPSHOT_VERSION} is supported"
+                ),
+                source: None,
+            });
+        }
+
+        toml::from_str::<Snapshot>(toml).map_err(parsing_error)
+    }
+
+    pub fn to_toml(&self) -> ApplicationResult<String> {
+        trace_fn!();
+
+        toml::to_string(self).map_err(|error| ApplicationError::Snapshot {
+            message: format!("Failed to serialize the snapshot: {error}"),
+            source: Some(ErrorSource::new(error)),
+        })
+    }
+}
+
+fn parsing_error(error: toml::de::Error) -> ApplicationError {
+    trace_fn!();
+
+    ApplicationError::Snapshot {
+        message: format!("Failed to parse the snapshot: {error}"),
+        source: Some(ErrorSource::new(error)),
+    }
+}
+
+/// Stores each snapshot in its own file, named after the snapshot.
+pub struct SnapshotStore {
+    directory: Option<PathBuf>,
+}
+
+impl SnapshotStore {
+    pub fn new(directory: Option<PathBuf>) -> Self {
+        trace_fn!();
+
+        Self { directory }
+    }
+
+    pub fn save(&self, snapshot_name: &str, snapshot: &Snapshot) -> ApplicationResult<()> {
+        trace_fn!();
+
+        let snapshot_path = self.get_snapshot_path(snapshot_name)?;
+
+        info!("Saving snapshot to {}", snapshot_path.display());
+
+        let toml = snapshot.to_toml()?;
+
+        if let Some(directory) = snapshot_path.parent() {
+            fs::create_dir_all(directory)
+                .map_err(|error| io_error("create the snapshots directory", directory, error))?;
+        }
+
+        fs::write(&snapshot_path, toml)
+            .map_err(|error| io_error("write the snapshot file", &snapshot_path, error))
+    }
+
+    pub fn load(&self, snapshot_name: &str) -> ApplicationResult<Snapshot> {
+        trace_fn!();
+
+        let snapshot_path = self.get_snapshot_path(snapshot_name)?;
+
+        info!("Loading snapshot from {}", snapshot_path.display());
+
+        match fs::read_to_string(&snapshot_path) {
+            Ok(toml) => Snapshot::from_toml(&toml),
+            Err(error) if error.kind() == io::ErrorKind::NotFound => {
+                Err(ApplicationError::Snapshot {
+                    message: format!("Snapshot {snapshot_name} does not exist"),
+                    source: None,
+                })
+            }
+            Err(error) => Err(io_error("read the snapshot file", &snapshot_path, error)),
+        }
+    }
+
+    /// A snapshot name becomes a file name, so it may neither escape the snapshots directory
+    /// nor be hidden.
+    fn get_snapshot_path(&self, snapshot_name: &str) -> ApplicationResult<PathBuf> {
+        trace_fn!();
+
+        if snapshot_name.is_empty()
+            || snapshot_name.starts_with('.')
+            || snapshot_name.contains(['/', '\\'])
+        {
+            return Err(ApplicationError::Snapshot {
+                message: format!(
+                    "Snapshot name {snapshot_name} is invalid, it must neither be empty, start with a dot nor contain a path separator"
+                ),
+                source: None,
+            });
+        }
+
+        let Some(directory) = &self.directory else {
+            return Err(ApplicationError::Snapshot {
+                message: String::from("The snapshots directory could not be determined"),
+                source: None,
+            });
+        };
+
+        Ok(directory.join(format!("{snapshot_name}.{SNAPSHOT_FILE_EXTENSION}")))
+    }
+}
+
+fn io_error(action: &str, path: &Path, error: io::Error) -> ApplicationError {
+    trace_fn!();
+
+    ApplicationError::Snapshot {
+        message: format!("Failed to {action} {}: {error}", path.display()),
+        source: Some(ErrorSource::new(error)),
+    }
+}
+
+/// The snapshots live next to the configuration file.
+pub fn get_default_snapshots_directory(environment_api: &dyn EnvironmentApi) -> Option<PathBuf> {
+    trace_fn!();
+
+    get_default_configuration_directory(environment_api)
+        .map(|configuration_directory| configuration_directory.join(SNAPSHOTS_DIRECTORY_NAME))
+}
+
+#[cfg(test)]
+mod should {
+    use std::{env, process};
+
+    use test_case::test_case;
+
+    use crate::{
+        application_error::ApplicationError,
+        displays_settings::{
+            DisplayConnector, DisplayRotation, DisplaysSnapshot,
+            in_memory::in_memory_api::InMemoryDisplay,
+        },
+        snapshot::{Snapshot, SnapshotStore},
+        speakers_settings::SpeakersSnapshot,
+    };
+
+    fn snapshot() -> Snapshot {
+        Snapshot::new(
+            DisplaysSnapshot::InMemory {
+                displays: vec![InMemoryDisplay {
+                    id: String::from("in-memory-2"),
+                    name: String::from("LG TV SSCR2"),
+                    connector: DisplayConnector::Hdmi,
+                    width: 3840,
+                    height: 2160,
+                    x: 0,
+                    y: 0,
+                    refresh_rate_millihertz: 60000,
+                    rotation: DisplayRotation::Degrees90,
+                    scale_percent: 200,
+                    is_active: true,
+                    modes: Vec::new(),
+                    advanced_color: None,
+                }],
+            },
+            SpeakersSnapshot::InMemory {
+                default_speaker_id: Some(String::from("{0.0.0.00000000}.{9b61d3e7}")),
+            },
+        )
+    }
+
+    #[test]
+    fn round_trip_a_snapshot_through_toml() {
+        // Arrange
+        let snapshot = snapshot();
+
+        // Act
+        let toml = snapshot.to_toml().unwrap();
+
+        // Assert
+        assert_eq!(Snapshot::from_toml(&toml), Ok(snapshot));
+    }
+
+    #[test]
+    fn fail_to_parse_a_snapshot_of_another_version() {
+        // Arrange
+        let toml = snapshot()
+            .to_toml()
+            .unwrap()
+            .replace("version = 1", "version = 2");
+
+        // Act
+        let result = Snapshot::from_toml(&toml);
+
+        // Assert
+        assert_eq!(
+            result,
+            Err(ApplicationError::Snapshot {
+                message: String::from(
+                    "The snapshot has the version 2 whereas only the version 1 is supported"
+                ),
+                source: None,
+            })
+        );
+    }
+
+    #[test]
+    fn load_a_saved_snapshot() {
+        // Arrange
+        let snapshot_store = SnapshotStore::new(Some(
+            env::temp_dir().join(format!("convertible-couch-snapshots-{}", process::id())),
+        ));
+        let snapshot = snapshot();
+
+        snapshot_store.save("couch", &snapshot).unwrap();
+
+        // Act
+        let loaded_snapshot = snapshot_store.load("couch");
+
+        // Assert
+        assert_eq!(loaded_snapshot, Ok(snapshot));
+    }
+
+    #[test_case("../couch"; "when the name contains a path separator")]
+    #[test_case(".couch"; "when the name starts with a dot")]
+    #[test_case(""; "when the name is empty")]
+    fn fail_to_save_a_snapshot_with_an_invalid_name(snapshot_name: &str) {
+        // Arrange
+        let snapshot_store = SnapshotStore::new(Some(env::temp_dir()));
+
+        // Act
+        let result = snapshot_store.save(snapshot_name, &snapshot());
+
+        // Assert
+        assert!(matches!(result, Err(ApplicationError::Snapshot { .. })));
+    }
+}
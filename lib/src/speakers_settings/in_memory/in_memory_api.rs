@@ -0,0 +1,27 @@
+use crate::{
+    application_result::ApplicationResult,
+    speakers_settings::{SpeakerFormFactor, SpeakerState},
+};
+
+/// A speaker of the simulated machine.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InMemorySpeaker {
+    pub id: String,
+    pub name: String,
+    pub is_default: bool,
+    pub state: SpeakerState,
+    pub form_factor: SpeakerFormFactor,
+    pub channels: u16,
+    pub sample_format: String,
+    pub sample_rate_hertz: u32,
+    pub volume_percent: u32,
+    pub is_muted: bool,
+}
+
+pub trait InMemoryApi {
+    /// Retrieves every speaker of the simulated machine, whatever its state.
+    fn get_speakers(&self) -> ApplicationResult<Vec<InMemorySpeaker>>;
+
+    /// Makes the speaker identified by `speaker_id` the default one.
+    fn set_default_speaker(&mut self, speaker_id: &str) -> ApplicationResult<()>;
+}
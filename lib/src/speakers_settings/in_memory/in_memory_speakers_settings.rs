@@ -0,0 +1,174 @@
+use log::{debug, info};
+
+use crate::{
+    application_error::{ApplicationError, DeviceKind, DeviceRole},
+    application_result::ApplicationResult,
+    speakers_settings::{
+        SpeakerInfo, SpeakersSettings, SpeakersSettingsResult, SpeakersSnapshot,
+        in_memory::in_memory_api::InMemoryApi,
+    },
+    trace_fn,
+};
+
+pub struct InMemorySpeakersSettings {
+    in_memory_api: Box<dyn InMemoryApi>,
+}
+
+impl InMemorySpeakersSettings {
+    pub fn new(speakers_settings_api: Box<dyn InMemoryApi>) -> Self {
+        trace_fn!();
+
+        Self {
+            in_memory_api: speakers_settings_api,
+        }
+    }
+}
+
+impl SpeakersSettings for InMemorySpeakersSettings {
+    fn change_default_speaker(
+        &mut self,
+        desktop_speaker_id: &str,
+        couch_speaker_id: &str,
+    ) -> ApplicationResult<SpeakersSettingsResult> {
+        trace_fn!();
+        debug!(
+            "desktop_speaker_id = \"{desktop_speaker_id}\", couch_speaker_id = \"{couch_speaker_id}\""
+        );
+        info!("Changing default speaker");
+
+        let speakers = self.in_memory_api.get_speakers()?;
+
+        let mut default_speaker_id = None;
+        let mut desktop_speaker_name = None;
+        let mut couch_speaker_name = None;
+        let mut speaker_ids = Vec::with_capacity(speakers.len());
+
+        for speaker in speakers {
+            if speaker.is_default {
+                default_speaker_id = Some(speaker.id.clone());
+            }
+
+            if speaker.id == desktop_speaker_id {
+                desktop_speaker_name = Some(speaker.name);
+            } else if speaker.id == couch_speaker_id {
+                couch_speaker_name = Some(speaker.name);
+            }
+
+            speaker_ids.push(speaker.id);
+        }
+
+        let (desktop_speaker_name, couch_speaker_name) =
+            match (desktop_speaker_name, couch_speaker_name) {
+                (Some(desktop_speaker_name), Some(couch_speaker_name)) => {
+                    (desktop_speaker_name, couch_speaker_name)
+                }
+                (desktop_speaker_name, couch_speaker_name) => {
+                    let (invalid_role, invalid_ids) =
+                        match (desktop_speaker_name.is_none(), couch_speaker_name.is_none()) {
+                            (true, true) => (
+                                DeviceRole::DesktopAndCouch,
+                                vec![desktop_speaker_id, couch_speaker_id],
+                            ),
+                            (true, _) => (DeviceRole::Desktop, vec![desktop_speaker_id]),
+                            _ => (DeviceRole::Couch, vec![couch_speaker_id]),
+                        };
+                    let error = ApplicationError::device_not_found(
+                        DeviceKind::Speaker,
+                        invalid_role,
+                        &invalid_ids,
+                        speaker_ids,
+                    );
+
+                    return Err(error);
+                }
+            };
+
+        let (new_default_speaker_id, new_default_speaker_name) =
+            if default_speaker_id.is_some_and(|id| id == desktop_speaker_id) {
+                (couch_speaker_id, couch_speaker_name)
+            } else {
+                (desktop_speaker_id, desktop_speaker_name)
+            };
+
+        self.in_memory_api
+            .set_default_speaker(new_default_speaker_id)?;
+
+        Ok(SpeakersSettingsResult {
+            new_default_speaker: new_default_speaker_name,
+        })
+    }
+
+    fn get_speakers_infos(&mut self) -> ApplicationResult<Vec<SpeakerInfo>> {
+        trace_fn!();
+        info!("Getting speakers informations");
+
+        let mut speakers_infos = self
+            .in_memory_api
+            .get_speakers()?
+            .into_iter()
+            .map(|speaker| SpeakerInfo {
+                is_default: speaker.is_default,
+                name: speaker.name,
+                id: speaker.id,
+                state: speaker.state,
+                form_factor: speaker.form_factor,
+                channels: Some(speaker.channels),
+                sample_format: Some(speaker.sample_format),
+                sample_rate_hertz: Some(speaker.sample_rate_hertz),
+                volume_percent: Some(speaker.volume_percent),
+                is_muted: Some(speaker.is_muted),
+            })
+            .collect::<Vec<SpeakerInfo>>();
+
+        speakers_infos.sort();
+
+        Ok(speakers_infos)
+    }
+
+    fn save_snapshot(&mut self) -> ApplicationResult<SpeakersSnapshot> {
+        trace_fn!();
+        info!("Saving speakers snapshot");
+
+        let default_speaker_id = self
+            .in_memory_api
+            .get_speakers()?
+            .into_iter()
+            .find(|speaker| speaker.is_default)
+            .map(|speaker| speaker.id);
+
+        Ok(SpeakersSnapshot::InMemory { default_speaker_id })
+    }
+
+    fn restore_snapshot(&mut self, snapshot: &SpeakersSnapshot) -> ApplicationResult<()> {
+        trace_fn!();
+        info!("Restoring speakers snapshot");
+
+        let SpeakersSnapshot::InMemory { default_speaker_id } = snapshot else {
+            return Err(ApplicationError::snapshot_of_another_backend(
+                DeviceKind::Speaker,
+                snapshot.backend_name(),
+                "in-memory",
+            ));
+        };
+
+        let Some(default_speaker_id) = default_speaker_id else {
+            return Ok(());
+        };
+
+        if !self
+            .in_memory_api
+            .get_speakers()?
+            .iter()
+            .any(|speaker| speaker.id == *default_speaker_id)
+        {
+            return Err(ApplicationError::Snapshot {
+                message: format!(
+                    "The speaker {default_speaker_id} of the snapshot is not connected anymore"
+                ),
+                source: None,
+            });
+        }
+
+        self.in_memory_api.set_default_speaker(default_speaker_id)
+    }
+}
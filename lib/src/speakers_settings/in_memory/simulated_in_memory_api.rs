@@ -0,0 +1,78 @@
+use crate::{
+    application_error::ApplicationError,
+    application_result::ApplicationResult,
+    speakers_settings::{
+        SpeakerFormFactor, SpeakerState,
+        in_memory::in_memory_api::{InMemoryApi, InMemorySpeaker},
+    },
+    trace_fn,
+};
+
+pub struct SimulatedInMemoryApi {
+    speakers: Vec<InMemorySpeaker>,
+}
+
+impl SimulatedInMemoryApi {
+    pub fn new(speakers: Vec<InMemorySpeaker>) -> Self {
+        trace_fn!();
+
+        Self { speakers }
+    }
+}
+
+impl Default for SimulatedInMemoryApi {
+    fn default() -> Self {
+        trace_fn!();
+
+        Self::new(vec![
+            InMemorySpeaker {
+                id: String::from("desktop-speakers"),
+                name: String::from("Desktop Speakers"),
+                is_default: true,
+                state: SpeakerState::Active,
+                form_factor: SpeakerFormFactor::Speakers,
+                channels: 2,
+                sample_format: String::from("float32le"),
+                sample_rate_hertz: 48000,
+                volume_percent: 40,
+                is_muted: false,
+            },
+            InMemorySpeaker {
+                id: String::from("living-room-tv"),
+                name: String::from("Living Room TV"),
+                is_default: false,
+                state: SpeakerState::Active,
+                form_factor: SpeakerFormFactor::Hdmi,
+                channels: 6,
+                sample_format: String::from("s16le"),
+                sample_rate_hertz: 48000,
+                volume_percent: 100,
+                is_muted: false,
+            },
+        ])
+    }
+}
+
+impl InMemoryApi for SimulatedInMemoryApi {
+    fn get_speakers(&self) -> ApplicationResult<Vec<InMemorySpeaker>> {
+        trace_fn!();
+
+        Ok(self.speakers.clone())
+    }
+
+    fn set_default_speaker(&mut self, speaker_id: &str) -> ApplicationResult<()> {
+        trace_fn!();
+
+        if !self.speakers.iter().any(|speaker| speaker.id == speaker_id) {
+            return Err(ApplicationError::Custom(format!(
+                "Speaker {speaker_id} does not exist"
+            )));
+        }
+
+        for speaker in &mut self.speakers {
+            speaker.is_default = speaker.id == speaker_id;
+        }
+
+        Ok(())
+    }
+}
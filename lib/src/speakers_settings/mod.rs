@@ -1,17 +1,84 @@
 use std::cmp::Ordering;
 
+use serde::{Deserialize, Serialize};
+
+use crate::application_error::{DeviceKind, DeviceRole, VerificationMismatch};
 use crate::application_result::ApplicationResult;
+use crate::configuration::verification::Verification;
+use crate::device_selector::{
+    SelectableDevice, resolve_device_pair, resolve_existing_device, resolve_next_device,
+    to_id_selector,
+};
+#[cfg(target_os = "windows")]
+use crate::speakers_settings::windows::windows_sound_settings::WindowsDefaultEndpoint;
 use crate::trace_fn;
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Serialize)]
 pub struct SpeakersSettingsResult {
     pub new_default_speaker: String,
 }
 
-#[derive(Debug, PartialEq, Eq)]
+/// What a change of default speaker would do, the speakers being reported as they would be once
+/// changed.
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct SpeakersPlan {
+    pub new_default_speaker: String,
+    pub roles: Vec<SpeakerRole>,
+    pub speakers: Vec<SpeakerInfo>,
+}
+
+/// What a default speaker is used for. Windows keeps a default endpoint per role, whereas the
+/// other platforms have a single default speaker used for all of them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SpeakerRole {
+    All,
+    Console,
+    Multimedia,
+    Communications,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SpeakerState {
+    Active,
+    Disabled,
+    Unplugged,
+    NotPresent,
+    #[default]
+    Unknown,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SpeakerFormFactor {
+    Speakers,
+    Headphones,
+    Headset,
+    /// A display fed over HDMI or DisplayPort, such as a TV.
+    Hdmi,
+    Spdif,
+    LineOut,
+    Other,
+    #[default]
+    Unknown,
+}
+
+/// The id is the one of the backend, which stays the same when two speakers share a name. The
+/// fields a backend cannot report are unknown rather than making the whole call fail.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize)]
 pub struct SpeakerInfo {
     pub is_default: bool,
     pub name: String,
+    pub id: String,
+    pub state: SpeakerState,
+    pub form_factor: SpeakerFormFactor,
+    pub channels: Option<u16>,
+    /// The sample format in the PulseAudio notation, such as `s16le` or `float32le`.
+    pub sample_format: Option<String>,
+    pub sample_rate_hertz: Option<u32>,
+    pub volume_percent: Option<u32>,
+    pub is_muted: Option<bool>,
 }
 
 impl Ord for SpeakerInfo {
@@ -34,23 +101,330 @@ impl PartialOrd for SpeakerInfo {
     }
 }
 
-pub trait SpeakersSettings {
-    fn new(speakers_settings_api: Box<dyn CurrentSpeakersSettingsApiTrait>) -> Self;
+impl SelectableDevice for SpeakerInfo {
+    fn name(&self) -> &str {
+        trace_fn!();
 
+        &self.name
+    }
+
+    fn id(&self) -> &str {
+        trace_fn!();
+
+        &self.id
+    }
+}
+
+/// The default endpoints as their backend sets them, so that they can be restored exactly. A
+/// snapshot can only be restored by the backend which saved it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "backend")]
+pub enum SpeakersSnapshot {
+    #[serde(rename = "in-memory")]
+    InMemory { default_speaker_id: Option<String> },
+    /// The default render and capture endpoints of every role.
+    #[cfg(target_os = "windows")]
+    #[serde(rename = "windows")]
+    Windows {
+        default_endpoints: Vec<WindowsDefaultEndpoint>,
+    },
+    #[cfg(target_os = "linux")]
+    #[serde(rename = "pulseaudio")]
+    PulseAudio {
+        default_sink: Option<String>,
+        default_source: Option<String>,
+    },
+}
+
+impl SpeakersSnapshot {
+    pub fn backend_name(&self) -> &'static str {
+        trace_fn!();
+
+        match self {
+            SpeakersSnapshot::InMemory { .. } => "in-memory",
+            #[cfg(target_os = "windows")]
+            SpeakersSnapshot::Windows { .. } => "windows",
+            #[cfg(target_os = "linux")]
+            SpeakersSnapshot::PulseAudio { .. } => "pulseaudio",
+        }
+    }
+}
+
+pub trait SpeakersSettings {
+    /// Toggles the default speaker between the desktop and couch speakers, given by their ids
+    /// since two speakers may share a name.
     fn change_default_speaker(
         &mut self,
-        desktop_speaker_name: &str,
-        couch_speaker_name: &str,
+        desktop_speaker_id: &str,
+        couch_speaker_id: &str,
     ) -> ApplicationResult<SpeakersSettingsResult>;
 
     fn get_speakers_infos(&mut self) -> ApplicationResult<Vec<SpeakerInfo>>;
+
+    fn save_snapshot(&mut self) -> ApplicationResult<SpeakersSnapshot>;
+
+    fn restore_snapshot(&mut self, snapshot: &SpeakersSnapshot) -> ApplicationResult<()>;
+
+    /// The roles for which the default speaker is changed.
+    fn default_speaker_roles(&self) -> Vec<SpeakerRole> {
+        trace_fn!();
+
+        vec![SpeakerRole::All]
+    }
+
+    /// Swaps the desktop and couch speakers, once their selectors are resolved against the
+    /// speakers reported.
+    fn swap_default_speaker(
+        &mut self,
+        desktop_speaker_selector: &str,
+        couch_speaker_selector: &str,
+    ) -> ApplicationResult<SpeakersSettingsResult> {
+        trace_fn!();
+
+        let speakers_infos = self.get_speakers_infos()?;
+        let (desktop_speaker, couch_speaker) = resolve_device_pair(
+            DeviceKind::Speaker,
+            desktop_speaker_selector,
+            couch_speaker_selector,
+            &speakers_infos,
+        )?;
+
+        self.change_default_speaker(&desktop_speaker.id, &couch_speaker.id)
+    }
+
+    /// Sets a speaker as default whether or not it already is, by toggling between it and the
+    /// current default speaker.
+    fn set_default_speaker(
+        &mut self,
+        speaker_selector: &str,
+    ) -> ApplicationResult<SpeakersSettingsResult> {
+        trace_fn!();
+
+        let speakers_infos = self.get_speakers_infos()?;
+        let (speaker, toggled_speaker) =
+            resolve_default_speaker_toggle(speaker_selector, &speakers_infos)?;
+
+        match toggled_speaker {
+            Some(toggled_speaker) => self.change_default_speaker(&toggled_speaker.id, &speaker.id),
+            None => Ok(SpeakersSettingsResult {
+                new_default_speaker: speaker.name.clone(),
+            }),
+        }
+    }
+
+    /// Sets as default the speaker following the current default one in a cycle.
+    fn cycle_default_speaker(
+        &mut self,
+        speaker_selectors: &[String],
+        reverse: bool,
+    ) -> ApplicationResult<SpeakersSettingsResult> {
+        trace_fn!();
+
+        let speakers_infos = self.get_speakers_infos()?;
+        let speaker = resolve_next_default_speaker(speaker_selectors, &speakers_infos, reverse)?;
+
+        self.set_default_speaker(&to_id_selector(&speaker.id))
+    }
+
+    /// Queries the speakers until the one the plan set is reported as default, since a change
+    /// may be accepted without being applied.
+    fn verify_speakers(
+        &mut self,
+        speakers_plan: &SpeakersPlan,
+        verification: &Verification,
+    ) -> ApplicationResult<()> {
+        trace_fn!();
+
+        verification.verify(DeviceKind::Speaker, || {
+            let speakers_infos = self.get_speakers_infos()?;
+
+            Ok(find_speakers_mismatches(speakers_plan, &speakers_infos))
+        })
+    }
+
+    /// Computes what swapping the desktop and couch speakers would do, without changing them.
+    fn plan_swap_default_speaker(
+        &mut self,
+        desktop_speaker_selector: &str,
+        couch_speaker_selector: &str,
+    ) -> ApplicationResult<SpeakersPlan> {
+        trace_fn!();
+
+        let speakers_infos = self.get_speakers_infos()?;
+        let (desktop_speaker, couch_speaker) = resolve_device_pair(
+            DeviceKind::Speaker,
+            desktop_speaker_selector,
+            couch_speaker_selector,
+            &speakers_infos,
+        )?;
+
+        Ok(plan_default_speaker_change(
+            &speakers_infos,
+            desktop_speaker,
+            couch_speaker,
+            self.default_speaker_roles(),
+        ))
+    }
+
+    /// Computes what cycling to the next speaker would do, without changing it.
+    fn plan_cycle_default_speaker(
+        &mut self,
+        speaker_selectors: &[String],
+        reverse: bool,
+    ) -> ApplicationResult<SpeakersPlan> {
+        trace_fn!();
+
+        let speakers_infos = self.get_speakers_infos()?;
+        let speaker = resolve_next_default_speaker(speaker_selectors, &speakers_infos, reverse)?;
+
+        self.plan_set_default_speaker(&to_id_selector(&speaker.id))
+    }
+
+    /// Computes what setting a speaker as default would do, without changing it.
+    fn plan_set_default_speaker(
+        &mut self,
+        speaker_selector: &str,
+    ) -> ApplicationResult<SpeakersPlan> {
+        trace_fn!();
+
+        let speakers_infos = self.get_speakers_infos()?;
+        let (speaker, toggled_speaker) =
+            resolve_default_speaker_toggle(speaker_selector, &speakers_infos)?;
+
+        Ok(plan_default_speaker_change(
+            &speakers_infos,
+            toggled_speaker.unwrap_or(speaker),
+            speaker,
+            self.default_speaker_roles(),
+        ))
+    }
+}
+
+/// The id is compared too, since two speakers may share the name of the one set.
+fn find_speakers_mismatches(
+    speakers_plan: &SpeakersPlan,
+    speakers_infos: &[SpeakerInfo],
+) -> Vec<VerificationMismatch> {
+    trace_fn!();
+
+    let speaker_name = &speakers_plan.new_default_speaker;
+    let observed_speaker = speakers_infos
+        .iter()
+        .find(|speaker_info| speaker_info.is_default);
+
+    let Some(observed_speaker) = observed_speaker.filter(|speaker| speaker.name == *speaker_name)
+    else {
+        return vec![VerificationMismatch::device(
+            DeviceKind::Speaker,
+            speaker_name,
+            observed_speaker.map(|speaker| speaker.name.as_str()),
+        )];
+    };
+
+    let planned_speakers_ids = speakers_plan
+        .speakers
+        .iter()
+        .filter(|speaker| speaker.is_default)
+        .map(|speaker| speaker.id.as_str())
+        .collect::<Vec<&str>>();
+
+    match planned_speakers_ids.as_slice() {
+        [planned_speaker_id] if *planned_speaker_id != observed_speaker.id => {
+            vec![VerificationMismatch::new(
+                &format!("id of {speaker_name}"),
+                planned_speaker_id,
+                &observed_speaker.id,
+            )]
+        }
+        _ => Vec::new(),
+    }
+}
+
+fn resolve_next_default_speaker<'a>(
+    speaker_selectors: &[String],
+    speakers_infos: &'a [SpeakerInfo],
+    reverse: bool,
+) -> ApplicationResult<&'a SpeakerInfo> {
+    trace_fn!();
+
+    let default_speaker_id = speakers_infos
+        .iter()
+        .find(|speaker_info| speaker_info.is_default)
+        .map(|speaker_info| speaker_info.id.as_str());
+
+    resolve_next_device(
+        DeviceKind::Speaker,
+        speaker_selectors,
+        speakers_infos,
+        default_speaker_id,
+        reverse,
+    )
 }
 
-cfg_select! {
-    target_os = "windows" => {
-        pub mod windows;
-        pub use windows::windows_sound_settings::WindowsSoundSettings as CurrentSpeakersSettings;
-        pub use windows::win_32_based_windows_api::Win32BasedWindowsApi as CurrentSpeakersSettingsApi;
-        pub use windows::windows_api::WindowsApi as CurrentSpeakersSettingsApiTrait;
+/// Resolves the speaker to set as default along with the one to toggle from, which is the
+/// current default speaker, or none when the speaker already is the default one.
+fn resolve_default_speaker_toggle<'a>(
+    speaker_selector: &str,
+    speakers_infos: &'a [SpeakerInfo],
+) -> ApplicationResult<(&'a SpeakerInfo, Option<&'a SpeakerInfo>)> {
+    trace_fn!();
+
+    let speaker = resolve_existing_device(
+        DeviceKind::Speaker,
+        DeviceRole::Target,
+        speaker_selector,
+        speakers_infos,
+    )?;
+
+    let toggled_speaker = match speakers_infos
+        .iter()
+        .find(|speaker_info| speaker_info.is_default)
+    {
+        Some(default_speaker) if default_speaker.id == speaker.id => None,
+        Some(default_speaker) => Some(default_speaker),
+        None => Some(speaker),
+    };
+
+    Ok((speaker, toggled_speaker))
+}
+
+/// The couch speaker becomes the default when the desktop one is, the desktop speaker
+/// otherwise, the same way the backends toggle between them.
+fn plan_default_speaker_change(
+    speakers_infos: &[SpeakerInfo],
+    desktop_speaker: &SpeakerInfo,
+    couch_speaker: &SpeakerInfo,
+    roles: Vec<SpeakerRole>,
+) -> SpeakersPlan {
+    trace_fn!();
+
+    let new_default_speaker = if desktop_speaker.is_default {
+        couch_speaker
+    } else {
+        desktop_speaker
+    };
+
+    let mut speakers = speakers_infos
+        .iter()
+        .map(|speaker_info| SpeakerInfo {
+            is_default: speaker_info.id == new_default_speaker.id,
+            ..speaker_info.clone()
+        })
+        .collect::<Vec<SpeakerInfo>>();
+
+    speakers.sort();
+
+    SpeakersPlan {
+        new_default_speaker: new_default_speaker.name.clone(),
+        roles,
+        speakers,
     }
 }
+
+pub mod in_memory;
+
+#[cfg(target_os = "linux")]
+pub mod pulse_audio;
+
+#[cfg(target_os = "windows")]
+pub mod windows;
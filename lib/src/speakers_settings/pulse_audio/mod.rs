@@ -0,0 +1,3 @@
+pub mod pactl_based_pulse_audio_api;
+pub mod pulse_audio_api;
+pub mod pulse_audio_speakers_settings;
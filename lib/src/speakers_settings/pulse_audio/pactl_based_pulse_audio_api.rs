@@ -0,0 +1,35 @@
+use crate::{
+    application_result::ApplicationResult, command::run_command,
+    speakers_settings::pulse_audio::pulse_audio_api::PulseAudioApi, trace_fn,
+};
+
+pub struct PactlBasedPulseAudioApi {
+    program: String,
+}
+
+impl PactlBasedPulseAudioApi {
+    pub fn new(program: &str) -> Self {
+        trace_fn!();
+
+        Self {
+            program: String::from(program),
+        }
+    }
+}
+
+impl Default for PactlBasedPulseAudioApi {
+    fn default() -> Self {
+        trace_fn!();
+
+        Self::new("pactl")
+    }
+}
+
+impl PulseAudioApi for PactlBasedPulseAudioApi {
+    fn pactl(&mut self, args: &[String]) -> ApplicationResult<String> {
+        trace_fn!();
+
+        // The output of pactl is translated, forcing the C locale keeps it parsable.
+        run_command(&self.program, args, &[("LC_ALL", "C")])
+    }
+}
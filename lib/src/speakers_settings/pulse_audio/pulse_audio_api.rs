@@ -0,0 +1,9 @@
+use crate::application_result::ApplicationResult;
+
+pub trait PulseAudioApi {
+    /// Runs `pactl` against the current PulseAudio or PipeWire server.
+    ///
+    /// This is a thin wrapper around the `pactl` executable: the arguments are forwarded as is
+    /// and its standard output is returned without being parsed.
+    fn pactl(&mut self, args: &[String]) -> ApplicationResult<String>;
+}
@@ -0,0 +1,669 @@
+use log::{debug, info};
+
+use crate::{
+    application_error::{ApplicationError, DeviceKind, DeviceRole},
+    application_result::ApplicationResult,
+    speakers_settings::{
+        SpeakerFormFactor, SpeakerInfo, SpeakerState, SpeakersSettings, SpeakersSettingsResult,
+        SpeakersSnapshot, pulse_audio::pulse_audio_api::PulseAudioApi,
+    },
+    trace_fn,
+};
+
+const DEFAULT_SINK_PREFIX: &str = "Default Sink: ";
+const DEFAULT_SOURCE_PREFIX: &str = "Default Source: ";
+const SINK_HEADER_PREFIX: &str = "Sink #";
+const SINK_NAME_PREFIX: &str = "Name: ";
+const SINK_DESCRIPTION_PREFIX: &str = "Description: ";
+const SINK_STATE_PREFIX: &str = "State: ";
+const SINK_SAMPLE_SPECIFICATION_PREFIX: &str = "Sample Specification: ";
+const SINK_MUTE_PREFIX: &str = "Mute: ";
+const SINK_VOLUME_PREFIX: &str = "Volume: ";
+const SINK_ACTIVE_PORT_PREFIX: &str = "Active Port: ";
+const SINK_PROPERTIES_HEADER: &str = "Properties:";
+const SINK_PORTS_HEADER: &str = "Ports:";
+const FORM_FACTOR_PROPERTY_PREFIX: &str = "device.form_factor = ";
+const PORT_TYPE_PREFIX: &str = "type: ";
+const PORT_NOT_AVAILABLE: &str = "not available";
+
+pub struct PulseAudioSpeakersSettings {
+    pulse_audio_api: Box<dyn PulseAudioApi>,
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct PulseAudioSink {
+    name: String,
+    description: String,
+    is_default: bool,
+    state: Option<String>,
+    sample_specification: Option<String>,
+    is_muted: Option<bool>,
+    volume_percent: Option<u32>,
+    form_factor_property: Option<String>,
+    ports: Vec<PulseAudioPort>,
+    active_port_name: Option<String>,
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct PulseAudioPort {
+    name: String,
+    port_type: Option<String>,
+    is_available: bool,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum SinkSection {
+    Fields,
+    Properties,
+    Ports,
+    Other,
+}
+
+impl PulseAudioSink {
+    fn active_port(&self) -> Option<&PulseAudioPort> {
+        trace_fn!();
+
+        self.ports
+            .iter()
+            .find(|port| Some(&port.name) == self.active_port_name.as_ref())
+    }
+
+    /// Suspended and idle sinks are still usable, only a sink whose active port is reported as
+    /// not available, such as headphones which are not plugged in, is considered unplugged.
+    fn speaker_state(&self) -> SpeakerState {
+        trace_fn!();
+
+        if self.active_port().is_some_and(|port| !port.is_available) {
+            return SpeakerState::Unplugged;
+        }
+
+        match self.state.as_deref() {
+            Some("RUNNING" | "IDLE" | "SUSPENDED") => SpeakerState::Active,
+            _ => SpeakerState::Unknown,
+        }
+    }
+
+    /// The type of the active port is the most accurate, older servers only setting the form
+    /// factor of the device.
+    fn speaker_form_factor(&self) -> SpeakerFormFactor {
+        trace_fn!();
+
+        let port_form_factor = self
+            .active_port()
+            .and_then(|port| port.port_type.as_deref())
+            .map(|port_type| match port_type {
+                "Speaker" => SpeakerFormFactor::Speakers,
+                "Headphones" => SpeakerFormFactor::Headphones,
+                "Headset" | "Handsfree" => SpeakerFormFactor::Headset,
+                "HDMI" => SpeakerFormFactor::Hdmi,
+                "SPDIF" => SpeakerFormFactor::Spdif,
+                "Line" => SpeakerFormFactor::LineOut,
+                "Unknown" => SpeakerFormFactor::Unknown,
+                _ => SpeakerFormFactor::Other,
+            })
+            .filter(|form_factor| *form_factor != SpeakerFormFactor::Unknown);
+
+        port_form_factor.unwrap_or(match self.form_factor_property.as_deref() {
+            Some("internal" | "speaker") => SpeakerFormFactor::Speakers,
+            Some("headphone") => SpeakerFormFactor::Headphones,
+            Some("headset" | "hands-free") => SpeakerFormFactor::Headset,
+            Some(_) => SpeakerFormFactor::Other,
+            None => SpeakerFormFactor::Unknown,
+        })
+    }
+
+    fn to_speaker_info(&self) -> SpeakerInfo {
+        trace_fn!();
+
+        // A sample specification reads like `s16le 2ch 48000Hz`.
+        let mut sample_specification = self
+            .sample_specification
+            .as_deref()
+            .unwrap_or_default()
+            .split_whitespace();
+        let sample_format = sample_specification.next().map(String::from);
+        let channels = sample_specification
+            .next()
+            .and_then(|channels| channels.strip_suffix("ch"))
+            .and_then(|channels| channels.parse::<u16>().ok());
+        let sample_rate_hertz = sample_specification
+            .next()
+            .and_then(|sample_rate| sample_rate.strip_suffix("Hz"))
+            .and_then(|sample_rate| sample_rate.parse::<u32>().ok());
+
+        SpeakerInfo {
+            is_default: self.is_default,
+            name: self.description.clone(),
+            id: self.name.clone(),
+            state: self.speaker_state(),
+            form_factor: self.speaker_form_factor(),
+            channels,
+            sample_format,
+            sample_rate_hertz,
+            volume_percent: self.volume_percent,
+            is_muted: self.is_muted,
+        }
+    }
+}
+
+impl PulseAudioSpeakersSettings {
+    pub fn new(speakers_settings_api: Box<dyn PulseAudioApi>) -> Self {
+        trace_fn!();
+
+        Self {
+            pulse_audio_api: speakers_settings_api,
+        }
+    }
+
+    fn list_sinks(&mut self) -> ApplicationResult<Vec<PulseAudioSink>> {
+        trace_fn!();
+
+        let server_info = self.pulse_audio_api.pactl(&[String::from("info")])?;
+        let default_sink_name = server_info
+            .lines()
+            .find_map(|line| line.strip_prefix(DEFAULT_SINK_PREFIX))
+            .map(str::trim);
+
+        let sinks_list = self
+            .pulse_audio_api
+            .pactl(&[String::from("list"), String::from("sinks")])?;
+        let mut sinks = parse_sinks_list(&sinks_list);
+
+        for sink in &mut sinks {
+            sink.is_default = default_sink_name.is_some_and(|name| name == sink.name);
+        }
+
+        Ok(sinks)
+    }
+}
+
+impl SpeakersSettings for PulseAudioSpeakersSettings {
+    fn change_default_speaker(
+        &mut self,
+        desktop_speaker_id: &str,
+        couch_speaker_id: &str,
+    ) -> ApplicationResult<SpeakersSettingsResult> {
+        trace_fn!();
+        debug!(
+            "desktop_speaker_id = \"{desktop_speaker_id}\", couch_speaker_id = \"{couch_speaker_id}\""
+        );
+        info!("Changing default speaker");
+
+        let sinks = self.list_sinks()?;
+
+        let mut default_sink_name = None;
+        let mut desktop_speaker_name = None;
+        let mut couch_speaker_name = None;
+        let mut sink_names = Vec::with_capacity(sinks.len());
+
+        // The sinks are reported by their description, their name being their id.
+        for sink in sinks {
+            if sink.is_default {
+                default_sink_name = Some(sink.name.clone());
+            }
+
+            if sink.name == desktop_speaker_id {
+                desktop_speaker_name = Some(sink.description);
+            } else if sink.name == couch_speaker_id {
+                couch_speaker_name = Some(sink.description);
+            }
+
+            sink_names.push(sink.name);
+        }
+
+        let (desktop_speaker_name, couch_speaker_name) =
+            match (desktop_speaker_name, couch_speaker_name) {
+                (Some(desktop_speaker_name), Some(couch_speaker_name)) => {
+                    (desktop_speaker_name, couch_speaker_name)
+                }
+                (desktop_speaker_name, couch_speaker_name) => {
+                    let (invalid_role, invalid_ids) =
+                        match (desktop_speaker_name.is_none(), couch_speaker_name.is_none()) {
+                            (true, true) => (
+                                DeviceRole::DesktopAndCouch,
+                                vec![desktop_speaker_id, couch_speaker_id],
+                            ),
+                            (true, _) => (DeviceRole::Desktop, vec![desktop_speaker_id]),
+                            _ => (DeviceRole::Couch, vec![couch_speaker_id]),
+                        };
+                    let error = ApplicationError::device_not_found(
+                        DeviceKind::Speaker,
+                        invalid_role,
+                        &invalid_ids,
+                        sink_names,
+                    );
+
+                    return Err(error);
+                }
+            };
+
+        let (new_default_sink_name, new_default_speaker_name) =
+            if default_sink_name.is_some_and(|name| name == desktop_speaker_id) {
+                (couch_speaker_id, couch_speaker_name)
+            } else {
+                (desktop_speaker_id, desktop_speaker_name)
+            };
+
+        self.pulse_audio_api.pactl(&[
+            String::from("set-default-sink"),
+            String::from(new_default_sink_name),
+        ])?;
+
+        Ok(SpeakersSettingsResult {
+            new_default_speaker: new_default_speaker_name,
+        })
+    }
+
+    fn get_speakers_infos(&mut self) -> ApplicationResult<Vec<SpeakerInfo>> {
+        trace_fn!();
+        info!("Getting speakers informations");
+
+        let mut speakers_infos = self
+            .list_sinks()?
+            .iter()
+            .map(PulseAudioSink::to_speaker_info)
+            .collect::<Vec<SpeakerInfo>>();
+
+        speakers_infos.sort();
+
+        Ok(speakers_infos)
+    }
+
+    fn save_snapshot(&mut self) -> ApplicationResult<SpeakersSnapshot> {
+        trace_fn!();
+        info!("Saving speakers snapshot");
+
+        let server_info = self.pulse_audio_api.pactl(&[String::from("info")])?;
+        let find_default = |prefix: &str| {
+            server_info
+                .lines()
+                .find_map(|line| line.strip_prefix(prefix))
+                .map(|name| String::from(name.trim()))
+        };
+
+        Ok(SpeakersSnapshot::PulseAudio {
+            default_sink: find_default(DEFAULT_SINK_PREFIX),
+            default_source: find_default(DEFAULT_SOURCE_PREFIX),
+        })
+    }
+
+    fn restore_snapshot(&mut self, snapshot: &SpeakersSnapshot) -> ApplicationResult<()> {
+        trace_fn!();
+        info!("Restoring speakers snapshot");
+
+        let SpeakersSnapshot::PulseAudio {
+            default_sink,
+            default_source,
+        } = snapshot
+        else {
+            return Err(ApplicationError::snapshot_of_another_backend(
+                DeviceKind::Speaker,
+                snapshot.backend_name(),
+                "pulseaudio",
+            ));
+        };
+
+        if let Some(default_sink) = default_sink {
+            self.pulse_audio_api
+                .pactl(&[String::from("set-default-sink"), default_sink.clone()])?;
+        }
+
+        if let Some(default_source) = default_source {
+            self.pulse_audio_api
+                .pactl(&[String::from("set-default-source"), default_source.clone()])?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The top level fields of each sink are indented once, the properties and the ports being
+/// indented twice under their own header.
+fn parse_sinks_list(sinks_list: &str) -> Vec<PulseAudioSink> {
+    trace_fn!();
+
+    let mut sinks = Vec::new();
+    let mut section = SinkSection::Fields;
+
+    for line in sinks_list.lines() {
+        if line.starts_with(SINK_HEADER_PREFIX) {
+            sinks.push(PulseAudioSink::default());
+            section = SinkSection::Fields;
+            continue;
+        }
+
+        let Some(sink) = sinks.last_mut() else {
+            continue;
+        };
+
+        if let Some(nested_field) = line.strip_prefix("\t\t") {
+            match section {
+                SinkSection::Properties => {
+                    if let Some(form_factor) =
+                        nested_field.strip_prefix(FORM_FACTOR_PROPERTY_PREFIX)
+                    {
+                        sink.form_factor_property =
+                            Some(String::from(form_factor.trim().trim_matches('"')));
+                    }
+                }
+                SinkSection::Ports => sink.ports.extend(parse_port(nested_field)),
+                SinkSection::Fields | SinkSection::Other => {}
+            }
+
+            continue;
+        }
+
+        let Some(field) = line.strip_prefix('\t') else {
+            continue;
+        };
+
+        section = SinkSection::Fields;
+
+        if let Some(name) = field.strip_prefix(SINK_NAME_PREFIX) {
+            sink.name = String::from(name.trim());
+        } else if let Some(description) = field.strip_prefix(SINK_DESCRIPTION_PREFIX) {
+            sink.description = String::from(description.trim());
+        } else if let Some(state) = field.strip_prefix(SINK_STATE_PREFIX) {
+            sink.state = Some(String::from(state.trim()));
+        } else if let Some(sample_specification) =
+            field.strip_prefix(SINK_SAMPLE_SPECIFICATION_PREFIX)
+        {
+            sink.sample_specification = Some(String::from(sample_specification.trim()));
+        } else if let Some(mute) = field.strip_prefix(SINK_MUTE_PREFIX) {
+            sink.is_muted = match mute.trim() {
+                "yes" => Some(true),
+                "no" => Some(false),
+                _ => None,
+            };
+        } else if let Some(volume) = field.strip_prefix(SINK_VOLUME_PREFIX) {
+            sink.volume_percent = parse_volume_percent(volume);
+        } else if let Some(active_port_name) = field.strip_prefix(SINK_ACTIVE_PORT_PREFIX) {
+            sink.active_port_name = Some(String::from(active_port_name.trim()));
+        } else if field.starts_with(SINK_PROPERTIES_HEADER) {
+            section = SinkSection::Properties;
+        } else if field.starts_with(SINK_PORTS_HEADER) {
+            section = SinkSection::Ports;
+        } else if field.ends_with(':') {
+            section = SinkSection::Other;
+        }
+    }
+
+    for sink in &mut sinks {
+        if sink.description.is_empty() {
+            sink.description = sink.name.clone();
+        }
+    }
+
+    sinks
+}
+
+/// A port reads like `hdmi-output-0: HDMI / DisplayPort (type: HDMI, priority: 5900, available)`,
+/// the type being missing on older servers.
+fn parse_port(port: &str) -> Option<PulseAudioPort> {
+    trace_fn!();
+
+    let (name, description) = port.split_once(':')?;
+    let attributes = description
+        .rsplit_once('(')
+        .map(|(_, attributes)| attributes.trim_end_matches(')'))
+        .unwrap_or_default();
+    let port_type = attributes
+        .split(',')
+        .find_map(|attribute| attribute.trim().strip_prefix(PORT_TYPE_PREFIX))
+        .map(String::from);
+    let is_available = !attributes
+        .split(',')
+        .any(|attribute| attribute.trim() == PORT_NOT_AVAILABLE);
+
+    Some(PulseAudioPort {
+        name: String::from(name.trim()),
+        port_type,
+        is_available,
+    })
+}
+
+/// A volume reads like `front-left: 42597 /  65% / -11.23 dB,   front-right: 42597 /  65% /
+/// -11.23 dB`, the average of the channels being reported.
+fn parse_volume_percent(volume: &str) -> Option<u32> {
+    trace_fn!();
+
+    let channels_percents = volume
+        .split(',')
+        .filter_map(|channel_volume| {
+            channel_volume
+                .split('/')
+                .find_map(|part| part.trim().strip_suffix('%'))
+                .and_then(|percent| percent.trim().parse::<u32>().ok())
+        })
+        .collect::<Vec<u32>>();
+
+    let channels_count = u32::try_from(channels_percents.len()).ok()?;
+
+    (channels_count > 0).then(|| channels_percents.iter().sum::<u32>() / channels_count)
+}
+
+#[cfg(test)]
+mod should {
+    use std::{cell::RefCell, rc::Rc};
+
+    use test_case::test_case;
+
+    use crate::{
+        application_error::{ApplicationError, DeviceKind, DeviceRole},
+        application_result::ApplicationResult,
+        speakers_settings::{
+            SpeakerFormFactor, SpeakerInfo, SpeakerState, SpeakersSettings, SpeakersSettingsResult,
+            SpeakersSnapshot,
+            pulse_audio::{
+                pulse_audio_api::PulseAudioApi,
+                pulse_audio_speakers_settings::PulseAudioSpeakersSettings,
+            },
+        },
+    };
+
+    const SINKS_LIST: &str = "Sink #56\n\
+        \tState: RUNNING\n\
+        \tName: alsa_output.usb-Logitech_G560-00.analog-stereo\n\
+        \tDescription: G560 Gaming Speaker Analog Stereo\n\
+        \tDriver: PipeWire\n\
+        \tSample Specification: s16le 2ch 48000Hz\n\
+        \tChannel Map: front-left,front-right\n\
+        \tMute: no\n\
+        \tVolume: front-left: 42597 /  65% / -11.23 dB,   front-right: 45875 /  70% / -9.29 dB\n\
+        \t        balance 0.07\n\
+        \tProperties:\n\
+        \t\tdevice.description = \"G560 Gaming Speaker\"\n\
+        \t\tdevice.form_factor = \"speaker\"\n\
+        \tPorts:\n\
+        \t\tanalog-output: Analog Output (type: Unknown, priority: 9900, availability unknown)\n\
+        \tActive Port: analog-output\n\
+        \tFormats:\n\
+        \t\tpcm\n\
+        \n\
+        Sink #57\n\
+        \tState: SUSPENDED\n\
+        \tName: alsa_output.pci-0000_01_00.1.hdmi-stereo\n\
+        \tDescription: LG TV SSCR2\n\
+        \tDriver: PipeWire\n\
+        \tSample Specification: float32le 6ch 44100Hz\n\
+        \tMute: yes\n\
+        \tVolume: front-left: 65536 / 100% / 0.00 dB\n\
+        \tPorts:\n\
+        \t\thdmi-output-0: HDMI / DisplayPort (type: HDMI, priority: 5900, available)\n\
+        \tActive Port: hdmi-output-0\n\
+        \n\
+        Sink #58\n\
+        \tState: SUSPENDED\n\
+        \tName: alsa_output.pci-0000_00_1f.3.analog-stereo\n\
+        \tDescription: Built-in Audio Analog Stereo\n\
+        \tDriver: PipeWire\n\
+        \tPorts:\n\
+        \t\tanalog-output-headphones: Headphones (type: Headphones, priority: 9900, availability group: Legacy 2, not available)\n\
+        \tActive Port: analog-output-headphones\n";
+
+    struct FakePulseAudioApi {
+        default_sink_name: String,
+        calls: Rc<RefCell<Vec<Vec<String>>>>,
+    }
+
+    impl PulseAudioApi for FakePulseAudioApi {
+        fn pactl(&mut self, args: &[String]) -> ApplicationResult<String> {
+            self.calls.borrow_mut().push(args.to_vec());
+
+            match args.first().map(String::as_str) {
+                Some("info") => Ok(format!(
+                    "Server Name: PulseAudio (on PipeWire 1.0.5)\nDefault Sink: {}\nDefault Source: alsa_input.pci-0000_00_1f.3.analog-stereo\n",
+                    self.default_sink_name
+                )),
+                Some("list") => Ok(String::from(SINKS_LIST)),
+                _ => Ok(String::new()),
+            }
+        }
+    }
+
+    fn pulse_audio_speakers_settings(
+        default_sink_name: &str,
+    ) -> (PulseAudioSpeakersSettings, Rc<RefCell<Vec<Vec<String>>>>) {
+        let calls = Rc::new(RefCell::new(Vec::new()));
+        let pulse_audio_api = FakePulseAudioApi {
+            default_sink_name: String::from(default_sink_name),
+            calls: Rc::clone(&calls),
+        };
+
+        (
+            PulseAudioSpeakersSettings::new(Box::new(pulse_audio_api)),
+            calls,
+        )
+    }
+
+    #[test]
+    fn get_the_sinks_by_their_description_with_their_details() {
+        // Arrange
+        let (mut pulse_audio_speakers_settings, _) =
+            pulse_audio_speakers_settings("alsa_output.pci-0000_01_00.1.hdmi-stereo");
+
+        // Act
+        let speakers_infos = pulse_audio_speakers_settings.get_speakers_infos();
+
+        // Assert
+        assert_eq!(
+            speakers_infos,
+            Ok(vec![
+                SpeakerInfo {
+                    is_default: true,
+                    name: String::from("LG TV SSCR2"),
+                    id: String::from("alsa_output.pci-0000_01_00.1.hdmi-stereo"),
+                    state: SpeakerState::Active,
+                    form_factor: SpeakerFormFactor::Hdmi,
+                    channels: Some(6),
+                    sample_format: Some(String::from("float32le")),
+                    sample_rate_hertz: Some(44100),
+                    volume_percent: Some(100),
+                    is_muted: Some(true),
+                },
+                SpeakerInfo {
+                    is_default: false,
+                    name: String::from("Built-in Audio Analog Stereo"),
+                    id: String::from("alsa_output.pci-0000_00_1f.3.analog-stereo"),
+                    state: SpeakerState::Unplugged,
+                    form_factor: SpeakerFormFactor::Headphones,
+                    ..Default::default()
+                },
+                SpeakerInfo {
+                    is_default: false,
+                    name: String::from("G560 Gaming Speaker Analog Stereo"),
+                    id: String::from("alsa_output.usb-Logitech_G560-00.analog-stereo"),
+                    state: SpeakerState::Active,
+                    form_factor: SpeakerFormFactor::Speakers,
+                    channels: Some(2),
+                    sample_format: Some(String::from("s16le")),
+                    sample_rate_hertz: Some(48000),
+                    volume_percent: Some(67),
+                    is_muted: Some(false),
+                },
+            ])
+        );
+    }
+
+    #[test_case("alsa_output.usb-Logitech_G560-00.analog-stereo" => (String::from("LG TV SSCR2"), String::from("alsa_output.pci-0000_01_00.1.hdmi-stereo")); "when the desktop speaker is the default one")]
+    #[test_case("alsa_output.pci-0000_01_00.1.hdmi-stereo" => (String::from("G560 Gaming Speaker Analog Stereo"), String::from("alsa_output.usb-Logitech_G560-00.analog-stereo")); "when the couch speaker is the default one")]
+    fn swap_the_default_sink(default_sink_name: &str) -> (String, String) {
+        // Arrange
+        let (mut pulse_audio_speakers_settings, calls) =
+            pulse_audio_speakers_settings(default_sink_name);
+
+        // Act
+        let result = pulse_audio_speakers_settings
+            .swap_default_speaker("G560 Gaming Speaker Analog Stereo", "LG TV SSCR2")
+            .unwrap();
+
+        // Assert
+        let calls = calls.borrow();
+        let set_default_sink_call = calls.last().unwrap();
+
+        assert_eq!(set_default_sink_call[0], "set-default-sink");
+
+        (result.new_default_speaker, set_default_sink_call[1].clone())
+    }
+
+    #[test_case("Unknown Speaker", "LG TV SSCR2" => Err(ApplicationError::DeviceNotFound { kind: DeviceKind::Speaker, role: DeviceRole::Desktop, requested: vec![String::from("Unknown Speaker")], candidates: vec![String::from("Built-in Audio Analog Stereo"), String::from("G560 Gaming Speaker Analog Stereo"), String::from("LG TV SSCR2")], suggestions: Vec::new() }); "when the desktop speaker is invalid")]
+    #[test_case("G560 Gaming Speaker Analog Stereo", "Unknown TV" => Err(ApplicationError::DeviceNotFound { kind: DeviceKind::Speaker, role: DeviceRole::Couch, requested: vec![String::from("Unknown TV")], candidates: vec![String::from("Built-in Audio Analog Stereo"), String::from("G560 Gaming Speaker Analog Stereo"), String::from("LG TV SSCR2")], suggestions: Vec::new() }); "when the couch speaker is invalid")]
+    #[test_case("Unknown Speaker", "Unknown TV" => Err(ApplicationError::DeviceNotFound { kind: DeviceKind::Speaker, role: DeviceRole::DesktopAndCouch, requested: vec![String::from("Unknown Speaker"), String::from("Unknown TV")], candidates: vec![String::from("Built-in Audio Analog Stereo"), String::from("G560 Gaming Speaker Analog Stereo"), String::from("LG TV SSCR2")], suggestions: Vec::new() }); "when both speakers are invalid")]
+    #[test_case("g560 gaming speaker", "LG TV SSCR2" => Err(ApplicationError::DeviceNotFound { kind: DeviceKind::Speaker, role: DeviceRole::Desktop, requested: vec![String::from("g560 gaming speaker")], candidates: vec![String::from("Built-in Audio Analog Stereo"), String::from("G560 Gaming Speaker Analog Stereo"), String::from("LG TV SSCR2")], suggestions: vec![String::from("G560 Gaming Speaker Analog Stereo")] }); "when the desktop speaker is misspelled")]
+    fn fail_to_change_the_default_speaker(
+        desktop_speaker_name: &str,
+        couch_speaker_name: &str,
+    ) -> ApplicationResult<SpeakersSettingsResult> {
+        // Arrange
+        let (mut pulse_audio_speakers_settings, calls) =
+            pulse_audio_speakers_settings("alsa_output.usb-Logitech_G560-00.analog-stereo");
+
+        // Act
+        let result = pulse_audio_speakers_settings
+            .swap_default_speaker(desktop_speaker_name, couch_speaker_name);
+
+        // Assert
+        assert!(
+            calls
+                .borrow()
+                .iter()
+                .all(|call| call[0] != "set-default-sink")
+        );
+
+        result
+    }
+
+    #[test]
+    fn restore_the_default_sink_and_source_of_a_snapshot() {
+        // Arrange
+        let (mut pulse_audio_speakers_settings, calls) =
+            pulse_audio_speakers_settings("alsa_output.pci-0000_01_00.1.hdmi-stereo");
+        let snapshot = pulse_audio_speakers_settings.save_snapshot().unwrap();
+
+        // Act
+        let result = pulse_audio_speakers_settings.restore_snapshot(&snapshot);
+
+        // Assert
+        assert_eq!(result, Ok(()));
+        assert_eq!(
+            snapshot,
+            SpeakersSnapshot::PulseAudio {
+                default_sink: Some(String::from("alsa_output.pci-0000_01_00.1.hdmi-stereo")),
+                default_source: Some(String::from("alsa_input.pci-0000_00_1f.3.analog-stereo")),
+            }
+        );
+        assert_eq!(
+            calls.borrow()[1..],
+            [
+                vec![
+                    String::from("set-default-sink"),
+                    String::from("alsa_output.pci-0000_01_00.1.hdmi-stereo")
+                ],
+                vec![
+                    String::from("set-default-source"),
+                    String::from("alsa_input.pci-0000_00_1f.3.analog-stereo")
+                ],
+            ]
+        );
+    }
+}
@@ -0,0 +1,34 @@
+use crate::{
+    speakers_settings::windows::windows_api::IAudioEndpointVolume as IAudioEndpointVolumeTrait,
+    trace_fn,
+};
+use windows::Win32::Media::Audio::Endpoints::IAudioEndpointVolume;
+use windows_core::{BOOL, Result};
+
+pub struct Win32BasedIAudioEndpointVolume {
+    iaudio_endpoint_volume: IAudioEndpointVolume,
+}
+
+impl Win32BasedIAudioEndpointVolume {
+    pub fn new(iaudio_endpoint_volume: IAudioEndpointVolume) -> Self {
+        trace_fn!();
+
+        Self {
+            iaudio_endpoint_volume,
+        }
+    }
+}
+
+impl IAudioEndpointVolumeTrait for Win32BasedIAudioEndpointVolume {
+    unsafe fn get_master_volume_level_scalar(&self) -> Result<f32> {
+        trace_fn!();
+
+        unsafe { self.iaudio_endpoint_volume.GetMasterVolumeLevelScalar() }
+    }
+
+    unsafe fn get_mute(&self) -> Result<BOOL> {
+        trace_fn!();
+
+        unsafe { self.iaudio_endpoint_volume.GetMute() }
+    }
+}
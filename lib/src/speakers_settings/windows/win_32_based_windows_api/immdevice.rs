@@ -1,11 +1,20 @@
 use crate::{
     speakers_settings::windows::{
-        win_32_based_windows_api::iproperty_store::Win32BasedIPropertyStore,
-        windows_api::{IMMDevice as IMMDeviceTrait, IPropertyStore as IPropertyStoreTrait},
+        win_32_based_windows_api::{
+            iaudio_endpoint_volume::Win32BasedIAudioEndpointVolume,
+            iproperty_store::Win32BasedIPropertyStore,
+        },
+        windows_api::{
+            IAudioEndpointVolume as IAudioEndpointVolumeTrait, IMMDevice as IMMDeviceTrait,
+            IPropertyStore as IPropertyStoreTrait,
+        },
     },
     trace_fn,
 };
-use windows::Win32::{Media::Audio::IMMDevice, System::Com::STGM};
+use windows::Win32::{
+    Media::Audio::{DEVICE_STATE, Endpoints::IAudioEndpointVolume, IMMDevice},
+    System::Com::{CLSCTX_ALL, STGM},
+};
 use windows_core::{PWSTR, Result};
 
 pub struct Win32ApiBasedIMMDevice {
@@ -39,4 +48,25 @@ impl IMMDeviceTrait for Win32ApiBasedIMMDevice {
             Ok(boxed_windows_api_based_iproperty_store)
         }
     }
+
+    unsafe fn get_state(&self) -> Result<DEVICE_STATE> {
+        trace_fn!();
+
+        unsafe { self.immdevice.GetState() }
+    }
+
+    unsafe fn activate_audio_endpoint_volume(&self) -> Result<Box<dyn IAudioEndpointVolumeTrait>> {
+        trace_fn!();
+
+        unsafe {
+            let iaudio_endpoint_volume: IAudioEndpointVolume =
+                self.immdevice.Activate(CLSCTX_ALL, None)?;
+            let windows_api_based_iaudio_endpoint_volume =
+                Win32BasedIAudioEndpointVolume::new(iaudio_endpoint_volume);
+            let boxed_windows_api_based_iaudio_endpoint_volume =
+                Box::new(windows_api_based_iaudio_endpoint_volume);
+
+            Ok(boxed_windows_api_based_iaudio_endpoint_volume)
+        }
+    }
 }
@@ -19,6 +19,7 @@ use windows::Win32::{
 };
 use windows_core::{GUID, HRESULT, Result};
 
+pub mod iaudio_endpoint_volume;
 pub mod immdevice;
 pub mod immdevice_collection;
 pub mod immdevice_enumerator;
@@ -27,6 +28,7 @@ pub mod iproperty_store;
 
 const POLICY_CONFIG_VISTA: GUID = GUID::from_u128(0x294935ce_f637_4e7c_a41b_ab255460b862);
 
+#[derive(Default)]
 pub struct Win32BasedWindowsApi;
 
 impl WindowsApi for Win32BasedWindowsApi {
@@ -5,7 +5,7 @@ use windows::Win32::{
     Media::Audio::{DEVICE_STATE, EDataFlow, ERole},
     System::Com::{COINIT, STGM, StructuredStorage::PROPVARIANT},
 };
-use windows_core::{HRESULT, PCWSTR, PWSTR, Result};
+use windows_core::{BOOL, HRESULT, PCWSTR, PWSTR, Result};
 
 pub trait WindowsApi {
     /// Initializes COM for the current thread.
@@ -101,6 +101,41 @@ pub trait IMMDevice {
     /// - `stgmaccess` must be a valid `STGM` access flag.
     /// - The returned property store must follow COM lifetime and threading rules.
     unsafe fn open_property_store(&self, stgmaccess: STGM) -> Result<Box<dyn IPropertyStore>>;
+
+    /// Retrieves the current state of the device.
+    ///
+    /// # Safety
+    ///
+    /// - The COM object must be valid.
+    /// - COM must be initialized on the calling thread.
+    unsafe fn get_state(&self) -> Result<DEVICE_STATE>;
+
+    /// Activates the `IAudioEndpointVolume` interface of the device.
+    ///
+    /// # Safety
+    ///
+    /// - The COM object must be valid.
+    /// - COM must be initialized on the calling thread.
+    /// - The returned interface must follow COM lifetime and threading rules.
+    unsafe fn activate_audio_endpoint_volume(&self) -> Result<Box<dyn IAudioEndpointVolume>>;
+}
+
+pub trait IAudioEndpointVolume {
+    /// Gets the master volume level, between 0.0 and 1.0.
+    ///
+    /// # Safety
+    ///
+    /// - The COM object must be valid.
+    /// - COM must be initialized on the calling thread.
+    unsafe fn get_master_volume_level_scalar(&self) -> Result<f32>;
+
+    /// Gets the muting state.
+    ///
+    /// # Safety
+    ///
+    /// - The COM object must be valid.
+    /// - COM must be initialized on the calling thread.
+    unsafe fn get_mute(&self) -> Result<BOOL>;
 }
 
 pub trait IMMDeviceCollection {
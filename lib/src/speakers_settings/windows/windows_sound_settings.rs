@@ -1,41 +1,122 @@
 use log::{debug, info};
+use serde::{Deserialize, Serialize};
 use windows::Win32::{
     Devices::FunctionDiscovery::PKEY_Device_FriendlyName,
-    Media::Audio::{DEVICE_STATE_ACTIVE, EDataFlow, eConsole, eRender},
+    Foundation::ERROR_NOT_FOUND,
+    Media::Audio::{
+        DEVICE_STATE, DEVICE_STATE_ACTIVE, DEVICE_STATE_DISABLED, DEVICE_STATE_NOTPRESENT,
+        DEVICE_STATE_UNPLUGGED, DigitalAudioDisplayDevice, EDataFlow, ERole, EndpointFormFactor,
+        Handset, Headphones, Headset, LineLevel, PKEY_AudioEndpoint_FormFactor,
+        PKEY_AudioEngine_DeviceFormat, SPDIF, Speakers, UnknownFormFactor, eCapture,
+        eCommunications, eConsole, eMultimedia, eRender,
+    },
     System::Com::{COINIT_MULTITHREADED, STGM_READ},
 };
 use windows_core::{PCWSTR, PWSTR};
 
 use crate::{
-    application_error::ApplicationError,
+    application_error::{ApplicationError, DeviceKind, DeviceRole},
     application_result::ApplicationResult,
     speakers_settings::{
-        SpeakerInfo, SpeakersSettings, SpeakersSettingsResult, windows::windows_api::WindowsApi,
+        SpeakerFormFactor, SpeakerInfo, SpeakerRole, SpeakerState, SpeakersSettings,
+        SpeakersSettingsResult, SpeakersSnapshot,
+        windows::windows_api::{IMMDevice, WindowsApi},
     },
     trace_fn,
 };
 
+const WAVE_FORMAT_PCM: u16 = 0x0001;
+const WAVE_FORMAT_IEEE_FLOAT: u16 = 0x0003;
+const WAVE_FORMAT_EXTENSIBLE: u16 = 0xFFFE;
+const WAVEFORMATEX_SIZE: usize = 18;
+const WAVEFORMATEXTENSIBLE_SIZE: usize = 40;
+const WAVEFORMATEXTENSIBLE_SUB_FORMAT_OFFSET: usize = 24;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EndpointDataFlow {
+    Render,
+    Capture,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EndpointRole {
+    Console,
+    Multimedia,
+    Communications,
+}
+
+/// The endpoint set as the default one for a data flow and a role.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WindowsDefaultEndpoint {
+    pub data_flow: EndpointDataFlow,
+    pub role: EndpointRole,
+    pub id: String,
+}
+
+const DEFAULT_ENDPOINTS: [(EndpointDataFlow, EndpointRole); 6] = [
+    (EndpointDataFlow::Render, EndpointRole::Console),
+    (EndpointDataFlow::Render, EndpointRole::Multimedia),
+    (EndpointDataFlow::Render, EndpointRole::Communications),
+    (EndpointDataFlow::Capture, EndpointRole::Console),
+    (EndpointDataFlow::Capture, EndpointRole::Multimedia),
+    (EndpointDataFlow::Capture, EndpointRole::Communications),
+];
+
+impl EndpointDataFlow {
+    fn to_edataflow(self) -> EDataFlow {
+        trace_fn!();
+
+        match self {
+            EndpointDataFlow::Render => eRender,
+            EndpointDataFlow::Capture => eCapture,
+        }
+    }
+}
+
+impl EndpointRole {
+    fn to_erole(self) -> ERole {
+        trace_fn!();
+
+        match self {
+            EndpointRole::Console => eConsole,
+            EndpointRole::Multimedia => eMultimedia,
+            EndpointRole::Communications => eCommunications,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct DeviceFormat {
+    channels: u16,
+    sample_format: Option<String>,
+    sample_rate_hertz: u32,
+}
+
 pub struct WindowsSoundSettings {
     windows_com: Box<dyn WindowsApi>,
 }
 
-impl SpeakersSettings for WindowsSoundSettings {
-    fn new(speakers_settings_api: Box<dyn WindowsApi>) -> Self {
+impl WindowsSoundSettings {
+    pub fn new(speakers_settings_api: Box<dyn WindowsApi>) -> Self {
         trace_fn!();
 
         Self {
             windows_com: speakers_settings_api,
         }
     }
+}
 
+impl SpeakersSettings for WindowsSoundSettings {
     fn change_default_speaker(
         &mut self,
-        desktop_speaker_name: &str,
-        couch_speaker_name: &str,
+        desktop_speaker_id: &str,
+        couch_speaker_id: &str,
     ) -> ApplicationResult<SpeakersSettingsResult> {
         trace_fn!();
         debug!(
-            "desktop_speaker_name = \"{desktop_speaker_name}\", couch_speaker_name = \"{couch_speaker_name}\""
+            "desktop_speaker_id = \"{desktop_speaker_id}\", couch_speaker_id = \"{couch_speaker_id}\""
         );
         info!("Changing default speaker");
 
@@ -61,55 +142,61 @@ impl SpeakersSettings for WindowsSoundSettings {
 
             let speaker_count = unsafe { immdevice_collection.get_count() }?;
 
-            let mut desktop_speaker_id: PWSTR = PWSTR::default();
-            let mut couch_speaker_id: PWSTR = PWSTR::default();
-            let mut speaker_names = Vec::with_capacity(speaker_count as usize);
+            let mut desktop_speaker: Option<(PWSTR, String)> = None;
+            let mut couch_speaker: Option<(PWSTR, String)> = None;
+            let mut speaker_ids = Vec::with_capacity(speaker_count as usize);
 
             for speaker_index in 0..speaker_count {
                 let immdevice = unsafe { immdevice_collection.item(speaker_index) }?;
                 let immdevice_id = unsafe { immdevice.get_id() }?;
-                let property_store = unsafe { immdevice.open_property_store(STGM_READ) }?;
-                let propvariant = unsafe { property_store.get_value(&PKEY_Device_FriendlyName) }?;
-                let pwsz_val = unsafe { propvariant.Anonymous.Anonymous.Anonymous.pwszVal };
-                let friendly_name = String::from_utf16(unsafe { pwsz_val.as_wide() })?;
-
-                if friendly_name == desktop_speaker_name {
-                    desktop_speaker_id = immdevice_id;
-                } else if friendly_name == couch_speaker_name {
-                    couch_speaker_id = immdevice_id;
+                let id = String::from_utf16(unsafe { immdevice_id.as_wide() })?;
+
+                if id == desktop_speaker_id || id == couch_speaker_id {
+                    let property_store = unsafe { immdevice.open_property_store(STGM_READ) }?;
+                    let propvariant =
+                        unsafe { property_store.get_value(&PKEY_Device_FriendlyName) }?;
+                    let pwsz_val = unsafe { propvariant.Anonymous.Anonymous.Anonymous.pwszVal };
+                    let friendly_name = String::from_utf16(unsafe { pwsz_val.as_wide() })?;
+
+                    if id == desktop_speaker_id {
+                        desktop_speaker = Some((immdevice_id, friendly_name));
+                    } else {
+                        couch_speaker = Some((immdevice_id, friendly_name));
+                    }
                 }
 
-                speaker_names.push(friendly_name);
+                speaker_ids.push(id);
             }
 
-            speaker_names.sort();
-
-            let invalid_params_error_message =
-                match (desktop_speaker_id.is_null(), couch_speaker_id.is_null()) {
-                    (true, true) => Some("Desktop and couch speakers are invalid"),
-                    (true, _) => Some("Desktop speaker is invalid"),
-                    (_, true) => Some("Couch speaker is invalid"),
-                    _ => None,
-                };
-
-            if let Some(invalid_params_error_message_fragment) = invalid_params_error_message {
-                let possible_values_fragment = speaker_names.join(", ");
-                let error_message = format!(
-                    "{invalid_params_error_message_fragment}, possible values are [{possible_values_fragment}]"
-                );
-                let error = ApplicationError::Custom(error_message);
-
-                return Err(error);
-            }
+            let (desktop_speaker, couch_speaker) = match (desktop_speaker, couch_speaker) {
+                (Some(desktop_speaker), Some(couch_speaker)) => (desktop_speaker, couch_speaker),
+                (desktop_speaker, couch_speaker) => {
+                    let (invalid_role, invalid_ids) =
+                        match (desktop_speaker.is_none(), couch_speaker.is_none()) {
+                            (true, true) => (
+                                DeviceRole::DesktopAndCouch,
+                                vec![desktop_speaker_id, couch_speaker_id],
+                            ),
+                            (true, _) => (DeviceRole::Desktop, vec![desktop_speaker_id]),
+                            _ => (DeviceRole::Couch, vec![couch_speaker_id]),
+                        };
+                    let error = ApplicationError::device_not_found(
+                        DeviceKind::Speaker,
+                        invalid_role,
+                        &invalid_ids,
+                        speaker_ids,
+                    );
+
+                    return Err(error);
+                }
+            };
 
             let new_default_speaker_id: PWSTR;
 
-            if pwstr_eq(default_speaker_id, desktop_speaker_id) {
-                new_default_speaker_id = couch_speaker_id;
-                new_default_speaker_name = couch_speaker_name.to_string();
+            if pwstr_eq(default_speaker_id, desktop_speaker.0) {
+                (new_default_speaker_id, new_default_speaker_name) = couch_speaker;
             } else {
-                new_default_speaker_id = desktop_speaker_id;
-                new_default_speaker_name = desktop_speaker_name.to_string();
+                (new_default_speaker_id, new_default_speaker_name) = desktop_speaker;
             }
 
             let mut policy = unsafe { self.windows_com.co_create_ipolicy_config_vista() }?;
@@ -155,7 +242,12 @@ impl SpeakersSettings for WindowsSoundSettings {
             }
 
             let immdevice_collection = unsafe {
-                immdevice_enumerator.enum_audio_endpoints(EDataFlow::default(), DEVICE_STATE_ACTIVE)
+                immdevice_enumerator.enum_audio_endpoints(
+                    EDataFlow::default(),
+                    DEVICE_STATE(
+                        DEVICE_STATE_ACTIVE.0 | DEVICE_STATE_DISABLED.0 | DEVICE_STATE_UNPLUGGED.0,
+                    ),
+                )
             }?;
 
             let speaker_count = unsafe { immdevice_collection.get_count() }?;
@@ -170,15 +262,64 @@ impl SpeakersSettings for WindowsSoundSettings {
                 let pwsz_val = unsafe { propvariant.Anonymous.Anonymous.Anonymous.pwszVal };
                 let friendly_name = String::from_utf16(unsafe { pwsz_val.as_wide() })?;
 
+                let id = String::from_utf16(unsafe { immdevice_id.as_wide() })?;
+
                 let is_default = if let Some(default_speaker_id) = default_speaker_id_option {
                     pwstr_eq(default_speaker_id, immdevice_id)
                 } else {
                     false
                 };
 
+                // The details below are best effort, a speaker which does not expose them is
+                // still reported.
+                let state = unsafe { immdevice.get_state() }
+                    .map(from_device_state_to_speaker_state)
+                    .unwrap_or_default();
+                let form_factor =
+                    unsafe { property_store.get_value(&PKEY_AudioEndpoint_FormFactor) }
+                        .map(|propvariant| {
+                            let ul_val = unsafe { propvariant.Anonymous.Anonymous.Anonymous.ulVal };
+
+                            from_endpoint_form_factor_to_speaker_form_factor(EndpointFormFactor(
+                                ul_val as i32,
+                            ))
+                        })
+                        .unwrap_or_default();
+                let device_format =
+                    unsafe { property_store.get_value(&PKEY_AudioEngine_DeviceFormat) }
+                        .ok()
+                        .and_then(|propvariant| {
+                            let blob = unsafe { propvariant.Anonymous.Anonymous.Anonymous.blob };
+
+                            if blob.pBlobData.is_null() {
+                                return None;
+                            }
+
+                            let wave_format = unsafe {
+                                std::slice::from_raw_parts(blob.pBlobData, blob.cbSize as usize)
+                            };
+
+                            from_wave_format_to_device_format(wave_format)
+                        });
+                let (volume_percent, is_muted) = get_volume_and_mute(immdevice.as_ref());
+
                 speakers_infos.push(SpeakerInfo {
                     is_default,
                     name: friendly_name,
+                    id,
+                    state,
+                    form_factor,
+                    channels: device_format
+                        .as_ref()
+                        .map(|device_format| device_format.channels),
+                    sample_format: device_format
+                        .as_ref()
+                        .and_then(|device_format| device_format.sample_format.clone()),
+                    sample_rate_hertz: device_format
+                        .as_ref()
+                        .map(|device_format| device_format.sample_rate_hertz),
+                    volume_percent,
+                    is_muted,
                 });
             }
         }
@@ -189,6 +330,191 @@ impl SpeakersSettings for WindowsSoundSettings {
 
         Ok(speakers_infos)
     }
+
+    /// Only the console role is changed, leaving the endpoints chosen for the multimedia and
+    /// communications ones, such as a headset for calls, untouched.
+    fn default_speaker_roles(&self) -> Vec<SpeakerRole> {
+        trace_fn!();
+
+        vec![SpeakerRole::Console]
+    }
+
+    fn save_snapshot(&mut self) -> ApplicationResult<SpeakersSnapshot> {
+        trace_fn!();
+        info!("Saving speakers snapshot");
+
+        unsafe {
+            self.windows_com
+                .co_initialize_ex(None, COINIT_MULTITHREADED)
+        }
+        .ok()?;
+
+        let mut default_endpoints = Vec::with_capacity(DEFAULT_ENDPOINTS.len());
+
+        {
+            let immdevice_enumerator =
+                unsafe { self.windows_com.co_create_immdevice_enumerator() }?;
+
+            // A data flow without any endpoint, such as a machine without microphone, has no
+            // default endpoint to restore, which Windows reports as not found.
+            for (data_flow, role) in DEFAULT_ENDPOINTS {
+                let default_endpoint = match unsafe {
+                    immdevice_enumerator
+                        .get_default_audio_endpoint(data_flow.to_edataflow(), role.to_erole())
+                } {
+                    Ok(default_endpoint) => default_endpoint,
+                    Err(error) if error.code() == ERROR_NOT_FOUND.to_hresult() => continue,
+                    Err(error) => return Err(error.into()),
+                };
+
+                let default_endpoint_id = unsafe { default_endpoint.get_id() }?;
+                let id = String::from_utf16(unsafe { default_endpoint_id.as_wide() })?;
+
+                default_endpoints.push(WindowsDefaultEndpoint {
+                    data_flow,
+                    role,
+                    id,
+                });
+            }
+        }
+
+        unsafe { self.windows_com.co_uninitialize() };
+
+        Ok(SpeakersSnapshot::Windows { default_endpoints })
+    }
+
+    fn restore_snapshot(&mut self, snapshot: &SpeakersSnapshot) -> ApplicationResult<()> {
+        trace_fn!();
+        info!("Restoring speakers snapshot");
+
+        let SpeakersSnapshot::Windows { default_endpoints } = snapshot else {
+            return Err(ApplicationError::snapshot_of_another_backend(
+                DeviceKind::Speaker,
+                snapshot.backend_name(),
+                "windows",
+            ));
+        };
+
+        unsafe {
+            self.windows_com
+                .co_initialize_ex(None, COINIT_MULTITHREADED)
+        }
+        .ok()?;
+
+        {
+            let mut policy = unsafe { self.windows_com.co_create_ipolicy_config_vista() }?;
+
+            for default_endpoint in default_endpoints {
+                let id = default_endpoint
+                    .id
+                    .encode_utf16()
+                    .chain([0])
+                    .collect::<Vec<u16>>();
+
+                unsafe {
+                    policy
+                        .set_default_endpoint(PCWSTR(id.as_ptr()), default_endpoint.role.to_erole())
+                }?;
+            }
+        }
+
+        unsafe { self.windows_com.co_uninitialize() };
+
+        Ok(())
+    }
+}
+
+fn get_volume_and_mute(immdevice: &dyn IMMDevice) -> (Option<u32>, Option<bool>) {
+    trace_fn!();
+
+    match unsafe { immdevice.activate_audio_endpoint_volume() } {
+        Ok(audio_endpoint_volume) => {
+            let volume_percent = unsafe { audio_endpoint_volume.get_master_volume_level_scalar() }
+                .ok()
+                .map(|volume_level_scalar| (volume_level_scalar * 100.0).round() as u32);
+            let is_muted = unsafe { audio_endpoint_volume.get_mute() }
+                .ok()
+                .map(|mute| mute.as_bool());
+
+            (volume_percent, is_muted)
+        }
+        Err(_) => (None, None),
+    }
+}
+
+fn from_device_state_to_speaker_state(device_state: DEVICE_STATE) -> SpeakerState {
+    trace_fn!();
+
+    match device_state {
+        DEVICE_STATE_ACTIVE => SpeakerState::Active,
+        DEVICE_STATE_DISABLED => SpeakerState::Disabled,
+        DEVICE_STATE_UNPLUGGED => SpeakerState::Unplugged,
+        DEVICE_STATE_NOTPRESENT => SpeakerState::NotPresent,
+        _ => SpeakerState::Unknown,
+    }
+}
+
+// The form factors are constants named after the Windows enumerators.
+#[allow(non_upper_case_globals)]
+fn from_endpoint_form_factor_to_speaker_form_factor(
+    endpoint_form_factor: EndpointFormFactor,
+) -> SpeakerFormFactor {
+    trace_fn!();
+
+    match endpoint_form_factor {
+        Speakers => SpeakerFormFactor::Speakers,
+        Headphones => SpeakerFormFactor::Headphones,
+        Headset | Handset => SpeakerFormFactor::Headset,
+        DigitalAudioDisplayDevice => SpeakerFormFactor::Hdmi,
+        SPDIF => SpeakerFormFactor::Spdif,
+        LineLevel => SpeakerFormFactor::LineOut,
+        UnknownFormFactor => SpeakerFormFactor::Unknown,
+        _ => SpeakerFormFactor::Other,
+    }
+}
+
+/// Decodes the `WAVEFORMATEX` (or `WAVEFORMATEXTENSIBLE`) the audio engine uses for the device,
+/// naming the sample format the way PulseAudio does.
+fn from_wave_format_to_device_format(wave_format: &[u8]) -> Option<DeviceFormat> {
+    trace_fn!();
+
+    if wave_format.len() < WAVEFORMATEX_SIZE {
+        return None;
+    }
+
+    let read_u16 =
+        |offset: usize| u16::from_le_bytes([wave_format[offset], wave_format[offset + 1]]);
+    let read_u32 = |offset: usize| {
+        u32::from_le_bytes([
+            wave_format[offset],
+            wave_format[offset + 1],
+            wave_format[offset + 2],
+            wave_format[offset + 3],
+        ])
+    };
+
+    let mut format_tag = read_u16(0);
+    let channels = read_u16(2);
+    let sample_rate_hertz = read_u32(4);
+    let bits_per_sample = read_u16(14);
+
+    if format_tag == WAVE_FORMAT_EXTENSIBLE && wave_format.len() >= WAVEFORMATEXTENSIBLE_SIZE {
+        // The first field of the sub format GUID holds the format tag it extends.
+        format_tag = read_u32(WAVEFORMATEXTENSIBLE_SUB_FORMAT_OFFSET) as u16;
+    }
+
+    let sample_format = match format_tag {
+        WAVE_FORMAT_PCM if bits_per_sample == 8 => Some(String::from("u8")),
+        WAVE_FORMAT_PCM => Some(format!("s{bits_per_sample}le")),
+        WAVE_FORMAT_IEEE_FLOAT => Some(format!("float{bits_per_sample}le")),
+        _ => None,
+    };
+
+    Some(DeviceFormat {
+        channels,
+        sample_format,
+        sample_rate_hertz,
+    })
 }
 
 fn pwstr_eq(a: PWSTR, b: PWSTR) -> bool {
@@ -226,7 +552,9 @@ mod should {
 
     use test_case::test_case;
 
-    use crate::speakers_settings::windows::windows_sound_settings::pwstr_eq;
+    use crate::speakers_settings::windows::windows_sound_settings::{
+        DeviceFormat, from_wave_format_to_device_format, pwstr_eq,
+    };
 
     #[test_case(None, None => true; "when both pointers are null")]
     #[test_case(None, Some("") => false; "when first pointer is null")]
@@ -254,4 +582,40 @@ mod should {
         // Act
         pwstr_eq(a, b)
     }
+
+    fn wave_format(format_tag: u16, bits_per_sample: u16, sub_format_tag: Option<u16>) -> Vec<u8> {
+        let mut wave_format = Vec::new();
+
+        wave_format.extend_from_slice(&format_tag.to_le_bytes());
+        wave_format.extend_from_slice(&2u16.to_le_bytes());
+        wave_format.extend_from_slice(&48000u32.to_le_bytes());
+        wave_format
+            .extend_from_slice(&(48000u32 * 2 * u32::from(bits_per_sample) / 8).to_le_bytes());
+        wave_format.extend_from_slice(&(2 * bits_per_sample / 8).to_le_bytes());
+        wave_format.extend_from_slice(&bits_per_sample.to_le_bytes());
+
+        if let Some(sub_format_tag) = sub_format_tag {
+            wave_format.extend_from_slice(&22u16.to_le_bytes());
+            wave_format.extend_from_slice(&bits_per_sample.to_le_bytes());
+            wave_format.extend_from_slice(&3u32.to_le_bytes());
+            wave_format.extend_from_slice(&u32::from(sub_format_tag).to_le_bytes());
+            wave_format.extend_from_slice(&[0x00, 0x00, 0x10, 0x00]);
+            wave_format.extend_from_slice(&[0x80, 0x00, 0x00, 0xAA, 0x00, 0x38, 0x9B, 0x71]);
+        } else {
+            wave_format.extend_from_slice(&0u16.to_le_bytes());
+        }
+
+        wave_format
+    }
+
+    #[test_case(wave_format(0x0001, 16, None) => Some(DeviceFormat { channels: 2, sample_format: Some(String::from("s16le")), sample_rate_hertz: 48000 }); "when the format is pcm")]
+    #[test_case(wave_format(0x0001, 8, None) => Some(DeviceFormat { channels: 2, sample_format: Some(String::from("u8")), sample_rate_hertz: 48000 }); "when the format is unsigned pcm")]
+    #[test_case(wave_format(0xFFFE, 32, Some(0x0003)) => Some(DeviceFormat { channels: 2, sample_format: Some(String::from("float32le")), sample_rate_hertz: 48000 }); "when the format extends ieee float")]
+    #[test_case(wave_format(0xFFFE, 24, Some(0x0001)) => Some(DeviceFormat { channels: 2, sample_format: Some(String::from("s24le")), sample_rate_hertz: 48000 }); "when the format extends pcm")]
+    #[test_case(wave_format(0x0092, 16, None) => Some(DeviceFormat { channels: 2, sample_format: None, sample_rate_hertz: 48000 }); "when the format is unknown")]
+    #[test_case(vec![0x01, 0x00] => None; "when the format is truncated")]
+    fn decode_the_device_format(wave_format: Vec<u8>) -> Option<DeviceFormat> {
+        // Act
+        from_wave_format_to_device_format(&wave_format)
+    }
 }
@@ -0,0 +1,70 @@
+#![cfg(target_os = "linux")]
+
+//! Drives the wlroots backend against a real compositor rather than the fake one of its unit
+//! tests. The tests are ignored unless asked for, as they need a compositor with two outputs,
+//! such as sway started with `WLR_BACKENDS=headless` and `WLR_HEADLESS_OUTPUTS=2`, which the CI
+//! does before running them with `--ignored`.
+
+use convertible_couch_lib::displays_settings::{
+    DisplaysSettings,
+    wlroots::{
+        wayland_based_wlroots_api::WaylandBasedWlrootsApi, wlroots_api::WlrootsApi,
+        wlroots_display_settings::WlrootsDisplaySettings,
+    },
+};
+
+#[test]
+#[ignore = "needs a headless wlroots compositor with two outputs, such as sway with WLR_BACKENDS=headless"]
+fn enumerate_the_heads_of_the_compositor() {
+    // Arrange
+    let mut wlroots_api = WaylandBasedWlrootsApi;
+
+    // Act
+    let heads = wlroots_api.get_heads().unwrap();
+
+    // Assert
+    assert!(heads.len() >= 2, "{heads:?}");
+    assert!(
+        heads
+            .iter()
+            .all(|head| head.is_enabled && head.name.starts_with("HEADLESS-")),
+        "{heads:?}"
+    );
+}
+
+#[test]
+#[ignore = "needs a headless wlroots compositor with two outputs, such as sway with WLR_BACKENDS=headless"]
+fn apply_a_layout_making_another_display_primary() {
+    // Arrange
+    let mut displays_settings = WlrootsDisplaySettings::new(Box::new(WaylandBasedWlrootsApi));
+
+    let initial_displays_infos = displays_settings.get_displays_infos().unwrap();
+    let new_primary_display_name = initial_displays_infos
+        .iter()
+        .find(|display_info| !display_info.is_primary)
+        .map(|display_info| display_info.name.clone())
+        .unwrap();
+
+    // Act
+    let displays_result = displays_settings
+        .set_primary_display(&new_primary_display_name)
+        .unwrap();
+
+    // Assert
+    let displays_infos = displays_settings.get_displays_infos().unwrap();
+    let primary_display_names = displays_infos
+        .iter()
+        .filter(|display_info| display_info.is_primary)
+        .map(|display_info| display_info.name.as_str())
+        .collect::<Vec<&str>>();
+    let are_displays_side_by_side = displays_infos
+        .iter()
+        .all(|display_info| display_info.position.y == 0);
+
+    assert_eq!(
+        displays_result.new_primary_display,
+        new_primary_display_name
+    );
+    assert_eq!(primary_display_names, [new_primary_display_name.as_str()]);
+    assert!(are_displays_side_by_side, "{displays_infos:?}");
+}
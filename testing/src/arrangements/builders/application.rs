@@ -1,20 +1,173 @@
+use std::{
+    env, process,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
 use convertible_couch::application::Application;
+use convertible_couch_lib::{
+    backends::{BackendsInfos, get_backends_infos},
+    configuration::{Configuration, cycle::Cycle, profile::Profile, verification::Verification},
+    displays_settings::{DisplayMode, DisplayTransformRequest},
+    snapshot::SnapshotStore,
+};
+
+use crate::arrangements::fuzzing::{
+    computer::FuzzedComputer,
+    displays::settings_api::{
+        CURRENT_DISPLAYS_BACKEND_UNDER_TEST, CurrentDisplaysSettingsUnderTest,
+    },
+    speakers::settings_api::{
+        CURRENT_SPEAKERS_BACKEND_UNDER_TEST, CurrentSpeakersSettingsUnderTest,
+    },
+};
 
-use crate::arrangements::fuzzing::computer::FuzzedComputer;
+/// Gives every application its own snapshots directory, the tests running in parallel.
+static SNAPSHOTS_DIRECTORIES_COUNT: AtomicUsize = AtomicUsize::new(0);
 
 pub struct ApplicationBuilder {
     computer: FuzzedComputer,
+    configuration: Configuration,
 }
 
 impl ApplicationBuilder {
     pub fn new(computer: FuzzedComputer) -> Self {
-        Self { computer }
+        Self {
+            computer,
+            configuration: Configuration {
+                // The fakes apply a change at once, there is nothing to wait for.
+                verification: Verification {
+                    settle_delay_milliseconds: 0,
+                    ..Verification::default()
+                },
+                ..Configuration::default()
+            },
+        }
+    }
+
+    pub fn with_profile(mut self, profile_name: &str, display: &str, speaker: &str) -> Self {
+        self.configuration.profiles.insert(
+            profile_name.to_string(),
+            Profile {
+                display: display.to_string(),
+                speaker: speaker.to_string(),
+                resolution: None,
+                refresh: None,
+                rotation: None,
+                scale: None,
+                hdr: None,
+            },
+        );
+
+        self
+    }
+
+    pub fn with_profile_at_mode(
+        mut self,
+        profile_name: &str,
+        display: &str,
+        speaker: &str,
+        mode: &DisplayMode,
+    ) -> Self {
+        self.configuration.profiles.insert(
+            profile_name.to_string(),
+            Profile {
+                display: display.to_string(),
+                speaker: speaker.to_string(),
+                resolution: Some(mode.resolution),
+                refresh: Some(mode.refresh_rate_millihertz / 1000),
+                rotation: None,
+                scale: None,
+                hdr: None,
+            },
+        );
+
+        self
+    }
+
+    pub fn with_profile_at_transform(
+        mut self,
+        profile_name: &str,
+        display: &str,
+        speaker: &str,
+        transform_request: &DisplayTransformRequest,
+    ) -> Self {
+        self.configuration.profiles.insert(
+            profile_name.to_string(),
+            Profile {
+                display: display.to_string(),
+                speaker: speaker.to_string(),
+                resolution: None,
+                refresh: None,
+                rotation: transform_request.rotation,
+                scale: transform_request.scale_percent,
+                hdr: None,
+            },
+        );
+
+        self
+    }
+
+    pub fn with_profile_at_hdr(
+        mut self,
+        profile_name: &str,
+        display: &str,
+        speaker: &str,
+        is_hdr_enabled: bool,
+    ) -> Self {
+        self.configuration.profiles.insert(
+            profile_name.to_string(),
+            Profile {
+                display: display.to_string(),
+                speaker: speaker.to_string(),
+                resolution: None,
+                refresh: None,
+                rotation: None,
+                scale: None,
+                hdr: Some(is_hdr_enabled),
+            },
+        );
+
+        self
+    }
+
+    pub fn with_cycle(mut self, display_names: &[&str], speaker_names: &[&str]) -> Self {
+        self.configuration.cycle = Cycle {
+            displays: display_names.iter().map(|name| name.to_string()).collect(),
+            speakers: speaker_names.iter().map(|name| name.to_string()).collect(),
+        };
+
+        self
     }
 
     pub fn build(self) -> Application {
         let displays_settings_api = Box::new(self.computer.displays_settings_api);
         let speakers_settings_api = Box::new(self.computer.speakers_settings_api);
+        let displays_settings = CurrentDisplaysSettingsUnderTest::new(displays_settings_api);
+        let speakers_settings = CurrentSpeakersSettingsUnderTest::new(speakers_settings_api);
+
+        let backends_infos = BackendsInfos {
+            displays_backends: get_backends_infos(
+                Some(CURRENT_DISPLAYS_BACKEND_UNDER_TEST),
+                CURRENT_DISPLAYS_BACKEND_UNDER_TEST,
+            ),
+            speakers_backends: get_backends_infos(
+                Some(CURRENT_SPEAKERS_BACKEND_UNDER_TEST),
+                CURRENT_SPEAKERS_BACKEND_UNDER_TEST,
+            ),
+        };
+
+        let snapshots_directory = env::temp_dir().join(format!(
+            "convertible-couch-snapshots-{}-{}",
+            process::id(),
+            SNAPSHOTS_DIRECTORIES_COUNT.fetch_add(1, Ordering::Relaxed)
+        ));
 
-        Application::bootstrap(displays_settings_api, speakers_settings_api)
+        Application::bootstrap(
+            Box::new(displays_settings),
+            Box::new(speakers_settings),
+            backends_infos,
+            self.configuration,
+            SnapshotStore::new(Some(snapshots_directory)),
+        )
     }
 }
@@ -1,9 +1,14 @@
 use convertible_couch::commands::{
     Arguments, Commands,
-    change::{ChangeCommands, DisplaysOptions, SpeakersOptions},
-    shared::{SharedOptions, log_level_option::LogLevelOption},
+    change::{
+        ChangeCommands, DisplaysOptions, HdrOption, RotationOption, SpeakersOptions, TopologyOption,
+    },
 };
 
+use convertible_couch_lib::displays_settings::DisplayResolution;
+
+use crate::arrangements::builders::arguments::silent_shared_options;
+
 #[derive(Default)]
 pub struct ChangeCommandBuilder {
     arguments: Option<Arguments>,
@@ -20,16 +25,22 @@ impl ChangeCommandBuilder {
         self.arguments = Some(Arguments {
             command: Commands::Change(ChangeCommands::DisplaysAndSpeakers {
                 displays: DisplaysOptions {
-                    desktop_display_name: desktop_display_name.to_string(),
-                    couch_display_name: couch_display_name.to_string(),
+                    desktop_display_name: Some(desktop_display_name.to_string()),
+                    couch_display_name: Some(couch_display_name.to_string()),
+                    to: None,
+                    resolution: None,
+                    refresh: None,
+                    rotation: None,
+                    scale: None,
+                    hdr: None,
                 },
                 speakers: SpeakersOptions {
-                    desktop_speaker_name: desktop_speaker_name.to_string(),
-                    couch_speaker_name: couch_speaker_name.to_string(),
-                },
-                shared: SharedOptions {
-                    log_level: LogLevelOption::Off,
+                    desktop_speaker_name: Some(desktop_speaker_name.to_string()),
+                    couch_speaker_name: Some(couch_speaker_name.to_string()),
+                    to_speaker: None,
                 },
+                dry_run: false,
+                shared: silent_shared_options(),
             }),
         });
 
@@ -44,12 +55,17 @@ impl ChangeCommandBuilder {
         self.arguments = Some(Arguments {
             command: Commands::Change(ChangeCommands::Displays {
                 displays: DisplaysOptions {
-                    desktop_display_name: desktop_display_name.to_string(),
-                    couch_display_name: couch_display_name.to_string(),
-                },
-                shared: SharedOptions {
-                    log_level: LogLevelOption::Off,
+                    desktop_display_name: Some(desktop_display_name.to_string()),
+                    couch_display_name: Some(couch_display_name.to_string()),
+                    to: None,
+                    resolution: None,
+                    refresh: None,
+                    rotation: None,
+                    scale: None,
+                    hdr: None,
                 },
+                dry_run: false,
+                shared: silent_shared_options(),
             }),
         });
 
@@ -64,18 +80,226 @@ impl ChangeCommandBuilder {
         self.arguments = Some(Arguments {
             command: Commands::Change(ChangeCommands::Speakers {
                 speakers: SpeakersOptions {
-                    desktop_speaker_name: desktop_speaker_name.to_string(),
-                    couch_speaker_name: couch_speaker_name.to_string(),
+                    desktop_speaker_name: Some(desktop_speaker_name.to_string()),
+                    couch_speaker_name: Some(couch_speaker_name.to_string()),
+                    to_speaker: None,
                 },
-                shared: SharedOptions {
-                    log_level: LogLevelOption::Off,
+                dry_run: false,
+                shared: silent_shared_options(),
+            }),
+        });
+
+        self
+    }
+
+    pub fn displays_and_speakers_to(
+        &mut self,
+        display_name: &str,
+        speaker_name: &str,
+    ) -> &mut Self {
+        self.arguments = Some(Arguments {
+            command: Commands::Change(ChangeCommands::DisplaysAndSpeakers {
+                displays: DisplaysOptions {
+                    desktop_display_name: None,
+                    couch_display_name: None,
+                    to: Some(display_name.to_string()),
+                    resolution: None,
+                    refresh: None,
+                    rotation: None,
+                    scale: None,
+                    hdr: None,
                 },
+                speakers: SpeakersOptions {
+                    desktop_speaker_name: None,
+                    couch_speaker_name: None,
+                    to_speaker: Some(speaker_name.to_string()),
+                },
+                dry_run: false,
+                shared: silent_shared_options(),
             }),
         });
 
         self
     }
 
+    pub fn displays_only_to(&mut self, display_name: &str) -> &mut Self {
+        self.arguments = Some(Arguments {
+            command: Commands::Change(ChangeCommands::Displays {
+                displays: DisplaysOptions {
+                    desktop_display_name: None,
+                    couch_display_name: None,
+                    to: Some(display_name.to_string()),
+                    resolution: None,
+                    refresh: None,
+                    rotation: None,
+                    scale: None,
+                    hdr: None,
+                },
+                dry_run: false,
+                shared: silent_shared_options(),
+            }),
+        });
+
+        self
+    }
+
+    pub fn speakers_only_to(&mut self, speaker_name: &str) -> &mut Self {
+        self.arguments = Some(Arguments {
+            command: Commands::Change(ChangeCommands::Speakers {
+                speakers: SpeakersOptions {
+                    desktop_speaker_name: None,
+                    couch_speaker_name: None,
+                    to_speaker: Some(speaker_name.to_string()),
+                },
+                dry_run: false,
+                shared: silent_shared_options(),
+            }),
+        });
+
+        self
+    }
+
+    pub fn profile(&mut self, profile_name: &str) -> &mut Self {
+        self.arguments = Some(Arguments {
+            command: Commands::Change(ChangeCommands::Profile {
+                profile_name: profile_name.to_string(),
+                dry_run: false,
+                shared: silent_shared_options(),
+            }),
+        });
+
+        self
+    }
+
+    pub fn cycle(&mut self, display_names: &[&str], speaker_names: &[&str]) -> &mut Self {
+        self.arguments = Some(Arguments {
+            command: Commands::Change(ChangeCommands::Cycle {
+                displays: display_names.iter().map(|name| name.to_string()).collect(),
+                speakers: speaker_names.iter().map(|name| name.to_string()).collect(),
+                reverse: false,
+                dry_run: false,
+                shared: silent_shared_options(),
+            }),
+        });
+
+        self
+    }
+
+    pub fn topology(&mut self, topology: TopologyOption) -> &mut Self {
+        self.arguments = Some(Arguments {
+            command: Commands::Change(ChangeCommands::Topology {
+                topology,
+                display: None,
+                dry_run: false,
+                shared: silent_shared_options(),
+            }),
+        });
+
+        self
+    }
+
+    pub fn only(&mut self, display_name: &str) -> &mut Self {
+        self.arguments = Some(Arguments {
+            command: Commands::Change(ChangeCommands::Topology {
+                topology: TopologyOption::Only,
+                display: Some(display_name.to_string()),
+                dry_run: false,
+                shared: silent_shared_options(),
+            }),
+        });
+
+        self
+    }
+
+    /// Makes the cycle built go to the previous devices.
+    pub fn reverse(&mut self) -> &mut Self {
+        if let Some(Arguments {
+            command: Commands::Change(ChangeCommands::Cycle { reverse, .. }),
+        }) = &mut self.arguments
+        {
+            *reverse = true;
+        }
+
+        self
+    }
+
+    /// Makes the change of primary display built drive it at the given mode.
+    pub fn at_mode(
+        &mut self,
+        resolution: Option<DisplayResolution>,
+        refresh_rate_hertz: Option<u32>,
+    ) -> &mut Self {
+        if let Some(Arguments {
+            command:
+                Commands::Change(
+                    ChangeCommands::DisplaysAndSpeakers { displays, .. }
+                    | ChangeCommands::Displays { displays, .. },
+                ),
+        }) = &mut self.arguments
+        {
+            displays.resolution = resolution;
+            displays.refresh = refresh_rate_hertz;
+        }
+
+        self
+    }
+
+    /// Makes the change of primary display built rotate and scale it.
+    pub fn at_transform(
+        &mut self,
+        rotation: Option<RotationOption>,
+        scale_percent: Option<u32>,
+    ) -> &mut Self {
+        if let Some(Arguments {
+            command:
+                Commands::Change(
+                    ChangeCommands::DisplaysAndSpeakers { displays, .. }
+                    | ChangeCommands::Displays { displays, .. },
+                ),
+        }) = &mut self.arguments
+        {
+            displays.rotation = rotation;
+            displays.scale = scale_percent;
+        }
+
+        self
+    }
+
+    /// Makes the change of primary display built turn HDR on or off on it.
+    pub fn at_hdr(&mut self, hdr: HdrOption) -> &mut Self {
+        if let Some(Arguments {
+            command:
+                Commands::Change(
+                    ChangeCommands::DisplaysAndSpeakers { displays, .. }
+                    | ChangeCommands::Displays { displays, .. },
+                ),
+        }) = &mut self.arguments
+        {
+            displays.hdr = Some(hdr);
+        }
+
+        self
+    }
+
+    /// Makes the change built a dry run, which only reports what would change.
+    pub fn dry_run(&mut self) -> &mut Self {
+        if let Some(Arguments {
+            command: Commands::Change(change_commands),
+        }) = &mut self.arguments
+        {
+            match change_commands {
+                ChangeCommands::DisplaysAndSpeakers { dry_run, .. }
+                | ChangeCommands::Displays { dry_run, .. }
+                | ChangeCommands::Speakers { dry_run, .. }
+                | ChangeCommands::Profile { dry_run, .. }
+                | ChangeCommands::Cycle { dry_run, .. }
+                | ChangeCommands::Topology { dry_run, .. } => *dry_run = true,
+            }
+        }
+
+        self
+    }
+
     pub fn build(&mut self) -> Arguments {
         self.arguments.take().unwrap()
     }
@@ -1,9 +1,10 @@
 use convertible_couch::commands::{
     Arguments, Commands,
-    info::Device,
-    shared::{SharedOptions, log_level_option::LogLevelOption},
+    info::{Device, InfoCommands},
 };
 
+use crate::arrangements::builders::arguments::silent_shared_options;
+
 #[derive(Default)]
 pub struct InfoCommandBuilder {
     arguments: Option<Arguments>,
@@ -13,10 +14,9 @@ impl InfoCommandBuilder {
     pub fn displays_and_speakers(&mut self) -> &mut Self {
         self.arguments = Some(Arguments {
             command: Commands::Info {
+                subject: None,
                 device: Device::DisplaysAndSpeakers,
-                shared: SharedOptions {
-                    log_level: LogLevelOption::Off,
-                },
+                shared: silent_shared_options(),
             },
         });
 
@@ -26,10 +26,9 @@ impl InfoCommandBuilder {
     pub fn displays_only(&mut self) -> &mut Self {
         self.arguments = Some(Arguments {
             command: Commands::Info {
+                subject: None,
                 device: Device::Displays,
-                shared: SharedOptions {
-                    log_level: LogLevelOption::Off,
-                },
+                shared: silent_shared_options(),
             },
         });
 
@@ -39,10 +38,21 @@ impl InfoCommandBuilder {
     pub fn speakers_only(&mut self) -> &mut Self {
         self.arguments = Some(Arguments {
             command: Commands::Info {
+                subject: None,
                 device: Device::Speakers,
-                shared: SharedOptions {
-                    log_level: LogLevelOption::Off,
-                },
+                shared: silent_shared_options(),
+            },
+        });
+
+        self
+    }
+
+    pub fn backends(&mut self) -> &mut Self {
+        self.arguments = Some(Arguments {
+            command: Commands::Info {
+                subject: Some(InfoCommands::Backends),
+                device: Device::DisplaysAndSpeakers,
+                shared: silent_shared_options(),
             },
         });
 
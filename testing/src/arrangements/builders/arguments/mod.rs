@@ -1,11 +1,16 @@
+use convertible_couch::commands::shared::{
+    SharedOptions, log_level_option::LogLevelOption, output_option::OutputOption,
+};
+
 use crate::arrangements::builders::arguments::{
     change::ChangeCommandBuilder, displays::DisplaysCommandBuilder, info::InfoCommandBuilder,
-    speakers::SpeakersCommandBuilder,
+    snapshot::SnapshotCommandBuilder, speakers::SpeakersCommandBuilder,
 };
 
 pub mod change;
 pub mod displays;
 pub mod info;
+pub mod snapshot;
 pub mod speakers;
 
 pub struct ArgumentsBuilder;
@@ -26,4 +31,20 @@ impl ArgumentsBuilder {
     pub fn speakers() -> SpeakersCommandBuilder {
         SpeakersCommandBuilder
     }
+
+    pub fn snapshot() -> SnapshotCommandBuilder {
+        SnapshotCommandBuilder::default()
+    }
+}
+
+/// The options shared by every command, with the logs turned off so they do not pollute the
+/// output of the tests.
+fn silent_shared_options() -> SharedOptions {
+    SharedOptions {
+        log_level: LogLevelOption::Off,
+        displays_backend: None,
+        speakers_backend: None,
+        config: None,
+        output: OutputOption::Table,
+    }
 }
@@ -0,0 +1,36 @@
+use convertible_couch::commands::{Arguments, Commands, snapshot::SnapshotCommands};
+
+use crate::arrangements::builders::arguments::silent_shared_options;
+
+#[derive(Default)]
+pub struct SnapshotCommandBuilder {
+    arguments: Option<Arguments>,
+}
+
+impl SnapshotCommandBuilder {
+    pub fn save(&mut self, snapshot_name: &str) -> &mut Self {
+        self.arguments = Some(Arguments {
+            command: Commands::Snapshot(SnapshotCommands::Save {
+                snapshot_name: String::from(snapshot_name),
+                shared: silent_shared_options(),
+            }),
+        });
+
+        self
+    }
+
+    pub fn restore(&mut self, snapshot_name: &str) -> &mut Self {
+        self.arguments = Some(Arguments {
+            command: Commands::Snapshot(SnapshotCommands::Restore {
+                snapshot_name: String::from(snapshot_name),
+                shared: silent_shared_options(),
+            }),
+        });
+
+        self
+    }
+
+    pub fn build(&mut self) -> Arguments {
+        self.arguments.take().unwrap()
+    }
+}
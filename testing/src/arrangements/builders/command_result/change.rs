@@ -1,6 +1,9 @@
 use convertible_couch::application::{ApplicationChangeResult, CommandResult};
 use convertible_couch_lib::{
-    application_result::ApplicationResult, displays_settings::DisplaysSettingsResult,
+    application_result::ApplicationResult,
+    displays_settings::{
+        DisplayMode, DisplayTransformRequest, DisplaysSettingsResult, DisplaysTopologyResult,
+    },
     speakers_settings::SpeakersSettingsResult,
 };
 
@@ -20,6 +23,10 @@ impl ChangeResultBuilder {
         };
         let displays_result = DisplaysSettingsResult {
             new_primary_display: new_primary_display.to_string(),
+            new_mode: None,
+            new_rotation: None,
+            new_scale_percent: None,
+            new_hdr_enabled: None,
         };
         let application_change_result = ApplicationChangeResult::DisplaysAndSpeakers {
             displays_result,
@@ -33,6 +40,10 @@ impl ChangeResultBuilder {
     pub fn displays_only(self, new_primary_display: &str) -> ApplicationResult<CommandResult> {
         let displays_result = DisplaysSettingsResult {
             new_primary_display: new_primary_display.to_string(),
+            new_mode: None,
+            new_rotation: None,
+            new_scale_percent: None,
+            new_hdr_enabled: None,
         };
         let application_change_result = ApplicationChangeResult::DisplaysOnly { displays_result };
         let command_result = CommandResult::Change(application_change_result);
@@ -40,6 +51,135 @@ impl ChangeResultBuilder {
         Ok(command_result)
     }
 
+    pub fn displays_only_at_mode(
+        self,
+        new_primary_display: &str,
+        new_mode: DisplayMode,
+    ) -> ApplicationResult<CommandResult> {
+        let displays_result = DisplaysSettingsResult {
+            new_primary_display: new_primary_display.to_string(),
+            new_mode: Some(new_mode),
+            new_rotation: None,
+            new_scale_percent: None,
+            new_hdr_enabled: None,
+        };
+        let application_change_result = ApplicationChangeResult::DisplaysOnly { displays_result };
+        let command_result = CommandResult::Change(application_change_result);
+
+        Ok(command_result)
+    }
+
+    pub fn displays_and_speakers_at_mode(
+        self,
+        new_primary_display: &str,
+        new_mode: DisplayMode,
+        new_default_speaker: &str,
+    ) -> ApplicationResult<CommandResult> {
+        let speakers_result = SpeakersSettingsResult {
+            new_default_speaker: new_default_speaker.to_string(),
+        };
+        let displays_result = DisplaysSettingsResult {
+            new_primary_display: new_primary_display.to_string(),
+            new_mode: Some(new_mode),
+            new_rotation: None,
+            new_scale_percent: None,
+            new_hdr_enabled: None,
+        };
+        let application_change_result = ApplicationChangeResult::DisplaysAndSpeakers {
+            displays_result,
+            speakers_result,
+        };
+        let command_result = CommandResult::Change(application_change_result);
+
+        Ok(command_result)
+    }
+
+    pub fn displays_only_at_transform(
+        self,
+        new_primary_display: &str,
+        transform_request: &DisplayTransformRequest,
+    ) -> ApplicationResult<CommandResult> {
+        let displays_result = DisplaysSettingsResult {
+            new_primary_display: new_primary_display.to_string(),
+            new_mode: None,
+            new_rotation: transform_request.rotation,
+            new_scale_percent: transform_request.scale_percent,
+            new_hdr_enabled: None,
+        };
+        let application_change_result = ApplicationChangeResult::DisplaysOnly { displays_result };
+        let command_result = CommandResult::Change(application_change_result);
+
+        Ok(command_result)
+    }
+
+    pub fn displays_and_speakers_at_transform(
+        self,
+        new_primary_display: &str,
+        transform_request: &DisplayTransformRequest,
+        new_default_speaker: &str,
+    ) -> ApplicationResult<CommandResult> {
+        let speakers_result = SpeakersSettingsResult {
+            new_default_speaker: new_default_speaker.to_string(),
+        };
+        let displays_result = DisplaysSettingsResult {
+            new_primary_display: new_primary_display.to_string(),
+            new_mode: None,
+            new_rotation: transform_request.rotation,
+            new_scale_percent: transform_request.scale_percent,
+            new_hdr_enabled: None,
+        };
+        let application_change_result = ApplicationChangeResult::DisplaysAndSpeakers {
+            displays_result,
+            speakers_result,
+        };
+        let command_result = CommandResult::Change(application_change_result);
+
+        Ok(command_result)
+    }
+
+    pub fn displays_only_at_hdr(
+        self,
+        new_primary_display: &str,
+        is_hdr_enabled: bool,
+    ) -> ApplicationResult<CommandResult> {
+        let displays_result = DisplaysSettingsResult {
+            new_primary_display: new_primary_display.to_string(),
+            new_mode: None,
+            new_rotation: None,
+            new_scale_percent: None,
+            new_hdr_enabled: Some(is_hdr_enabled),
+        };
+        let application_change_result = ApplicationChangeResult::DisplaysOnly { displays_result };
+        let command_result = CommandResult::Change(application_change_result);
+
+        Ok(command_result)
+    }
+
+    pub fn displays_and_speakers_at_hdr(
+        self,
+        new_primary_display: &str,
+        is_hdr_enabled: bool,
+        new_default_speaker: &str,
+    ) -> ApplicationResult<CommandResult> {
+        let speakers_result = SpeakersSettingsResult {
+            new_default_speaker: new_default_speaker.to_string(),
+        };
+        let displays_result = DisplaysSettingsResult {
+            new_primary_display: new_primary_display.to_string(),
+            new_mode: None,
+            new_rotation: None,
+            new_scale_percent: None,
+            new_hdr_enabled: Some(is_hdr_enabled),
+        };
+        let application_change_result = ApplicationChangeResult::DisplaysAndSpeakers {
+            displays_result,
+            speakers_result,
+        };
+        let command_result = CommandResult::Change(application_change_result);
+
+        Ok(command_result)
+    }
+
     pub fn speakers_only(self, new_default_speaker: &str) -> ApplicationResult<CommandResult> {
         let speakers_result = SpeakersSettingsResult {
             new_default_speaker: new_default_speaker.to_string(),
@@ -50,6 +190,25 @@ impl ChangeResultBuilder {
         Ok(command_result)
     }
 
+    /// The active displays are expected the primary one first, then sorted by name.
+    pub fn topology(
+        self,
+        active_display_names: &[&str],
+        is_cloned: bool,
+    ) -> ApplicationResult<CommandResult> {
+        let topology_result = DisplaysTopologyResult {
+            active_displays: active_display_names
+                .iter()
+                .map(|display_name| display_name.to_string())
+                .collect(),
+            is_cloned,
+        };
+        let application_change_result = ApplicationChangeResult::Topology { topology_result };
+        let command_result = CommandResult::Change(application_change_result);
+
+        Ok(command_result)
+    }
+
     pub fn displays(
         self,
         change_displays_command: &ChangeDisplaysCommand,
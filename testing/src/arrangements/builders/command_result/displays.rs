@@ -7,7 +7,19 @@ use convertible_couch_lib::{
     speakers_settings::{SpeakerInfo, SpeakersSettingsResult},
 };
 
-use crate::arrangements::builders::arguments::displays::DisplaysCommand;
+use crate::arrangements::{
+    builders::arguments::displays::DisplaysCommand,
+    fuzzing::{
+        displays::{
+            FuzzedDisplay,
+            settings_api::{CurrentFuzzedDisplaysSettingsApi, FuzzedDisplaysSettingsApi},
+        },
+        speakers::{
+            FuzzedSpeaker,
+            settings_api::{CurrentFuzzedSpeakersSettingsApi, FuzzedSpeakersSettingsApi},
+        },
+    },
+};
 
 pub struct DisplaysCommandResultBuilder;
 
@@ -15,19 +27,34 @@ impl DisplaysCommandResultBuilder {
     pub fn any(
         self,
         displays_command: &DisplaysCommand,
-        desktop_display_name: &str,
-        couch_display_name: &str,
-        desktop_speaker_name: &str,
-        couch_speaker_name: &str,
+        desktop_display: &FuzzedDisplay,
+        couch_display: &FuzzedDisplay,
+        desktop_speaker: &FuzzedSpeaker,
+        couch_speaker: &FuzzedSpeaker,
     ) -> ApplicationResult<CommandResult> {
+        let couch_display_name = &couch_display.name;
+        let displays_result = vec![
+            DisplayInfo {
+                is_primary: true,
+                ..CurrentFuzzedDisplaysSettingsApi::to_display_info(desktop_display)
+            },
+            DisplayInfo {
+                is_primary: false,
+                ..CurrentFuzzedDisplaysSettingsApi::to_display_info(couch_display)
+            },
+        ];
         let command_result = match displays_command {
             DisplaysCommand::ChangeDisplaysAndSpeakers => {
                 CommandResult::Change(ApplicationChangeResult::DisplaysAndSpeakers {
                     displays_result: DisplaysSettingsResult {
                         new_primary_display: couch_display_name.to_string(),
+                        new_mode: None,
+                        new_rotation: None,
+                        new_scale_percent: None,
+                        new_hdr_enabled: None,
                     },
                     speakers_result: SpeakersSettingsResult {
-                        new_default_speaker: couch_speaker_name.to_string(),
+                        new_default_speaker: couch_speaker.name.to_string(),
                     },
                 })
             }
@@ -35,46 +62,30 @@ impl DisplaysCommandResultBuilder {
                 CommandResult::Change(ApplicationChangeResult::DisplaysOnly {
                     displays_result: DisplaysSettingsResult {
                         new_primary_display: couch_display_name.to_string(),
+                        new_mode: None,
+                        new_rotation: None,
+                        new_scale_percent: None,
+                        new_hdr_enabled: None,
                     },
                 })
             }
             DisplaysCommand::InfoDisplaysAndSpeakers => {
                 CommandResult::Info(ApplicationInfoResult::DisplaysAndSpeakers {
-                    displays_result: vec![
-                        DisplayInfo {
-                            name: desktop_display_name.to_string(),
-                            is_primary: true,
-                        },
-                        DisplayInfo {
-                            name: couch_display_name.to_string(),
-                            is_primary: false,
-                        },
-                    ],
+                    displays_result,
                     speakers_result: vec![
                         SpeakerInfo {
-                            name: desktop_speaker_name.to_string(),
                             is_default: true,
+                            ..CurrentFuzzedSpeakersSettingsApi::to_speaker_info(desktop_speaker)
                         },
                         SpeakerInfo {
-                            name: couch_speaker_name.to_string(),
                             is_default: false,
+                            ..CurrentFuzzedSpeakersSettingsApi::to_speaker_info(couch_speaker)
                         },
                     ],
                 })
             }
             DisplaysCommand::InfoDisplays => {
-                CommandResult::Info(ApplicationInfoResult::DisplaysOnly {
-                    displays_result: vec![
-                        DisplayInfo {
-                            name: desktop_display_name.to_string(),
-                            is_primary: true,
-                        },
-                        DisplayInfo {
-                            name: couch_display_name.to_string(),
-                            is_primary: false,
-                        },
-                    ],
-                })
+                CommandResult::Info(ApplicationInfoResult::DisplaysOnly { displays_result })
             }
         };
 
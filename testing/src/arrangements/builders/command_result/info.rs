@@ -1,9 +1,30 @@
 use convertible_couch::application::{ApplicationInfoResult, CommandResult};
 use convertible_couch_lib::{
-    application_result::ApplicationResult, displays_settings::DisplayInfo,
+    application_result::ApplicationResult,
+    backends::{BackendsInfos, get_backends_infos},
+    displays_settings::{
+        DisplayAdvancedColor, DisplayInfo, DisplayPosition, DisplayTransformRequest,
+    },
     speakers_settings::SpeakerInfo,
 };
 
+use crate::arrangements::fuzzing::{
+    displays::{
+        FuzzedDisplay,
+        settings_api::{
+            CURRENT_DISPLAYS_BACKEND_UNDER_TEST, CurrentFuzzedDisplaysSettingsApi,
+            FuzzedDisplaysSettingsApi,
+        },
+    },
+    speakers::{
+        FuzzedSpeaker,
+        settings_api::{
+            CURRENT_SPEAKERS_BACKEND_UNDER_TEST, CurrentFuzzedSpeakersSettingsApi,
+            FuzzedSpeakersSettingsApi,
+        },
+    },
+};
+
 #[derive(Default)]
 pub struct InfoResultBuilder;
 
@@ -19,6 +40,10 @@ impl InfoResultBuilder {
     pub fn speakers_only(self) -> InfoSpeakersOnlyResultBuilder {
         InfoSpeakersOnlyResultBuilder::default()
     }
+
+    pub fn backends(self) -> InfoBackendsResultBuilder {
+        InfoBackendsResultBuilder
+    }
 }
 
 #[derive(Default)]
@@ -28,37 +53,37 @@ pub struct InfoDisplaysAndSpeakersResultBuilder {
 }
 
 impl InfoDisplaysAndSpeakersResultBuilder {
-    pub fn with_primary_display(mut self, display_name: &str) -> Self {
+    pub fn with_primary_display(mut self, display: &FuzzedDisplay) -> Self {
         self.displays_result.push(DisplayInfo {
             is_primary: true,
-            name: display_name.to_string(),
+            ..CurrentFuzzedDisplaysSettingsApi::to_display_info(display)
         });
 
         self
     }
 
-    pub fn with_secondary_display(mut self, display_name: &str) -> Self {
+    pub fn with_secondary_display(mut self, display: &FuzzedDisplay) -> Self {
         self.displays_result.push(DisplayInfo {
             is_primary: false,
-            name: display_name.to_string(),
+            ..CurrentFuzzedDisplaysSettingsApi::to_display_info(display)
         });
 
         self
     }
 
-    pub fn with_default_speaker(mut self, speaker_name: &str) -> Self {
+    pub fn with_default_speaker(mut self, speaker: &FuzzedSpeaker) -> Self {
         self.speakers_result.push(SpeakerInfo {
             is_default: true,
-            name: speaker_name.to_string(),
+            ..CurrentFuzzedSpeakersSettingsApi::to_speaker_info(speaker)
         });
 
         self
     }
 
-    pub fn with_alternative_speaker(mut self, speaker_name: &str) -> Self {
+    pub fn with_alternative_speaker(mut self, speaker: &FuzzedSpeaker) -> Self {
         self.speakers_result.push(SpeakerInfo {
             is_default: false,
-            name: speaker_name.to_string(),
+            ..CurrentFuzzedSpeakersSettingsApi::to_speaker_info(speaker)
         });
 
         self
@@ -81,19 +106,78 @@ pub struct InfoDisplaysOnlyResultBuilder {
 }
 
 impl InfoDisplaysOnlyResultBuilder {
-    pub fn with_primary_display(mut self, display_name: &str) -> Self {
+    pub fn with_primary_display(mut self, display: &FuzzedDisplay) -> Self {
         self.displays_result.push(DisplayInfo {
             is_primary: true,
-            name: display_name.to_string(),
+            ..CurrentFuzzedDisplaysSettingsApi::to_display_info(display)
         });
 
         self
     }
 
-    pub fn with_secondary_display(mut self, display_name: &str) -> Self {
+    pub fn with_secondary_display(mut self, display: &FuzzedDisplay) -> Self {
         self.displays_result.push(DisplayInfo {
             is_primary: false,
-            name: display_name.to_string(),
+            ..CurrentFuzzedDisplaysSettingsApi::to_display_info(display)
+        });
+
+        self
+    }
+
+    /// The display is expected rotated and scaled as asked, its resolution following its
+    /// rotation.
+    pub fn with_transformed_secondary_display(
+        mut self,
+        display: &FuzzedDisplay,
+        transform_request: &DisplayTransformRequest,
+    ) -> Self {
+        let display_info = CurrentFuzzedDisplaysSettingsApi::to_display_info(display);
+        let rotation = transform_request.rotation.unwrap_or(display_info.rotation);
+
+        self.displays_result.push(DisplayInfo {
+            is_primary: false,
+            resolution: display_info
+                .resolution
+                .rotated(display_info.rotation)
+                .rotated(rotation),
+            rotation,
+            scale_percent: transform_request
+                .scale_percent
+                .or(display_info.scale_percent),
+            ..display_info
+        });
+
+        self
+    }
+
+    /// The display is expected with HDR turned on or off as asked.
+    pub fn with_hdr_secondary_display(
+        mut self,
+        display: &FuzzedDisplay,
+        is_hdr_enabled: bool,
+    ) -> Self {
+        let display_info = CurrentFuzzedDisplaysSettingsApi::to_display_info(display);
+
+        self.displays_result.push(DisplayInfo {
+            is_primary: false,
+            advanced_color: display_info.advanced_color.map(|advanced_color| {
+                DisplayAdvancedColor {
+                    is_hdr_enabled,
+                    ..advanced_color
+                }
+            }),
+            ..display_info
+        });
+
+        self
+    }
+
+    /// The only display left turned on is moved to the origin, which makes it the primary one.
+    pub fn with_only_display(mut self, display: &FuzzedDisplay) -> Self {
+        self.displays_result.push(DisplayInfo {
+            is_primary: true,
+            position: DisplayPosition { x: 0, y: 0 },
+            ..CurrentFuzzedDisplaysSettingsApi::to_display_info(display)
         });
 
         self
@@ -115,19 +199,19 @@ pub struct InfoSpeakersOnlyResultBuilder {
 }
 
 impl InfoSpeakersOnlyResultBuilder {
-    pub fn with_default_speaker(mut self, speaker_name: &str) -> Self {
+    pub fn with_default_speaker(mut self, speaker: &FuzzedSpeaker) -> Self {
         self.speakers_result.push(SpeakerInfo {
             is_default: true,
-            name: speaker_name.to_string(),
+            ..CurrentFuzzedSpeakersSettingsApi::to_speaker_info(speaker)
         });
 
         self
     }
 
-    pub fn with_alternative_speaker(mut self, speaker_name: &str) -> Self {
+    pub fn with_alternative_speaker(mut self, speaker: &FuzzedSpeaker) -> Self {
         self.speakers_result.push(SpeakerInfo {
             is_default: false,
-            name: speaker_name.to_string(),
+            ..CurrentFuzzedSpeakersSettingsApi::to_speaker_info(speaker)
         });
 
         self
@@ -142,3 +226,26 @@ impl InfoSpeakersOnlyResultBuilder {
         Ok(command_result)
     }
 }
+
+#[derive(Default)]
+pub struct InfoBackendsResultBuilder;
+
+impl InfoBackendsResultBuilder {
+    pub fn build(self) -> ApplicationResult<CommandResult> {
+        let application_info_result = ApplicationInfoResult::Backends {
+            backends_result: BackendsInfos {
+                displays_backends: get_backends_infos(
+                    Some(CURRENT_DISPLAYS_BACKEND_UNDER_TEST),
+                    CURRENT_DISPLAYS_BACKEND_UNDER_TEST,
+                ),
+                speakers_backends: get_backends_infos(
+                    Some(CURRENT_SPEAKERS_BACKEND_UNDER_TEST),
+                    CURRENT_SPEAKERS_BACKEND_UNDER_TEST,
+                ),
+            },
+        };
+        let command_result = CommandResult::Info(application_info_result);
+
+        Ok(command_result)
+    }
+}
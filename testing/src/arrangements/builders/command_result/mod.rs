@@ -1,15 +1,34 @@
 use convertible_couch::application::CommandResult;
 use convertible_couch_lib::{
-    application_error::ApplicationError, application_result::ApplicationResult,
+    application_error::{ApplicationError, DeviceKind, DeviceRole, VerificationMismatch},
+    application_result::ApplicationResult,
+    configuration::verification::Verification,
+    displays_settings::{DisplayInfo, DisplayMode},
+    speakers_settings::SpeakerInfo,
 };
 
-use crate::arrangements::builders::command_result::{
-    change::ChangeResultBuilder, displays::DisplaysCommandResultBuilder, info::InfoResultBuilder,
+use crate::arrangements::{
+    builders::command_result::{
+        change::ChangeResultBuilder, displays::DisplaysCommandResultBuilder,
+        info::InfoResultBuilder, plan::PlanResultBuilder, snapshot::SnapshotResultBuilder,
+    },
+    fuzzing::{
+        displays::{
+            FuzzedDisplay,
+            settings_api::{CurrentFuzzedDisplaysSettingsApi, FuzzedDisplaysSettingsApi},
+        },
+        speakers::{
+            FuzzedSpeaker,
+            settings_api::{CurrentFuzzedSpeakersSettingsApi, FuzzedSpeakersSettingsApi},
+        },
+    },
 };
 
 pub mod change;
 pub mod displays;
 pub mod info;
+pub mod plan;
+pub mod snapshot;
 
 pub struct CommandResultBuilder;
 
@@ -26,9 +45,217 @@ impl CommandResultBuilder {
         DisplaysCommandResultBuilder
     }
 
+    pub fn snapshot() -> SnapshotResultBuilder {
+        SnapshotResultBuilder
+    }
+
+    pub fn plan() -> PlanResultBuilder {
+        PlanResultBuilder
+    }
+
     pub fn custom_error(expected_message: String) -> ApplicationResult<CommandResult> {
         let application_error = ApplicationError::Custom(expected_message);
 
         Err(application_error)
     }
+
+    pub fn ambiguous_display_error(
+        expected_role: DeviceRole,
+        expected_selector: &str,
+        expected_candidates: &[&FuzzedDisplay],
+    ) -> ApplicationResult<CommandResult> {
+        let candidates = expected_candidates
+            .iter()
+            .map(|candidate| CurrentFuzzedDisplaysSettingsApi::to_display_info(candidate))
+            .collect::<Vec<DisplayInfo>>();
+        let application_error = ApplicationError::ambiguous_device(
+            DeviceKind::Display,
+            expected_role,
+            expected_selector,
+            &candidates.iter().collect::<Vec<&DisplayInfo>>(),
+        );
+
+        Err(application_error)
+    }
+
+    pub fn ambiguous_speaker_error(
+        expected_role: DeviceRole,
+        expected_selector: &str,
+        expected_candidates: &[&FuzzedSpeaker],
+    ) -> ApplicationResult<CommandResult> {
+        let candidates = expected_candidates
+            .iter()
+            .map(|candidate| CurrentFuzzedSpeakersSettingsApi::to_speaker_info(candidate))
+            .collect::<Vec<SpeakerInfo>>();
+        let application_error = ApplicationError::ambiguous_device(
+            DeviceKind::Speaker,
+            expected_role,
+            expected_selector,
+            &candidates.iter().collect::<Vec<&SpeakerInfo>>(),
+        );
+
+        Err(application_error)
+    }
+
+    pub fn config_error(expected_message: &str) -> ApplicationResult<CommandResult> {
+        let application_error = ApplicationError::Config {
+            message: String::from(expected_message),
+            source: None,
+        };
+
+        Err(application_error)
+    }
+
+    pub fn missing_argument_error(expected_message: &str) -> ApplicationResult<CommandResult> {
+        let application_error = ApplicationError::MissingArgument {
+            message: String::from(expected_message),
+        };
+
+        Err(application_error)
+    }
+
+    pub fn invalid_topology_error(expected_message: &str) -> ApplicationResult<CommandResult> {
+        let application_error = ApplicationError::InvalidTopology {
+            message: String::from(expected_message),
+        };
+
+        Err(application_error)
+    }
+
+    pub fn snapshot_error(expected_message: &str) -> ApplicationResult<CommandResult> {
+        let application_error = ApplicationError::Snapshot {
+            message: String::from(expected_message),
+            source: None,
+        };
+
+        Err(application_error)
+    }
+
+    pub fn partial_failure_error(
+        expected_completed: DeviceKind,
+        expected_failed: DeviceKind,
+        expected_source: ApplicationResult<CommandResult>,
+    ) -> ApplicationResult<CommandResult> {
+        let application_error = ApplicationError::partial_failure(
+            expected_completed,
+            expected_failed,
+            expected_source.unwrap_err(),
+        );
+
+        Err(application_error)
+    }
+
+    pub fn rollback_performed_error(
+        expected_rolled_back: DeviceKind,
+        expected_failed: DeviceKind,
+        expected_source: ApplicationResult<CommandResult>,
+    ) -> ApplicationResult<CommandResult> {
+        let application_error = ApplicationError::rollback_performed(
+            expected_rolled_back,
+            expected_failed,
+            expected_source.unwrap_err(),
+        );
+
+        Err(application_error)
+    }
+
+    pub fn rollback_failed_error(
+        expected_completed: DeviceKind,
+        expected_failed: DeviceKind,
+        expected_source: ApplicationResult<CommandResult>,
+        expected_rollback_error: ApplicationResult<CommandResult>,
+    ) -> ApplicationResult<CommandResult> {
+        let application_error = ApplicationError::rollback_failed(
+            expected_completed,
+            expected_failed,
+            expected_source.unwrap_err(),
+            expected_rollback_error.unwrap_err(),
+        );
+
+        Err(application_error)
+    }
+
+    /// The change is expected to be checked as many times as the default verification allows.
+    pub fn verification_failed_error(
+        expected_kind: DeviceKind,
+        expected_device: &str,
+        expected_observed_device: &str,
+    ) -> ApplicationResult<CommandResult> {
+        Self::settings_verification_failed_error(
+            expected_kind,
+            vec![VerificationMismatch::device(
+                expected_kind,
+                expected_device,
+                Some(expected_observed_device),
+            )],
+        )
+    }
+
+    /// The change is expected to be checked as many times as the default verification allows.
+    pub fn settings_verification_failed_error(
+        expected_kind: DeviceKind,
+        expected_mismatches: Vec<VerificationMismatch>,
+    ) -> ApplicationResult<CommandResult> {
+        let application_error = ApplicationError::VerificationFailed {
+            kind: expected_kind,
+            mismatches: expected_mismatches,
+            attempts: Verification::default().retries + 1,
+        };
+
+        Err(application_error)
+    }
+
+    pub fn device_not_found_error(
+        expected_kind: DeviceKind,
+        expected_role: DeviceRole,
+        expected_requested: &[&str],
+        expected_suggestions: &[&str],
+        expected_candidates: &[&str],
+    ) -> ApplicationResult<CommandResult> {
+        let application_error = ApplicationError::DeviceNotFound {
+            kind: expected_kind,
+            role: expected_role,
+            requested: to_strings(expected_requested),
+            candidates: to_strings(expected_candidates),
+            suggestions: to_strings(expected_suggestions),
+        };
+
+        Err(application_error)
+    }
+
+    /// The modes of the display are expected from the highest to the lowest.
+    pub fn unsupported_mode_error(
+        expected_display: &FuzzedDisplay,
+        expected_requested: &str,
+    ) -> ApplicationResult<CommandResult> {
+        let mut supported_modes =
+            CurrentFuzzedDisplaysSettingsApi::to_display_modes(expected_display);
+
+        supported_modes.sort_by(|mode, other_mode| other_mode.cmp(mode));
+        supported_modes.dedup();
+
+        let application_error = ApplicationError::UnsupportedMode {
+            display: CurrentFuzzedDisplaysSettingsApi::to_display_info(expected_display).name,
+            requested: String::from(expected_requested),
+            supported: supported_modes.iter().map(DisplayMode::to_string).collect(),
+        };
+
+        Err(application_error)
+    }
+
+    pub fn unsupported_setting_error(
+        expected_display: &FuzzedDisplay,
+        expected_setting: &str,
+    ) -> ApplicationResult<CommandResult> {
+        let application_error = ApplicationError::UnsupportedSetting {
+            display: CurrentFuzzedDisplaysSettingsApi::to_display_info(expected_display).name,
+            setting: String::from(expected_setting),
+        };
+
+        Err(application_error)
+    }
+}
+
+fn to_strings(values: &[&str]) -> Vec<String> {
+    values.iter().map(|value| String::from(*value)).collect()
 }
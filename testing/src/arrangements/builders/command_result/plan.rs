@@ -0,0 +1,172 @@
+use convertible_couch::application::{ApplicationInfoResult, ApplicationPlanResult, CommandResult};
+use convertible_couch_lib::{
+    application_result::ApplicationResult,
+    displays_settings::{DisplayMode, DisplayTransformRequest, DisplaysPlan},
+    speakers_settings::SpeakersPlan,
+};
+
+use crate::arrangements::fuzzing::speakers::settings_api::CURRENT_SPEAKER_ROLES_UNDER_TEST;
+
+/// Builds the plans expected from the devices reported once the change is actually made, since a
+/// plan reports the devices as they would be once changed.
+pub struct PlanResultBuilder;
+
+impl PlanResultBuilder {
+    pub fn displays_and_speakers(
+        self,
+        new_primary_display: &str,
+        new_default_speaker: &str,
+        info_result_after_change: ApplicationResult<CommandResult>,
+    ) -> ApplicationResult<CommandResult> {
+        let Ok(CommandResult::Info(ApplicationInfoResult::DisplaysAndSpeakers {
+            displays_result,
+            speakers_result,
+        })) = info_result_after_change
+        else {
+            panic!("An info about the displays and speakers is expected");
+        };
+
+        let application_plan_result = ApplicationPlanResult::DisplaysAndSpeakers {
+            displays_plan: DisplaysPlan {
+                new_primary_display: String::from(new_primary_display),
+                new_mode: None,
+                new_rotation: None,
+                new_scale_percent: None,
+                new_hdr_enabled: None,
+                displays: displays_result,
+            },
+            speakers_plan: SpeakersPlan {
+                new_default_speaker: String::from(new_default_speaker),
+                roles: CURRENT_SPEAKER_ROLES_UNDER_TEST.to_vec(),
+                speakers: speakers_result,
+            },
+        };
+
+        Ok(CommandResult::Plan(application_plan_result))
+    }
+
+    pub fn displays_only(
+        self,
+        new_primary_display: &str,
+        info_result_after_change: ApplicationResult<CommandResult>,
+    ) -> ApplicationResult<CommandResult> {
+        let Ok(CommandResult::Info(ApplicationInfoResult::DisplaysOnly { displays_result })) =
+            info_result_after_change
+        else {
+            panic!("An info about the displays is expected");
+        };
+
+        let application_plan_result = ApplicationPlanResult::DisplaysOnly {
+            displays_plan: DisplaysPlan {
+                new_primary_display: String::from(new_primary_display),
+                new_mode: None,
+                new_rotation: None,
+                new_scale_percent: None,
+                new_hdr_enabled: None,
+                displays: displays_result,
+            },
+        };
+
+        Ok(CommandResult::Plan(application_plan_result))
+    }
+
+    pub fn displays_only_at_mode(
+        self,
+        new_primary_display: &str,
+        new_mode: DisplayMode,
+        info_result_after_change: ApplicationResult<CommandResult>,
+    ) -> ApplicationResult<CommandResult> {
+        let Ok(CommandResult::Info(ApplicationInfoResult::DisplaysOnly { displays_result })) =
+            info_result_after_change
+        else {
+            panic!("An info about the displays is expected");
+        };
+
+        let application_plan_result = ApplicationPlanResult::DisplaysOnly {
+            displays_plan: DisplaysPlan {
+                new_primary_display: String::from(new_primary_display),
+                new_mode: Some(new_mode),
+                new_rotation: None,
+                new_scale_percent: None,
+                new_hdr_enabled: None,
+                displays: displays_result,
+            },
+        };
+
+        Ok(CommandResult::Plan(application_plan_result))
+    }
+
+    pub fn displays_only_at_transform(
+        self,
+        new_primary_display: &str,
+        transform_request: &DisplayTransformRequest,
+        info_result_after_change: ApplicationResult<CommandResult>,
+    ) -> ApplicationResult<CommandResult> {
+        let Ok(CommandResult::Info(ApplicationInfoResult::DisplaysOnly { displays_result })) =
+            info_result_after_change
+        else {
+            panic!("An info about the displays is expected");
+        };
+
+        let application_plan_result = ApplicationPlanResult::DisplaysOnly {
+            displays_plan: DisplaysPlan {
+                new_primary_display: String::from(new_primary_display),
+                new_mode: None,
+                new_rotation: transform_request.rotation,
+                new_scale_percent: transform_request.scale_percent,
+                new_hdr_enabled: None,
+                displays: displays_result,
+            },
+        };
+
+        Ok(CommandResult::Plan(application_plan_result))
+    }
+
+    pub fn displays_only_at_hdr(
+        self,
+        new_primary_display: &str,
+        is_hdr_enabled: bool,
+        info_result_after_change: ApplicationResult<CommandResult>,
+    ) -> ApplicationResult<CommandResult> {
+        let Ok(CommandResult::Info(ApplicationInfoResult::DisplaysOnly { displays_result })) =
+            info_result_after_change
+        else {
+            panic!("An info about the displays is expected");
+        };
+
+        let application_plan_result = ApplicationPlanResult::DisplaysOnly {
+            displays_plan: DisplaysPlan {
+                new_primary_display: String::from(new_primary_display),
+                new_mode: None,
+                new_rotation: None,
+                new_scale_percent: None,
+                new_hdr_enabled: Some(is_hdr_enabled),
+                displays: displays_result,
+            },
+        };
+
+        Ok(CommandResult::Plan(application_plan_result))
+    }
+
+    pub fn speakers_only(
+        self,
+        new_default_speaker: &str,
+        info_result_after_change: ApplicationResult<CommandResult>,
+    ) -> ApplicationResult<CommandResult> {
+        let Ok(CommandResult::Info(ApplicationInfoResult::SpeakersOnly { speakers_result })) =
+            info_result_after_change
+        else {
+            panic!("An info about the speakers is expected");
+        };
+
+        let application_plan_result = ApplicationPlanResult::SpeakersOnly {
+            speakers_plan: SpeakersPlan {
+                new_default_speaker: String::from(new_default_speaker),
+                roles: CURRENT_SPEAKER_ROLES_UNDER_TEST.to_vec(),
+                speakers: speakers_result,
+            },
+        };
+
+        Ok(CommandResult::Plan(application_plan_result))
+    }
+}
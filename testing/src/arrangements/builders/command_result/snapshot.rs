@@ -0,0 +1,22 @@
+use convertible_couch::application::{ApplicationSnapshotResult, CommandResult};
+use convertible_couch_lib::application_result::ApplicationResult;
+
+pub struct SnapshotResultBuilder;
+
+impl SnapshotResultBuilder {
+    pub fn saved(self, snapshot_name: &str) -> ApplicationResult<CommandResult> {
+        let application_snapshot_result = ApplicationSnapshotResult::Saved {
+            snapshot_name: String::from(snapshot_name),
+        };
+
+        Ok(CommandResult::Snapshot(application_snapshot_result))
+    }
+
+    pub fn restored(self, snapshot_name: &str) -> ApplicationResult<CommandResult> {
+        let application_snapshot_result = ApplicationSnapshotResult::Restored {
+            snapshot_name: String::from(snapshot_name),
+        };
+
+        Ok(CommandResult::Snapshot(application_snapshot_result))
+    }
+}
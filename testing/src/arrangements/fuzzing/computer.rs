@@ -1,19 +1,71 @@
+use convertible_couch_lib::displays_settings::INTERNAL_DISPLAY_NAME;
 use rand::rngs::StdRng;
 
 use crate::arrangements::fuzzing::{
     ComputerBuilder,
-    displays::{DisplaysFuzzer, settings_api::CurrentFuzzedDisplaysSettingsApi},
-    speakers::{SpeakersFuzzer, settings_api::CurrentFuzzedSpeakersSettingsApi},
+    displays::{DisplaysFuzzer, FuzzedDisplay, settings_api::CurrentFuzzedDisplaysSettingsApi},
+    speakers::{FuzzedSpeaker, SpeakersFuzzer, settings_api::CurrentFuzzedSpeakersSettingsApi},
 };
 
 pub struct FuzzedComputer {
+    pub displays: Vec<FuzzedDisplay>,
     pub displays_settings_api: CurrentFuzzedDisplaysSettingsApi,
+    pub speakers: Vec<FuzzedSpeaker>,
     pub speakers_settings_api: CurrentFuzzedSpeakersSettingsApi,
 }
 
+impl FuzzedComputer {
+    pub fn display_named(&self, display_name: &str) -> FuzzedDisplay {
+        self.displays
+            .iter()
+            .find(|display| display.name == display_name)
+            .unwrap_or_else(|| panic!("The computer has no display named {display_name}"))
+            .clone()
+    }
+
+    /// The names of the displays as a topology reports them, the primary one first.
+    pub fn displays_names_primary_first(&self) -> Vec<String> {
+        let mut displays = self
+            .displays
+            .iter()
+            .map(|display| {
+                let name = if display.name.is_empty() {
+                    String::from(INTERNAL_DISPLAY_NAME)
+                } else {
+                    display.name.clone()
+                };
+
+                (!display.primary, name)
+            })
+            .collect::<Vec<(bool, String)>>();
+
+        displays.sort();
+
+        displays.into_iter().map(|(_, name)| name).collect()
+    }
+
+    pub fn speaker_named(&self, speaker_name: &str) -> FuzzedSpeaker {
+        self.speakers
+            .iter()
+            .find(|speaker| speaker.name == speaker_name)
+            .unwrap_or_else(|| panic!("The computer has no speaker named {speaker_name}"))
+            .clone()
+    }
+
+    pub fn speakers_named(&self, speaker_name: &str) -> Vec<FuzzedSpeaker> {
+        self.speakers
+            .iter()
+            .filter(|speaker| speaker.name == speaker_name)
+            .cloned()
+            .collect()
+    }
+}
+
 pub struct ComputerFuzzer<'a> {
     pub rand: &'a mut StdRng,
+    displays: Vec<FuzzedDisplay>,
     displays_settings_api: CurrentFuzzedDisplaysSettingsApi,
+    speakers: Vec<FuzzedSpeaker>,
     speakers_settings_api: CurrentFuzzedSpeakersSettingsApi,
 }
 
@@ -21,11 +73,19 @@ impl<'a> ComputerFuzzer<'a> {
     pub fn new(rand: &'a mut StdRng) -> Self {
         Self {
             rand,
+            displays: Vec::new(),
             displays_settings_api: CurrentFuzzedDisplaysSettingsApi::default(),
+            speakers: Vec::new(),
             speakers_settings_api: CurrentFuzzedSpeakersSettingsApi::default(),
         }
     }
 
+    pub fn set_displays(&mut self, displays: Vec<FuzzedDisplay>) -> &mut Self {
+        self.displays = displays;
+
+        self
+    }
+
     pub fn set_displays_settings_api(
         &mut self,
         displays_settings_api: CurrentFuzzedDisplaysSettingsApi,
@@ -35,6 +95,12 @@ impl<'a> ComputerFuzzer<'a> {
         self
     }
 
+    pub fn set_speakers(&mut self, speakers: Vec<FuzzedSpeaker>) -> &mut Self {
+        self.speakers = speakers;
+
+        self
+    }
+
     pub fn set_speakers_settings_api(
         &mut self,
         speakers_settings_api: CurrentFuzzedSpeakersSettingsApi,
@@ -56,7 +122,9 @@ impl<'a> ComputerFuzzer<'a> {
 impl ComputerBuilder<'_> for ComputerFuzzer<'_> {
     fn build_computer(&mut self) -> FuzzedComputer {
         FuzzedComputer {
+            displays: self.displays.clone(),
             displays_settings_api: self.displays_settings_api.clone(),
+            speakers: self.speakers.clone(),
             speakers_settings_api: self.speakers_settings_api.clone(),
         }
     }
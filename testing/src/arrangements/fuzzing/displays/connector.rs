@@ -0,0 +1,27 @@
+use convertible_couch_lib::displays_settings::DisplayConnector;
+use rand::{rngs::StdRng, seq::IndexedRandom};
+
+/// The connectors an external display can be plugged in.
+const EXTERNAL_CONNECTORS: [DisplayConnector; 3] = [
+    DisplayConnector::Hdmi,
+    DisplayConnector::DisplayPort,
+    DisplayConnector::Dvi,
+];
+
+pub struct ConnectorFuzzer<'a> {
+    rand: &'a mut StdRng,
+}
+
+impl<'a> ConnectorFuzzer<'a> {
+    pub fn new(rand: &'a mut StdRng) -> Self {
+        Self { rand }
+    }
+
+    pub fn generate_one(&mut self) -> DisplayConnector {
+        *EXTERNAL_CONNECTORS.choose(self.rand).unwrap()
+    }
+
+    pub fn generate_several(&mut self, count: usize) -> Vec<DisplayConnector> {
+        (0..count).map(|_| self.generate_one()).collect()
+    }
+}
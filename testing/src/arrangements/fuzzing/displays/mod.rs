@@ -3,8 +3,11 @@ use crate::arrangements::fuzzing::{
     computer::{ComputerFuzzer, FuzzedComputer},
     displays::{
         config_mod_info_id::ConfigModeInfoIdFuzzer,
+        connector::ConnectorFuzzer,
         display_name::DisplayNameFuzzer,
+        mode::{FuzzedMode, ModeFuzzer},
         position::{DisplayPositionFuzzer, FuzzedDisplayPosition},
+        refresh_rate::{FuzzedRefreshRate, RefreshRateFuzzer},
         resolution::{FuzzedResolution, ResolutionFuzzer},
         settings_api::{
             CurrentFuzzedDisplaysSettingsApi, FuzzedDisplaysSettingsApi,
@@ -13,12 +16,18 @@ use crate::arrangements::fuzzing::{
     },
 };
 
+use convertible_couch_lib::displays_settings::{
+    DisplayAdvancedColor, DisplayColorEncoding, DisplayConnector,
+};
 use rand::RngExt;
 use std::collections::HashSet;
 
 pub mod config_mod_info_id;
+pub mod connector;
 pub mod display_name;
+pub mod mode;
 pub mod position;
+pub mod refresh_rate;
 pub mod resolution;
 pub mod settings_api;
 
@@ -29,6 +38,25 @@ pub struct FuzzedDisplay {
     pub config_mode_info_id: u32,
     pub resolution: FuzzedResolution,
     pub position: FuzzedDisplayPosition,
+    pub refresh_rate: FuzzedRefreshRate,
+    pub connector: DisplayConnector,
+    /// The modes supported besides the current one.
+    pub modes: Vec<FuzzedMode>,
+    /// HDR is always off, the displays supporting it being the ones asked for.
+    pub is_hdr_supported: bool,
+}
+
+impl FuzzedDisplay {
+    pub const BITS_PER_COLOR_CHANNEL: u32 = 8;
+
+    pub fn advanced_color(&self) -> DisplayAdvancedColor {
+        DisplayAdvancedColor {
+            is_hdr_supported: self.is_hdr_supported,
+            is_hdr_enabled: false,
+            bits_per_color_channel: Some(Self::BITS_PER_COLOR_CHANNEL),
+            color_encoding: DisplayColorEncoding::Rgb,
+        }
+    }
 }
 
 pub struct DisplaysFuzzer<'a> {
@@ -38,6 +66,7 @@ pub struct DisplaysFuzzer<'a> {
     includes_an_internal_display: bool,
     primary_display_name: Option<String>,
     secondary_display_names: HashSet<String>,
+    hdr_display_names: HashSet<String>,
     behaviour: CurrentFuzzedDisplaysSettingsApiBehaviour,
 }
 
@@ -54,6 +83,7 @@ impl<'a> DisplaysFuzzer<'a> {
             includes_an_internal_display: false,
             primary_display_name: None,
             secondary_display_names: HashSet::new(),
+            hdr_display_names: HashSet::new(),
             behaviour: CurrentFuzzedDisplaysSettingsApiBehaviour::default(),
         }
     }
@@ -91,6 +121,16 @@ impl<'a> DisplaysFuzzer<'a> {
         self
     }
 
+    pub fn with_a_secondary_supporting_hdr_named(
+        &mut self,
+        secondary_display_name: &str,
+    ) -> &mut Self {
+        self.hdr_display_names
+            .insert(secondary_display_name.to_string());
+
+        self.with_a_secondary_named(secondary_display_name)
+    }
+
     pub fn build_displays(&'a mut self) -> &'a mut ComputerFuzzer<'a> {
         let n_video_output = self
             .computer_fuzzer
@@ -111,9 +151,10 @@ impl<'a> DisplaysFuzzer<'a> {
         );
 
         let displays_settings_api =
-            CurrentFuzzedDisplaysSettingsApi::new(displays, self.behaviour.clone());
+            CurrentFuzzedDisplaysSettingsApi::new(displays.clone(), self.behaviour.clone());
 
         self.computer_fuzzer
+            .set_displays(displays)
             .set_displays_settings_api(displays_settings_api)
     }
 
@@ -154,6 +195,17 @@ impl<'a> DisplaysFuzzer<'a> {
 
         let config_mode_info_ids =
             ConfigModeInfoIdFuzzer::new(self.computer_fuzzer.rand).generate_several(n_display);
+        let refresh_rates =
+            RefreshRateFuzzer::new(self.computer_fuzzer.rand).generate_several(n_display);
+        let connectors =
+            ConnectorFuzzer::new(self.computer_fuzzer.rand).generate_several(n_display);
+        let modes = positioned_resolutions
+            .iter()
+            .map(|positioned_resolution| {
+                ModeFuzzer::new(self.computer_fuzzer.rand)
+                    .generate_several(positioned_resolution.resolution)
+            })
+            .collect::<Vec<Vec<FuzzedMode>>>();
 
         (0..n_display)
             .map(|display_index| {
@@ -166,6 +218,13 @@ impl<'a> DisplaysFuzzer<'a> {
                     names[display_index].to_owned()
                 };
                 let config_mode_info_id = config_mode_info_ids[display_index];
+                let refresh_rate = refresh_rates[display_index];
+                let is_hdr_supported = self.hdr_display_names.contains(&name);
+                let connector = if self.includes_an_internal_display && primary {
+                    DisplayConnector::Internal
+                } else {
+                    connectors[display_index]
+                };
 
                 FuzzedDisplay {
                     config_mode_info_id,
@@ -173,6 +232,10 @@ impl<'a> DisplaysFuzzer<'a> {
                     position,
                     primary,
                     resolution,
+                    refresh_rate,
+                    connector,
+                    modes: modes[display_index].clone(),
+                    is_hdr_supported,
                 }
             })
             .collect()
@@ -253,6 +316,82 @@ cfg_select! {
 
                 self
             }
+
+            pub fn for_which_set_display_config_is_not_applied(&mut self) -> &mut Self {
+                self.behaviour.set_display_config_is_not_applied = true;
+
+                self
+            }
+        }
+    }
+    _ => {
+        pub enum Function {
+            GetDisplays,
+            SetDisplays,
+        }
+
+        impl<'a> DisplaysFuzzer<'a> {
+            pub fn for_which_function_fails_with(
+                &mut self,
+                function: Function,
+                error: String,
+            ) -> &mut Self {
+                match function {
+                    Function::GetDisplays => self.for_which_get_displays_fails_with(error),
+                    Function::SetDisplays => self.for_which_set_displays_fails_with(error),
+                }
+            }
+
+            pub fn for_which_get_displays_fails_with(&mut self, error: String) -> &mut Self {
+                self.behaviour.get_displays_error = Some(error);
+
+                self
+            }
+
+            pub fn for_which_set_displays_fails_with(&mut self, error: String) -> &mut Self {
+                self.behaviour.set_displays_error = Some(error);
+
+                self
+            }
+
+            pub fn for_which_set_displays_fails_after_succeeding_with(
+                &mut self,
+                successes: usize,
+                error: String,
+            ) -> &mut Self {
+                self.behaviour.set_displays_error = Some(error);
+                self.behaviour.set_displays_successes_before_error = successes;
+
+                self
+            }
+
+            pub fn for_which_set_displays_fails_once_after_succeeding_with(
+                &mut self,
+                successes: usize,
+                error: String,
+            ) -> &mut Self {
+                self.for_which_set_displays_fails_after_succeeding_with(successes, error);
+                self.behaviour.set_displays_fails_once = true;
+
+                self
+            }
+
+            pub fn for_which_set_displays_is_not_applied(&mut self) -> &mut Self {
+                self.behaviour.set_displays_is_not_applied = true;
+
+                self
+            }
+
+            pub fn for_which_set_displays_is_not_applied_once_after_applying(
+                &mut self,
+                applications: usize,
+            ) -> &mut Self {
+                self.for_which_set_displays_is_not_applied();
+                self.behaviour.set_displays_applications_before_not_applied = applications;
+                self.behaviour.set_displays_is_not_applied_once = true;
+
+                self
+            }
         }
     }
 }
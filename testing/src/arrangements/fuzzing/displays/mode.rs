@@ -0,0 +1,61 @@
+use crate::arrangements::fuzzing::displays::{
+    refresh_rate::FuzzedRefreshRate, resolution::FuzzedResolution,
+};
+use convertible_couch_lib::displays_settings::{DisplayMode, DisplayResolution};
+use rand::{RngExt, rngs::StdRng, seq::IndexedRandom};
+
+/// A mode supported by a display besides its current one, always at a whole refresh rate.
+#[derive(Clone, Copy)]
+pub struct FuzzedMode {
+    pub resolution: FuzzedResolution,
+    pub refresh_rate: FuzzedRefreshRate,
+}
+
+impl FuzzedMode {
+    pub fn to_display_mode(&self) -> DisplayMode {
+        DisplayMode {
+            resolution: DisplayResolution {
+                width: self.resolution.width,
+                height: self.resolution.height,
+            },
+            refresh_rate_millihertz: self.refresh_rate.millihertz(),
+        }
+    }
+}
+
+pub struct ModeFuzzer<'a> {
+    rand: &'a mut StdRng,
+}
+
+impl<'a> ModeFuzzer<'a> {
+    const MAX_N_MODE: usize = 4;
+
+    pub fn new(rand: &'a mut StdRng) -> Self {
+        Self { rand }
+    }
+
+    pub fn generate_several(&mut self, current_resolution: FuzzedResolution) -> Vec<FuzzedMode> {
+        let n_mode = self.rand.random_range(1..=Self::MAX_N_MODE);
+        let resolutions = FuzzedResolution::ALL
+            .iter()
+            .filter(|resolution| **resolution != current_resolution)
+            .copied()
+            .collect::<Vec<FuzzedResolution>>();
+        let refresh_rates = FuzzedRefreshRate::ALL
+            .iter()
+            .filter(|refresh_rate| refresh_rate.denominator == 1)
+            .copied()
+            .collect::<Vec<FuzzedRefreshRate>>();
+
+        resolutions
+            .sample(self.rand, n_mode)
+            .copied()
+            .collect::<Vec<FuzzedResolution>>()
+            .into_iter()
+            .map(|resolution| FuzzedMode {
+                resolution,
+                refresh_rate: *refresh_rates.choose(self.rand).unwrap(),
+            })
+            .collect()
+    }
+}
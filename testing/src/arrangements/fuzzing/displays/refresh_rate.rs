@@ -0,0 +1,63 @@
+use rand::{rngs::StdRng, seq::IndexedRandom};
+
+/// A refresh rate as reported by Windows, the NTSC ones not being whole numbers.
+#[derive(Clone, Copy)]
+pub struct FuzzedRefreshRate {
+    pub numerator: u32,
+    pub denominator: u32,
+}
+
+impl FuzzedRefreshRate {
+    pub const ALL: [FuzzedRefreshRate; 7] = [
+        FuzzedRefreshRate {
+            numerator: 60000,
+            denominator: 1001,
+        },
+        FuzzedRefreshRate {
+            numerator: 60,
+            denominator: 1,
+        },
+        FuzzedRefreshRate {
+            numerator: 75,
+            denominator: 1,
+        },
+        FuzzedRefreshRate {
+            numerator: 120,
+            denominator: 1,
+        },
+        FuzzedRefreshRate {
+            numerator: 144,
+            denominator: 1,
+        },
+        FuzzedRefreshRate {
+            numerator: 165,
+            denominator: 1,
+        },
+        FuzzedRefreshRate {
+            numerator: 240,
+            denominator: 1,
+        },
+    ];
+
+    pub fn millihertz(&self) -> u32 {
+        self.numerator * 1000 / self.denominator
+    }
+}
+
+pub struct RefreshRateFuzzer<'a> {
+    rand: &'a mut StdRng,
+}
+
+impl<'a> RefreshRateFuzzer<'a> {
+    pub fn new(rand: &'a mut StdRng) -> Self {
+        Self { rand }
+    }
+
+    pub fn generate_one(&mut self) -> FuzzedRefreshRate {
+        *FuzzedRefreshRate::ALL.choose(self.rand).unwrap()
+    }
+
+    pub fn generate_several(&mut self, count: usize) -> Vec<FuzzedRefreshRate> {
+        (0..count).map(|_| self.generate_one()).collect()
+    }
+}
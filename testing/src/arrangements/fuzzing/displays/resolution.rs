@@ -2,7 +2,7 @@ use std::fmt::Display;
 
 use rand::{rngs::StdRng, seq::IndexedRandom};
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, PartialEq)]
 pub struct FuzzedResolution {
     pub width: u32,
     pub height: u32,
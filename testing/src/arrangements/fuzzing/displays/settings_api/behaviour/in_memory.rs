@@ -0,0 +1,19 @@
+use crate::arrangements::fuzzing::displays::settings_api::behaviour::FuzzedDisplaysSettingsApiBehaviour;
+
+#[derive(Clone, Default)]
+pub struct FuzzedInMemoryDisplaysSettingsApiBehaviour {
+    pub get_displays_error: Option<String>,
+    pub set_displays_error: Option<String>,
+    /// The number of calls to `set_displays` which succeed before it fails with the error above.
+    pub set_displays_successes_before_error: usize,
+    /// Whether `set_displays` fails with the error above only once, succeeding again afterwards.
+    pub set_displays_fails_once: bool,
+    /// Whether `set_displays` succeeds without changing the displays.
+    pub set_displays_is_not_applied: bool,
+    /// The number of calls to `set_displays` which are applied before one is not.
+    pub set_displays_applications_before_not_applied: usize,
+    /// Whether only one call to `set_displays` is not applied, the next ones being applied again.
+    pub set_displays_is_not_applied_once: bool,
+}
+
+impl FuzzedDisplaysSettingsApiBehaviour for FuzzedInMemoryDisplaysSettingsApiBehaviour {}
@@ -5,4 +5,8 @@ cfg_select! {
         pub mod windows;
         pub use windows::FuzzedWindowsDisplaysSettingsApiBehaviour as CurrentFuzzedDisplaysSettingsApiBehaviour;
     }
+    _ => {
+        pub mod in_memory;
+        pub use in_memory::FuzzedInMemoryDisplaysSettingsApiBehaviour as CurrentFuzzedDisplaysSettingsApiBehaviour;
+    }
 }
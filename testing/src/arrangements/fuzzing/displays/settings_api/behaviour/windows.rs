@@ -8,6 +8,8 @@ pub struct FuzzedWindowsDisplaysSettingsApiBehaviour {
     pub query_display_config_errors: Vec<WIN32_ERROR>,
     pub display_config_get_device_info_error: Option<WIN32_ERROR>,
     pub set_display_config_error: Option<WIN32_ERROR>,
+    /// Whether `set_display_config` succeeds without changing the displays.
+    pub set_display_config_is_not_applied: bool,
 }
 
 impl FuzzedDisplaysSettingsApiBehaviour for FuzzedWindowsDisplaysSettingsApiBehaviour {}
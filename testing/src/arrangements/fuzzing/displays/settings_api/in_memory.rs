@@ -0,0 +1,139 @@
+use crate::arrangements::fuzzing::displays::{
+    FuzzedDisplay,
+    settings_api::{
+        FuzzedDisplaysSettingsApi, behaviour::in_memory::FuzzedInMemoryDisplaysSettingsApiBehaviour,
+    },
+};
+use convertible_couch_lib::{
+    application_error::ApplicationError,
+    application_result::ApplicationResult,
+    displays_settings::{
+        DisplayInfo, DisplayMode, DisplayPosition, DisplayResolution, DisplayRotation,
+        INTERNAL_DISPLAY_NAME,
+        in_memory::in_memory_api::{InMemoryApi, InMemoryDisplay},
+    },
+};
+
+const SCALE_PERCENT: u32 = 100;
+
+#[derive(Clone, Default)]
+pub struct FuzzedInMemoryApi {
+    displays: Vec<InMemoryDisplay>,
+    behaviour: FuzzedInMemoryDisplaysSettingsApiBehaviour,
+}
+
+impl FuzzedDisplaysSettingsApi for FuzzedInMemoryApi {
+    fn new(
+        displays: Vec<FuzzedDisplay>,
+        behaviour: FuzzedInMemoryDisplaysSettingsApiBehaviour,
+    ) -> Self {
+        let displays = displays
+            .iter()
+            .map(|display| InMemoryDisplay {
+                id: display.config_mode_info_id.to_string(),
+                name: display.name.clone(),
+                connector: display.connector,
+                width: display.resolution.width,
+                height: display.resolution.height,
+                x: display.position.x,
+                y: display.position.y,
+                refresh_rate_millihertz: display.refresh_rate.millihertz(),
+                rotation: DisplayRotation::Degrees0,
+                scale_percent: SCALE_PERCENT,
+                is_active: true,
+                modes: display
+                    .modes
+                    .iter()
+                    .map(|mode| mode.to_display_mode())
+                    .collect(),
+                advanced_color: Some(display.advanced_color()),
+            })
+            .collect();
+
+        Self {
+            displays,
+            behaviour,
+        }
+    }
+
+    fn to_display_info(display: &FuzzedDisplay) -> DisplayInfo {
+        DisplayInfo {
+            is_primary: display.primary,
+            name: if display.name.is_empty() {
+                String::from(INTERNAL_DISPLAY_NAME)
+            } else {
+                display.name.clone()
+            },
+            id: display.config_mode_info_id.to_string(),
+            connector: display.connector,
+            resolution: DisplayResolution {
+                width: display.resolution.width,
+                height: display.resolution.height,
+            },
+            position: DisplayPosition {
+                x: display.position.x,
+                y: display.position.y,
+            },
+            refresh_rate_millihertz: Some(display.refresh_rate.millihertz()),
+            rotation: DisplayRotation::Degrees0,
+            scale_percent: Some(SCALE_PERCENT),
+            advanced_color: Some(display.advanced_color()),
+        }
+    }
+
+    fn to_display_modes(display: &FuzzedDisplay) -> Vec<DisplayMode> {
+        [DisplayMode {
+            resolution: DisplayResolution {
+                width: display.resolution.width,
+                height: display.resolution.height,
+            },
+            refresh_rate_millihertz: display.refresh_rate.millihertz(),
+        }]
+        .into_iter()
+        .chain(display.modes.iter().map(|mode| mode.to_display_mode()))
+        .collect()
+    }
+}
+
+impl InMemoryApi for FuzzedInMemoryApi {
+    fn get_displays(&self) -> ApplicationResult<Vec<InMemoryDisplay>> {
+        if let Some(error) = &self.behaviour.get_displays_error {
+            return Err(ApplicationError::Custom(error.clone()));
+        }
+
+        Ok(self.displays.clone())
+    }
+
+    fn set_displays(&mut self, displays: Vec<InMemoryDisplay>) -> ApplicationResult<()> {
+        if let Some(error) = &self.behaviour.set_displays_error {
+            if self.behaviour.set_displays_successes_before_error == 0 {
+                let error = ApplicationError::Custom(error.clone());
+
+                if self.behaviour.set_displays_fails_once {
+                    self.behaviour.set_displays_error = None;
+                }
+
+                return Err(error);
+            }
+
+            self.behaviour.set_displays_successes_before_error -= 1;
+        }
+
+        if displays.len() != self.displays.len() {
+            return Err(ApplicationError::Custom(String::from(
+                "The displays do not match the ones of the computer",
+            )));
+        }
+
+        if !self.behaviour.set_displays_is_not_applied {
+            self.displays = displays;
+        } else if self.behaviour.set_displays_applications_before_not_applied > 0 {
+            self.behaviour.set_displays_applications_before_not_applied -= 1;
+            self.displays = displays;
+        } else if self.behaviour.set_displays_is_not_applied_once {
+            self.behaviour.set_displays_is_not_applied = false;
+        }
+
+        Ok(())
+    }
+}
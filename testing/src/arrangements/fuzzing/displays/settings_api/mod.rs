@@ -1,3 +1,8 @@
+use convertible_couch_lib::{
+    backends::displays_backend::DisplaysBackend,
+    displays_settings::{DisplayInfo, DisplayMode},
+};
+
 use crate::arrangements::fuzzing::displays::{
     FuzzedDisplay, settings_api::behaviour::CurrentFuzzedDisplaysSettingsApiBehaviour,
 };
@@ -9,11 +14,25 @@ pub trait FuzzedDisplaysSettingsApi: Default {
         displays: Vec<FuzzedDisplay>,
         behaviour: CurrentFuzzedDisplaysSettingsApiBehaviour,
     ) -> Self;
+
+    /// The information the backend under test is expected to report about a display.
+    fn to_display_info(display: &FuzzedDisplay) -> DisplayInfo;
+
+    /// The modes the backend under test is expected to report as supported by a display.
+    fn to_display_modes(display: &FuzzedDisplay) -> Vec<DisplayMode>;
 }
 
 cfg_select! {
     target_os = "windows" => {
         pub mod windows;
         pub use windows::FuzzedWindowsApi as CurrentFuzzedDisplaysSettingsApi;
+        pub const CURRENT_DISPLAYS_BACKEND_UNDER_TEST: DisplaysBackend = DisplaysBackend::Windows;
+        pub use convertible_couch_lib::displays_settings::windows::windows_display_settings::WindowsDisplaySettings as CurrentDisplaysSettingsUnderTest;
+    }
+    _ => {
+        pub mod in_memory;
+        pub use in_memory::FuzzedInMemoryApi as CurrentFuzzedDisplaysSettingsApi;
+        pub const CURRENT_DISPLAYS_BACKEND_UNDER_TEST: DisplaysBackend = DisplaysBackend::InMemory;
+        pub use convertible_couch_lib::displays_settings::in_memory::in_memory_displays_settings::InMemoryDisplaysSettings as CurrentDisplaysSettingsUnderTest;
     }
 }
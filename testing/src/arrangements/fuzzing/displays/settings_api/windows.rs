@@ -4,26 +4,65 @@ use crate::arrangements::fuzzing::displays::{
         FuzzedDisplaysSettingsApi, behaviour::windows::FuzzedWindowsDisplaysSettingsApiBehaviour,
     },
 };
-use convertible_couch_lib::displays_settings::windows::windows_api::WindowsApi;
+use convertible_couch_lib::displays_settings::{
+    DisplayAdvancedColor, DisplayConnector, DisplayInfo, DisplayMode, DisplayPosition,
+    DisplayResolution, DisplayRotation, INTERNAL_DISPLAY_NAME,
+    windows::windows_api::{
+        DISPLAYCONFIG_DEVICE_INFO_GET_DPI_SCALE, DISPLAYCONFIG_DEVICE_INFO_SET_DPI_SCALE,
+        DisplayConfigGetDpiScale, DisplayConfigSetDpiScale, WindowsApi,
+    },
+};
 use std::collections::HashMap;
 use windows::Win32::{
     Devices::Display::{
-        DISPLAYCONFIG_DEVICE_INFO_HEADER, DISPLAYCONFIG_MODE_INFO, DISPLAYCONFIG_MODE_INFO_0,
+        DISPLAYCONFIG_DEVICE_INFO_GET_ADVANCED_COLOR_INFO,
+        DISPLAYCONFIG_DEVICE_INFO_GET_SOURCE_NAME, DISPLAYCONFIG_DEVICE_INFO_GET_TARGET_NAME,
+        DISPLAYCONFIG_DEVICE_INFO_HEADER, DISPLAYCONFIG_DEVICE_INFO_SET_ADVANCED_COLOR_STATE,
+        DISPLAYCONFIG_GET_ADVANCED_COLOR_INFO, DISPLAYCONFIG_MODE_INFO, DISPLAYCONFIG_MODE_INFO_0,
         DISPLAYCONFIG_MODE_INFO_TYPE_SOURCE, DISPLAYCONFIG_MODE_INFO_TYPE_TARGET,
+        DISPLAYCONFIG_OUTPUT_TECHNOLOGY_DISPLAYPORT_EXTERNAL, DISPLAYCONFIG_OUTPUT_TECHNOLOGY_DVI,
+        DISPLAYCONFIG_OUTPUT_TECHNOLOGY_HD15, DISPLAYCONFIG_OUTPUT_TECHNOLOGY_HDMI,
+        DISPLAYCONFIG_OUTPUT_TECHNOLOGY_INTERNAL, DISPLAYCONFIG_OUTPUT_TECHNOLOGY_OTHER,
         DISPLAYCONFIG_PATH_INFO, DISPLAYCONFIG_PATH_SOURCE_INFO, DISPLAYCONFIG_PATH_SOURCE_INFO_0,
-        DISPLAYCONFIG_PATH_TARGET_INFO, DISPLAYCONFIG_PATH_TARGET_INFO_0,
-        DISPLAYCONFIG_SOURCE_MODE, DISPLAYCONFIG_TARGET_DEVICE_NAME, DISPLAYCONFIG_TOPOLOGY_ID,
-        QDC_ONLY_ACTIVE_PATHS, QUERY_DISPLAY_CONFIG_FLAGS, SDC_ALLOW_CHANGES, SDC_APPLY,
-        SDC_SAVE_TO_DATABASE, SDC_USE_SUPPLIED_DISPLAY_CONFIG, SET_DISPLAY_CONFIG_FLAGS,
+        DISPLAYCONFIG_PATH_TARGET_INFO, DISPLAYCONFIG_PATH_TARGET_INFO_0, DISPLAYCONFIG_RATIONAL,
+        DISPLAYCONFIG_ROTATION_IDENTITY, DISPLAYCONFIG_SET_ADVANCED_COLOR_STATE,
+        DISPLAYCONFIG_SOURCE_DEVICE_NAME, DISPLAYCONFIG_SOURCE_MODE,
+        DISPLAYCONFIG_TARGET_DEVICE_NAME, DISPLAYCONFIG_TOPOLOGY_ID,
+        DISPLAYCONFIG_VIDEO_OUTPUT_TECHNOLOGY, QDC_ALL_PATHS, QDC_ONLY_ACTIVE_PATHS,
+        QUERY_DISPLAY_CONFIG_FLAGS, SDC_ALLOW_CHANGES, SDC_APPLY, SDC_SAVE_TO_DATABASE,
+        SDC_TOPOLOGY_CLONE, SDC_TOPOLOGY_EXTEND, SDC_USE_SUPPLIED_DISPLAY_CONFIG,
+        SET_DISPLAY_CONFIG_FLAGS,
     },
     Foundation::{ERROR_INVALID_PARAMETER, ERROR_SUCCESS, LUID, POINTL, WIN32_ERROR},
+    Graphics::Gdi::{
+        CDS_TYPE, CDS_UPDATEREGISTRY, DEVMODEW, DISP_CHANGE, DISP_CHANGE_BADMODE,
+        DISP_CHANGE_SUCCESSFUL, DISPLAYCONFIG_COLOR_ENCODING_RGB, DISPLAYCONFIG_PATH_ACTIVE,
+        DM_DISPLAYFREQUENCY, DM_PELSHEIGHT, DM_PELSWIDTH, ENUM_CURRENT_SETTINGS,
+        ENUM_DISPLAY_SETTINGS_MODE,
+    },
+};
+use windows_core::{BOOL, PCWSTR};
+
+const ADAPTER_ID: LUID = LUID {
+    LowPart: 62504,
+    HighPart: 0,
 };
+/// The displays are at 100% while Windows recommends 150% for them, and offers up to 225%.
+const MIN_SCALE_STEP: i32 = -2;
+const MAX_SCALE_STEP: i32 = 3;
+const SCALE_PERCENT: u32 = 100;
 
 #[derive(Clone, Default)]
 pub struct FuzzedWindowsApi {
     patharray: Vec<DISPLAYCONFIG_PATH_INFO>,
     modeinfoarray: Vec<DISPLAYCONFIG_MODE_INFO>,
     displays_names: HashMap<(i32, u32, u32), String>,
+    /// The whole width, height and refresh rate of the modes of each display source.
+    sources_modes: HashMap<u32, Vec<(u32, u32, u32)>>,
+    /// The advanced colour of each display target.
+    advanced_colors: HashMap<u32, DisplayAdvancedColor>,
+    /// The scale step of each display source.
+    scale_steps: HashMap<u32, i32>,
     behaviour: FuzzedWindowsDisplaysSettingsApiBehaviour,
 }
 
@@ -35,11 +74,11 @@ impl FuzzedDisplaysSettingsApi for FuzzedWindowsApi {
         let mut patharray: Vec<DISPLAYCONFIG_PATH_INFO> = Vec::new();
         let mut modeinfoarray: Vec<DISPLAYCONFIG_MODE_INFO> = Vec::new();
         let mut displays_names = HashMap::new();
+        let mut sources_modes = HashMap::new();
+        let mut advanced_colors = HashMap::new();
+        let mut scale_steps = HashMap::new();
 
-        let adapter_id = LUID {
-            LowPart: 62504,
-            HighPart: 0,
-        };
+        let adapter_id = ADAPTER_ID;
 
         for (i, display) in displays.iter().enumerate() {
             patharray.push(DISPLAYCONFIG_PATH_INFO {
@@ -57,9 +96,16 @@ impl FuzzedDisplaysSettingsApi for FuzzedWindowsApi {
                     Anonymous: DISPLAYCONFIG_PATH_TARGET_INFO_0 {
                         modeInfoIdx: modeinfoarray.len() as u32,
                     },
+                    outputTechnology: to_output_technology(display.connector),
+                    rotation: DISPLAYCONFIG_ROTATION_IDENTITY,
+                    refreshRate: DISPLAYCONFIG_RATIONAL {
+                        Numerator: display.refresh_rate.numerator,
+                        Denominator: display.refresh_rate.denominator,
+                    },
+                    targetAvailable: true.into(),
                     ..Default::default()
                 },
-                ..Default::default()
+                flags: DISPLAYCONFIG_PATH_ACTIVE,
             });
 
             modeinfoarray.push(DISPLAYCONFIG_MODE_INFO {
@@ -96,15 +142,241 @@ impl FuzzedDisplaysSettingsApi for FuzzedWindowsApi {
                 ),
                 display.name.clone(),
             );
+
+            advanced_colors.insert(display.config_mode_info_id, display.advanced_color());
+            scale_steps.insert(i as u32, MIN_SCALE_STEP);
+
+            sources_modes.insert(
+                i as u32,
+                [(display.resolution, display.refresh_rate)]
+                    .into_iter()
+                    .chain(
+                        display
+                            .modes
+                            .iter()
+                            .map(|mode| (mode.resolution, mode.refresh_rate)),
+                    )
+                    .map(|(resolution, refresh_rate)| {
+                        (
+                            resolution.width,
+                            resolution.height,
+                            refresh_rate.numerator / refresh_rate.denominator,
+                        )
+                    })
+                    .collect(),
+            );
         }
 
         Self {
             patharray,
             modeinfoarray,
             displays_names,
+            sources_modes,
+            advanced_colors,
+            scale_steps,
             behaviour,
         }
     }
+
+    fn to_display_info(display: &FuzzedDisplay) -> DisplayInfo {
+        DisplayInfo {
+            is_primary: display.primary,
+            name: if display.name.is_empty() {
+                String::from(INTERNAL_DISPLAY_NAME)
+            } else {
+                display.name.clone()
+            },
+            id: format!(
+                "{:08X}{:08X}:{}",
+                ADAPTER_ID.HighPart as u32, ADAPTER_ID.LowPart, display.config_mode_info_id
+            ),
+            connector: display.connector,
+            resolution: DisplayResolution {
+                width: display.resolution.width,
+                height: display.resolution.height,
+            },
+            position: DisplayPosition {
+                x: display.position.x,
+                y: display.position.y,
+            },
+            refresh_rate_millihertz: Some(display.refresh_rate.millihertz()),
+            rotation: DisplayRotation::Degrees0,
+            scale_percent: Some(SCALE_PERCENT),
+            advanced_color: Some(display.advanced_color()),
+        }
+    }
+
+    /// Windows rounds the refresh rates of the modes down to whole numbers.
+    fn to_display_modes(display: &FuzzedDisplay) -> Vec<DisplayMode> {
+        [DisplayMode {
+            resolution: DisplayResolution {
+                width: display.resolution.width,
+                height: display.resolution.height,
+            },
+            refresh_rate_millihertz: display.refresh_rate.numerator
+                / display.refresh_rate.denominator
+                * 1000,
+        }]
+        .into_iter()
+        .chain(display.modes.iter().map(|mode| mode.to_display_mode()))
+        .collect()
+    }
+}
+
+impl FuzzedWindowsApi {
+    /// The mode infos of the inactive paths are kept so that their indices stay valid.
+    fn paths(&self, flags: QUERY_DISPLAY_CONFIG_FLAGS) -> Option<Vec<DISPLAYCONFIG_PATH_INFO>> {
+        match flags {
+            QDC_ALL_PATHS => Some(self.patharray.clone()),
+            QDC_ONLY_ACTIVE_PATHS => Some(
+                self.patharray
+                    .iter()
+                    .filter(|path| is_active_path(path))
+                    .copied()
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
+
+    fn source_mode(&mut self, path_index: usize) -> &mut DISPLAYCONFIG_SOURCE_MODE {
+        let source_mode_info_idx =
+            unsafe { self.patharray[path_index].sourceInfo.Anonymous.modeInfoIdx };
+
+        unsafe {
+            &mut self.modeinfoarray[source_mode_info_idx as usize]
+                .Anonymous
+                .sourceMode
+        }
+    }
+
+    /// The sources are named after their index, such as `\\.\DISPLAY1` for the first one.
+    fn source_index(device_name: PCWSTR) -> Option<usize> {
+        let device_name = unsafe { device_name.to_string() }.ok()?;
+        let source_number = device_name
+            .strip_prefix(r"\\.\DISPLAY")?
+            .parse::<usize>()
+            .ok()?;
+
+        source_number.checked_sub(1)
+    }
+
+    /// The newly extended displays are put on the right of the ones already active.
+    fn apply_topology(&mut self, flags: SET_DISPLAY_CONFIG_FLAGS) {
+        let mut right = 0;
+
+        for path_index in 0..self.patharray.len() {
+            if is_active_path(&self.patharray[path_index]) {
+                let source_mode = self.source_mode(path_index);
+
+                right = right.max(source_mode.position.x + source_mode.width as i32);
+            }
+        }
+
+        for path_index in 0..self.patharray.len() {
+            let was_active = is_active_path(&self.patharray[path_index]);
+
+            self.patharray[path_index].flags |= DISPLAYCONFIG_PATH_ACTIVE;
+
+            let source_mode = self.source_mode(path_index);
+
+            if flags.contains(SDC_TOPOLOGY_CLONE) {
+                source_mode.position = POINTL { x: 0, y: 0 };
+            } else if !was_active {
+                source_mode.position = POINTL { x: right, y: 0 };
+                right += source_mode.width as i32;
+            }
+        }
+    }
+
+    /// Windows picks the modes of the supplied paths, laying them out from the origin.
+    fn apply_supplied_paths(&mut self, paths: &[DISPLAYCONFIG_PATH_INFO]) {
+        let mut x = 0;
+
+        for path in paths {
+            let Some(path_index) = self
+                .patharray
+                .iter()
+                .position(|own_path| own_path.targetInfo.id == path.targetInfo.id)
+            else {
+                continue;
+            };
+
+            let source_mode = self.source_mode(path_index);
+
+            source_mode.position = POINTL { x, y: 0 };
+            x += source_mode.width as i32;
+        }
+
+        for own_path in &mut self.patharray {
+            if paths
+                .iter()
+                .any(|path| path.targetInfo.id == own_path.targetInfo.id)
+            {
+                own_path.flags |= DISPLAYCONFIG_PATH_ACTIVE;
+            } else {
+                own_path.flags &= !DISPLAYCONFIG_PATH_ACTIVE;
+            }
+        }
+    }
+
+    /// The supplied paths are the active ones, the other ones being turned off. Their source
+    /// modes are copied into the own ones, so that the indices of the inactive paths stay valid.
+    fn apply_supplied_config(
+        &mut self,
+        paths: &[DISPLAYCONFIG_PATH_INFO],
+        modes: &[DISPLAYCONFIG_MODE_INFO],
+    ) {
+        for path_index in 0..self.patharray.len() {
+            let own_path = self.patharray[path_index];
+
+            match paths
+                .iter()
+                .find(|path| path.targetInfo.id == own_path.targetInfo.id)
+            {
+                Some(path) => {
+                    let source_mode_info_idx = unsafe { path.sourceInfo.Anonymous.modeInfoIdx };
+                    let source_mode =
+                        unsafe { modes[source_mode_info_idx as usize].Anonymous.sourceMode };
+
+                    self.patharray[path_index] = DISPLAYCONFIG_PATH_INFO {
+                        sourceInfo: own_path.sourceInfo,
+                        targetInfo: DISPLAYCONFIG_PATH_TARGET_INFO {
+                            Anonymous: own_path.targetInfo.Anonymous,
+                            ..path.targetInfo
+                        },
+                        flags: path.flags,
+                    };
+                    *self.source_mode(path_index) = source_mode;
+                }
+                None => self.patharray[path_index].flags &= !DISPLAYCONFIG_PATH_ACTIVE,
+            }
+        }
+    }
+
+    /// The LUIDs of the adapters change on reboot, so paths of another boot are rejected.
+    fn is_of_this_boot(paths: &[DISPLAYCONFIG_PATH_INFO]) -> bool {
+        paths.iter().all(|path| {
+            path.sourceInfo.adapterId == ADAPTER_ID && path.targetInfo.adapterId == ADAPTER_ID
+        })
+    }
+}
+
+fn is_active_path(path: &DISPLAYCONFIG_PATH_INFO) -> bool {
+    path.flags & DISPLAYCONFIG_PATH_ACTIVE != 0
+}
+
+fn to_output_technology(connector: DisplayConnector) -> DISPLAYCONFIG_VIDEO_OUTPUT_TECHNOLOGY {
+    match connector {
+        DisplayConnector::Internal => DISPLAYCONFIG_OUTPUT_TECHNOLOGY_INTERNAL,
+        DisplayConnector::Hdmi => DISPLAYCONFIG_OUTPUT_TECHNOLOGY_HDMI,
+        DisplayConnector::DisplayPort => DISPLAYCONFIG_OUTPUT_TECHNOLOGY_DISPLAYPORT_EXTERNAL,
+        DisplayConnector::Dvi => DISPLAYCONFIG_OUTPUT_TECHNOLOGY_DVI,
+        DisplayConnector::Vga => DISPLAYCONFIG_OUTPUT_TECHNOLOGY_HD15,
+        DisplayConnector::Other | DisplayConnector::Unknown => {
+            DISPLAYCONFIG_OUTPUT_TECHNOLOGY_OTHER
+        }
+    }
 }
 
 impl WindowsApi for FuzzedWindowsApi {
@@ -118,12 +390,12 @@ impl WindowsApi for FuzzedWindowsApi {
             return error;
         }
 
-        if !flags.contains(QDC_ONLY_ACTIVE_PATHS) {
+        let Some(paths) = self.paths(flags) else {
             return ERROR_INVALID_PARAMETER;
-        }
+        };
 
         unsafe {
-            *numpatharrayelements = self.patharray.len() as u32;
+            *numpatharrayelements = paths.len() as u32;
             *nummodeinfoarrayelements = self.modeinfoarray.len() as u32;
         }
 
@@ -143,13 +415,17 @@ impl WindowsApi for FuzzedWindowsApi {
             return self.behaviour.query_display_config_errors.remove(0);
         }
 
-        if !flags.contains(QDC_ONLY_ACTIVE_PATHS) || currenttopologyid.is_some() {
+        let Some(paths) = self.paths(flags) else {
+            return ERROR_INVALID_PARAMETER;
+        };
+
+        if currenttopologyid.is_some() {
             return ERROR_INVALID_PARAMETER;
         }
 
         for i in 0..unsafe { *numpatharrayelements } {
             unsafe {
-                *patharray.add(i as usize) = self.patharray[i as usize];
+                *patharray.add(i as usize) = paths[i as usize];
             }
         }
 
@@ -170,6 +446,60 @@ impl WindowsApi for FuzzedWindowsApi {
             return error.0 as i32;
         }
 
+        match unsafe { (*requestpacket).r#type } {
+            DISPLAYCONFIG_DEVICE_INFO_GET_TARGET_NAME => {}
+            DISPLAYCONFIG_DEVICE_INFO_GET_SOURCE_NAME => {
+                let request_packet = requestpacket.cast::<DISPLAYCONFIG_SOURCE_DEVICE_NAME>();
+                let source_id = unsafe { (*request_packet).header.id };
+
+                if !self.sources_modes.contains_key(&source_id) {
+                    return ERROR_INVALID_PARAMETER.0 as i32;
+                }
+
+                let view_gdi_device_name =
+                    encode_utf16::<32>(&format!(r"\\.\DISPLAY{}", source_id + 1));
+
+                unsafe { (*request_packet).viewGdiDeviceName = view_gdi_device_name };
+
+                return ERROR_SUCCESS.0 as i32;
+            }
+            DISPLAYCONFIG_DEVICE_INFO_GET_ADVANCED_COLOR_INFO => {
+                let request_packet = requestpacket.cast::<DISPLAYCONFIG_GET_ADVANCED_COLOR_INFO>();
+                let target_id = unsafe { (*request_packet).header.id };
+
+                let Some(advanced_color) = self.advanced_colors.get(&target_id) else {
+                    return ERROR_INVALID_PARAMETER.0 as i32;
+                };
+
+                unsafe {
+                    (*request_packet).Anonymous.value = u32::from(advanced_color.is_hdr_supported)
+                        | u32::from(advanced_color.is_hdr_enabled) << 1;
+                    (*request_packet).colorEncoding = DISPLAYCONFIG_COLOR_ENCODING_RGB;
+                    (*request_packet).bitsPerColorChannel =
+                        advanced_color.bits_per_color_channel.unwrap_or_default();
+                }
+
+                return ERROR_SUCCESS.0 as i32;
+            }
+            DISPLAYCONFIG_DEVICE_INFO_GET_DPI_SCALE => {
+                let request_packet = requestpacket.cast::<DisplayConfigGetDpiScale>();
+                let source_id = unsafe { (*request_packet).header.id };
+
+                let Some(scale_step) = self.scale_steps.get(&source_id) else {
+                    return ERROR_INVALID_PARAMETER.0 as i32;
+                };
+
+                unsafe {
+                    (*request_packet).min_scale_step = MIN_SCALE_STEP;
+                    (*request_packet).current_scale_step = *scale_step;
+                    (*request_packet).max_scale_step = MAX_SCALE_STEP;
+                }
+
+                return ERROR_SUCCESS.0 as i32;
+            }
+            _ => return ERROR_INVALID_PARAMETER.0 as i32,
+        }
+
         let request_packet = requestpacket.cast::<DISPLAYCONFIG_TARGET_DEVICE_NAME>();
 
         let display_name_option = self.displays_names.get(&(
@@ -189,6 +519,45 @@ impl WindowsApi for FuzzedWindowsApi {
         ERROR_INVALID_PARAMETER.0 as i32
     }
 
+    unsafe fn display_config_set_device_info(
+        &mut self,
+        setpacket: *const DISPLAYCONFIG_DEVICE_INFO_HEADER,
+    ) -> i32 {
+        match unsafe { (*setpacket).r#type } {
+            DISPLAYCONFIG_DEVICE_INFO_SET_ADVANCED_COLOR_STATE => {}
+            DISPLAYCONFIG_DEVICE_INFO_SET_DPI_SCALE => {
+                let set_packet = setpacket.cast::<DisplayConfigSetDpiScale>();
+                let source_id = unsafe { (*set_packet).header.id };
+                let new_scale_step = unsafe { (*set_packet).scale_step };
+
+                return match self.scale_steps.get_mut(&source_id) {
+                    Some(scale_step)
+                        if (MIN_SCALE_STEP..=MAX_SCALE_STEP).contains(&new_scale_step) =>
+                    {
+                        *scale_step = new_scale_step;
+
+                        ERROR_SUCCESS.0 as i32
+                    }
+                    _ => ERROR_INVALID_PARAMETER.0 as i32,
+                };
+            }
+            _ => return ERROR_INVALID_PARAMETER.0 as i32,
+        }
+
+        let set_packet = setpacket.cast::<DISPLAYCONFIG_SET_ADVANCED_COLOR_STATE>();
+        let target_id = unsafe { (*set_packet).header.id };
+        let is_hdr_enabled = unsafe { (*set_packet).Anonymous.value } & 0b1 != 0;
+
+        match self.advanced_colors.get_mut(&target_id) {
+            Some(advanced_color) if advanced_color.is_hdr_supported => {
+                advanced_color.is_hdr_enabled = is_hdr_enabled;
+
+                ERROR_SUCCESS.0 as i32
+            }
+            _ => ERROR_INVALID_PARAMETER.0 as i32,
+        }
+    }
+
     unsafe fn set_display_config(
         &mut self,
         patharray: Option<&[DISPLAYCONFIG_PATH_INFO]>,
@@ -199,24 +568,126 @@ impl WindowsApi for FuzzedWindowsApi {
             return error.0 as i32;
         }
 
-        if !flags.contains(SDC_APPLY)
-            || !flags.contains(SDC_USE_SUPPLIED_DISPLAY_CONFIG)
-            || !flags.contains(SDC_ALLOW_CHANGES)
-            || !flags.contains(SDC_SAVE_TO_DATABASE)
-        {
+        if !flags.contains(SDC_APPLY) {
             return ERROR_INVALID_PARAMETER.0 as i32;
         }
 
-        if let Some(paths) = patharray
-            && let Some(modes) = modeinfoarray
-        {
-            self.patharray = paths.to_vec();
-            self.modeinfoarray = modes.to_vec();
+        let is_topology = flags.contains(SDC_TOPOLOGY_EXTEND) || flags.contains(SDC_TOPOLOGY_CLONE);
+        let is_supplied_config = flags.contains(SDC_USE_SUPPLIED_DISPLAY_CONFIG)
+            && flags.contains(SDC_ALLOW_CHANGES)
+            && flags.contains(SDC_SAVE_TO_DATABASE);
 
-            return ERROR_SUCCESS.0 as i32;
+        match (patharray, modeinfoarray) {
+            (None, None) if is_topology => {
+                if !self.behaviour.set_display_config_is_not_applied {
+                    self.apply_topology(flags);
+                }
+            }
+            (Some(paths), _) if !Self::is_of_this_boot(paths) => {
+                return ERROR_INVALID_PARAMETER.0 as i32;
+            }
+            (Some(paths), None) if is_supplied_config => {
+                if !self.behaviour.set_display_config_is_not_applied {
+                    self.apply_supplied_paths(paths);
+                }
+            }
+            (Some(paths), Some(modes)) if is_supplied_config => {
+                if !self.behaviour.set_display_config_is_not_applied {
+                    self.apply_supplied_config(paths, modes);
+                }
+            }
+            _ => return ERROR_INVALID_PARAMETER.0 as i32,
         }
 
-        ERROR_INVALID_PARAMETER.0 as i32
+        ERROR_SUCCESS.0 as i32
+    }
+
+    unsafe fn enum_display_settings(
+        &self,
+        lpszdevicename: PCWSTR,
+        imodenum: ENUM_DISPLAY_SETTINGS_MODE,
+        lpdevmode: *mut DEVMODEW,
+    ) -> BOOL {
+        let Some(source_index) = Self::source_index(lpszdevicename) else {
+            return false.into();
+        };
+        let Some(source_modes) = self.sources_modes.get(&(source_index as u32)) else {
+            return false.into();
+        };
+
+        let mode = if imodenum == ENUM_CURRENT_SETTINGS {
+            let path = self.patharray[source_index];
+            let source_mode_info_idx = unsafe { path.sourceInfo.Anonymous.modeInfoIdx };
+            let source_mode = unsafe {
+                self.modeinfoarray[source_mode_info_idx as usize]
+                    .Anonymous
+                    .sourceMode
+            };
+
+            (
+                source_mode.width,
+                source_mode.height,
+                path.targetInfo.refreshRate.Numerator / path.targetInfo.refreshRate.Denominator,
+            )
+        } else {
+            let Some(mode) = source_modes.get(imodenum.0 as usize) else {
+                return false.into();
+            };
+
+            *mode
+        };
+
+        unsafe {
+            (*lpdevmode).dmPelsWidth = mode.0;
+            (*lpdevmode).dmPelsHeight = mode.1;
+            (*lpdevmode).dmDisplayFrequency = mode.2;
+        }
+
+        true.into()
+    }
+
+    unsafe fn change_display_settings_ex(
+        &mut self,
+        lpszdevicename: PCWSTR,
+        lpdevmode: Option<*const DEVMODEW>,
+        dwflags: CDS_TYPE,
+    ) -> DISP_CHANGE {
+        let (Some(source_index), Some(devmode)) = (Self::source_index(lpszdevicename), lpdevmode)
+        else {
+            return DISP_CHANGE_BADMODE;
+        };
+
+        let devmode = unsafe { *devmode };
+        let mode = (
+            devmode.dmPelsWidth,
+            devmode.dmPelsHeight,
+            devmode.dmDisplayFrequency,
+        );
+        let is_supported = self
+            .sources_modes
+            .get(&(source_index as u32))
+            .is_some_and(|source_modes| source_modes.contains(&mode));
+
+        if dwflags != CDS_UPDATEREGISTRY
+            || !devmode
+                .dmFields
+                .contains(DM_PELSWIDTH | DM_PELSHEIGHT | DM_DISPLAYFREQUENCY)
+            || !is_supported
+        {
+            return DISP_CHANGE_BADMODE;
+        }
+
+        let source_mode = self.source_mode(source_index);
+
+        source_mode.width = mode.0;
+        source_mode.height = mode.1;
+
+        self.patharray[source_index].targetInfo.refreshRate = DISPLAYCONFIG_RATIONAL {
+            Numerator: mode.2,
+            Denominator: 1,
+        };
+
+        DISP_CHANGE_SUCCESSFUL
     }
 }
 
@@ -0,0 +1,30 @@
+use rand::{
+    distr::{Alphanumeric, SampleString},
+    rngs::StdRng,
+    seq::IndexedRandom,
+};
+
+pub struct InMemoryErrorFuzzer<'a> {
+    rand: &'a mut StdRng,
+}
+
+impl<'a> InMemoryErrorFuzzer<'a> {
+    const FAILURES: [&'static str; 5] = [
+        "The device is not ready",
+        "The device is in use",
+        "Access is denied",
+        "The request is not supported",
+        "A device attached to the system is not functioning",
+    ];
+
+    pub fn new(rand: &'a mut StdRng) -> Self {
+        Self { rand }
+    }
+
+    pub fn generate_one(&mut self) -> String {
+        let failure = Self::FAILURES.choose(self.rand).unwrap();
+        let code = Alphanumeric.sample_string(self.rand, 8);
+
+        format!("{failure} ({code})")
+    }
+}
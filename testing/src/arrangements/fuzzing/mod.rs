@@ -1,9 +1,8 @@
 use rand::{Rng, SeedableRng, rng, rngs::StdRng};
-use windows::Win32::Foundation::WIN32_ERROR;
 
 use crate::arrangements::fuzzing::{
     computer::FuzzedComputer, displays::display_name::DisplayNameFuzzer,
-    speakers::speaker_name::SpeakerNameFuzzer, win_32_error::Win32ErrorFuzzer,
+    speakers::speaker_name::SpeakerNameFuzzer,
 };
 
 use self::computer::ComputerFuzzer;
@@ -12,7 +11,6 @@ pub mod computer;
 pub mod displays;
 pub mod guid;
 pub mod speakers;
-pub mod win_32_error;
 
 pub trait ComputerBuilder<'a> {
     fn build_computer(&'a mut self) -> FuzzedComputer;
@@ -66,8 +64,31 @@ impl Fuzzer {
     pub fn generate_four_speakers_names(&mut self) -> (String, String, String, String) {
         SpeakerNameFuzzer::new(&mut self.rand).generate_four()
     }
+}
+
+cfg_select! {
+    target_os = "windows" => {
+        use windows::Win32::Foundation::WIN32_ERROR;
+
+        use crate::arrangements::fuzzing::win_32_error::Win32ErrorFuzzer;
 
-    pub fn generate_win_32_error(&mut self, forbidden_errors: &[WIN32_ERROR]) -> WIN32_ERROR {
-        Win32ErrorFuzzer::new(&mut self.rand).generate_one(forbidden_errors)
+        pub mod win_32_error;
+
+        impl Fuzzer {
+            pub fn generate_win_32_error(&mut self, forbidden_errors: &[WIN32_ERROR]) -> WIN32_ERROR {
+                Win32ErrorFuzzer::new(&mut self.rand).generate_one(forbidden_errors)
+            }
+        }
+    }
+    _ => {
+        use crate::arrangements::fuzzing::in_memory_error::InMemoryErrorFuzzer;
+
+        pub mod in_memory_error;
+
+        impl Fuzzer {
+            pub fn generate_in_memory_error(&mut self) -> String {
+                InMemoryErrorFuzzer::new(&mut self.rand).generate_one()
+            }
+        }
     }
 }
@@ -0,0 +1,30 @@
+use convertible_couch_lib::speakers_settings::SpeakerFormFactor;
+use rand::{rngs::StdRng, seq::IndexedRandom};
+
+/// The form factors a speaker can be reported with by every backend.
+const FORM_FACTORS: [SpeakerFormFactor; 6] = [
+    SpeakerFormFactor::Speakers,
+    SpeakerFormFactor::Headphones,
+    SpeakerFormFactor::Headset,
+    SpeakerFormFactor::Hdmi,
+    SpeakerFormFactor::Spdif,
+    SpeakerFormFactor::LineOut,
+];
+
+pub struct FormFactorFuzzer<'a> {
+    rand: &'a mut StdRng,
+}
+
+impl<'a> FormFactorFuzzer<'a> {
+    pub fn new(rand: &'a mut StdRng) -> Self {
+        Self { rand }
+    }
+
+    pub fn generate_one(&mut self) -> SpeakerFormFactor {
+        *FORM_FACTORS.choose(self.rand).unwrap()
+    }
+
+    pub fn generate_several(&mut self, count: usize) -> Vec<SpeakerFormFactor> {
+        (0..count).map(|_| self.generate_one()).collect()
+    }
+}
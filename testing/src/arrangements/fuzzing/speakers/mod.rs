@@ -1,29 +1,36 @@
 use std::collections::HashSet;
 
+use convertible_couch_lib::speakers_settings::SpeakerFormFactor;
 use rand::RngExt;
 
 use crate::arrangements::fuzzing::{
     ComputerBuilder,
     computer::{ComputerFuzzer, FuzzedComputer},
     speakers::{
+        form_factor::FormFactorFuzzer,
         settings_api::{
             CurrentFuzzedSpeakersSettingsApi, FuzzedSpeakersSettingsApi,
             behaviour::CurrentFuzzedSpeakersSettingsApiBehaviour,
         },
         speaker_id::SpeakerIdFuzzer,
         speaker_name::SpeakerNameFuzzer,
+        volume::{FuzzedVolume, VolumeFuzzer},
     },
 };
 
+pub mod form_factor;
 pub mod settings_api;
 pub mod speaker_id;
 pub mod speaker_name;
+pub mod volume;
 
 #[derive(Clone)]
 pub struct FuzzedSpeaker {
     pub name: String,
     pub id: String,
     pub is_default: bool,
+    pub form_factor: SpeakerFormFactor,
+    pub volume: FuzzedVolume,
 }
 
 pub struct SpeakersFuzzer<'a> {
@@ -32,6 +39,8 @@ pub struct SpeakersFuzzer<'a> {
     max_count: usize,
     default_speaker_name: Option<String>,
     alternative_names: HashSet<String>,
+    /// The names given to a second speaker, which shares them with an alternative one.
+    shared_names: Vec<String>,
     behaviour: CurrentFuzzedSpeakersSettingsApiBehaviour,
 }
 
@@ -45,6 +54,7 @@ impl<'a> SpeakersFuzzer<'a> {
             max_count: 0,
             default_speaker_name: None,
             alternative_names: HashSet::new(),
+            shared_names: Vec::new(),
             behaviour: CurrentFuzzedSpeakersSettingsApiBehaviour::default(),
         }
     }
@@ -76,6 +86,13 @@ impl<'a> SpeakersFuzzer<'a> {
         self
     }
 
+    pub fn with_two_alternative_ones_named(&mut self, alternative_speaker_name: &str) -> &mut Self {
+        self.with_an_alternative_one_named(alternative_speaker_name);
+        self.shared_names.push(alternative_speaker_name.to_string());
+
+        self
+    }
+
     pub fn build_speakers(&'a mut self) -> &'a mut ComputerFuzzer<'a> {
         let mut names_already_taken = HashSet::new();
 
@@ -93,11 +110,16 @@ impl<'a> SpeakersFuzzer<'a> {
         let names_not_taken = SpeakerNameFuzzer::new(self.computer_fuzzer.rand)
             .generate_several(count - names_already_taken.len(), &names_already_taken);
 
-        let mut names = Vec::with_capacity(count);
+        let mut names = Vec::with_capacity(count + self.shared_names.len());
         names.extend(names_already_taken);
         names.extend(names_not_taken);
+        names.extend(self.shared_names.clone());
+
+        let count = names.len();
 
         let ids = SpeakerIdFuzzer::new(self.computer_fuzzer.rand).generate_several(count);
+        let form_factors = FormFactorFuzzer::new(self.computer_fuzzer.rand).generate_several(count);
+        let volumes = VolumeFuzzer::new(self.computer_fuzzer.rand).generate_several(count);
 
         let default_speaker_index =
             self.default_speaker_name
@@ -114,13 +136,16 @@ impl<'a> SpeakersFuzzer<'a> {
                 name: names[i].clone(),
                 id: ids[i].clone(),
                 is_default: default_speaker_index.is_some_and(|x| x == i),
+                form_factor: form_factors[i],
+                volume: volumes[i],
             })
             .collect::<Vec<FuzzedSpeaker>>();
 
         let fuzzed_speakers_settings_api =
-            CurrentFuzzedSpeakersSettingsApi::new(speakers, self.behaviour.clone());
+            CurrentFuzzedSpeakersSettingsApi::new(speakers.clone(), self.behaviour.clone());
 
         self.computer_fuzzer
+            .set_speakers(speakers)
             .set_speakers_settings_api(fuzzed_speakers_settings_api)
     }
 }
@@ -283,7 +308,55 @@ cfg_select! {
 
                 self
             }
+
+            pub fn for_which_ipolicy_config_vista_set_default_endpoint_is_not_applied(
+                &mut self,
+            ) -> &mut Self {
+                self.behaviour
+                    .ipolicy_config_vista_set_default_endpoint_is_not_applied = true;
+
+                self
+            }
         }
 
     }
+    _ => {
+        pub enum Function {
+            GetSpeakers,
+            SetDefaultSpeaker,
+        }
+
+        impl<'a> SpeakersFuzzer<'a> {
+            pub fn for_which_function_fails_with(
+                &mut self,
+                function: Function,
+                error: String,
+            ) -> &mut Self {
+                match function {
+                    Function::GetSpeakers => self.for_which_get_speakers_fails_with(error),
+                    Function::SetDefaultSpeaker => {
+                        self.for_which_set_default_speaker_fails_with(error)
+                    }
+                }
+            }
+
+            pub fn for_which_get_speakers_fails_with(&mut self, error: String) -> &mut Self {
+                self.behaviour.get_speakers_error = Some(error);
+
+                self
+            }
+
+            pub fn for_which_set_default_speaker_fails_with(&mut self, error: String) -> &mut Self {
+                self.behaviour.set_default_speaker_error = Some(error);
+
+                self
+            }
+
+            pub fn for_which_set_default_speaker_is_not_applied(&mut self) -> &mut Self {
+                self.behaviour.set_default_speaker_is_not_applied = true;
+
+                self
+            }
+        }
+    }
 }
@@ -0,0 +1,11 @@
+use crate::arrangements::fuzzing::speakers::settings_api::behaviour::FuzzedSpeakersSettingsApiBehaviour;
+
+#[derive(Clone, Default)]
+pub struct FuzzedInMemorySpeakersSettingsApiBehaviour {
+    pub get_speakers_error: Option<String>,
+    pub set_default_speaker_error: Option<String>,
+    /// Whether `set_default_speaker` succeeds without changing the default speaker.
+    pub set_default_speaker_is_not_applied: bool,
+}
+
+impl FuzzedSpeakersSettingsApiBehaviour for FuzzedInMemorySpeakersSettingsApiBehaviour {}
@@ -5,4 +5,8 @@ cfg_select! {
         pub mod windows;
         pub use windows::FuzzedWindowsSpeakersSettingsApiBehaviour as CurrentFuzzedSpeakersSettingsApiBehaviour;
     }
+    _ => {
+        pub mod in_memory;
+        pub use in_memory::FuzzedInMemorySpeakersSettingsApiBehaviour as CurrentFuzzedSpeakersSettingsApiBehaviour;
+    }
 }
@@ -15,6 +15,8 @@ pub struct FuzzedWindowsSpeakersSettingsApiBehaviour {
     pub property_store_get_value_error: Option<WIN32_ERROR>,
     pub co_create_ipolicy_config_vista_error: Option<WIN32_ERROR>,
     pub ipolicy_config_vista_set_default_endpoint_error: Option<WIN32_ERROR>,
+    /// Whether `set_default_endpoint` succeeds without changing the default endpoint.
+    pub ipolicy_config_vista_set_default_endpoint_is_not_applied: bool,
 }
 
 impl FuzzedSpeakersSettingsApiBehaviour for FuzzedWindowsSpeakersSettingsApiBehaviour {}
@@ -0,0 +1,99 @@
+use crate::arrangements::fuzzing::speakers::{
+    FuzzedSpeaker,
+    settings_api::{
+        FuzzedSpeakersSettingsApi, behaviour::in_memory::FuzzedInMemorySpeakersSettingsApiBehaviour,
+    },
+};
+use convertible_couch_lib::{
+    application_error::ApplicationError,
+    application_result::ApplicationResult,
+    speakers_settings::{
+        SpeakerInfo, SpeakerState,
+        in_memory::in_memory_api::{InMemoryApi, InMemorySpeaker},
+    },
+};
+
+const CHANNELS: u16 = 2;
+const SAMPLE_FORMAT: &str = "s16le";
+const SAMPLE_RATE_HERTZ: u32 = 48000;
+
+#[derive(Clone, Default)]
+pub struct FuzzedInMemoryApi {
+    speakers: Vec<InMemorySpeaker>,
+    behaviour: FuzzedInMemorySpeakersSettingsApiBehaviour,
+}
+
+impl FuzzedSpeakersSettingsApi for FuzzedInMemoryApi {
+    fn new(
+        speakers: Vec<FuzzedSpeaker>,
+        behaviour: FuzzedInMemorySpeakersSettingsApiBehaviour,
+    ) -> Self {
+        let speakers = speakers
+            .into_iter()
+            .map(|speaker| InMemorySpeaker {
+                id: speaker.id,
+                name: speaker.name,
+                is_default: speaker.is_default,
+                state: SpeakerState::Active,
+                form_factor: speaker.form_factor,
+                channels: CHANNELS,
+                sample_format: String::from(SAMPLE_FORMAT),
+                sample_rate_hertz: SAMPLE_RATE_HERTZ,
+                volume_percent: speaker.volume.percent,
+                is_muted: speaker.volume.is_muted,
+            })
+            .collect();
+
+        Self {
+            speakers,
+            behaviour,
+        }
+    }
+
+    fn to_speaker_info(speaker: &FuzzedSpeaker) -> SpeakerInfo {
+        SpeakerInfo {
+            is_default: speaker.is_default,
+            name: speaker.name.clone(),
+            id: speaker.id.clone(),
+            state: SpeakerState::Active,
+            form_factor: speaker.form_factor,
+            channels: Some(CHANNELS),
+            sample_format: Some(String::from(SAMPLE_FORMAT)),
+            sample_rate_hertz: Some(SAMPLE_RATE_HERTZ),
+            volume_percent: Some(speaker.volume.percent),
+            is_muted: Some(speaker.volume.is_muted),
+        }
+    }
+}
+
+impl InMemoryApi for FuzzedInMemoryApi {
+    fn get_speakers(&self) -> ApplicationResult<Vec<InMemorySpeaker>> {
+        if let Some(error) = &self.behaviour.get_speakers_error {
+            return Err(ApplicationError::Custom(error.clone()));
+        }
+
+        Ok(self.speakers.clone())
+    }
+
+    fn set_default_speaker(&mut self, speaker_id: &str) -> ApplicationResult<()> {
+        if let Some(error) = &self.behaviour.set_default_speaker_error {
+            return Err(ApplicationError::Custom(error.clone()));
+        }
+
+        if !self.speakers.iter().any(|speaker| speaker.id == speaker_id) {
+            return Err(ApplicationError::Custom(String::from(
+                "One or more arguments are not valid",
+            )));
+        }
+
+        if self.behaviour.set_default_speaker_is_not_applied {
+            return Ok(());
+        }
+
+        for speaker in &mut self.speakers {
+            speaker.is_default = speaker.id == speaker_id;
+        }
+
+        Ok(())
+    }
+}
@@ -1,3 +1,8 @@
+use convertible_couch_lib::{
+    backends::speakers_backend::SpeakersBackend,
+    speakers_settings::{SpeakerInfo, SpeakerRole},
+};
+
 use crate::arrangements::fuzzing::speakers::{
     FuzzedSpeaker, settings_api::behaviour::CurrentFuzzedSpeakersSettingsApiBehaviour,
 };
@@ -9,11 +14,24 @@ pub trait FuzzedSpeakersSettingsApi: Default {
         speakers: Vec<FuzzedSpeaker>,
         behaviour: CurrentFuzzedSpeakersSettingsApiBehaviour,
     ) -> Self;
+
+    /// The informations the backend under test reports for the speaker.
+    fn to_speaker_info(speaker: &FuzzedSpeaker) -> SpeakerInfo;
 }
 
 cfg_select! {
     target_os = "windows" => {
         pub mod windows;
         pub use windows::FuzzedWindowsApi as CurrentFuzzedSpeakersSettingsApi;
+        pub const CURRENT_SPEAKERS_BACKEND_UNDER_TEST: SpeakersBackend = SpeakersBackend::Windows;
+        pub const CURRENT_SPEAKER_ROLES_UNDER_TEST: &[SpeakerRole] = &[SpeakerRole::Console];
+        pub use convertible_couch_lib::speakers_settings::windows::windows_sound_settings::WindowsSoundSettings as CurrentSpeakersSettingsUnderTest;
+    }
+    _ => {
+        pub mod in_memory;
+        pub use in_memory::FuzzedInMemoryApi as CurrentFuzzedSpeakersSettingsApi;
+        pub const CURRENT_SPEAKERS_BACKEND_UNDER_TEST: SpeakersBackend = SpeakersBackend::InMemory;
+        pub const CURRENT_SPEAKER_ROLES_UNDER_TEST: &[SpeakerRole] = &[SpeakerRole::All];
+        pub use convertible_couch_lib::speakers_settings::in_memory::in_memory_speakers_settings::InMemorySpeakersSettings as CurrentSpeakersSettingsUnderTest;
     }
 }
@@ -1,13 +1,17 @@
 use windows::Win32::{
     Devices::FunctionDiscovery::PKEY_Device_FriendlyName,
-    Foundation::{E_INVALIDARG, PROPERTYKEY, S_FALSE, S_OK},
-    Media::Audio::{DEVICE_STATE, DEVICE_STATE_ACTIVE, EDataFlow, ERole, eConsole, eRender},
+    Foundation::{E_INVALIDARG, ERROR_NOT_FOUND, PROPERTYKEY, S_FALSE, S_OK},
+    Media::Audio::{
+        DEVICE_STATE, DEVICE_STATE_ACTIVE, DigitalAudioDisplayDevice, EDataFlow, ERole,
+        EndpointFormFactor, Headphones, Headset, LineLevel, PKEY_AudioEndpoint_FormFactor,
+        PKEY_AudioEngine_DeviceFormat, SPDIF, Speakers, UnknownFormFactor, eConsole, eRender,
+    },
     System::Com::{
-        COINIT, COINIT_MULTITHREADED, STGM, STGM_READ,
+        BLOB, COINIT, COINIT_MULTITHREADED, STGM, STGM_READ,
         StructuredStorage::{PROPVARIANT, PROPVARIANT_0, PROPVARIANT_0_0, PROPVARIANT_0_0_0},
     },
 };
-use windows_core::{Error, HRESULT, PCWSTR, PWSTR, Result};
+use windows_core::{BOOL, Error, HRESULT, PCWSTR, PWSTR, Result};
 
 use crate::arrangements::fuzzing::speakers::{
     FuzzedSpeaker,
@@ -15,13 +19,27 @@ use crate::arrangements::fuzzing::speakers::{
         FuzzedSpeakersSettingsApi, behaviour::windows::FuzzedWindowsSpeakersSettingsApiBehaviour,
     },
 };
-use convertible_couch_lib::speakers_settings::windows::windows_api::{
-    IMMDevice, IMMDeviceCollection, IMMDeviceEnumerator, IPolicyConfigVista, IPropertyStore,
-    WindowsApi,
+use convertible_couch_lib::speakers_settings::{
+    SpeakerFormFactor, SpeakerInfo, SpeakerState,
+    windows::windows_api::{
+        IAudioEndpointVolume, IMMDevice, IMMDeviceCollection, IMMDeviceEnumerator,
+        IPolicyConfigVista, IPropertyStore, WindowsApi,
+    },
 };
 
 use std::{cell::RefCell, ffi::c_void, mem::ManuallyDrop, rc::Rc};
 
+const CHANNELS: u16 = 2;
+const SAMPLE_RATE_HERTZ: u32 = 48000;
+const BITS_PER_SAMPLE: u16 = 32;
+/// The `WAVEFORMATEXTENSIBLE` of a 32-bit float stereo stream at 48 kHz, as the audio engine
+/// usually reports it.
+const DEVICE_FORMAT: [u8; 40] = [
+    0xFE, 0xFF, 0x02, 0x00, 0x80, 0xBB, 0x00, 0x00, 0x00, 0xDC, 0x05, 0x00, 0x08, 0x00, 0x20, 0x00,
+    0x16, 0x00, 0x20, 0x00, 0x03, 0x00, 0x00, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00,
+    0x80, 0x00, 0x00, 0xAA, 0x00, 0x38, 0x9B, 0x71,
+];
+
 #[allow(dead_code)]
 #[derive(Clone, Default)]
 pub struct FuzzedWindowsApi {
@@ -41,6 +59,21 @@ impl FuzzedSpeakersSettingsApi for FuzzedWindowsApi {
             com_library_initialized: false,
         }
     }
+
+    fn to_speaker_info(speaker: &FuzzedSpeaker) -> SpeakerInfo {
+        SpeakerInfo {
+            is_default: speaker.is_default,
+            name: speaker.name.clone(),
+            id: speaker.id.clone(),
+            state: SpeakerState::Active,
+            form_factor: speaker.form_factor,
+            channels: Some(CHANNELS),
+            sample_format: Some(format!("float{BITS_PER_SAMPLE}le")),
+            sample_rate_hertz: Some(SAMPLE_RATE_HERTZ),
+            volume_percent: Some(speaker.volume.percent),
+            is_muted: Some(speaker.volume.is_muted),
+        }
+    }
 }
 
 impl WindowsApi for FuzzedWindowsApi {
@@ -129,18 +162,15 @@ impl IMMDeviceEnumerator for FuzzedIMMDeviceEnumerator {
             return Err(error.into());
         }
 
+        // Only the console speakers are fuzzed, so there is no endpoint for the other roles.
         if dataflow != eRender || role != eConsole {
-            let error = Error::new(E_INVALIDARG, "One or more arguments are not valid");
-
-            return Err(error);
+            return Err(ERROR_NOT_FOUND.into());
         }
 
         let default_speaker_option = self.speakers.iter().find(|s| s.is_default);
 
         if default_speaker_option.is_none() {
-            let error = Error::new(E_INVALIDARG, "One or more arguments are not valid");
-
-            return Err(error);
+            return Err(ERROR_NOT_FOUND.into());
         }
 
         let default_speaker = default_speaker_option.unwrap();
@@ -165,7 +195,8 @@ impl IMMDeviceEnumerator for FuzzedIMMDeviceEnumerator {
             return Err(error.into());
         }
 
-        if dataflow != eRender || dwstatemask != DEVICE_STATE_ACTIVE {
+        // Every fuzzed speaker is active.
+        if dataflow != eRender || dwstatemask.0 & DEVICE_STATE_ACTIVE.0 == 0 {
             let error = Error::new(E_INVALIDARG, "One or more arguments are not valid");
 
             return Err(error);
@@ -221,6 +252,33 @@ impl IMMDevice for FuzzedIMMDevice {
 
         Ok(boxed_fuzzed_iproperty_store)
     }
+
+    unsafe fn get_state(&self) -> Result<DEVICE_STATE> {
+        Ok(DEVICE_STATE_ACTIVE)
+    }
+
+    unsafe fn activate_audio_endpoint_volume(&self) -> Result<Box<dyn IAudioEndpointVolume>> {
+        let fuzzed_iaudio_endpoint_volume = FuzzedIAudioEndpointVolume {
+            speaker: self.speaker.clone(),
+        };
+        let boxed_fuzzed_iaudio_endpoint_volume = Box::new(fuzzed_iaudio_endpoint_volume);
+
+        Ok(boxed_fuzzed_iaudio_endpoint_volume)
+    }
+}
+
+pub struct FuzzedIAudioEndpointVolume {
+    speaker: FuzzedSpeaker,
+}
+
+impl IAudioEndpointVolume for FuzzedIAudioEndpointVolume {
+    unsafe fn get_master_volume_level_scalar(&self) -> Result<f32> {
+        Ok(self.speaker.volume.percent as f32 / 100.0)
+    }
+
+    unsafe fn get_mute(&self) -> Result<BOOL> {
+        Ok(self.speaker.volume.is_muted.into())
+    }
 }
 
 pub struct FuzzedIMMDeviceCollection {
@@ -273,6 +331,42 @@ impl IPropertyStore for FuzzedIPropertyStore {
         }
 
         unsafe {
+            if *key == PKEY_AudioEndpoint_FormFactor {
+                let endpoint_form_factor =
+                    from_speaker_form_factor_to_endpoint_form_factor(self.speaker.form_factor);
+                let propvariant = PROPVARIANT {
+                    Anonymous: PROPVARIANT_0 {
+                        Anonymous: ManuallyDrop::<PROPVARIANT_0_0>::new(PROPVARIANT_0_0 {
+                            Anonymous: PROPVARIANT_0_0_0 {
+                                ulVal: endpoint_form_factor.0 as u32,
+                            },
+                            ..Default::default()
+                        }),
+                    },
+                };
+
+                return Ok(propvariant);
+            }
+
+            if *key == PKEY_AudioEngine_DeviceFormat {
+                let leaked = Box::leak(Box::new(DEVICE_FORMAT));
+                let propvariant = PROPVARIANT {
+                    Anonymous: PROPVARIANT_0 {
+                        Anonymous: ManuallyDrop::<PROPVARIANT_0_0>::new(PROPVARIANT_0_0 {
+                            Anonymous: PROPVARIANT_0_0_0 {
+                                blob: BLOB {
+                                    cbSize: DEVICE_FORMAT.len() as u32,
+                                    pBlobData: leaked.as_mut_ptr(),
+                                },
+                            },
+                            ..Default::default()
+                        }),
+                    },
+                };
+
+                return Ok(propvariant);
+            }
+
             if *key != PKEY_Device_FriendlyName {
                 let error = Error::new(E_INVALIDARG, "One or more arguments are not valid");
 
@@ -300,6 +394,22 @@ impl IPropertyStore for FuzzedIPropertyStore {
     }
 }
 
+// The form factors are constants named after the Windows enumerators.
+#[allow(non_upper_case_globals)]
+fn from_speaker_form_factor_to_endpoint_form_factor(
+    form_factor: SpeakerFormFactor,
+) -> EndpointFormFactor {
+    match form_factor {
+        SpeakerFormFactor::Speakers => Speakers,
+        SpeakerFormFactor::Headphones => Headphones,
+        SpeakerFormFactor::Headset => Headset,
+        SpeakerFormFactor::Hdmi => DigitalAudioDisplayDevice,
+        SpeakerFormFactor::Spdif => SPDIF,
+        SpeakerFormFactor::LineOut => LineLevel,
+        SpeakerFormFactor::Other | SpeakerFormFactor::Unknown => UnknownFormFactor,
+    }
+}
+
 pub struct FuzzedIPolicyConfigVista {
     speakers: Rc<RefCell<Vec<FuzzedSpeaker>>>,
     behaviour: Rc<FuzzedWindowsSpeakersSettingsApiBehaviour>,
@@ -321,6 +431,13 @@ impl IPolicyConfigVista for FuzzedIPolicyConfigVista {
 
             let speaker_id = String::from_utf16(device_id.as_wide())?;
 
+            if self
+                .behaviour
+                .ipolicy_config_vista_set_default_endpoint_is_not_applied
+            {
+                return Ok(());
+            }
+
             for speaker in self.speakers.borrow_mut().iter_mut() {
                 if speaker.is_default {
                     speaker.is_default = false;
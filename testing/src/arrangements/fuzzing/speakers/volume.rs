@@ -0,0 +1,29 @@
+use rand::{RngExt, rngs::StdRng};
+
+/// A volume and whether it is muted, as set by the user.
+#[derive(Clone, Copy)]
+pub struct FuzzedVolume {
+    pub percent: u32,
+    pub is_muted: bool,
+}
+
+pub struct VolumeFuzzer<'a> {
+    rand: &'a mut StdRng,
+}
+
+impl<'a> VolumeFuzzer<'a> {
+    pub fn new(rand: &'a mut StdRng) -> Self {
+        Self { rand }
+    }
+
+    pub fn generate_one(&mut self) -> FuzzedVolume {
+        FuzzedVolume {
+            percent: self.rand.random_range(0..=100),
+            is_muted: self.rand.random_bool(0.2),
+        }
+    }
+
+    pub fn generate_several(&mut self, count: usize) -> Vec<FuzzedVolume> {
+        (0..count).map(|_| self.generate_one()).collect()
+    }
+}
@@ -1,22 +1,53 @@
-use convertible_couch_lib::{
-    application_error::ApplicationError, application_result::ApplicationResult,
-};
+use convertible_couch::{application::CommandResult, exit_code::map_to_exit_code};
+use convertible_couch_lib::application_result::ApplicationResult;
 
-use convertible_couch::application::CommandResult;
-use windows::Win32::Foundation::WIN32_ERROR;
-
-pub fn assert_that_result_is_a_win32_error(
-    actual_result: ApplicationResult<CommandResult>,
-    expected_win32_error: WIN32_ERROR,
+pub fn assert_that_exit_code_is(
+    actual_result: &ApplicationResult<CommandResult>,
+    expected_exit_code: u8,
 ) {
-    let expected = expected_win32_error.to_hresult().to_string();
+    let actual_exit_code = actual_result.as_ref().err().map(map_to_exit_code);
 
-    assert!(
-        actual_result.as_ref().is_err_and(|error| match error {
-            ApplicationError::Custom(message) => message.contains(&expected),
-        }),
-        " expected {:?} to be an error message containing {:?}",
+    assert_eq!(
+        actual_exit_code,
+        Some(expected_exit_code),
+        " expected {:?} to exit with the code {:?}",
         actual_result,
-        expected
+        expected_exit_code
     );
 }
+
+cfg_select! {
+    target_os = "windows" => {
+        use convertible_couch_lib::application_error::ApplicationError;
+        use windows::Win32::Foundation::WIN32_ERROR;
+
+        /// The error may have been raised after the displays were changed, in which case it is
+        /// the source of a partial failure or of a rollback.
+        pub fn assert_that_result_is_a_win32_error(
+            actual_result: ApplicationResult<CommandResult>,
+            expected_win32_error: WIN32_ERROR,
+        ) {
+            let expected_code = expected_win32_error.to_hresult().0;
+            let actual_error = match &actual_result {
+                Err(
+                    ApplicationError::PartialFailure { source, .. }
+                    | ApplicationError::RollbackPerformed { source, .. }
+                    | ApplicationError::RollbackFailed { source, .. },
+                ) => Some(source.as_ref()),
+                Err(error) => Some(error),
+                Ok(_) => None,
+            };
+
+            assert!(
+                matches!(
+                    actual_error,
+                    Some(ApplicationError::PlatformCall { code: Some(code), .. }) if *code == expected_code
+                ),
+                " expected {:?} to be a platform call error with the code {:?}",
+                actual_result,
+                expected_code
+            );
+        }
+    }
+    _ => {}
+}